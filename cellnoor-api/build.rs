@@ -0,0 +1,35 @@
+use std::{env, fmt::Write, fs, path::Path};
+
+// Every `index-sets/<version>/<file>.json` is compiled into the binary, so that
+// initial data can refer to it as `bundle:<version>/<file>.json` without
+// network access
+fn main() {
+    let bundle_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("index-sets");
+    println!("cargo::rerun-if-changed={}", bundle_dir.display());
+
+    let mut files = Vec::new();
+    for version in fs::read_dir(&bundle_dir).into_iter().flatten() {
+        let version = version.unwrap().path();
+        if !version.is_dir() {
+            continue;
+        }
+
+        for file in fs::read_dir(&version).unwrap() {
+            let file = file.unwrap().path();
+            if file.extension().is_some_and(|e| e == "json") {
+                let name = file.strip_prefix(&bundle_dir).unwrap().to_str().unwrap();
+                files.push((name.replace('\\', "/"), file.display().to_string()));
+            }
+        }
+    }
+    files.sort();
+
+    let mut generated = String::from("pub(super) static BUNDLE: &[(&str, &[u8])] = &[\n");
+    for (name, path) in files {
+        writeln!(generated, "    ({name:?}, include_bytes!({path:?})),").unwrap();
+    }
+    generated.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("index_set_bundle.rs");
+    fs::write(out_path, generated).unwrap();
+}
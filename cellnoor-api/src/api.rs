@@ -1,28 +1,49 @@
+use std::future::IntoFuture;
+
 use anyhow::Context;
-use axum::{Extension, Router, routing::get};
-use camino::Utf8Path;
+use axum::{Extension, Router, middleware};
 use serde_qs::axum::QsQueryConfig;
 use tokio::net::TcpListener;
 use zeroize::Zeroize;
 
-use crate::{config::Config, state::AppState};
+use crate::{
+    api::openapi::ApiRouter,
+    change_feed::ChangeFeed,
+    config::{Config, UploadLimits},
+    metrics, setup,
+    state::{self, AppState},
+    telemetry, webhooks,
+};
 
 mod error;
+mod export;
 mod extract;
+mod health;
+mod openapi;
+mod rate_limit;
+mod read_options;
+mod request_id;
 mod routes;
+mod timeout;
 
 pub use error::{Error, ErrorResponse};
+#[cfg(test)]
+pub(crate) use extract::auth::API_KEY_HEADER;
+pub(crate) use extract::auth::generate_api_key;
 
 #[cfg(test)]
 pub async fn serve_integration_test(config: Config) -> anyhow::Result<()> {
     serve_inner(config).await
 }
 
-pub async fn serve(config: Config) -> anyhow::Result<()> {
+pub async fn serve(config: Config, setup: bool) -> anyhow::Result<()> {
     #[cfg(feature = "dummy-data")]
     use crate::test_state::database;
 
-    initialize_logging(config.log_dir());
+    if setup {
+        setup::run_all(&config).await?;
+    }
+
     #[cfg(feature = "dummy-data")]
     {
         // This populates the database with dummy-data
@@ -32,12 +53,36 @@ pub async fn serve(config: Config) -> anyhow::Result<()> {
 }
 
 async fn serve_inner(mut config: Config) -> anyhow::Result<()> {
-    let app_state = AppState::initialize(&config)
-        .await
-        .context("failed to initialize app state")?;
+    telemetry::record_queries();
+
+    let app_state = AppState::initialize(&config).context("failed to initialize app state")?;
     tracing::info!("initialized app state");
 
-    let app = app(app_state.clone());
+    let metrics_handle =
+        metrics::install_recorder().context("failed to install metrics recorder")?;
+    let metrics_app = metrics_routes()
+        .into_router()
+        .layer(Extension(metrics_handle))
+        .with_state(app_state.clone());
+
+    let change_feed =
+        ChangeFeed::start(state::app_db_url(&config)).context("failed to start change feed")?;
+
+    webhooks::start(app_state.clone()).context("failed to start webhook delivery")?;
+
+    let mut app = app(app_state.clone(), change_feed.clone(), &config);
+
+    let metrics_listener = if let Some(metrics_addr) = config.metrics_address() {
+        let listener = TcpListener::bind(&metrics_addr)
+            .await
+            .context(format!("failed to listen on {metrics_addr}"))?;
+        tracing::info!("cellnoor metrics listening on {}", listener.local_addr()?);
+
+        Some(listener)
+    } else {
+        app = app.merge(metrics_app.clone());
+        None
+    };
 
     let app_addr = config.address();
     let listener = TcpListener::bind(&app_addr)
@@ -47,48 +92,110 @@ async fn serve_inner(mut config: Config) -> anyhow::Result<()> {
 
     config.zeroize();
 
-    axum::serve(listener, app)
-        .await
-        .context("failed to serve app")?;
+    // Event streams never finish on their own, so they have to be ended for the
+    // server to finish serving in-flight requests
+    let app_server = axum::serve(listener, app)
+        .with_graceful_shutdown(async move {
+            shutdown_signal().await;
+            change_feed.close();
+        })
+        .into_future();
+    match metrics_listener {
+        Some(metrics_listener) => {
+            tokio::try_join!(
+                app_server,
+                axum::serve(metrics_listener, metrics_app)
+                    .with_graceful_shutdown(shutdown_signal())
+                    .into_future()
+            )
+            .context("failed to serve app")?;
+        }
+        None => app_server.await.context("failed to serve app")?,
+    }
+
+    tracing::info!("finished serving in-flight requests");
 
     Ok(())
 }
 
-fn initialize_logging(log_dir: Option<&Utf8Path>) {
-    use tracing::Level;
-    use tracing_subscriber::{filter::Targets, prelude::*};
-
-    let log_layer = tracing_subscriber::fmt::layer();
-
-    match log_dir {
-        None => {
-            let dev_test_log_filter = Targets::new().with_target("cellnoor", Level::DEBUG);
-            let log_layer = log_layer.pretty().with_filter(dev_test_log_filter);
-
-            tracing_subscriber::registry().with(log_layer).init();
+/// Resolves on Ctrl+C or, on Unix, SIGTERM, at which point the server stops
+/// accepting connections and waits for in-flight requests to finish
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(err) = tokio::signal::ctrl_c().await {
+            tracing::error!("failed to listen for Ctrl+C: {err}");
+            std::future::pending::<()>().await;
         }
-        Some(path) => {
-            let log_writer = tracing_appender::rolling::daily(path, "cellnoor.log");
-            let prod_log_filter = Targets::new().with_target("cellnoor", Level::INFO);
-            let log_layer = log_layer
-                .json()
-                .with_writer(log_writer)
-                .with_filter(prod_log_filter);
-
-            tracing_subscriber::registry().with(log_layer).init();
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                sigterm.recv().await;
+            }
+            Err(err) => {
+                tracing::error!("failed to listen for SIGTERM: {err}");
+                std::future::pending::<()>().await;
+            }
         }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {},
+        () = terminate => {},
     }
+
+    tracing::info!("received shutdown signal");
+}
+
+/// Every route served under `/api`, along with the documentation of those
+/// served at the root
+fn api_routes(upload_limits: UploadLimits) -> ApiRouter {
+    routes::router(upload_limits)
+        .nest("/health", health::router())
+        .get("/openapi.json", openapi::serve_document)
+        .document_root(metrics_routes())
+}
+
+// These are served alongside the app unless a separate metrics address is
+// configured
+fn metrics_routes() -> ApiRouter {
+    ApiRouter::new().get("/metrics", metrics::render)
 }
 
-fn app(app_state: AppState) -> Router {
+pub(crate) fn app(app_state: AppState, change_feed: ChangeFeed, config: &Config) -> Router {
     // The browser form-encodes everything so we have to enable the less-readable
     // form-encoding
     let query_string_config =
         QsQueryConfig::new().config(serde_qs::Config::new().use_form_encoding(true));
-    let api_router = routes::router()
-        .route("/health", get(async || "OK"))
+    let routes = api_routes(config.upload_limits());
+    let openapi = openapi::Document::new(routes.openapi());
+    let rate_limiter =
+        rate_limit::RateLimiter::new(config.read_rate_limit(), config.write_rate_limit());
+
+    let api_router = routes
+        .into_router()
+        .route_layer(middleware::from_fn(metrics::track_requests))
+        .route_layer(middleware::from_fn_with_state(
+            timeout::Timeouts {
+                request: config.request_timeout(),
+                upload: config.upload_timeout(),
+            },
+            timeout::enforce,
+        ))
         .layer(Extension(query_string_config))
+        .layer(Extension(rate_limiter))
+        .layer(Extension(change_feed))
+        .layer(Extension(openapi))
         .with_state(app_state);
 
-    Router::new().nest("/api", api_router)
+    Router::new()
+        .nest("/api", api_router)
+        .layer(middleware::from_fn(request_id::propagate))
 }
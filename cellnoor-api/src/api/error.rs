@@ -1,18 +1,25 @@
+use std::time::Duration;
+
 use axum::{
     Json,
     extract::{
         multipart::MultipartError,
         rejection::{JsonRejection, PathRejection},
     },
-    http::StatusCode,
+    http::{StatusCode, header::RETRY_AFTER},
     response::IntoResponse,
 };
 
-use crate::{api::extract::auth, db, validate};
+use crate::{
+    api::{extract::auth, request_id},
+    db, validate,
+};
 
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "ApiError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "ApiError")]
 #[serde(rename_all = "snake_case", tag = "type", content = "info")]
 #[error(transparent)]
 pub enum Error {
@@ -23,6 +30,10 @@ pub enum Error {
     MalformedRequest {
         message: String,
     },
+    #[error("too many requests, retry after {retry_after_secs} seconds")]
+    RateLimited {
+        retry_after_secs: u32,
+    },
     #[error("something went wrong")]
     Other,
 }
@@ -44,6 +55,8 @@ impl From<csv::Error> for Error {
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "ApiErrorResponse"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "ApiErrorResponse")]
 #[error("{self:?}")]
 pub struct ErrorResponse {
     pub status: u16,
@@ -51,6 +64,26 @@ pub struct ErrorResponse {
     pub public_error: Error,
     #[serde(skip)]
     pub internal_error: Option<Error>,
+    /// Filled in from the request's `X-Request-Id` when the response is sent,
+    /// so that users can quote it to support
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "typescript", ts(optional))]
+    pub request_id: Option<String>,
+}
+
+impl ErrorResponse {
+    pub(super) fn rate_limited(retry_after: Duration) -> Self {
+        // `Retry-After` is in whole seconds, so round up
+        let retry_after_secs = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+        let retry_after_secs = u32::try_from(retry_after_secs).unwrap_or(u32::MAX);
+
+        Self {
+            status: StatusCode::TOO_MANY_REQUESTS.as_u16(),
+            public_error: Error::RateLimited { retry_after_secs },
+            internal_error: None,
+            request_id: None,
+        }
+    }
 }
 
 impl From<JsonRejection> for ErrorResponse {
@@ -61,6 +94,7 @@ impl From<JsonRejection> for ErrorResponse {
                 message: err.body_text(),
             },
             internal_error: None,
+            request_id: None,
         }
     }
 }
@@ -73,6 +107,7 @@ impl From<PathRejection> for ErrorResponse {
                 message: err.body_text(),
             },
             internal_error: None,
+            request_id: None,
         }
     }
 }
@@ -85,6 +120,7 @@ impl From<MultipartError> for ErrorResponse {
                 message: err.body_text(),
             },
             internal_error: None,
+            request_id: None,
         }
     }
 }
@@ -97,6 +133,7 @@ impl From<serde_qs::axum::QsQueryRejection> for ErrorResponse {
                 message: err.to_string(),
             },
             internal_error: None,
+            request_id: None,
         }
     }
 }
@@ -107,6 +144,7 @@ impl From<deadpool_diesel::InteractError> for ErrorResponse {
             status: StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
             public_error: Error::from(err),
             internal_error: None,
+            request_id: None,
         }
     }
 }
@@ -119,11 +157,13 @@ impl From<auth::Error> for ErrorResponse {
                 status: StatusCode::UNAUTHORIZED.as_u16(),
                 public_error: err.into(),
                 internal_error: None,
+                request_id: None,
             },
             Database(e) => Self {
                 status: StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
                 public_error: Error::Other,
                 internal_error: Some(e.into()),
+                request_id: None,
             },
         }
     }
@@ -142,6 +182,7 @@ impl From<db::Error> for ErrorResponse {
                         status: StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
                         public_error: Error::Other,
                         internal_error: Some(err.into()),
+                        request_id: None,
                     }
                 };
             }
@@ -151,6 +192,7 @@ impl From<db::Error> for ErrorResponse {
             status: status.as_u16(),
             public_error: Error::Database(err),
             internal_error: None,
+            request_id: None,
         }
     }
 }
@@ -163,14 +205,30 @@ impl From<validate::Error> for ErrorResponse {
                 status: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
                 public_error: err.into(),
                 internal_error: None,
+                request_id: None,
             },
         }
     }
 }
 
 impl IntoResponse for ErrorResponse {
-    fn into_response(self) -> axum::response::Response {
+    fn into_response(mut self) -> axum::response::Response {
+        self.request_id = self.request_id.or_else(request_id::current);
         tracing::error!("{self}");
-        (StatusCode::from_u16(self.status).unwrap(), Json(self)).into_response()
+
+        let retry_after = match &self.public_error {
+            Error::RateLimited { retry_after_secs } => Some(retry_after_secs.to_string()),
+            _ => None,
+        };
+
+        let mut response = (StatusCode::from_u16(self.status).unwrap(), Json(self)).into_response();
+        if let Some(retry_after) = retry_after {
+            // A number is always a valid header value
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, retry_after.parse().unwrap());
+        }
+
+        response
     }
 }
@@ -0,0 +1,349 @@
+use std::{convert::Infallible, fmt::Debug};
+
+use axum::{
+    body::Body,
+    extract::OptionalFromRequestParts,
+    http::{StatusCode, header, request::Parts},
+    response::{IntoResponse, Response},
+};
+use cellnoor_models::read_options::ReadOptions;
+use futures_util::{StreamExt, stream};
+use serde::Serialize;
+use serde_json::Value;
+use tokio::sync::mpsc;
+
+use crate::{
+    api::{self, ErrorResponse, extract::auth::AuthenticatedUser, read_options::retain_fields},
+    db,
+    state::AppState,
+};
+
+// Rows are serialized on the database thread and then buffered here until the
+// client reads them
+const CHANNEL_CAPACITY: usize = 256;
+
+/// A non-JSON format requested in the `Accept` header of a list request
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Tsv,
+    Ndjson,
+}
+
+impl ExportFormat {
+    pub(super) const ALL: [Self; 3] = [Self::Csv, Self::Tsv, Self::Ndjson];
+
+    pub(super) fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Tsv => "text/tab-separated-values",
+            Self::Ndjson => "application/x-ndjson",
+        }
+    }
+}
+
+impl<S: Send + Sync> OptionalFromRequestParts<S> for ExportFormat {
+    type Rejection = Infallible;
+
+    // The first supported media type wins, so `application/json, text/csv` is
+    // still JSON
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Option<Self>, Self::Rejection> {
+        let Some(accept) = parts
+            .headers
+            .get(header::ACCEPT)
+            .and_then(|h| h.to_str().ok())
+        else {
+            return Ok(None);
+        };
+
+        for media_range in accept.split(',') {
+            let media_type = media_range.split(';').next().unwrap_or_default().trim();
+
+            match media_type {
+                "application/json" | "*/*" => return Ok(None),
+                "text/csv" => return Ok(Some(Self::Csv)),
+                "text/tab-separated-values" => return Ok(Some(Self::Tsv)),
+                "application/x-ndjson" => return Ok(Some(Self::Ndjson)),
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Turns rows into chunks of the response body, trimmed to `fields` if there
+/// are any. For CSV and TSV, each top-level field is a column, and nested
+/// objects and arrays are written as JSON. Every row of a type has the same
+/// top-level fields, unlike nested objects such as `additional_data`, so the
+/// columns can be taken from the first row.
+struct RowWriter {
+    format: ExportFormat,
+    fields: Vec<String>,
+    columns: Option<Vec<String>>,
+    csv: csv::WriterBuilder,
+}
+
+impl RowWriter {
+    fn new(format: ExportFormat, fields: Vec<String>) -> Self {
+        let mut csv = csv::WriterBuilder::new();
+        if format == ExportFormat::Tsv {
+            csv.delimiter(b'\t');
+        }
+
+        Self {
+            format,
+            fields,
+            columns: None,
+            csv,
+        }
+    }
+
+    fn write_row(&mut self, row: &impl Serialize) -> std::io::Result<Vec<u8>> {
+        let mut row = serde_json::to_value(row)?;
+        if let Value::Object(object) = &mut row {
+            retain_fields(object, &self.fields);
+        }
+
+        if self.format == ExportFormat::Ndjson {
+            let mut line = serde_json::to_vec(&row)?;
+            line.push(b'\n');
+
+            return Ok(line);
+        }
+
+        let Value::Object(mut object) = row else {
+            return Err(std::io::Error::other("only objects can be written as rows"));
+        };
+
+        let mut writer = self.csv.from_writer(Vec::new());
+
+        if self.columns.is_none() {
+            let columns: Vec<_> = object.keys().cloned().collect();
+            writer.write_record(&columns)?;
+            self.columns = Some(columns);
+        }
+        let columns = self.columns.as_deref().unwrap_or_default();
+
+        if let Some(column) = object.keys().find(|key| !columns.contains(key)) {
+            return Err(std::io::Error::other(format!(
+                "row has a field that isn't in the header: {column}"
+            )));
+        }
+
+        writer.write_record(
+            columns
+                .iter()
+                .map(|column| object.remove(column).map(cell).unwrap_or_default()),
+        )?;
+
+        writer.into_inner().map_err(csv::IntoInnerError::into_error)
+    }
+}
+
+fn cell(value: Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s,
+        value => value.to_string(),
+    }
+}
+
+fn internal_error(err: impl std::fmt::Display) -> ErrorResponse {
+    tracing::error!("failed to export rows: {err}");
+
+    ErrorResponse {
+        status: StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
+        public_error: api::Error::Other,
+        internal_error: None,
+        request_id: None,
+    }
+}
+
+/// Stream the rows of `request` in `format`. The database is read row by row,
+/// so this doesn't buffer the whole result set in memory
+pub(super) async fn stream_rows<Request, Include>(
+    state: AppState,
+    user: AuthenticatedUser,
+    request: Request,
+    format: ExportFormat,
+    ReadOptions { fields, include }: ReadOptions<Include>,
+) -> Result<Response, ErrorResponse>
+where
+    Request: Debug + db::Export + Send + 'static,
+    Request::Row: Serialize,
+{
+    tracing::info!("{request:?}");
+
+    // Embedding related objects needs every item at once, which defeats the
+    // point of streaming
+    if !include.is_empty() {
+        return Err(ErrorResponse {
+            status: StatusCode::BAD_REQUEST.as_u16(),
+            public_error: api::Error::MalformedRequest {
+                message: "`include` is not supported for exports".to_owned(),
+            },
+            internal_error: None,
+            request_id: None,
+        });
+    }
+
+    let db_conn = state.db_conn().await?;
+    let (sender, mut receiver) = mpsc::channel::<Result<Vec<u8>, ErrorResponse>>(CHANNEL_CAPACITY);
+
+    let span = db::operation_span::<Request>();
+
+    tokio::spawn(async move {
+        let row_sender = sender.clone();
+
+        let result = db_conn
+            .interact(move |db_conn| {
+                let _span = span.entered();
+                let mut writer = RowWriter::new(format, fields);
+
+                request.export_as_user(user.id(), db_conn, &mut |row| {
+                    let chunk = writer.write_row(&row).map_err(internal_error);
+                    let is_ok = chunk.is_ok();
+
+                    // If the client has gone away, there's no point in reading
+                    // more rows
+                    row_sender.blocking_send(chunk).is_ok() && is_ok
+                })
+            })
+            .await;
+
+        let err = match result {
+            Ok(Ok(())) => return,
+            Ok(Err(err)) => ErrorResponse::from(err),
+            Err(err) => ErrorResponse::from(err),
+        };
+
+        sender.send(Err(err)).await.ok();
+    });
+
+    // Errors that happen before the first row (e.g. permissions) can still be
+    // returned as a normal error response
+    let first = receiver.recv().await.transpose()?;
+    let rest = stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|chunk| (chunk, receiver))
+    });
+    let body = Body::from_stream(stream::iter(first.map(Ok)).chain(rest));
+
+    Ok(([(header::CONTENT_TYPE, format.content_type())], body).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode, header},
+    };
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::{ExportFormat, RowWriter};
+    use crate::{
+        test_app::{TestApp, app},
+        test_state::{Database, database},
+    };
+
+    #[rstest]
+    #[case(
+        ExportFormat::Csv,
+        "id,inner\n1,\"{\"\"name\"\":\"\"a, b\"\"}\"\n2,\"{\"\"name\"\":null,\"\"tags\"\":[]}\"\n"
+    )]
+    #[case(
+        ExportFormat::Tsv,
+        "id\tinner\n1\t\"{\"\"name\"\":\"\"a, \
+         b\"\"}\"\n2\t\"{\"\"name\"\":null,\"\"tags\"\":[]}\"\n"
+    )]
+    fn nested_objects_are_written_as_json(#[case] format: ExportFormat, #[case] expected: &str) {
+        // The second row's `inner` has a key that the first row's doesn't,
+        // which would be lost if nested objects were split into columns
+        let rows = [
+            json!({"id": 1, "inner": {"name": "a, b"}}),
+            json!({"id": 2, "inner": {"name": null, "tags": []}}),
+        ];
+
+        let mut writer = RowWriter::new(format, Vec::new());
+        let output: Vec<u8> = rows
+            .iter()
+            .flat_map(|row| writer.write_row(row).unwrap())
+            .collect();
+
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn rows_with_new_fields_are_rejected() {
+        let mut writer = RowWriter::new(ExportFormat::Csv, Vec::new());
+        writer.write_row(&json!({"id": 1})).unwrap();
+
+        assert!(writer.write_row(&json!({"id": 2, "name": "a"})).is_err());
+    }
+
+    #[rstest]
+    fn rows_are_trimmed_to_fields() {
+        let rows = [
+            json!({"id": 1, "name": "a", "lab": {"name": "b"}}),
+            json!({"id": 2, "name": "c", "lab": {"name": "d"}}),
+        ];
+
+        let mut writer = RowWriter::new(ExportFormat::Csv, vec!["name".to_owned()]);
+        let output: Vec<u8> = rows
+            .iter()
+            .flat_map(|row| writer.write_row(row).unwrap())
+            .collect();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "id,name\n1,a\n2,c\n");
+    }
+
+    async fn export(app: &TestApp, uri: &str) -> (StatusCode, String) {
+        let request = Request::get(uri)
+            .header(header::ACCEPT, "text/csv")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.send(request).await;
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn export_ignores_paging(#[future] app: TestApp, #[future] database: &'static Database) {
+        let (status, body) = export(&app, "/api/specimens?limit=2&offset=1&fields=name").await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+
+        let mut lines = body.lines();
+        assert_eq!(lines.next(), Some("id,name"));
+        assert_eq!(lines.count(), database.specimens.len());
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn unpaged_list_can_be_exported(#[future] app: TestApp) {
+        let (status, body) = export(&app, "/api/multiplexing-tags").await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+
+        let mut lines = body.lines();
+        let mut columns: Vec<_> = lines.next().unwrap().split(',').collect();
+        columns.sort_unstable();
+        assert_eq!(columns, ["id", "tag_id", "type_"]);
+        assert!(lines.next().is_some());
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn export_rejects_include(#[future] app: TestApp) {
+        let (status, body) = export(&app, "/api/specimens?include=lab").await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{body}");
+    }
+}
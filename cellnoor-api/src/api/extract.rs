@@ -1,5 +1,7 @@
 pub mod auth;
+mod last_event_id;
 pub mod query;
 mod valid_json;
 
+pub use last_event_id::{LAST_EVENT_ID_HEADER, LastEventId};
 pub use valid_json::{ValidJson, ValidPathJson};
@@ -2,5 +2,6 @@ mod api_key;
 mod error;
 mod user;
 
+pub(crate) use api_key::generate_api_key;
 pub use error::Error;
-pub use user::AuthenticatedUser;
+pub use user::{API_KEY_HEADER, AuthenticatedUser};
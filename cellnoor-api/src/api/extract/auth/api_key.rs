@@ -1,4 +1,19 @@
-use argon2::{Argon2, PasswordHash, PasswordVerifier};
+use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier, password_hash::SaltString};
+
+pub(crate) const API_KEY_LENGTH: usize = 32;
+
+/// A random API key and its hash, which is what gets stored
+pub(crate) fn generate_api_key() -> ([u8; API_KEY_LENGTH], String) {
+    let api_key: [u8; API_KEY_LENGTH] = rand::random();
+    let salt = SaltString::encode_b64(&rand::random::<[u8; 16]>())
+        .expect("16 bytes should be a valid salt");
+    let hash = Argon2::default()
+        .hash_password(&api_key, &salt)
+        .expect("default argon2 parameters should hash any key")
+        .to_string();
+
+    (api_key, hash)
+}
 
 pub(super) trait AsApiKey {
     fn prefix(&self, prefix_length: usize) -> &[u8];
@@ -3,6 +3,8 @@ use crate::db;
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "AuthError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "AuthError")]
 #[serde(rename_all = "snake_case", tag = "type", content = "info")]
 #[error(transparent)]
 pub enum Error {
@@ -1,4 +1,6 @@
-use axum::extract::FromRequestParts;
+use std::time::Duration;
+
+use axum::{extract::FromRequestParts, http::Method};
 use cellnoor_schema::api_keys;
 use diesel::{PgConnection, prelude::*};
 use uuid::Uuid;
@@ -6,8 +8,13 @@ use uuid::Uuid;
 use crate::{
     api::{
         self,
-        extract::auth::{self, api_key::AsApiKey},
+        extract::auth::{
+            self,
+            api_key::{API_KEY_LENGTH, AsApiKey},
+        },
+        rate_limit::{RateLimiter, Requester},
     },
+    db,
     state::AppState,
 };
 
@@ -48,7 +55,7 @@ impl AuthenticatedUser {
     }
 }
 
-const API_KEY_HEADER: &str = "X-API-Key";
+pub const API_KEY_HEADER: &str = "X-API-Key";
 
 impl FromRequestParts<AppState> for AuthenticatedUser {
     type Rejection = api::ErrorResponse;
@@ -56,6 +63,26 @@ impl FromRequestParts<AppState> for AuthenticatedUser {
     async fn from_request_parts(
         parts: &mut axum::http::request::Parts,
         app_state: &AppState,
+    ) -> Result<Self, api::ErrorResponse> {
+        let rate_limiter = parts.extensions.get::<RateLimiter>().cloned();
+
+        let user = Self::authenticate(parts, app_state, rate_limiter.as_ref()).await?;
+        limit(
+            rate_limiter.as_ref(),
+            &Requester::User(user.id()),
+            &parts.method,
+        )
+        .map_err(api::ErrorResponse::rate_limited)?;
+
+        Ok(user)
+    }
+}
+
+impl AuthenticatedUser {
+    async fn authenticate(
+        parts: &mut axum::http::request::Parts,
+        app_state: &AppState,
+        rate_limiter: Option<&RateLimiter>,
     ) -> Result<Self, api::ErrorResponse> {
         let api_key_prefix_length = match app_state {
             AppState::Production {
@@ -76,16 +103,47 @@ impl FromRequestParts<AppState> for AuthenticatedUser {
             return Err(auth::Error::no_api_key())?;
         };
 
-        let mut decoded: [u8; 32] = [0; 32];
+        let mut decoded = [0; API_KEY_LENGTH];
         base16ct::lower::decode(api_key, &mut decoded)
             .map_err(|_| auth::Error::invalid_api_key())?;
 
         let db_conn = app_state.db_conn().await?;
+        let span = db::operation_span::<Self>();
 
-        Ok(db_conn
+        let result = db_conn
             .interact(move |db_conn| {
+                let _span = span.entered();
                 AuthenticatedUser::fetch_by_api_key(&decoded, api_key_prefix_length, db_conn)
             })
-            .await??)
+            .await?;
+
+        // Only failures are charged to the prefix, because anyone who knows a
+        // prefix could otherwise use up its owner's requests. The prefix is
+        // looked up in plain text, so it's no secret.
+        if let Err(auth::Error::Unauthorized { .. }) = &result {
+            let requester = Requester::ApiKeyPrefix(base16ct::lower::encode_string(
+                decoded.prefix(api_key_prefix_length),
+            ));
+            limit(rate_limiter, &requester, &parts.method)
+                .map_err(api::ErrorResponse::rate_limited)?;
+        }
+
+        Ok(result?)
     }
 }
+
+fn limit(
+    rate_limiter: Option<&RateLimiter>,
+    requester: &Requester,
+    method: &Method,
+) -> Result<(), Duration> {
+    let Some(rate_limiter) = rate_limiter else {
+        return Ok(());
+    };
+
+    rate_limiter
+        .check(requester, method)
+        .inspect_err(|retry_after| {
+            tracing::warn!(?requester, "rate limited for {retry_after:?}");
+        })
+}
@@ -0,0 +1,40 @@
+use axum::{
+    extract::OptionalFromRequestParts,
+    http::{StatusCode, request::Parts},
+};
+
+use crate::{
+    api::{Error, ErrorResponse},
+    change_feed::EventCursor,
+};
+
+pub const LAST_EVENT_ID_HEADER: &str = "Last-Event-ID";
+
+/// The ID of the last event a client received, which browsers send when they
+/// reconnect to an event stream
+pub struct LastEventId(pub EventCursor);
+
+impl<S: Send + Sync> OptionalFromRequestParts<S> for LastEventId {
+    type Rejection = ErrorResponse;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Option<Self>, Self::Rejection> {
+        let Some(header) = parts.headers.get(LAST_EVENT_ID_HEADER) else {
+            return Ok(None);
+        };
+
+        let cursor = header
+            .to_str()
+            .ok()
+            .and_then(|cursor| cursor.parse().ok())
+            .ok_or_else(|| ErrorResponse {
+                status: StatusCode::BAD_REQUEST.as_u16(),
+                public_error: Error::MalformedRequest {
+                    message: format!("{LAST_EVENT_ID_HEADER} must be the ID of an event"),
+                },
+                internal_error: None,
+                request_id: None,
+            })?;
+
+        Ok(Some(Self(cursor)))
+    }
+}
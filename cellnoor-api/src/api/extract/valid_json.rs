@@ -1,10 +1,16 @@
+use std::time::Instant;
+
 use axum::{
     Json,
     extract::{FromRequest, FromRequestParts, Request},
 };
 use serde::{Serialize, de::DeserializeOwned};
 
-use crate::{api, state::AppState, validate::Validate};
+use crate::{
+    api, db, metrics,
+    state::AppState,
+    validate::{self, Validate},
+};
 
 #[derive(Default, Serialize)]
 pub struct ValidJson<T>(pub T);
@@ -22,13 +28,23 @@ where
         let Json(data) = <Json<T> as FromRequest<AppState>>::from_request(req, state).await?;
 
         let db_conn = state.db_conn().await?;
+        let queued_at = Instant::now();
+        let span = db::operation_span::<Self>();
 
+        // Validation errors are large, so they're boxed on their way back from the
+        // connection's thread
         db_conn
-            .interact(move |db_conn| {
-                data.validate(db_conn)?;
+            .interact(move |db_conn| -> Result<_, Box<validate::Error>> {
+                let _span = span.entered();
+                metrics::record_interact_wait(queued_at);
+                data.validate(db_conn).map_err(Box::new)?;
                 Ok(Self(data))
             })
             .await?
+            .map_err(|err| {
+                metrics::record_validation_failure(&err);
+                api::ErrorResponse::from(*err)
+            })
     }
 }
 
@@ -58,14 +74,22 @@ where
         .await?;
 
         let db_conn = state.db_conn().await?;
+        let queued_at = Instant::now();
+        let span = db::operation_span::<Self>();
 
         db_conn
-            .interact(move |db_conn| {
+            .interact(move |db_conn| -> Result<_, Box<validate::Error>> {
+                let _span = span.entered();
+                metrics::record_interact_wait(queued_at);
                 let path_and_data = (path, data);
-                path_and_data.validate(db_conn)?;
+                path_and_data.validate(db_conn).map_err(Box::new)?;
                 let (path, data) = path_and_data;
                 Ok(Self(path, data))
             })
             .await?
+            .map_err(|err| {
+                metrics::record_validation_failure(&err);
+                <api::ErrorResponse as From<validate::Error>>::from(*err)
+            })
     }
 }
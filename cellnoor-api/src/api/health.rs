@@ -0,0 +1,207 @@
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail};
+use axum::{Json, extract::State, http::StatusCode};
+use cellnoor_schema::{index_set_files, institutions, tenx_assays};
+use diesel::{
+    PgConnection,
+    dsl::{exists, select},
+    migration::MigrationSource,
+    pg::Pg,
+    prelude::*,
+    sql_types::Text,
+};
+
+use crate::{api::openapi::ApiRouter, setup::MIGRATIONS, state::AppState};
+
+// A check that takes longer than this is treated as failed, so that an
+// exhausted connection pool doesn't make the readiness probe hang
+const CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
+        .get("/", status)
+        .get("/live", live)
+        .get("/ready", ready)
+}
+
+async fn status() -> &'static str {
+    "OK"
+}
+
+async fn live() -> &'static str {
+    "OK"
+}
+
+#[derive(Debug, serde::Serialize, schemars::JsonSchema)]
+struct ReadinessReport {
+    ready: bool,
+    checks: Vec<CheckReport>,
+}
+
+#[derive(Debug, serde::Serialize, schemars::JsonSchema)]
+struct CheckReport {
+    name: &'static str,
+    ok: bool,
+    latency_ms: f64,
+    message: Option<String>,
+}
+
+async fn ready(State(state): State<AppState>) -> (StatusCode, Json<ReadinessReport>) {
+    let checks = vec![
+        check("database", db_round_trip(&state)).await,
+        check("migrations", with_db_conn(&state, pending_migrations)).await,
+        check("initial_data", with_db_conn(&state, missing_initial_data)).await,
+        check("db_pool", async { db_pool_saturation(&state) }).await,
+    ];
+
+    let ready = checks.iter().all(|c| c.ok);
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (status, Json(ReadinessReport { ready, checks }))
+}
+
+async fn check(
+    name: &'static str,
+    check: impl Future<Output = anyhow::Result<Option<String>>>,
+) -> CheckReport {
+    let start = Instant::now();
+    let result = tokio::time::timeout(CHECK_TIMEOUT, check)
+        .await
+        .unwrap_or_else(|_| Err(anyhow!("timed out after {CHECK_TIMEOUT:?}")));
+    let latency_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    if let Err(err) = &result {
+        tracing::warn!("readiness check '{name}' failed: {err:#}");
+    }
+
+    let (ok, message) = match result {
+        Ok(message) => (true, message),
+        Err(err) => (false, Some(format!("{err:#}"))),
+    };
+
+    CheckReport {
+        name,
+        ok,
+        latency_ms,
+        message,
+    }
+}
+
+async fn db_round_trip(state: &AppState) -> anyhow::Result<Option<String>> {
+    with_db_conn(state, |db_conn| {
+        select(1.into_sql::<diesel::sql_types::Integer>()).execute(db_conn)?;
+        Ok(None)
+    })
+    .await
+}
+
+async fn with_db_conn<F>(state: &AppState, f: F) -> anyhow::Result<Option<String>>
+where
+    F: 'static + Send + FnOnce(&mut PgConnection) -> anyhow::Result<Option<String>>,
+{
+    let db_conn = state.db_conn().await?;
+    db_conn.interact(f).await.map_err(|e| anyhow!("{e}"))?
+}
+
+#[derive(QueryableByName)]
+struct AppliedMigration {
+    #[diesel(sql_type = Text)]
+    version: String,
+}
+
+// `MigrationHarness::pending_migrations` would try to create the migrations
+// table, which `cellnoor_api` isn't allowed to do
+fn pending_migrations(db_conn: &mut PgConnection) -> anyhow::Result<Option<String>> {
+    let applied: HashSet<String> =
+        diesel::sql_query("select version from __diesel_schema_migrations")
+            .load::<AppliedMigration>(db_conn)?
+            .into_iter()
+            .map(|m| m.version)
+            .collect();
+
+    let pending: Vec<String> = MigrationSource::<Pg>::migrations(&MIGRATIONS)
+        .map_err(|e| anyhow!(e))?
+        .iter()
+        .filter(|m| !applied.contains(&m.name().version().to_string()))
+        .map(|m| m.name().to_string())
+        .collect();
+
+    if !pending.is_empty() {
+        bail!("pending migrations: {}", pending.join(", "));
+    }
+
+    Ok(None)
+}
+
+fn missing_initial_data(db_conn: &mut PgConnection) -> anyhow::Result<Option<String>> {
+    let (has_institutions, has_tenx_assays, has_index_sets): (bool, bool, bool) = select((
+        exists(institutions::table.select(institutions::id)),
+        exists(tenx_assays::table.select(tenx_assays::id)),
+        exists(index_set_files::table.select(index_set_files::url)),
+    ))
+    .get_result(db_conn)?;
+
+    let missing: Vec<_> = [
+        (has_institutions, "institutions"),
+        (has_tenx_assays, "10x assays"),
+        (has_index_sets, "index sets"),
+    ]
+    .into_iter()
+    .filter_map(|(present, name)| (!present).then_some(name))
+    .collect();
+
+    if !missing.is_empty() {
+        bail!(
+            "initial data has not been loaded: no {}",
+            missing.join(", ")
+        );
+    }
+
+    Ok(None)
+}
+
+fn db_pool_saturation(state: &AppState) -> anyhow::Result<Option<String>> {
+    let status = state.db_pool_status();
+    let message = format!(
+        "{} of {} connections in use, {} waiting",
+        status.size - status.available,
+        status.max_size,
+        status.waiting
+    );
+
+    if status.waiting > 0 {
+        bail!(message);
+    }
+
+    Ok(Some(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use deadpool_diesel::postgres::Connection;
+    use rstest::rstest;
+
+    use super::{missing_initial_data, pending_migrations};
+    use crate::test_state::root_db_conn;
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn migrated_and_seeded_db_is_ready(#[future] root_db_conn: Connection) {
+        root_db_conn
+            .interact(|db_conn| {
+                pending_migrations(db_conn).unwrap();
+                missing_initial_data(db_conn).unwrap();
+            })
+            .await
+            .unwrap();
+    }
+}
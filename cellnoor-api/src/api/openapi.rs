@@ -0,0 +1,812 @@
+use std::{any::type_name, marker::PhantomData, sync::Arc};
+
+use axum::{
+    Extension, Json, Router,
+    extract::{DefaultBodyLimit, Multipart, Request, State},
+    handler::Handler,
+    http::{Method, StatusCode},
+    response::{Html, IntoResponse, Response, sse::Sse},
+    routing::{self, MethodRouter},
+};
+use axum_extra::routing::{RouterExt, SecondElementIs, TypedPath};
+use metrics_exporter_prometheus::PrometheusHandle;
+use schemars::{JsonSchema, SchemaGenerator, generate::SchemaSettings};
+use serde_json::{Map, Value, json};
+
+use crate::{
+    api::{
+        ErrorResponse,
+        export::ExportFormat,
+        extract::{
+            LAST_EVENT_ID_HEADER, LastEventId, ValidJson, ValidPathJson,
+            auth::{API_KEY_HEADER, AuthenticatedUser},
+            query,
+        },
+    },
+    change_feed::ChangeFeed,
+    state::AppState,
+};
+
+const API_KEY_SCHEME: &str = "api_key";
+
+/// A [`Router`] that records each operation added to it, so that the `OpenAPI`
+/// document is derived from the same handlers that serve requests
+#[derive(Default)]
+pub(super) struct ApiRouter {
+    router: Router<AppState>,
+    operations: Vec<Operation>,
+}
+
+struct Operation {
+    method: Method,
+    path: String,
+    handler: &'static str,
+    document: fn(&mut OperationDoc, &mut SchemaGenerator),
+    // Served at the root rather than under `/api`
+    root: bool,
+}
+
+impl ApiRouter {
+    pub(super) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(super) fn typed_get<H, T, P>(self, handler: H) -> Self
+    where
+        H: Handler<T, AppState> + DocumentTypedHandler<T>,
+        T: SecondElementIs<P> + 'static,
+        P: TypedPath,
+    {
+        let mut this = self.record::<H, P>(Method::GET, H::document);
+        this.router = this.router.typed_get(handler);
+        this
+    }
+
+    pub(super) fn typed_post<H, T, P>(self, handler: H) -> Self
+    where
+        H: Handler<T, AppState> + DocumentTypedHandler<T>,
+        T: SecondElementIs<P> + 'static,
+        P: TypedPath,
+    {
+        let mut this = self.record::<H, P>(Method::POST, H::document);
+        this.router = this.router.typed_post(handler);
+        this
+    }
+
+    pub(super) fn typed_patch<H, T, P>(self, handler: H) -> Self
+    where
+        H: Handler<T, AppState> + DocumentTypedHandler<T>,
+        T: SecondElementIs<P> + 'static,
+        P: TypedPath,
+    {
+        let mut this = self.record::<H, P>(Method::PATCH, H::document);
+        this.router = this.router.typed_patch(handler);
+        this
+    }
+
+    pub(super) fn typed_delete<H, T, P>(self, handler: H) -> Self
+    where
+        H: Handler<T, AppState> + DocumentTypedHandler<T>,
+        T: SecondElementIs<P> + 'static,
+        P: TypedPath,
+    {
+        let mut this = self.record::<H, P>(Method::DELETE, H::document);
+        this.router = this.router.typed_delete(handler);
+        this
+    }
+
+    /// Add a `GET` route that lives outside the resource hierarchy, and so has
+    /// no [`TypedPath`]
+    pub(super) fn get<H, T>(self, path: &str, handler: H) -> Self
+    where
+        H: Handler<T, AppState> + DocumentHandler<T>,
+        T: 'static,
+    {
+        self.route::<H>(Method::GET, path, routing::get(handler), H::document)
+    }
+
+    /// Add a `POST` route whose path is extracted along with the body, such as
+    /// by [`ValidPathJson`]
+    pub(super) fn post<H, T>(self, path: &str, handler: H) -> Self
+    where
+        H: Handler<T, AppState> + DocumentHandler<T>,
+        T: 'static,
+    {
+        self.route::<H>(Method::POST, path, routing::post(handler), H::document)
+    }
+
+    /// Add a `PATCH` route whose path is extracted along with the body, such as
+    /// by [`ValidPathJson`]
+    pub(super) fn patch<H, T>(self, path: &str, handler: H) -> Self
+    where
+        H: Handler<T, AppState> + DocumentHandler<T>,
+        T: 'static,
+    {
+        self.route::<H>(Method::PATCH, path, routing::patch(handler), H::document)
+    }
+
+    pub(super) fn nest(mut self, prefix: &str, other: Self) -> Self {
+        self.router = self.router.nest(prefix, other.router);
+        self.operations
+            .extend(other.operations.into_iter().map(|o| Operation {
+                path: match o.path.as_str() {
+                    "/" => prefix.to_owned(),
+                    path => format!("{prefix}{path}"),
+                },
+                ..o
+            }));
+
+        self
+    }
+
+    pub(super) fn merge(mut self, other: Self) -> Self {
+        self.router = self.router.merge(other.router);
+        self.operations.extend(other.operations);
+
+        self
+    }
+
+    /// Document the operations of `root`, which is served at the root rather
+    /// than under `/api`, without routing them
+    pub(super) fn document_root(mut self, root: Self) -> Self {
+        self.operations.extend(
+            root.operations
+                .into_iter()
+                .map(|o| Operation { root: true, ..o }),
+        );
+
+        self
+    }
+
+    /// Raise the request body limit of the routes added so far to `limit` bytes
+    pub(super) fn body_limit(mut self, limit: usize) -> Self {
+        self.router = self.router.layer(DefaultBodyLimit::max(limit));
+        self
+    }
+
+    pub(super) fn into_router(self) -> Router<AppState> {
+        self.router
+    }
+
+    /// The `OpenAPI` 3.1 document describing every operation, relative to the
+    /// `/api` prefix
+    pub(super) fn openapi(&self) -> Value {
+        let mut generator = SchemaSettings::draft2020_12()
+            .with(|s| s.definitions_path = "/components/schemas".into())
+            .into_generator();
+
+        let mut paths = Map::new();
+        for operation in &self.operations {
+            let mut doc = OperationDoc::new(&operation.method, &operation.path);
+            (operation.document)(&mut doc, &mut generator);
+
+            let Value::Object(path_item) = paths
+                .entry(&operation.path)
+                .or_insert_with(|| Value::Object(Map::new()))
+            else {
+                unreachable!("path items are always objects");
+            };
+            path_item.insert(
+                operation.method.as_str().to_lowercase(),
+                doc.into_json(operation.handler),
+            );
+            if operation.root {
+                path_item.insert("servers".to_owned(), json!([{ "url": "/" }]));
+            }
+        }
+
+        json!({
+            "openapi": "3.1.0",
+            "info": {
+                "title": "cellnoor",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "servers": [{ "url": "/api" }],
+            "paths": paths,
+            "components": {
+                "schemas": generator.take_definitions(true),
+                "securitySchemes": {
+                    API_KEY_SCHEME: {
+                        "type": "apiKey",
+                        "in": "header",
+                        "name": API_KEY_HEADER,
+                    },
+                },
+            },
+        })
+    }
+
+    fn record<H, P: TypedPath>(
+        mut self,
+        method: Method,
+        document: fn(&mut OperationDoc, &mut SchemaGenerator),
+    ) -> Self {
+        self.operations.push(Operation {
+            method,
+            path: P::PATH.to_owned(),
+            handler: type_name::<H>(),
+            document,
+            root: false,
+        });
+
+        self
+    }
+
+    fn route<H>(
+        mut self,
+        method: Method,
+        path: &str,
+        method_router: MethodRouter<AppState>,
+        document: fn(&mut OperationDoc, &mut SchemaGenerator),
+    ) -> Self {
+        self.router = self.router.route(path, method_router);
+        self.operations.push(Operation {
+            method,
+            path: path.to_owned(),
+            handler: type_name::<H>(),
+            document,
+            root: false,
+        });
+
+        self
+    }
+}
+
+/// The parts of an operation contributed by a handler's arguments and return
+/// type
+pub(super) struct OperationDoc {
+    method: Method,
+    parameters: Vec<Value>,
+    request_body: Option<Value>,
+    responses: Map<String, Value>,
+    security: Option<Value>,
+    export: bool,
+}
+
+impl OperationDoc {
+    fn new(method: &Method, path: &str) -> Self {
+        // Path parameters are strings, and those that name a resource are UUIDs
+        let parameters = path
+            .split('/')
+            .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+            .map(|name| {
+                let schema = if name == "id" || name.ends_with("_id") {
+                    json!({ "type": "string", "format": "uuid" })
+                } else {
+                    json!({ "type": "string" })
+                };
+
+                json!({ "name": name, "in": "path", "required": true, "schema": schema })
+            })
+            .collect();
+
+        Self {
+            method: method.clone(),
+            parameters,
+            request_body: None,
+            responses: Map::new(),
+            security: None,
+            export: false,
+        }
+    }
+
+    fn success_status(&self) -> &'static str {
+        // Most `POST` routes create a resource, but some add existing resources
+        // to another
+        if self.method == Method::POST {
+            "2XX"
+        } else {
+            "200"
+        }
+    }
+
+    fn respond(&mut self, content: Map<String, Value>) {
+        let mut response = json!({ "description": "success" });
+        if !content.is_empty() {
+            response["content"] = Value::Object(content);
+        }
+
+        self.responses
+            .insert(self.success_status().to_owned(), response);
+    }
+
+    fn into_json(self, handler: &str) -> Value {
+        // Handlers are unique, so their path within the routes module, or within
+        // the crate for those outside it, is a unique and stable operation ID
+        let operation_id = handler
+            .split_once("routes::")
+            .or_else(|| handler.split_once("::api::"))
+            .or_else(|| handler.split_once("::"))
+            .map_or(handler, |(_, id)| id)
+            .replace("::", ".");
+
+        let mut operation = json!({
+            "operationId": operation_id,
+            "parameters": self.parameters,
+            "responses": self.responses,
+        });
+
+        if let Some(body) = self.request_body {
+            operation["requestBody"] = body;
+        }
+        if let Some(security) = self.security {
+            operation["security"] = security;
+        }
+
+        operation
+    }
+}
+
+fn json_content(schema: impl Into<Value>) -> Map<String, Value> {
+    let mut content = Map::new();
+    content.insert(
+        "application/json".to_owned(),
+        json!({ "schema": schema.into() }),
+    );
+
+    content
+}
+
+/// Describe a handler argument
+pub(super) trait DocumentInput {
+    fn document(_: &mut OperationDoc, _: &mut SchemaGenerator) {}
+}
+
+impl DocumentInput for State<AppState> {}
+
+impl DocumentInput for Request {}
+
+impl DocumentInput for AuthenticatedUser {
+    fn document(operation: &mut OperationDoc, _: &mut SchemaGenerator) {
+        operation.security = Some(json!([{ API_KEY_SCHEME: [] }]));
+    }
+}
+
+impl DocumentInput for Extension<ChangeFeed> {}
+
+impl DocumentInput for Extension<Document> {}
+
+impl DocumentInput for Extension<PrometheusHandle> {}
+
+impl DocumentInput for Option<LastEventId> {
+    fn document(operation: &mut OperationDoc, _: &mut SchemaGenerator) {
+        operation.parameters.push(json!({
+            "name": LAST_EVENT_ID_HEADER,
+            "in": "header",
+            "required": false,
+            "description": "The `id` of the last event received",
+            "schema": { "type": "string" },
+        }));
+    }
+}
+
+impl DocumentInput for Option<ExportFormat> {
+    fn document(operation: &mut OperationDoc, _: &mut SchemaGenerator) {
+        operation.export = true;
+    }
+}
+
+impl<T: JsonSchema> DocumentInput for query::QsQuery<T> {
+    fn document(operation: &mut OperationDoc, generator: &mut SchemaGenerator) {
+        document_query::<T>(operation, generator);
+    }
+}
+
+impl<T: JsonSchema> DocumentInput for serde_qs::axum::QsQuery<T> {
+    fn document(operation: &mut OperationDoc, generator: &mut SchemaGenerator) {
+        document_query::<T>(operation, generator);
+    }
+}
+
+impl<T: JsonSchema> DocumentInput for ValidJson<T> {
+    fn document(operation: &mut OperationDoc, generator: &mut SchemaGenerator) {
+        let content = json_content(generator.subschema_for::<T>());
+        operation.request_body = Some(json!({ "required": true, "content": content }));
+    }
+}
+
+impl<T, U: JsonSchema> DocumentInput for ValidPathJson<T, U> {
+    fn document(operation: &mut OperationDoc, generator: &mut SchemaGenerator) {
+        ValidJson::<U>::document(operation, generator);
+    }
+}
+
+impl DocumentInput for Multipart {
+    fn document(operation: &mut OperationDoc, _: &mut SchemaGenerator) {
+        operation.request_body = Some(json!({
+            "required": true,
+            "content": { "multipart/form-data": { "schema": { "type": "object" } } },
+        }));
+    }
+}
+
+/// Query strings are parsed by `serde_qs`, so nested objects and arrays use
+/// the bracket notation of `deepObject`, such as `filter[ids][0]=...`
+fn document_query<T: JsonSchema>(operation: &mut OperationDoc, generator: &mut SchemaGenerator) {
+    let schema = T::json_schema(generator);
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|r| r.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return;
+    };
+
+    for (name, property) in properties {
+        let mut parameter = json!({
+            "name": name,
+            "in": "query",
+            "required": required.contains(&name.as_str()),
+            "schema": property,
+        });
+        if !is_scalar(property, generator) {
+            parameter["style"] = "deepObject".into();
+            parameter["explode"] = true.into();
+        }
+
+        operation.parameters.push(parameter);
+    }
+}
+
+fn is_scalar(schema: &Value, generator: &SchemaGenerator) -> bool {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        let name = reference.rsplit('/').next().unwrap_or_default();
+        return generator
+            .definitions()
+            .get(name)
+            .is_some_and(|s| is_scalar(s, generator));
+    }
+
+    if let Some(variants) = schema.get("anyOf").or_else(|| schema.get("oneOf")) {
+        return variants
+            .as_array()
+            .is_some_and(|v| v.iter().all(|s| is_scalar(s, generator)));
+    }
+
+    let is_scalar_type = |t: &Value| t.as_str().is_some_and(|t| !matches!(t, "object" | "array"));
+    match schema.get("type") {
+        Some(Value::Array(types)) => types.iter().all(is_scalar_type),
+        Some(t) => is_scalar_type(t),
+        None => schema.get("enum").is_some() || schema.get("const").is_some(),
+    }
+}
+
+/// Describe a handler's return type
+pub(super) trait DocumentOutput {
+    fn document(operation: &mut OperationDoc, generator: &mut SchemaGenerator);
+}
+
+impl<T: DocumentOutput> DocumentOutput for Result<T, ErrorResponse> {
+    fn document(operation: &mut OperationDoc, generator: &mut SchemaGenerator) {
+        T::document(operation, generator);
+
+        let content = json_content(generator.subschema_for::<ErrorResponse>());
+        operation.responses.insert(
+            "default".to_owned(),
+            json!({ "description": "error", "content": content }),
+        );
+    }
+}
+
+impl<T: JsonSchema> DocumentOutput for (StatusCode, Json<T>) {
+    fn document(operation: &mut OperationDoc, generator: &mut SchemaGenerator) {
+        operation.respond(json_content(generator.subschema_for::<T>()));
+    }
+}
+
+impl<T: JsonSchema> DocumentOutput for Json<T> {
+    fn document(operation: &mut OperationDoc, generator: &mut SchemaGenerator) {
+        operation.respond(json_content(generator.subschema_for::<T>()));
+    }
+}
+
+impl DocumentOutput for &'static str {
+    fn document(operation: &mut OperationDoc, generator: &mut SchemaGenerator) {
+        String::document(operation, generator);
+    }
+}
+
+impl DocumentOutput for String {
+    fn document(operation: &mut OperationDoc, _: &mut SchemaGenerator) {
+        let mut content = Map::new();
+        content.insert("text/plain".to_owned(), json!({}));
+        operation.respond(content);
+    }
+}
+
+impl DocumentOutput for (StatusCode, Html<Vec<u8>>) {
+    fn document(operation: &mut OperationDoc, _: &mut SchemaGenerator) {
+        let mut content = Map::new();
+        content.insert("text/html".to_owned(), json!({}));
+        operation.respond(content);
+    }
+}
+
+// The content type of a raw response isn't known until it's built
+impl DocumentOutput for (StatusCode, Response) {
+    fn document(operation: &mut OperationDoc, _: &mut SchemaGenerator) {
+        operation.respond(Map::new());
+    }
+}
+
+impl<S> DocumentOutput for Sse<S> {
+    fn document(operation: &mut OperationDoc, _: &mut SchemaGenerator) {
+        let mut content = Map::new();
+        content.insert("text/event-stream".to_owned(), json!({}));
+        operation.respond(content);
+    }
+}
+
+impl<T: JsonSchema> DocumentOutput for JsonResponse<T> {
+    fn document(operation: &mut OperationDoc, generator: &mut SchemaGenerator) {
+        let mut content = json_content(generator.subschema_for::<T>());
+
+        if operation.export {
+            for format in ExportFormat::ALL {
+                content.insert(format.content_type().to_owned(), json!({}));
+            }
+        }
+
+        operation.respond(content);
+    }
+}
+
+/// A response that is JSON shaped like `T`, but that is built in some other
+/// way, such as by trimming it to the requested `fields`
+pub(super) struct JsonResponse<T> {
+    response: Response,
+    body: PhantomData<fn() -> T>,
+}
+
+impl<T> From<Response> for JsonResponse<T> {
+    fn from(response: Response) -> Self {
+        Self {
+            response,
+            body: PhantomData,
+        }
+    }
+}
+
+impl<T> IntoResponse for JsonResponse<T> {
+    fn into_response(self) -> Response {
+        self.response
+    }
+}
+
+/// The `OpenAPI` document, built once when the app is assembled
+#[derive(Clone)]
+pub(super) struct Document(Arc<Value>);
+
+impl Document {
+    pub(super) fn new(document: Value) -> Self {
+        Self(Arc::new(document))
+    }
+}
+
+pub(super) async fn serve_document(Extension(document): Extension<Document>) -> Json<Value> {
+    Json(Value::clone(&document.0))
+}
+
+/// Describe a handler that isn't given its path as its first argument
+pub(super) trait DocumentHandler<T> {
+    fn document(operation: &mut OperationDoc, generator: &mut SchemaGenerator);
+}
+
+/// Describe a handler whose first argument is a [`TypedPath`], which is
+/// already described by the path itself
+pub(super) trait DocumentTypedHandler<T> {
+    fn document(operation: &mut OperationDoc, generator: &mut SchemaGenerator);
+}
+
+// These mirror the implementations of `Handler`, whose type parameter is the
+// tuple of a marker followed by each argument
+macro_rules! impl_document_handler {
+    ($($arg:ident),*) => {
+        impl<F, Fut, M, $($arg,)*> DocumentHandler<(M, $($arg,)*)> for F
+        where
+            F: FnOnce($($arg),*) -> Fut,
+            Fut: Future,
+            Fut::Output: DocumentOutput,
+            $($arg: DocumentInput,)*
+        {
+            fn document(operation: &mut OperationDoc, generator: &mut SchemaGenerator) {
+                $($arg::document(operation, generator);)*
+                <Fut::Output as DocumentOutput>::document(operation, generator);
+            }
+        }
+
+        impl<F, Fut, M, P, $($arg,)*> DocumentTypedHandler<(M, P, $($arg,)*)> for F
+        where
+            F: FnOnce(P, $($arg),*) -> Fut,
+            Fut: Future,
+            Fut::Output: DocumentOutput,
+            $($arg: DocumentInput,)*
+        {
+            fn document(operation: &mut OperationDoc, generator: &mut SchemaGenerator) {
+                $($arg::document(operation, generator);)*
+                <Fut::Output as DocumentOutput>::document(operation, generator);
+            }
+        }
+    };
+}
+
+impl_document_handler!();
+impl_document_handler!(T1);
+impl_document_handler!(T1, T2);
+impl_document_handler!(T1, T2, T3);
+impl_document_handler!(T1, T2, T3, T4);
+impl_document_handler!(T1, T2, T3, T4, T5);
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Method, Request, StatusCode},
+    };
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::{Value, json};
+    use uuid::Uuid;
+
+    use crate::{
+        api::api_routes,
+        config::UploadLimits,
+        test_app::{TestApp, app},
+    };
+
+    fn references(value: &Value) -> Vec<&str> {
+        match value {
+            Value::Object(object) => object
+                .iter()
+                .flat_map(|(key, value)| match (key.as_str(), value) {
+                    ("$ref", Value::String(reference)) => vec![reference.as_str()],
+                    _ => references(value),
+                })
+                .collect(),
+            Value::Array(array) => array.iter().flat_map(references).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn spec() -> Value {
+        api_routes(UploadLimits::default()).openapi()
+    }
+
+    #[rstest]
+    fn every_route_is_documented() {
+        let spec = spec();
+        let paths = spec["paths"].as_object().unwrap();
+
+        // These are routed outside `routes::router`, so they're the ones most
+        // easily forgotten
+        for path in ["/health", "/health/live", "/health/ready", "/openapi.json"] {
+            assert!(paths[path]["get"].is_object(), "{path} is not documented");
+            assert!(paths[path].get("servers").is_none());
+        }
+        assert!(paths["/metrics"]["get"].is_object());
+        assert_eq!(paths["/metrics"]["servers"], json!([{ "url": "/" }]));
+
+        for (path, path_item) in paths {
+            let n_path_parameters = path.matches('{').count();
+
+            for (method, documented) in path_item.as_object().unwrap() {
+                if method == "servers" {
+                    continue;
+                }
+
+                let documented_path_parameters = documented["parameters"]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .filter(|p| p["in"] == "path")
+                    .count();
+                assert_eq!(
+                    documented_path_parameters, n_path_parameters,
+                    "{method} {path}"
+                );
+            }
+        }
+    }
+
+    // A request that doesn't match a route gets axum's fallback, an empty 404 or
+    // a 405, whereas one that reaches a handler is rejected with a body at worst
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn documented_routes_are_served(#[future] app: TestApp) {
+        async fn is_routed(app: &TestApp, method: Method, uri: &str) -> bool {
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .body(Body::empty())
+                .unwrap();
+            let response = app.send(request).await;
+
+            match response.status() {
+                StatusCode::METHOD_NOT_ALLOWED => false,
+                StatusCode::NOT_FOUND => !to_bytes(response.into_body(), usize::MAX)
+                    .await
+                    .unwrap()
+                    .is_empty(),
+                _ => true,
+            }
+        }
+
+        assert!(!is_routed(&app, Method::GET, "/api/undocumented").await);
+
+        let spec = spec();
+        for (path, path_item) in spec["paths"].as_object().unwrap() {
+            // The app's router doesn't include the routes served at the root
+            if path_item.get("servers").is_some() {
+                continue;
+            }
+
+            let uri: Vec<_> = path
+                .split('/')
+                .map(|segment| {
+                    if segment.starts_with('{') {
+                        Uuid::nil().to_string()
+                    } else {
+                        segment.to_owned()
+                    }
+                })
+                .collect();
+            let uri = format!("/api{}", uri.join("/"));
+
+            for method in path_item.as_object().unwrap().keys() {
+                let method: Method = method.to_uppercase().parse().unwrap();
+                assert!(
+                    is_routed(&app, method.clone(), &uri).await,
+                    "{method} {path} is documented but not served"
+                );
+            }
+        }
+    }
+
+    #[rstest]
+    fn references_resolve() {
+        let spec = spec();
+
+        for reference in references(&spec) {
+            let name = reference
+                .strip_prefix("#/components/schemas/")
+                .unwrap_or_else(|| panic!("unexpected reference {reference}"));
+            assert!(
+                spec["components"]["schemas"].get(name).is_some(),
+                "{reference} does not resolve"
+            );
+        }
+    }
+
+    #[rstest]
+    fn shaped_responses_document_embedded_objects() {
+        let spec = spec();
+        let schemas = &spec["components"]["schemas"];
+
+        let fetch_specimen = &spec["paths"]["/specimens/{id}"]["get"];
+        assert_eq!(
+            fetch_specimen["responses"]["200"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/Shaped_Specimen"
+        );
+
+        let [full, shaped] = schemas["Shaped_SpecimenSummary"]["anyOf"]
+            .as_array()
+            .unwrap()
+            .as_slice()
+        else {
+            panic!("shaped schema should be the full object or the shaped one");
+        };
+        assert_eq!(full["$ref"], "#/components/schemas/SpecimenSummary");
+        assert_eq!(shaped["required"], serde_json::json!(["id"]));
+        assert_eq!(
+            shaped["properties"]["submitted_by"]["$ref"],
+            "#/components/schemas/PersonSummary"
+        );
+        assert_eq!(
+            shaped["properties"]["lab"]["$ref"],
+            "#/components/schemas/LabSummary"
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use axum::http::Method;
+use uuid::Uuid;
+
+// Buckets that have refilled completely are the same as absent ones, so they're
+// dropped once there are this many, rather than letting requests with made-up
+// API keys grow the map without bound
+const MAX_BUCKETS: usize = 10_000;
+
+/// Token buckets per requester, one for reads and one for writes, so that a
+/// script hammering an upload route doesn't also lock its owner out of
+/// browsing. Each bucket holds a minute's worth of requests and refills
+/// continuously.
+#[derive(Clone)]
+pub(crate) struct RateLimiter {
+    reads: Arc<Buckets>,
+    writes: Arc<Buckets>,
+}
+
+impl RateLimiter {
+    /// Limits are in requests per minute, where 0 means unlimited
+    pub(crate) fn new(reads_per_minute: u32, writes_per_minute: u32) -> Self {
+        Self {
+            reads: Arc::new(Buckets::new(reads_per_minute)),
+            writes: Arc::new(Buckets::new(writes_per_minute)),
+        }
+    }
+
+    /// Take a token from the requester's bucket for `method`, or return how
+    /// long until one is available
+    pub(crate) fn check(&self, requester: &Requester, method: &Method) -> Result<(), Duration> {
+        let buckets = if method.is_safe() {
+            &self.reads
+        } else {
+            &self.writes
+        };
+
+        buckets.take(requester, Instant::now())
+    }
+}
+
+/// Requests are limited by user once their API key is verified, so that having
+/// several keys doesn't raise the limit. Keys that fail verification are
+/// limited by their prefix instead, which slows down guessing the rest of a key
+/// without ever turning away the real one.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Requester {
+    ApiKeyPrefix(String),
+    User(Uuid),
+}
+
+struct Buckets {
+    capacity: f64,
+    tokens_per_second: f64,
+    by_requester: Mutex<HashMap<Requester, Bucket>>,
+}
+
+#[derive(Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl Buckets {
+    fn new(per_minute: u32) -> Self {
+        let capacity = f64::from(per_minute);
+
+        Self {
+            capacity,
+            tokens_per_second: capacity / 60.0,
+            by_requester: Mutex::default(),
+        }
+    }
+
+    fn take(&self, requester: &Requester, now: Instant) -> Result<(), Duration> {
+        if self.capacity == 0.0 {
+            return Ok(());
+        }
+
+        // A panic while holding the lock can't leave a bucket in an invalid state
+        let mut buckets = self
+            .by_requester
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        if buckets.len() >= MAX_BUCKETS {
+            buckets.retain(|_, bucket| self.refill(bucket, now) < self.capacity);
+        }
+
+        let bucket = buckets.entry(requester.clone()).or_insert(Bucket {
+            tokens: self.capacity,
+            refilled_at: now,
+        });
+
+        bucket.tokens = self.refill(bucket, now);
+        bucket.refilled_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(());
+        }
+
+        let missing = 1.0 - bucket.tokens;
+        Err(Duration::from_secs_f64(missing / self.tokens_per_second))
+    }
+
+    /// The tokens `bucket` holds at `now`
+    fn refill(&self, bucket: &Bucket, now: Instant) -> f64 {
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        (bucket.tokens + elapsed.as_secs_f64() * self.tokens_per_second).min(self.capacity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode, header::RETRY_AFTER},
+    };
+    use deadpool_diesel::postgres::Connection;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    use super::{Buckets, MAX_BUCKETS, Requester};
+    use crate::{config::Config, test_app::TestApp, test_state::root_db_conn};
+
+    #[rstest]
+    fn bucket_empties_and_refills() {
+        let buckets = Buckets::new(60);
+        let user = Requester::User(Uuid::now_v7());
+        let start = Instant::now();
+
+        for _ in 0..60 {
+            buckets.take(&user, start).unwrap();
+        }
+
+        let retry_after = buckets.take(&user, start).unwrap_err();
+        assert_eq!(retry_after.as_secs(), 1);
+
+        buckets.take(&user, start + Duration::from_secs(1)).unwrap();
+
+        // Other users have their own buckets
+        buckets
+            .take(&Requester::User(Uuid::now_v7()), start)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn zero_is_unlimited() {
+        let buckets = Buckets::new(0);
+        let user = Requester::User(Uuid::now_v7());
+        let now = Instant::now();
+
+        for _ in 0..1000 {
+            buckets.take(&user, now).unwrap();
+        }
+    }
+
+    #[rstest]
+    fn full_buckets_are_dropped() {
+        let buckets = Buckets::new(60);
+        let start = Instant::now();
+
+        for i in 0..MAX_BUCKETS {
+            buckets
+                .take(&Requester::ApiKeyPrefix(i.to_string()), start)
+                .unwrap();
+        }
+
+        buckets
+            .take(
+                &Requester::User(Uuid::now_v7()),
+                start + Duration::from_secs(1),
+            )
+            .unwrap();
+
+        assert_eq!(buckets.by_requester.lock().unwrap().len(), 1);
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn failed_attempts_do_not_lock_out_the_key(#[future] root_db_conn: Connection) {
+        // Getting a connection waits for the database to be set up
+        drop(root_db_conn);
+
+        let config = Config::read().unwrap().with_rate_limits(2, 0);
+        let app = TestApp::with_config(&config);
+        let request = || Request::get("/api/labs").body(Body::empty()).unwrap();
+
+        // Wrong keys with the same prefix as the real one use up the prefix's
+        // bucket
+        let mut wrong_key = app.api_key().to_owned();
+        wrong_key.replace_range(wrong_key.len() - 2.., "00");
+        for _ in 0..2 {
+            let response = app.send_with_api_key(request(), &wrong_key).await;
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        let response = app.send_with_api_key(request(), &wrong_key).await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        // ...but the real key is only limited by its user's bucket
+        for _ in 0..2 {
+            assert_eq!(app.send(request()).await.status(), StatusCode::OK);
+        }
+
+        let response = app.send(request()).await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        let retry_after: u64 = response.headers()[RETRY_AFTER]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!((1..=30).contains(&retry_after));
+    }
+}
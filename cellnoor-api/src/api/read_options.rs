@@ -0,0 +1,362 @@
+use std::{collections::HashMap, fmt::Debug, marker::PhantomData};
+
+use cellnoor_models::{
+    generic_query::{Page, Query},
+    read_options::{NoInclude, ReadOptions},
+};
+use diesel::PgConnection;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::db;
+
+pub(super) type Object = Map<String, Value>;
+
+/// A related object that can be embedded in a read response with `include`
+pub(super) trait Relation: Copy + Debug + Send + 'static {
+    fn embed(self, items: &mut [Object], db_conn: &mut PgConnection) -> Result<(), db::Error>;
+}
+
+impl Relation for NoInclude {
+    fn embed(self, _: &mut [Object], _: &mut PgConnection) -> Result<(), db::Error> {
+        match self {}
+    }
+}
+
+/// A list request that carries [`ReadOptions`]
+pub(super) trait WithReadOptions {
+    type Include: Relation;
+
+    fn take_read_options(&mut self) -> ReadOptions<Self::Include>;
+}
+
+impl<F, O, I> WithReadOptions for Query<F, O, I>
+where
+    O: Default,
+    I: Relation,
+{
+    type Include = I;
+
+    fn take_read_options(&mut self) -> ReadOptions<I> {
+        Query::take_read_options(self)
+    }
+}
+
+// Nested list routes pair the parent ID with the query
+impl<P, Q> WithReadOptions for (P, Q)
+where
+    Q: WithReadOptions,
+{
+    type Include = Q::Include;
+
+    fn take_read_options(&mut self) -> ReadOptions<Self::Include> {
+        self.1.take_read_options()
+    }
+}
+
+/// Runs `request` and then applies `options` to its output, in the same
+/// transaction so that related objects are subject to the same permissions
+#[derive(Debug)]
+pub(super) struct ShapedRequest<R, I, T> {
+    request: R,
+    options: ReadOptions<I>,
+    output: PhantomData<fn() -> T>,
+}
+
+impl<R, I, T> ShapedRequest<R, I, T> {
+    pub(super) fn new(request: R, options: ReadOptions<I>) -> Self {
+        Self {
+            request,
+            options,
+            output: PhantomData,
+        }
+    }
+}
+
+impl<R, I, Row> db::Operation<Page<Object>> for ShapedRequest<R, I, Page<Row>>
+where
+    R: db::Operation<Page<Row>>,
+    I: Relation,
+    Row: Serialize,
+{
+    fn execute(self, db_conn: &mut PgConnection) -> Result<Page<Object>, db::Error> {
+        let Page {
+            items,
+            total,
+            next_cursor,
+        } = self.request.execute(db_conn)?;
+
+        let mut items: Vec<Object> = items.iter().map(to_object).collect();
+        apply(self.options, &mut items, db_conn)?;
+
+        Ok(Page {
+            items,
+            total,
+            next_cursor,
+        })
+    }
+}
+
+impl<R, I, T> db::Operation<Object> for ShapedRequest<R, I, T>
+where
+    R: db::Operation<T>,
+    I: Relation,
+    T: Serialize,
+{
+    fn execute(self, db_conn: &mut PgConnection) -> Result<Object, db::Error> {
+        let item = self.request.execute(db_conn)?;
+
+        let mut items = [to_object(&item)];
+        apply(self.options, &mut items, db_conn)?;
+
+        let [item] = items;
+        Ok(item)
+    }
+}
+
+fn to_object<T: Serialize>(item: &T) -> Object {
+    match serde_json::to_value(item) {
+        Ok(Value::Object(object)) => object,
+        _ => Object::new(),
+    }
+}
+
+fn apply<I: Relation>(
+    ReadOptions { fields, include }: ReadOptions<I>,
+    items: &mut [Object],
+    db_conn: &mut PgConnection,
+) -> Result<(), db::Error> {
+    for relation in include {
+        relation.embed(items, db_conn)?;
+    }
+
+    for item in items {
+        retain_fields(item, &fields);
+    }
+
+    Ok(())
+}
+
+/// Trim `item` to `fields`, keeping `id`. An empty `fields` keeps everything
+pub(super) fn retain_fields(item: &mut Object, fields: &[String]) {
+    if !fields.is_empty() {
+        item.retain(|key, _| key == "id" || fields.contains(key));
+    }
+}
+
+fn uuid_at(item: &Object, key: &str) -> Option<Uuid> {
+    item.get(key)?.as_str()?.parse().ok()
+}
+
+/// Replace the ID found at `id_field` with the object it refers to, stored at
+/// `key`. `load` is called once with every ID.
+pub(super) fn embed_foreign_key<T: Serialize>(
+    items: &mut [Object],
+    id_field: &str,
+    key: &str,
+    load: impl FnOnce(Vec<Uuid>) -> Result<Vec<T>, db::Error>,
+) -> Result<(), db::Error> {
+    let mut ids: Vec<Uuid> = items.iter().filter_map(|i| uuid_at(i, id_field)).collect();
+    ids.sort_unstable();
+    ids.dedup();
+
+    let related: HashMap<Uuid, Object> = load(ids)?
+        .iter()
+        .map(to_object)
+        .filter_map(|o| Some((uuid_at(&o, "id")?, o)))
+        .collect();
+
+    for item in items {
+        let Some(id) = uuid_at(item, id_field) else {
+            continue;
+        };
+
+        let value = related.get(&id).cloned().map_or(Value::Null, Value::Object);
+        item.insert(key.to_owned(), value);
+    }
+
+    Ok(())
+}
+
+/// Store the objects related to each item as an array at `key`. `load` is
+/// called once with the ID of every item and returns pairs of item ID and
+/// related object.
+pub(super) fn embed_many<T: Serialize>(
+    items: &mut [Object],
+    key: &str,
+    load: impl FnOnce(Vec<Uuid>) -> Result<Vec<(Uuid, T)>, db::Error>,
+) -> Result<(), db::Error> {
+    let ids: Vec<Uuid> = items.iter().filter_map(|i| uuid_at(i, "id")).collect();
+
+    let mut related: HashMap<Uuid, Vec<Value>> = HashMap::new();
+    for (id, object) in load(ids)? {
+        related
+            .entry(id)
+            .or_default()
+            .push(Value::Object(to_object(&object)));
+    }
+
+    for item in items {
+        let Some(id) = uuid_at(item, "id") else {
+            continue;
+        };
+
+        let values = related.remove(&id).unwrap_or_default();
+        item.insert(key.to_owned(), Value::Array(values));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{Method, StatusCode};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::{Value, json};
+    use uuid::Uuid;
+
+    use super::{Object, embed_foreign_key};
+    use crate::{
+        test_app::{TestApp, app},
+        test_state::{Database, database},
+    };
+
+    fn object(value: Value) -> Object {
+        let Value::Object(object) = value else {
+            panic!("not an object");
+        };
+
+        object
+    }
+
+    #[rstest]
+    fn foreign_key_is_replaced_by_object() {
+        let lab_id = Uuid::now_v7();
+        let mut items = [
+            object(json!({"id": Uuid::nil(), "lab_id": lab_id})),
+            object(json!({"id": Uuid::max(), "lab_id": lab_id})),
+        ];
+
+        let mut n_loads = 0;
+        embed_foreign_key(&mut items, "lab_id", "lab", |ids| {
+            n_loads += 1;
+            assert_eq!(ids, [lab_id]);
+
+            Ok(vec![json!({"id": lab_id, "name": "lab"})])
+        })
+        .unwrap();
+
+        assert_eq!(n_loads, 1);
+        for item in items {
+            assert_eq!(item["lab"], json!({"id": lab_id, "name": "lab"}));
+        }
+    }
+
+    fn keys(item: &Value) -> Vec<&str> {
+        let mut keys: Vec<&str> = item
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort_unstable();
+
+        keys
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn list_is_shaped(#[future] app: TestApp, #[future] database: &'static Database) {
+        let (status, body) = app
+            .send_json(
+                Method::GET,
+                "/api/specimens?limit=2&fields=name,lab,submitted_by&include=lab,submitted_by",
+                None,
+            )
+            .await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+
+        let items = body["items"].as_array().unwrap();
+        assert_eq!(items.len(), 2);
+
+        for item in items {
+            assert_eq!(keys(item), ["id", "lab", "name", "submitted_by"]);
+
+            let specimen = database
+                .specimens
+                .iter()
+                .find(|s| item["id"] == json!(s.id()))
+                .unwrap();
+            assert!(
+                database
+                    .labs
+                    .iter()
+                    .any(|l| item["lab"]["id"] == json!(l.id()))
+            );
+            assert_eq!(item["submitted_by"]["id"], json!(specimen.submitted_by()));
+        }
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn fetched_item_is_shaped(#[future] app: TestApp, #[future] database: &'static Database) {
+        let dataset = &database.chromium_datasets[0];
+
+        let (status, body) = app
+            .send_json(
+                Method::GET,
+                &format!(
+                    "/api/chromium-datasets/{}?fields=libraries&include=libraries",
+                    dataset.id()
+                ),
+                None,
+            )
+            .await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+
+        assert_eq!(keys(&body), ["id", "libraries"]);
+        assert!(!body["libraries"].as_array().unwrap().is_empty());
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn include_is_rejected_without_related_objects(
+        #[future] app: TestApp,
+        #[future] database: &'static Database,
+    ) {
+        let institution_id = database.institutions[0].id();
+
+        for uri in [
+            "/api/institutions?include=lab".to_owned(),
+            format!("/api/institutions/{institution_id}?include=lab"),
+        ] {
+            let (status, body) = app.send_json(Method::GET, &uri, None).await;
+
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{uri}: {body}");
+            assert_eq!(body["type"], "malformed_request");
+            assert!(
+                body["info"]["message"]
+                    .as_str()
+                    .unwrap()
+                    .contains("no related objects to include"),
+                "{body}"
+            );
+        }
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn unknown_include_is_rejected(#[future] app: TestApp) {
+        let (status, body) = app
+            .send_json(Method::GET, "/api/specimens?include=libraries", None)
+            .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{body}");
+    }
+}
@@ -0,0 +1,78 @@
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use tracing::Instrument;
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+// Anything longer is more likely to be abuse than a real correlation ID
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// The ID of the request being handled by the current task, if any
+pub(crate) fn current() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// Use the caller's `X-Request-Id` or generate one, attach it to every log
+/// line and span of the request, and echo it in the response
+pub(super) async fn propagate(request: Request, next: Next) -> Response {
+    let request_id = request
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| is_valid(value))
+        .map_or_else(|| Uuid::now_v7().to_string(), ToOwned::to_owned);
+
+    let span = tracing::info_span!(
+        "request",
+        request_id,
+        method = %request.method(),
+        uri = %request.uri(),
+    );
+
+    let mut response = REQUEST_ID
+        .scope(request_id.clone(), next.run(request))
+        .instrument(span)
+        .await;
+
+    // `is_valid` guarantees that this is a valid header value
+    response.headers_mut().insert(
+        REQUEST_ID_HEADER,
+        HeaderValue::from_str(&request_id).unwrap(),
+    );
+
+    response
+}
+
+fn is_valid(request_id: &str) -> bool {
+    !request_id.is_empty()
+        && request_id.len() <= MAX_REQUEST_ID_LENGTH
+        && request_id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b"-_.:".contains(&b))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::is_valid;
+
+    #[rstest]
+    #[case("0192d6c4-0f7e-7c3a-9b1e-1f2d3c4b5a69", true)]
+    #[case("support-ticket_42", true)]
+    #[case("", false)]
+    #[case("has spaces", false)]
+    #[case("line\nbreak", false)]
+    fn request_id_validity(#[case] request_id: &str, #[case] expected: bool) {
+        assert_eq!(is_valid(request_id), expected);
+    }
+}
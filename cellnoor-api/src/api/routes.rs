@@ -1,33 +1,61 @@
-use axum::{Json, Router, extract::State, http::StatusCode};
+use std::{marker::PhantomData, time::Instant};
+
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
 use axum_extra::routing::TypedPath;
+use cellnoor_models::{
+    generic_query::Page,
+    read_options::{NoInclude, ReadOptions, Shaped},
+};
+use serde::Serialize;
 
 use crate::{
-    api::{error::ErrorResponse, extract::auth::AuthenticatedUser},
-    db,
+    api::{
+        error::ErrorResponse,
+        export::{self, ExportFormat},
+        extract::auth::AuthenticatedUser,
+        openapi::{ApiRouter, JsonResponse},
+        read_options::{Object, Relation, ShapedRequest, WithReadOptions},
+    },
+    config::UploadLimits,
+    db, metrics,
     state::AppState,
 };
 
+mod api_keys;
 mod cdna;
 mod chromium_datasets;
 mod chromium_runs;
+mod committee_approvals;
+mod donors;
+mod events;
 mod gem_pools;
 mod institutions;
 mod labs;
 mod libraries;
+mod lineage;
 mod multiplexing_tags;
 mod people;
+mod projects;
+mod search;
 mod sequencing_runs;
 mod specimens;
+mod stats;
+mod storage;
 mod suspension_pools;
 mod suspensions;
 mod tenx_assays;
+mod webhooks;
 
-pub(super) fn router() -> Router<AppState> {
-    Router::new()
+pub(super) fn router(upload_limits: UploadLimits) -> ApiRouter {
+    ApiRouter::new()
         .nest("/institutions", institutions::router())
         .nest("/people", people::router())
         .nest("/labs", labs::router())
+        .nest("/projects", projects::router())
+        .nest("/committee-approvals", committee_approvals::router())
+        .nest("/donors", donors::router())
         .nest("/specimens", specimens::router())
+        .nest("/storage", storage::router())
         .nest("/10x-assays", tenx_assays::router())
         .nest("/sequencing-runs", sequencing_runs::router())
         .nest("/multiplexing-tags", multiplexing_tags::router())
@@ -37,11 +65,31 @@ pub(super) fn router() -> Router<AppState> {
         .nest("/gem-pools", gem_pools::router())
         .nest("/cdna", cdna::router())
         .nest("/libraries", libraries::router())
-        .nest("/chromium-datasets", chromium_datasets::router())
+        .nest(
+            "/chromium-datasets",
+            chromium_datasets::router(upload_limits),
+        )
+        .nest("/search", search::router())
+        .nest("/events", events::router())
+        .nest("/stats", stats::router())
+        .nest("/api-keys", api_keys::router())
+        .nest("/webhooks", webhooks::router())
 }
 
 type ApiResponse<T> = Result<(StatusCode, Json<T>), super::error::ErrorResponse>;
 
+/// A [`Page`] whose items may be shaped by the `fields` and `include` of the
+/// request
+type ListResponse<Row, Include = NoInclude> =
+    Result<JsonResponse<Page<Shaped<Row, Include>>>, super::error::ErrorResponse>;
+
+/// Every row of a list that's too small to need paging
+type UnpagedListResponse<Row> = Result<JsonResponse<Vec<Row>>, super::error::ErrorResponse>;
+
+/// An item that may be shaped by the `fields` and `include` of the request
+type FetchResponse<Item, Include = NoInclude> =
+    Result<JsonResponse<Shaped<Item, Include>>, super::error::ErrorResponse>;
+
 #[derive(TypedPath)]
 #[typed_path("/")]
 struct Root;
@@ -58,10 +106,149 @@ where
     tracing::info!("{request:?}");
 
     let db_conn = state.db_conn().await?;
+    let queued_at = Instant::now();
+    let span = db::operation_span::<Request>();
 
     db_conn
-        .interact(move |db_conn| request.execute_as_user(user.id(), db_conn))
+        .interact(move |db_conn| {
+            let _span = span.entered();
+            metrics::record_interact_wait(queued_at);
+            request.execute_as_user(user.id(), db_conn)
+        })
         .await?
         .map(Json)
         .map_err(ErrorResponse::from)
 }
+
+/// Respond with a [`Page`] of JSON, or stream every row if the client asked for
+/// an [`ExportFormat`]. Exports ignore paging and can't `include` related
+/// objects.
+async fn list_handler<Request, Row>(
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    mut request: Request,
+    format: Option<ExportFormat>,
+) -> ListResponse<Row, Request::Include>
+where
+    Request: std::fmt::Debug
+        + db::Operation<Page<Row>>
+        + db::Export<Row = Row>
+        + WithReadOptions
+        + Send
+        + 'static,
+    Row: std::fmt::Debug + Serialize + Send + 'static,
+{
+    let options = request.take_read_options();
+
+    if let Some(format) = format {
+        return export::stream_rows(state.0, user, request, format, options)
+            .await
+            .map(JsonResponse::from);
+    }
+
+    if options.is_empty() {
+        let page = inner_handler(state, user, request).await?;
+        return Ok((StatusCode::OK, page).into_response().into());
+    }
+
+    let page: Json<Page<Object>> = inner_handler(
+        state,
+        user,
+        ShapedRequest::<_, _, Page<Row>>::new(request, options),
+    )
+    .await?;
+    Ok((StatusCode::OK, page).into_response().into())
+}
+
+/// Respond with every row of a list that's too small to need paging, or stream
+/// them if the client asked for an [`ExportFormat`]
+async fn unpaged_list_handler<Request, Row>(
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    request: Request,
+    format: Option<ExportFormat>,
+) -> UnpagedListResponse<Row>
+where
+    Request: std::fmt::Debug + db::Operation<Vec<Row>> + Send + 'static,
+    Row: std::fmt::Debug + Serialize + Send + 'static,
+{
+    if let Some(format) = format {
+        let request = Unpaged {
+            request,
+            row: PhantomData,
+        };
+        return export::stream_rows(
+            state.0,
+            user,
+            request,
+            format,
+            ReadOptions::<NoInclude>::default(),
+        )
+        .await
+        .map(JsonResponse::from);
+    }
+
+    let rows = inner_handler(state, user, request).await?;
+    Ok((StatusCode::OK, rows).into_response().into())
+}
+
+/// Exports an unpaged list. Its rows are loaded all at once and then written
+/// out, rather than streamed from Postgres
+struct Unpaged<Request, Row> {
+    request: Request,
+    row: PhantomData<fn() -> Row>,
+}
+
+impl<Request: std::fmt::Debug, Row> std::fmt::Debug for Unpaged<Request, Row> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.request.fmt(f)
+    }
+}
+
+impl<Request, Row> db::Export for Unpaged<Request, Row>
+where
+    Request: db::Operation<Vec<Row>>,
+{
+    type Row = Row;
+
+    fn export(
+        self,
+        db_conn: &mut diesel::PgConnection,
+        on_row: &mut dyn FnMut(Row) -> bool,
+    ) -> Result<(), db::Error> {
+        for row in self.request.execute(db_conn)? {
+            if !on_row(row) {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Respond with a single item, trimmed and with related objects embedded
+/// according to `options`
+async fn fetch_handler<Request, Item, Include>(
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    request: Request,
+    options: ReadOptions<Include>,
+) -> FetchResponse<Item, Include>
+where
+    Request: std::fmt::Debug + db::Operation<Item> + Send + 'static,
+    Item: std::fmt::Debug + Serialize + Send + 'static,
+    Include: Relation,
+{
+    if options.is_empty() {
+        let item: Json<Item> = inner_handler(state, user, request).await?;
+        return Ok((StatusCode::OK, item).into_response().into());
+    }
+
+    let item: Json<Object> = inner_handler(
+        state,
+        user,
+        ShapedRequest::<_, _, Item>::new(request, options),
+    )
+    .await?;
+    Ok((StatusCode::OK, item).into_response().into())
+}
@@ -0,0 +1,131 @@
+use std::fmt::Debug;
+
+use axum::{Json, extract::State, http::StatusCode};
+use cellnoor_models::api_key::{ApiKey, ApiKeyPrefix, CreatedApiKey};
+use cellnoor_schema::api_keys;
+use diesel::prelude::*;
+use jiff::Timestamp;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        export::ExportFormat,
+        extract::auth::{AuthenticatedUser, generate_api_key},
+        openapi::ApiRouter,
+        routes::{ApiResponse, Root, UnpagedListResponse, inner_handler, unpaged_list_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
+        .typed_post(create_api_key)
+        .typed_get(list_api_keys)
+        .typed_delete(delete_api_key)
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = api_keys, check_for_backend(diesel::pg::Pg))]
+struct NewApiKey {
+    prefix: Vec<u8>,
+    hash: String,
+    user_id: Uuid,
+}
+
+// Only the prefix is logged
+impl Debug for NewApiKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NewApiKey")
+            .field("prefix", &hex(&self.prefix))
+            .field("user_id", &self.user_id)
+            .finish_non_exhaustive()
+    }
+}
+
+async fn create_api_key(
+    _: Root,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+) -> ApiResponse<CreatedApiKey> {
+    let (api_key, hash) = generate_api_key();
+    let prefix = api_key[..state.api_key_prefix_length()].to_vec();
+
+    let request = NewApiKey {
+        prefix,
+        hash,
+        user_id: user.id(),
+    };
+    let prefix = hex(&request.prefix);
+    let created_at: Timestamp = inner_handler(state, user, request).await?.0;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedApiKey::new(hex(&api_key), prefix, created_at)),
+    ))
+}
+
+impl db::Operation<Timestamp> for NewApiKey {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<Timestamp, db::Error> {
+        diesel::insert_into(api_keys::table)
+            .values(self)
+            .returning(api_keys::created_at)
+            .get_result(db_conn)
+            .map(jiff_diesel::Timestamp::to_jiff)
+            .map_err(db::Error::from)
+    }
+}
+
+#[derive(Debug)]
+struct UserApiKeys(Uuid);
+
+// Not paged, since these are only the caller's own keys
+async fn list_api_keys(
+    _: Root,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    format: Option<ExportFormat>,
+) -> UnpagedListResponse<ApiKey> {
+    let request = UserApiKeys(user.id());
+    unpaged_list_handler(state, user, request, format).await
+}
+
+impl db::Operation<Vec<ApiKey>> for UserApiKeys {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<Vec<ApiKey>, db::Error> {
+        let keys: Vec<(Vec<u8>, jiff_diesel::Timestamp)> = api_keys::table
+            .select((api_keys::prefix, api_keys::created_at))
+            .filter(api_keys::user_id.eq(self.0))
+            .order_by(api_keys::created_at)
+            .load(db_conn)?;
+
+        Ok(keys
+            .into_iter()
+            .map(|(prefix, created_at)| ApiKey::new(hex(&prefix), created_at.to_jiff()))
+            .collect())
+    }
+}
+
+// Row-level security means that a person can only delete their own API keys
+async fn delete_api_key(
+    prefix: ApiKeyPrefix,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+) -> ApiResponse<()> {
+    Ok((StatusCode::OK, inner_handler(state, user, prefix).await?))
+}
+
+impl db::Operation<()> for ApiKeyPrefix {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<(), db::Error> {
+        let prefix = base16ct::mixed::decode_vec(self.0.as_ref()).map_err(|_| db::Error::Data {
+            message: format!("API key prefix {} is not hex-encoded", self.0),
+        })?;
+
+        diesel::delete(api_keys::table.filter(api_keys::prefix.eq(prefix))).execute(db_conn)?;
+
+        Ok(())
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    base16ct::lower::encode_string(bytes)
+}
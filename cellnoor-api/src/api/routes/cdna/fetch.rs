@@ -1,12 +1,15 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::cdna::{Cdna, CdnaId};
+use axum::extract::State;
+use cellnoor_models::{
+    cdna::{Cdna, CdnaId},
+    read_options::{NoInclude, ReadOptions},
+};
 use cellnoor_schema::cdna;
 use diesel::{PgConnection, prelude::*};
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{FetchResponse, fetch_handler},
     },
     db,
     state::AppState,
@@ -16,9 +19,9 @@ pub(super) async fn fetch_cdna(
     request: CdnaId,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Cdna> {
-    let item = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, item))
+    QsQuery(options): QsQuery<ReadOptions<NoInclude>>,
+) -> FetchResponse<Cdna> {
+    fetch_handler(state, user, request, options).await
 }
 
 impl db::Operation<Cdna> for CdnaId {
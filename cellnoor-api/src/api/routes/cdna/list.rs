@@ -1,12 +1,17 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::cdna::{CdnaFilter, CdnaQuery, CdnaSummary};
-use cellnoor_schema::cdna::dsl::id;
+use axum::extract::State;
+use cellnoor_models::{
+    cdna::{CdnaFilter, CdnaQuery, CdnaSummary},
+    generic_query::Keyset,
+};
+use cellnoor_schema::cdna::dsl::{additional_data, id};
 use diesel::{SelectableExpression, prelude::*};
+use uuid::Uuid;
 
 use crate::{
     api::{
+        export::ExportFormat,
         extract::{auth::AuthenticatedUser, query::QsQuery},
-        routes::{ApiResponse, Root, inner_handler},
+        routes::{ListResponse, Root, list_handler},
     },
     db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter},
     state::AppState,
@@ -17,47 +22,74 @@ pub(super) async fn list_cdna(
     _: Root,
     state: State<AppState>,
     user: AuthenticatedUser,
+    format: Option<ExportFormat>,
     QsQuery(request): QsQuery<CdnaQuery>,
-) -> ApiResponse<Vec<CdnaSummary>> {
-    let items = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, items))
+) -> ListResponse<CdnaSummary> {
+    list_handler(state, user, request, format).await
 }
 
-impl db::Operation<Vec<CdnaSummary>> for CdnaQuery {
-    fn execute(self, db_conn: &mut PgConnection) -> Result<Vec<CdnaSummary>, db::Error> {
-        let Self {
+impl db::List for CdnaQuery {
+    type Row = CdnaSummary;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, CdnaSummary> {
+        let CdnaQuery {
             filter,
             limit,
             offset,
             order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
         } = self;
 
         let mut stmt = CdnaSummary::query()
-            .limit(limit)
-            .offset(offset)
             .filter(filter.to_boxed_filter())
             .into_boxed();
 
-        for ordering in order_by.as_ref() {
-            stmt = stmt.then_order_by(ordering);
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
+        }
+
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(id);
         }
 
-        Ok(stmt.load(db_conn)?)
+        stmt
+    }
+
+    fn id(row: &CdnaSummary) -> Uuid {
+        row.id()
     }
 }
 
 impl<'a, QS: 'a> ToBoxedFilter<'a, QS> for CdnaFilter
 where
     id: SelectableExpression<QS>,
+    additional_data: SelectableExpression<QS>,
 {
     fn to_boxed_filter(&'a self) -> BoxedFilter<'a, QS> {
-        let Self { ids } = self;
+        let Self {
+            ids,
+            additional_data_predicates,
+        } = self;
         let mut filter = BoxedFilter::new_true();
 
         if let Some(ids) = ids {
             filter = filter.and_condition(id.eq_any(ids));
         }
 
+        for predicate in additional_data_predicates.iter().flatten() {
+            filter = filter.and_condition(predicate.matches(additional_data));
+        }
+
         filter
     }
 }
@@ -2,24 +2,25 @@ use axum::extract::State;
 use cellnoor_models::cdna::{CdnaIdMeasurements, measurement::CdnaMeasurement};
 use cellnoor_schema::cdna_measurements;
 use diesel::prelude::*;
-use reqwest::StatusCode;
 
 use crate::{
     api::{
+        export::ExportFormat,
         extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        routes::{UnpagedListResponse, unpaged_list_handler},
     },
     db::{self},
     state::AppState,
 };
 
+// Not paged, since each cDNA is only QC'd a handful of times
 pub async fn list_measurements(
     cdna_id: CdnaIdMeasurements,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Vec<CdnaMeasurement>> {
-    let item = inner_handler(state, user, cdna_id).await?;
-    Ok((StatusCode::OK, item))
+    format: Option<ExportFormat>,
+) -> UnpagedListResponse<CdnaMeasurement> {
+    unpaged_list_handler(state, user, cdna_id, format).await
 }
 
 impl db::Operation<Vec<CdnaMeasurement>> for CdnaIdMeasurements {
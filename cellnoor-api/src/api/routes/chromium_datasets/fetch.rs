@@ -1,5 +1,8 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::chromium_dataset::{ChromiumDataset, ChromiumDatasetId};
+use axum::extract::State;
+use cellnoor_models::{
+    chromium_dataset::{ChromiumDataset, ChromiumDatasetId, ChromiumDatasetInclude},
+    read_options::ReadOptions,
+};
 use cellnoor_schema::{
     cdna, chromium_dataset_libraries, chromium_datasets, chromium_runs, gem_pools, labs, libraries,
     tenx_assays,
@@ -8,8 +11,8 @@ use diesel::prelude::*;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{FetchResponse, fetch_handler},
     },
     db,
     state::AppState,
@@ -19,9 +22,9 @@ pub(super) async fn fetch_chromium_dataset(
     request: ChromiumDatasetId,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<ChromiumDataset> {
-    let item = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, item))
+    QsQuery(options): QsQuery<ReadOptions<ChromiumDatasetInclude>>,
+) -> FetchResponse<ChromiumDataset, ChromiumDatasetInclude> {
+    fetch_handler(state, user, request, options).await
 }
 
 impl db::Operation<ChromiumDataset> for ChromiumDatasetId {
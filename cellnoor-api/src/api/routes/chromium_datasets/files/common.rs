@@ -66,6 +66,7 @@ fn extract_content_type(
                 message: "file-upload must have content-type".to_owned(),
             },
             internal_error: None,
+            request_id: None,
         });
     };
 
@@ -79,6 +80,7 @@ fn extract_content_type(
                 ),
             },
             internal_error: None,
+            request_id: None,
         });
     }
 
@@ -93,6 +95,7 @@ fn extract_path(filename: Option<&str>) -> Result<(&str, &str), ErrorResponse> {
                 message: "file-upload must have filename".to_owned(),
             },
             internal_error: None,
+            request_id: None,
         });
     };
 
@@ -110,6 +113,7 @@ fn extract_path(filename: Option<&str>) -> Result<(&str, &str), ErrorResponse> {
                     message: "filename must be of the form 'directory/filename'".to_owned(),
                 },
                 internal_error: None,
+                request_id: None,
             });
         };
 
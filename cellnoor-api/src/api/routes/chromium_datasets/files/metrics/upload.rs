@@ -49,6 +49,7 @@ pub async fn upload_metrics_file(
                     message: format!("error parsing JSON: {e}"),
                 },
                 internal_error: None,
+                request_id: None,
             })?
         } else {
             parse_single_row_csv(content)
@@ -58,6 +59,7 @@ pub async fn upload_metrics_file(
                     status: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
                     public_error: e,
                     internal_error: None,
+                    request_id: None,
                 })?
         };
 
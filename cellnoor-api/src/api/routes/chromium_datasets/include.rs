@@ -0,0 +1,33 @@
+use cellnoor_models::{
+    chromium_dataset::ChromiumDatasetInclude, lab::LabSummary, library::LibrarySummary,
+};
+use cellnoor_schema::{chromium_dataset_libraries, labs, libraries};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    api::read_options::{Object, Relation, embed_foreign_key, embed_many},
+    db,
+};
+
+impl Relation for ChromiumDatasetInclude {
+    fn embed(self, items: &mut [Object], db_conn: &mut PgConnection) -> Result<(), db::Error> {
+        match self {
+            Self::Lab => embed_foreign_key(items, "lab_id", "lab", |ids| {
+                Ok(LabSummary::query()
+                    .filter(labs::id.eq_any(ids))
+                    .load::<LabSummary>(db_conn)?)
+            }),
+            Self::Libraries => embed_many(items, "libraries", |ids| {
+                Ok(chromium_dataset_libraries::table
+                    .inner_join(libraries::table)
+                    .filter(chromium_dataset_libraries::dataset_id.eq_any(ids))
+                    .select((
+                        chromium_dataset_libraries::dataset_id,
+                        LibrarySummary::as_select(),
+                    ))
+                    .load::<(Uuid, LibrarySummary)>(db_conn)?)
+            }),
+        }
+    }
+}
@@ -1,14 +1,20 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::{chromium_dataset::ChromiumDatasetIdLibraries, library::LibrarySummary};
-use cellnoor_schema::{chromium_dataset_libraries, chromium_datasets, libraries};
+use axum::extract::State;
+use cellnoor_models::{
+    chromium_dataset::ChromiumDatasetIdLibraries,
+    generic_query::Keyset,
+    library::{LibraryQuery, LibrarySummary},
+};
+use cellnoor_schema::{chromium_dataset_libraries, libraries};
 use diesel::prelude::*;
+use uuid::Uuid;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, list_handler},
     },
-    db,
+    db::{self, ToBoxedFilter},
     state::AppState,
 };
 
@@ -16,19 +22,56 @@ pub async fn list_libraries(
     dataset_id: ChromiumDatasetIdLibraries,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Vec<LibrarySummary>> {
-    Ok((
-        StatusCode::OK,
-        inner_handler(state, user, dataset_id).await?,
-    ))
+    format: Option<ExportFormat>,
+    QsQuery(request): QsQuery<LibraryQuery>,
+) -> ListResponse<LibrarySummary> {
+    list_handler(state, user, (dataset_id, request), format).await
 }
 
-impl db::Operation<Vec<LibrarySummary>> for ChromiumDatasetIdLibraries {
-    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<Vec<LibrarySummary>, db::Error> {
-        Ok(chromium_datasets::table
-            .inner_join(chromium_dataset_libraries::table.inner_join(libraries::table))
+impl db::List for (ChromiumDatasetIdLibraries, LibraryQuery) {
+    type Row = LibrarySummary;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, LibrarySummary> {
+        let (dataset_id, query) = self;
+
+        let LibraryQuery {
+            limit,
+            offset,
+            filter,
+            order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
+        } = query;
+
+        let mut stmt = chromium_dataset_libraries::table
+            .filter(chromium_dataset_libraries::dataset_id.eq(dataset_id))
+            .inner_join(libraries::table)
             .select(LibrarySummary::as_select())
-            .filter(chromium_datasets::id.eq(self))
-            .load(db_conn)?)
+            .into_boxed();
+
+        stmt = stmt.filter(filter.to_boxed_filter());
+
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
+        }
+
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(libraries::id);
+        }
+
+        stmt
+    }
+
+    fn id(row: &LibrarySummary) -> Uuid {
+        row.id()
     }
 }
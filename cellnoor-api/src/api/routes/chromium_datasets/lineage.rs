@@ -0,0 +1,168 @@
+use axum::extract::State;
+use cellnoor_models::{
+    chromium_dataset::ChromiumDatasetIdLineage,
+    lineage::{Lineage, LineageNodeType, LineageQuery},
+};
+use cellnoor_schema::{
+    cdna, chip_loadings, chromium_dataset_libraries, chromium_datasets, gem_pools, libraries,
+    specimens, suspension_pools, suspension_tagging, suspensions,
+};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{
+            FetchResponse,
+            lineage::{add_sequencing_runs, lineage_response},
+        },
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn fetch_lineage(
+    dataset_id: ChromiumDatasetIdLineage,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    QsQuery(query): QsQuery<LineageQuery>,
+) -> FetchResponse<Lineage> {
+    lineage_response(state, user, dataset_id, query).await
+}
+
+// Walk upstream from the dataset, one table at a time. Sequencing runs of the
+// dataset's libraries are included, but other datasets made from the same
+// libraries are not
+impl db::Operation<Lineage> for ChromiumDatasetIdLineage {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<Lineage, db::Error> {
+        use LineageNodeType::{Cdna, ChromiumDataset, GemPool, Library};
+
+        let mut lineage = Lineage::default();
+
+        let (dataset_id, dataset_name): (Uuid, String) = chromium_datasets::table
+            .select((chromium_datasets::id, chromium_datasets::name))
+            .filter(chromium_datasets::id.eq(self))
+            .first(db_conn)?;
+        lineage.add_node(ChromiumDataset, dataset_id, dataset_name);
+
+        let libraries: Vec<(Uuid, String, Uuid)> = chromium_dataset_libraries::table
+            .inner_join(libraries::table)
+            .select((libraries::id, libraries::readable_id, libraries::cdna_id))
+            .filter(chromium_dataset_libraries::dataset_id.eq(dataset_id))
+            .load(db_conn)?;
+        let mut library_ids = Vec::with_capacity(libraries.len());
+        let mut cdna_ids = Vec::with_capacity(libraries.len());
+        for (library_id, readable_id, cdna_id) in libraries {
+            lineage.add_node(Library, library_id, readable_id);
+            lineage.add_edge(cdna_id, library_id);
+            lineage.add_edge(library_id, dataset_id);
+            library_ids.push(library_id);
+            cdna_ids.push(cdna_id);
+        }
+
+        add_sequencing_runs(&mut lineage, &library_ids, db_conn)?;
+
+        let cdna: Vec<(Uuid, String, Option<Uuid>)> = cdna::table
+            .select((cdna::id, cdna::readable_id, cdna::gem_pool_id))
+            .filter(cdna::id.eq_any(&cdna_ids))
+            .load(db_conn)?;
+        let mut gem_pool_ids = Vec::with_capacity(cdna.len());
+        for (cdna_id, readable_id, gem_pool_id) in cdna {
+            lineage.add_node(Cdna, cdna_id, readable_id);
+            if let Some(gem_pool_id) = gem_pool_id {
+                lineage.add_edge(gem_pool_id, cdna_id);
+                gem_pool_ids.push(gem_pool_id);
+            }
+        }
+
+        let gem_pools: Vec<(Uuid, String)> = gem_pools::table
+            .select((gem_pools::id, gem_pools::readable_id))
+            .filter(gem_pools::id.eq_any(&gem_pool_ids))
+            .load(db_conn)?;
+        for (gem_pool_id, readable_id) in gem_pools {
+            lineage.add_node(GemPool, gem_pool_id, readable_id);
+        }
+
+        add_ancestors_of_gem_pools(&mut lineage, &gem_pool_ids, db_conn)?;
+
+        Ok(lineage)
+    }
+}
+
+fn add_ancestors_of_gem_pools(
+    lineage: &mut Lineage,
+    gem_pool_ids: &[Uuid],
+    db_conn: &mut PgConnection,
+) -> Result<(), db::Error> {
+    use LineageNodeType::{ChipLoading, Specimen, Suspension, SuspensionPool};
+
+    let loadings: Vec<(Uuid, Uuid, Option<Uuid>, Option<Uuid>)> = chip_loadings::table
+        .select((
+            chip_loadings::id,
+            chip_loadings::gem_pool_id,
+            chip_loadings::suspension_id,
+            chip_loadings::suspension_pool_id,
+        ))
+        .filter(chip_loadings::gem_pool_id.eq_any(gem_pool_ids))
+        .load(db_conn)?;
+    let mut suspension_ids = Vec::with_capacity(loadings.len());
+    let mut pool_ids = Vec::with_capacity(loadings.len());
+    for (loading_id, gem_pool_id, suspension_id, pool_id) in loadings {
+        lineage.add_node(ChipLoading, loading_id, loading_id.to_string());
+        lineage.add_edge(loading_id, gem_pool_id);
+        if let Some(suspension_id) = suspension_id {
+            lineage.add_edge(suspension_id, loading_id);
+            suspension_ids.push(suspension_id);
+        }
+        if let Some(pool_id) = pool_id {
+            lineage.add_edge(pool_id, loading_id);
+            pool_ids.push(pool_id);
+        }
+    }
+
+    let pools: Vec<(Uuid, String)> = suspension_pools::table
+        .select((suspension_pools::id, suspension_pools::readable_id))
+        .filter(suspension_pools::id.eq_any(&pool_ids))
+        .load(db_conn)?;
+    for (pool_id, readable_id) in pools {
+        lineage.add_node(SuspensionPool, pool_id, readable_id);
+    }
+
+    let taggings: Vec<(Uuid, Uuid)> = suspension_tagging::table
+        .select((
+            suspension_tagging::suspension_id,
+            suspension_tagging::pool_id,
+        ))
+        .filter(suspension_tagging::pool_id.eq_any(&pool_ids))
+        .load(db_conn)?;
+    for (suspension_id, pool_id) in taggings {
+        lineage.add_edge(suspension_id, pool_id);
+        suspension_ids.push(suspension_id);
+    }
+
+    let suspensions: Vec<(Uuid, String, Uuid)> = suspensions::table
+        .select((
+            suspensions::id,
+            suspensions::readable_id,
+            suspensions::parent_specimen_id,
+        ))
+        .filter(suspensions::id.eq_any(&suspension_ids))
+        .load(db_conn)?;
+    let mut specimen_ids = Vec::with_capacity(suspensions.len());
+    for (suspension_id, readable_id, specimen_id) in suspensions {
+        lineage.add_node(Suspension, suspension_id, readable_id);
+        lineage.add_edge(specimen_id, suspension_id);
+        specimen_ids.push(specimen_id);
+    }
+
+    let specimens: Vec<(Uuid, String)> = specimens::table
+        .select((specimens::id, specimens::readable_id))
+        .filter(specimens::id.eq_any(&specimen_ids))
+        .load(db_conn)?;
+    for (specimen_id, readable_id) in specimens {
+        lineage.add_node(Specimen, specimen_id, readable_id);
+    }
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::chromium_dataset::{
-    ChromiumDatasetFilter, ChromiumDatasetQuery, ChromiumDatasetSummary,
+use axum::extract::State;
+use cellnoor_models::{
+    chromium_dataset::{
+        ChromiumDatasetFilter, ChromiumDatasetInclude, ChromiumDatasetQuery, ChromiumDatasetSummary,
+    },
+    generic_query::Keyset,
 };
 use cellnoor_schema::{
     cdna::dsl::cdna,
@@ -10,6 +13,7 @@ use cellnoor_schema::{
     chromium_runs::dsl::chromium_runs,
     gem_pools::dsl::gem_pools,
     libraries::dsl::libraries,
+    project_chromium_datasets,
     specimens::{self, table as specimens_table},
     suspension_pools::dsl::suspension_pools,
     suspension_tagging::dsl::suspension_tagging,
@@ -19,11 +23,13 @@ use cellnoor_schema::{
 use diesel::{dsl::AssumeNotNull, prelude::*};
 use jiff::Timestamp;
 use jiff_diesel::ToDiesel;
+use uuid::Uuid;
 
 use crate::{
     api::{
+        export::ExportFormat,
         extract::{auth::AuthenticatedUser, query::QsQuery},
-        routes::{ApiResponse, Root, inner_handler},
+        routes::{ListResponse, Root, list_handler},
     },
     db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter, utils::like_any},
     state::AppState,
@@ -33,35 +39,52 @@ pub async fn list_chromium_datasets(
     _: Root,
     state: State<AppState>,
     user: AuthenticatedUser,
+    format: Option<ExportFormat>,
     QsQuery(query): QsQuery<ChromiumDatasetQuery>,
-) -> ApiResponse<Vec<ChromiumDatasetSummary>> {
-    Ok((StatusCode::OK, inner_handler(state, user, query).await?))
+) -> ListResponse<ChromiumDatasetSummary, ChromiumDatasetInclude> {
+    list_handler(state, user, query, format).await
 }
 
-impl db::Operation<Vec<ChromiumDatasetSummary>> for ChromiumDatasetQuery {
-    fn execute(
-        self,
-        db_conn: &mut diesel::PgConnection,
-    ) -> Result<Vec<ChromiumDatasetSummary>, db::Error> {
-        let Self {
+impl db::List for ChromiumDatasetQuery {
+    type Row = ChromiumDatasetSummary;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, ChromiumDatasetSummary> {
+        let ChromiumDatasetQuery {
             filter,
             limit,
             offset,
             order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
         } = self;
 
         let mut stmt = chromium_datasets_to_all_specimens()
             .select(ChromiumDatasetSummary::as_select())
-            .limit(limit)
-            .offset(offset)
             .filter(filter.to_boxed_filter())
             .into_boxed();
 
-        for ordering in order_by.as_ref() {
-            stmt = stmt.then_order_by(ordering);
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
+        }
+
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(id);
         }
 
-        Ok(stmt.load(db_conn)?)
+        stmt
+    }
+
+    fn id(row: &ChromiumDatasetSummary) -> Uuid {
+        row.id()
     }
 }
 
@@ -101,6 +124,7 @@ where
     AssumeNotNull<specimens::name>: SelectableExpression<QS>,
     AssumeNotNull<specimens::submitted_by>: SelectableExpression<QS>,
     AssumeNotNull<specimens::lab_id>: SelectableExpression<QS>,
+    AssumeNotNull<specimens::donor_id>: SelectableExpression<QS>,
     AssumeNotNull<specimens::received_at>: SelectableExpression<QS>,
     AssumeNotNull<specimens::species>: SelectableExpression<QS>,
     AssumeNotNull<specimens::host_species>: SelectableExpression<QS>,
@@ -129,6 +153,7 @@ where
             specimen,
             assay,
             lab_ids,
+            project_ids,
             delivered_before,
             delivered_after,
         } = self;
@@ -154,6 +179,14 @@ where
             filter = filter.and_condition(lab_id.eq_any(lab_ids));
         }
 
+        if let Some(project_ids) = project_ids {
+            let project_datasets = project_chromium_datasets::table
+                .select(project_chromium_datasets::dataset_id)
+                .filter(project_chromium_datasets::project_id.eq_any(project_ids))
+                .into_boxed();
+            filter = filter.and_condition(id.eq_any(project_datasets));
+        }
+
         if let Some(delivered_before) = delivered_before.map(Timestamp::to_diesel) {
             filter = filter.and_condition(delivered_at.lt(delivered_before));
         }
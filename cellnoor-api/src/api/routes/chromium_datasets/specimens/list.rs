@@ -1,17 +1,23 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::{chromium_dataset::ChromiumDatasetIdSpecimens, specimen::SpecimenSummary};
+use axum::extract::State;
+use cellnoor_models::{
+    chromium_dataset::ChromiumDatasetIdSpecimens,
+    generic_query::Keyset,
+    specimen::{SpecimenInclude, SpecimenQuery, SpecimenSummary},
+};
 use cellnoor_schema::{
     cdna, chip_loadings, chromium_dataset_libraries, chromium_datasets, gem_pools, libraries,
     specimens, suspension_pools, suspension_tagging, suspensions,
 };
 use diesel::prelude::*;
+use uuid::Uuid;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, list_handler},
     },
-    db,
+    db::{self, ToBoxedFilter},
     state::AppState,
 };
 
@@ -19,37 +25,70 @@ pub async fn list_specimens(
     dataset_id: ChromiumDatasetIdSpecimens,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Vec<SpecimenSummary>> {
-    Ok((
-        StatusCode::OK,
-        inner_handler(state, user, dataset_id).await?,
-    ))
+    format: Option<ExportFormat>,
+    QsQuery(request): QsQuery<SpecimenQuery>,
+) -> ListResponse<SpecimenSummary, SpecimenInclude> {
+    list_handler(state, user, (dataset_id, request), format).await
 }
 
-impl db::Operation<Vec<SpecimenSummary>> for ChromiumDatasetIdSpecimens {
-    fn execute(
-        self,
-        db_conn: &mut diesel::PgConnection,
-    ) -> Result<Vec<SpecimenSummary>, db::Error> {
-        let filter = chromium_datasets::id.eq(&self);
-        let ordering = specimens::received_at;
-
-        let mut specimens = chromium_datasets_to_pooled_specimens()
-            .select(SpecimenSummary::as_select())
-            .filter(filter)
-            .order_by(ordering)
-            .load(db_conn)?;
-
-        // If we couldn't find pooled specimens, then we know they weren't pooled
-        if specimens.is_empty() {
-            specimens = chromium_datasets_to_unpooled_specimens()
-                .select(SpecimenSummary::as_select())
-                .filter(filter)
-                .order_by(ordering)
-                .load(db_conn)?;
+impl db::List for (ChromiumDatasetIdSpecimens, SpecimenQuery) {
+    type Row = SpecimenSummary;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, SpecimenSummary> {
+        let (dataset_id, query) = self;
+
+        let SpecimenQuery {
+            limit,
+            offset,
+            filter,
+            order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
+        } = query;
+
+        // A dataset's specimens were either pooled or loaded directly, so only
+        // one of these will match anything
+        let unpooled = chromium_datasets_to_unpooled_specimens()
+            .filter(chromium_datasets::id.eq(dataset_id))
+            .select(specimens::id)
+            .into_boxed();
+        let pooled = chromium_datasets_to_pooled_specimens()
+            .filter(chromium_datasets::id.eq(dataset_id))
+            .select(specimens::id)
+            .into_boxed();
+
+        let mut stmt = SpecimenSummary::query()
+            .filter(
+                specimens::id
+                    .eq_any(unpooled)
+                    .or(specimens::id.eq_any(pooled)),
+            )
+            .into_boxed();
+
+        stmt = stmt.filter(filter.to_boxed_filter());
+
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
         }
 
-        Ok(specimens)
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(specimens::id);
+        }
+
+        stmt
+    }
+
+    fn id(row: &SpecimenSummary) -> Uuid {
+        row.id()
     }
 }
 
@@ -87,8 +126,10 @@ mod tests {
     use cellnoor_models::{
         chromium_dataset::{
             ChromiumDatasetFilter, ChromiumDatasetIdSpecimens, ChromiumDatasetQuery,
+            ChromiumDatasetSummary,
         },
         chromium_run::MAX_SUSPENSIONS_PER_OCM_GEM_POOL,
+        specimen::{SpecimenQuery, SpecimenSummary},
         tenx_assay::{SampleMultiplexing, TenxAssayFilter},
     };
     use deadpool_diesel::postgres::Connection;
@@ -112,15 +153,18 @@ mod tests {
             )
             .build();
 
-        let ds = db_conn
+        let mut datasets: Vec<ChromiumDatasetSummary> = db_conn
             .interact(move |db_conn| q.execute(db_conn).unwrap())
             .await
-            .unwrap()
-            .remove(0);
+            .unwrap();
+        let ds = datasets.remove(0);
 
-        let query = ChromiumDatasetIdSpecimens(ds.id());
+        let query = (
+            ChromiumDatasetIdSpecimens(ds.id()),
+            SpecimenQuery::default_with_no_limit(),
+        );
 
-        let specimens = db_conn
+        let specimens: Vec<SpecimenSummary> = db_conn
             .interact(move |db_conn| query.execute(db_conn).unwrap())
             .await
             .unwrap();
@@ -1,14 +1,11 @@
-use axum::Router;
-use axum_extra::routing::RouterExt;
-
-use crate::state::AppState;
+use crate::api::openapi::ApiRouter;
 
 mod create;
 mod fetch;
 mod list;
 
-pub(super) fn router() -> Router<AppState> {
-    Router::new()
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
         .typed_post(create::create_chromium_run)
         .typed_get(fetch::fetch_chromium_run)
         .typed_get(list::list_chromium_runs)
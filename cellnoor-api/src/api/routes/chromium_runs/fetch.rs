@@ -1,12 +1,15 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::chromium_run::{ChromiumRun, ChromiumRunId};
+use axum::extract::State;
+use cellnoor_models::{
+    chromium_run::{ChromiumRun, ChromiumRunId},
+    read_options::{NoInclude, ReadOptions},
+};
 use cellnoor_schema::chromium_runs::dsl::*;
 use diesel::prelude::*;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{FetchResponse, fetch_handler},
     },
     db,
     state::AppState,
@@ -16,9 +19,9 @@ pub(super) async fn fetch_chromium_run(
     request: ChromiumRunId,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<ChromiumRun> {
-    let item = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, item))
+    QsQuery(options): QsQuery<ReadOptions<NoInclude>>,
+) -> FetchResponse<ChromiumRun> {
+    fetch_handler(state, user, request, options).await
 }
 
 impl db::Operation<ChromiumRun> for ChromiumRunId {
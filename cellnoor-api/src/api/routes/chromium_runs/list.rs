@@ -1,13 +1,17 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::chromium_run::{ChromiumRunFilter, ChromiumRunQuery, ChromiumRunSummary};
+use axum::extract::State;
+use cellnoor_models::{
+    chromium_run::{ChromiumRunFilter, ChromiumRunQuery, ChromiumRunSummary},
+    generic_query::Keyset,
+};
 use cellnoor_schema::chromium_runs::dsl::*;
 use diesel::prelude::*;
-use serde_qs::axum::QsQuery;
+use uuid::Uuid;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, Root, inner_handler},
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, Root, list_handler},
     },
     db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter},
     state::AppState,
@@ -17,50 +21,74 @@ pub(super) async fn list_chromium_runs(
     _: Root,
     state: State<AppState>,
     user: AuthenticatedUser,
+    format: Option<ExportFormat>,
     QsQuery(request): QsQuery<ChromiumRunQuery>,
-) -> ApiResponse<Vec<ChromiumRunSummary>> {
-    let items = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, items))
+) -> ListResponse<ChromiumRunSummary> {
+    list_handler(state, user, request, format).await
 }
 
-impl db::Operation<Vec<ChromiumRunSummary>> for ChromiumRunQuery {
-    fn execute(
-        self,
-        db_conn: &mut diesel::PgConnection,
-    ) -> Result<Vec<ChromiumRunSummary>, db::Error> {
-        let Self {
+impl db::List for ChromiumRunQuery {
+    type Row = ChromiumRunSummary;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, ChromiumRunSummary> {
+        let ChromiumRunQuery {
             filter,
             limit,
             offset,
             order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
         } = self;
 
         let mut stmt = ChromiumRunSummary::query()
-            .limit(limit)
-            .offset(offset)
             .filter(filter.to_boxed_filter())
             .into_boxed();
 
-        for ordering in order_by.as_ref() {
-            stmt = stmt.then_order_by(ordering);
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
+        }
+
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(id);
         }
 
-        Ok(stmt.load(db_conn)?)
+        stmt
+    }
+
+    fn id(row: &ChromiumRunSummary) -> Uuid {
+        row.id()
     }
 }
 
 impl<'a, QS: 'a> ToBoxedFilter<'a, QS> for ChromiumRunFilter
 where
     id: SelectableExpression<QS>,
+    additional_data: SelectableExpression<QS>,
 {
     fn to_boxed_filter(&'a self) -> crate::db::BoxedFilter<'a, QS> {
-        let Self { ids } = self;
+        let Self {
+            ids,
+            additional_data_predicates,
+        } = self;
         let mut filter = BoxedFilter::new_true();
 
         if let Some(ids) = ids {
             filter = filter.and_condition(id.eq_any(ids));
         }
 
+        for predicate in additional_data_predicates.iter().flatten() {
+            filter = filter.and_condition(predicate.matches(additional_data));
+        }
+
         filter
     }
 }
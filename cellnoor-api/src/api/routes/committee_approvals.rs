@@ -0,0 +1,19 @@
+use axum_extra::routing::TypedPath;
+use cellnoor_models::committee_approval::CommitteeApprovalId;
+
+use crate::api::openapi::ApiRouter;
+
+mod create;
+mod delete;
+mod fetch;
+mod list;
+mod update;
+
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
+        .typed_post(create::create_committee_approval)
+        .typed_get(fetch::fetch_committee_approval)
+        .typed_get(list::list_committee_approvals)
+        .patch(CommitteeApprovalId::PATH, update::update_committee_approval)
+        .typed_delete(delete::delete_committee_approval)
+}
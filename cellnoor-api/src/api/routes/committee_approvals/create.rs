@@ -0,0 +1,36 @@
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::committee_approval::{
+    CommitteeApproval, CommitteeApprovalCreation, CommitteeApprovalId,
+};
+use cellnoor_schema::committee_approvals::dsl::{committee_approvals, id};
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::{ValidJson, auth::AuthenticatedUser},
+        routes::{ApiResponse, Root, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn create_committee_approval(
+    _: Root,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    ValidJson(request): ValidJson<CommitteeApprovalCreation>,
+) -> ApiResponse<CommitteeApproval> {
+    let item = inner_handler(state, user, request).await?;
+    Ok((StatusCode::CREATED, item))
+}
+
+impl db::Operation<CommitteeApproval> for CommitteeApprovalCreation {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<CommitteeApproval, db::Error> {
+        let created_id = diesel::insert_into(committee_approvals)
+            .values(self)
+            .returning(id)
+            .get_result(db_conn)?;
+
+        CommitteeApprovalId(created_id).execute(db_conn)
+    }
+}
@@ -0,0 +1,34 @@
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::committee_approval::CommitteeApprovalId;
+use cellnoor_schema::committee_approvals;
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::auth::AuthenticatedUser,
+        routes::{ApiResponse, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+// Approvals that are attached to specimens can't be deleted because of the
+// foreign key on `specimen_committee_approvals`
+pub(super) async fn delete_committee_approval(
+    approval_id: CommitteeApprovalId,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+) -> ApiResponse<()> {
+    Ok((
+        StatusCode::OK,
+        inner_handler(state, user, approval_id).await?,
+    ))
+}
+
+impl db::Operation<()> for CommitteeApprovalId {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<(), db::Error> {
+        diesel::delete(committee_approvals::table.find(self)).execute(db_conn)?;
+
+        Ok(())
+    }
+}
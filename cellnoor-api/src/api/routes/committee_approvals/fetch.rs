@@ -0,0 +1,33 @@
+use axum::extract::State;
+use cellnoor_models::{
+    committee_approval::{CommitteeApproval, CommitteeApprovalId},
+    read_options::{NoInclude, ReadOptions},
+};
+use cellnoor_schema::committee_approvals::dsl::id;
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{FetchResponse, fetch_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn fetch_committee_approval(
+    approval_id: CommitteeApprovalId,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    QsQuery(options): QsQuery<ReadOptions<NoInclude>>,
+) -> FetchResponse<CommitteeApproval> {
+    fetch_handler::<_, CommitteeApproval, _>(state, user, approval_id, options).await
+}
+
+impl db::Operation<CommitteeApproval> for CommitteeApprovalId {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<CommitteeApproval, db::Error> {
+        Ok(CommitteeApproval::query()
+            .filter(id.eq(self))
+            .first(db_conn)?)
+    }
+}
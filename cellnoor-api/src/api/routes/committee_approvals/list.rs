@@ -0,0 +1,125 @@
+use axum::extract::State;
+use cellnoor_models::{
+    committee_approval::{
+        CommitteeApprovalFilter, CommitteeApprovalQuery, CommitteeApprovalSummary,
+    },
+    generic_query::Keyset,
+};
+use cellnoor_schema::committee_approvals as t;
+use diesel::{dsl::AssumeNotNull, prelude::*};
+use jiff_diesel::ToDiesel;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, Root, list_handler},
+    },
+    db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter, utils::like_any},
+    state::AppState,
+};
+
+pub(super) async fn list_committee_approvals(
+    _: Root,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    format: Option<ExportFormat>,
+    QsQuery(request): QsQuery<CommitteeApprovalQuery>,
+) -> ListResponse<CommitteeApprovalSummary> {
+    list_handler(state, user, request, format).await
+}
+
+impl db::List for CommitteeApprovalQuery {
+    type Row = CommitteeApprovalSummary;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, CommitteeApprovalSummary> {
+        let CommitteeApprovalQuery {
+            filter,
+            limit,
+            offset,
+            order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
+        } = self;
+
+        let mut stmt = CommitteeApprovalSummary::query()
+            .filter(filter.to_boxed_filter())
+            .into_boxed();
+
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
+        }
+
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(t::id);
+        }
+
+        stmt
+    }
+
+    fn id(row: &CommitteeApprovalSummary) -> Uuid {
+        row.id()
+    }
+}
+
+impl<'a, QS: 'a> ToBoxedFilter<'a, QS> for CommitteeApprovalFilter
+where
+    t::id: SelectableExpression<QS>,
+    t::institution_id: SelectableExpression<QS>,
+    t::committee_type: SelectableExpression<QS>,
+    t::compliance_identifier: SelectableExpression<QS>,
+    t::valid_from: SelectableExpression<QS>,
+    t::valid_until: SelectableExpression<QS>,
+    AssumeNotNull<t::valid_until>: SelectableExpression<QS>,
+{
+    fn to_boxed_filter(&'a self) -> BoxedFilter<'a, QS> {
+        let Self {
+            ids,
+            institution_ids,
+            committee_types,
+            compliance_identifiers,
+            valid_at,
+        } = self;
+
+        let mut filter = BoxedFilter::new_true();
+
+        if let Some(ids) = ids {
+            filter = filter.and_condition(t::id.eq_any(ids));
+        }
+
+        if let Some(institution_ids) = institution_ids {
+            filter = filter.and_condition(t::institution_id.eq_any(institution_ids));
+        }
+
+        if let Some(committee_types) = committee_types {
+            filter = filter.and_condition(t::committee_type.eq_any(committee_types));
+        }
+
+        if let Some(compliance_identifiers) = compliance_identifiers {
+            filter =
+                filter.and_condition(like_any(t::compliance_identifier, compliance_identifiers));
+        }
+
+        if let Some(valid_at) = valid_at.map(ToDiesel::to_diesel) {
+            let not_expired = BoxedFilter::new_false()
+                .or_condition(t::valid_until.is_null())
+                .or_condition(t::valid_until.assume_not_null().gt(valid_at));
+
+            filter = filter
+                .and_condition(t::valid_from.le(valid_at))
+                .and_condition(not_expired);
+        }
+
+        filter
+    }
+}
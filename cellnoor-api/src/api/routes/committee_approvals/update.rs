@@ -0,0 +1,36 @@
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::committee_approval::{
+    CommitteeApproval, CommitteeApprovalId, CommitteeApprovalUpdate,
+};
+use cellnoor_schema::committee_approvals;
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::{ValidPathJson, auth::AuthenticatedUser},
+        routes::{ApiResponse, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn update_committee_approval(
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    ValidPathJson(id, request): ValidPathJson<CommitteeApprovalId, CommitteeApprovalUpdate>,
+) -> ApiResponse<CommitteeApproval> {
+    let item = inner_handler(state, user, (id, request)).await?;
+    Ok((StatusCode::OK, item))
+}
+
+impl db::Operation<CommitteeApproval> for (CommitteeApprovalId, CommitteeApprovalUpdate) {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<CommitteeApproval, db::Error> {
+        let (id, update) = self;
+
+        diesel::update(committee_approvals::table.find(id))
+            .set(update)
+            .execute(db_conn)?;
+
+        id.execute(db_conn)
+    }
+}
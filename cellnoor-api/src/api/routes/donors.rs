@@ -0,0 +1,134 @@
+use axum_extra::routing::TypedPath;
+use cellnoor_models::donor::DonorId;
+
+use crate::api::openapi::ApiRouter;
+
+mod create;
+mod fetch;
+mod list;
+mod update;
+
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
+        .typed_post(create::create_donor)
+        .typed_get(fetch::fetch_donor)
+        .typed_get(list::list_donors)
+        .patch(DonorId::PATH, update::update_donor)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{Method, StatusCode};
+    use cellnoor_models::{
+        donor::{Donor, DonorCreation, DonorId, DonorUpdate},
+        specimen::Species,
+    };
+    use deadpool_diesel::postgres::Connection;
+    use diesel::Connection as _;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::{Value, json};
+    use uuid::Uuid;
+
+    use crate::{
+        db::{self, Operation},
+        test_app::{TestApp, app},
+        test_state::{Database, database, root_db_conn},
+    };
+
+    fn donor(lab_id: Uuid) -> Value {
+        json!({
+            "external_id": Uuid::now_v7(),
+            "lab_id": lab_id,
+            "species": Species::MusMusculus,
+        })
+    }
+
+    fn creation(donor: Value) -> DonorCreation {
+        serde_json::from_value(donor).unwrap()
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn create_fetch_and_update_donor(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let lab_id = database.labs[0].id();
+        let donor = creation(donor(lab_id));
+        let update: DonorUpdate = serde_json::from_value(json!({"strain": "C57BL/6J"})).unwrap();
+
+        root_db_conn
+            .interact(move |db_conn| {
+                db_conn.test_transaction::<_, db::Error, _>(|tx| {
+                    let created = donor.execute(tx)?;
+                    assert_eq!(created.lab_id(), lab_id);
+
+                    let id = DonorId(created.id());
+                    let fetched: Donor = id.execute(tx)?;
+                    assert_eq!(fetched, created);
+
+                    let updated = (id, update).execute(tx)?;
+                    let [created, updated] =
+                        [created, updated].map(|d| serde_json::to_value(d).unwrap());
+                    assert_eq!(updated["strain"], "C57BL/6J");
+                    assert_eq!(updated["external_id"], created["external_id"]);
+
+                    Ok(())
+                });
+            })
+            .await
+            .unwrap();
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn external_id_is_unique_within_lab(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let donor = donor(database.labs[0].id());
+        let mut other_lab = donor.clone();
+        other_lab["lab_id"] = json!(database.labs[1].id());
+
+        root_db_conn
+            .interact(move |db_conn| {
+                db_conn.test_transaction::<_, db::Error, _>(|tx| {
+                    creation(donor.clone()).execute(tx)?;
+
+                    // The savepoint keeps the transaction usable after the violation
+                    let duplicate = tx.transaction(|sp| creation(donor).execute(sp));
+                    assert!(
+                        matches!(duplicate, Err(db::Error::DuplicateResource { .. })),
+                        "{duplicate:?}"
+                    );
+
+                    creation(other_lab).execute(tx)?;
+
+                    Ok(())
+                });
+            })
+            .await
+            .unwrap();
+    }
+
+    // Nothing is inserted, so this can go through the router
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn donor_cannot_have_both_birth_time_and_age(
+        #[future] app: TestApp,
+        #[future] database: &'static Database,
+    ) {
+        let mut donor = donor(database.labs[0].id());
+        donor["born_at"] = json!("2025-01-01T00:00:00Z");
+        donor["age_in_days"] = json!(30);
+
+        let (status, body) = app
+            .send_json(Method::POST, "/api/donors", Some(donor))
+            .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{body}");
+    }
+}
@@ -0,0 +1,34 @@
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::donor::{Donor, DonorCreation, DonorId};
+use cellnoor_schema::donors::dsl::{donors, id};
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::{ValidJson, auth::AuthenticatedUser},
+        routes::{ApiResponse, Root, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn create_donor(
+    _: Root,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    ValidJson(request): ValidJson<DonorCreation>,
+) -> ApiResponse<Donor> {
+    let item = inner_handler(state, user, request).await?;
+    Ok((StatusCode::CREATED, item))
+}
+
+impl db::Operation<Donor> for DonorCreation {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<Donor, db::Error> {
+        let created_id = diesel::insert_into(donors)
+            .values(self)
+            .returning(id)
+            .get_result(db_conn)?;
+
+        DonorId(created_id).execute(db_conn)
+    }
+}
@@ -0,0 +1,31 @@
+use axum::extract::State;
+use cellnoor_models::{
+    donor::{Donor, DonorId},
+    read_options::{NoInclude, ReadOptions},
+};
+use cellnoor_schema::donors::dsl::id;
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{FetchResponse, fetch_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn fetch_donor(
+    donor_id: DonorId,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    QsQuery(options): QsQuery<ReadOptions<NoInclude>>,
+) -> FetchResponse<Donor> {
+    fetch_handler(state, user, donor_id, options).await
+}
+
+impl db::Operation<Donor> for DonorId {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<Donor, db::Error> {
+        Ok(Donor::query().filter(id.eq(self)).first(db_conn)?)
+    }
+}
@@ -0,0 +1,125 @@
+use axum::extract::State;
+use cellnoor_models::{
+    donor::{DonorFilter, DonorQuery, DonorSummary},
+    generic_query::Keyset,
+};
+use cellnoor_schema::donors as t;
+use diesel::{dsl::AssumeNotNull, prelude::*};
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, Root, list_handler},
+    },
+    db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter, utils::like_any},
+    state::AppState,
+};
+
+pub(super) async fn list_donors(
+    _: Root,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    format: Option<ExportFormat>,
+    QsQuery(request): QsQuery<DonorQuery>,
+) -> ListResponse<DonorSummary> {
+    list_handler(state, user, request, format).await
+}
+
+impl db::List for DonorQuery {
+    type Row = DonorSummary;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, DonorSummary> {
+        let DonorQuery {
+            filter,
+            limit,
+            offset,
+            order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
+        } = self;
+
+        let mut stmt = DonorSummary::query()
+            .filter(filter.to_boxed_filter())
+            .into_boxed();
+
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
+        }
+
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(t::id);
+        }
+
+        stmt
+    }
+
+    fn id(row: &DonorSummary) -> Uuid {
+        row.id()
+    }
+}
+
+impl<'a, QS: 'a> ToBoxedFilter<'a, QS> for DonorFilter
+where
+    t::id: SelectableExpression<QS>,
+    t::external_id: SelectableExpression<QS>,
+    t::lab_id: SelectableExpression<QS>,
+    t::species: SelectableExpression<QS>,
+    AssumeNotNull<t::sex>: SelectableExpression<QS>,
+    AssumeNotNull<t::strain>: SelectableExpression<QS>,
+    AssumeNotNull<t::genotype>: SelectableExpression<QS>,
+{
+    fn to_boxed_filter(&'a self) -> BoxedFilter<'a, QS> {
+        let Self {
+            ids,
+            external_ids,
+            labs,
+            species,
+            sexes,
+            strains,
+            genotypes,
+        } = self;
+
+        let mut filter = BoxedFilter::new_true();
+
+        if let Some(ids) = ids {
+            filter = filter.and_condition(t::id.eq_any(ids));
+        }
+
+        if let Some(external_ids) = external_ids {
+            filter = filter.and_condition(like_any(t::external_id, external_ids));
+        }
+
+        if let Some(labs) = labs {
+            filter = filter.and_condition(t::lab_id.eq_any(labs));
+        }
+
+        if let Some(species_list) = species {
+            filter = filter.and_condition(t::species.eq_any(species_list));
+        }
+
+        if let Some(sexes) = sexes {
+            filter = filter.and_condition(t::sex.assume_not_null().eq_any(sexes));
+        }
+
+        if let Some(strains) = strains {
+            filter = filter.and_condition(like_any(t::strain.assume_not_null(), strains));
+        }
+
+        if let Some(genotypes) = genotypes {
+            filter = filter.and_condition(like_any(t::genotype.assume_not_null(), genotypes));
+        }
+
+        filter
+    }
+}
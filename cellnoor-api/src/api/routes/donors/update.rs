@@ -0,0 +1,34 @@
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::donor::{Donor, DonorId, DonorUpdate};
+use cellnoor_schema::donors;
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::{ValidPathJson, auth::AuthenticatedUser},
+        routes::{ApiResponse, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn update_donor(
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    ValidPathJson(id, request): ValidPathJson<DonorId, DonorUpdate>,
+) -> ApiResponse<Donor> {
+    let item = inner_handler(state, user, (id, request)).await?;
+    Ok((StatusCode::OK, item))
+}
+
+impl db::Operation<Donor> for (DonorId, DonorUpdate) {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<Donor, db::Error> {
+        let (id, update) = self;
+
+        diesel::update(donors::table.find(id))
+            .set(update)
+            .execute(db_conn)?;
+
+        id.execute(db_conn)
+    }
+}
@@ -0,0 +1,123 @@
+use axum::{
+    Extension,
+    extract::State,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use cellnoor_models::change_event::{ChangeEvent, ChangeEventFilter};
+use cellnoor_schema::change_events as t;
+use diesel::{dsl::AssumeNotNull, prelude::*};
+use futures_util::{Stream, StreamExt, stream};
+
+use crate::{
+    api::{
+        error::ErrorResponse,
+        extract::{LastEventId, auth::AuthenticatedUser, query::QsQuery},
+        openapi::ApiRouter,
+        routes::{Root, inner_handler},
+    },
+    change_feed::{self, ChangeFeed, EventCursor},
+    db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter},
+    state::AppState,
+};
+
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new().typed_get(stream_events)
+}
+
+/// Stream inserts and updates as server-sent events, each with a cursor as its
+/// `id` and the event itself as JSON `data`. A client that sends
+/// `Last-Event-ID` first receives the matching events it missed.
+async fn stream_events(
+    _: Root,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    Extension(feed): Extension<ChangeFeed>,
+    QsQuery(filter): QsQuery<ChangeEventFilter>,
+    last_event_id: Option<LastEventId>,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, ErrorResponse> {
+    // Subscribing before reading the backlog means that nothing committed in
+    // between is lost
+    let subscription = feed.subscribe();
+
+    let (backlog, seen_up_to) = match last_event_id {
+        Some(LastEventId(after)) => {
+            let backlog: Vec<(EventCursor, ChangeEvent)> = inner_handler(
+                state,
+                user,
+                ChangeEventBacklog {
+                    after,
+                    filter: filter.clone(),
+                },
+            )
+            .await?
+            .0;
+            let seen_up_to = backlog.last().map_or(after, |(cursor, _)| *cursor);
+
+            (backlog, Some(seen_up_to))
+        }
+        None => (Vec::new(), None),
+    };
+
+    let live = stream::unfold(subscription, move |mut subscription| {
+        let filter = filter.clone();
+        async move {
+            loop {
+                let (cursor, event) = subscription.next().await?;
+
+                if filter.matches(&event) && seen_up_to.is_none_or(|seen| cursor > seen) {
+                    return Some(((cursor, event), subscription));
+                }
+            }
+        }
+    });
+
+    let events = stream::iter(backlog)
+        .chain(live)
+        .map(|(cursor, event)| Event::default().id(cursor.to_string()).json_data(&event));
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+#[derive(Debug)]
+struct ChangeEventBacklog {
+    after: EventCursor,
+    filter: ChangeEventFilter,
+}
+
+impl db::Operation<Vec<(EventCursor, ChangeEvent)>> for ChangeEventBacklog {
+    fn execute(
+        self,
+        db_conn: &mut PgConnection,
+    ) -> Result<Vec<(EventCursor, ChangeEvent)>, db::Error> {
+        let Self { after, filter } = self;
+
+        Ok(change_feed::events_after(
+            after,
+            filter.to_boxed_filter(),
+            db_conn,
+        )?)
+    }
+}
+
+impl<'a, QS: 'a> ToBoxedFilter<'a, QS> for ChangeEventFilter
+where
+    t::entity_type: SelectableExpression<QS>,
+    AssumeNotNull<t::lab_id>: SelectableExpression<QS>,
+{
+    fn to_boxed_filter(&'a self) -> BoxedFilter<'a, QS> {
+        let Self { entity_types, labs } = self;
+
+        let mut filter = BoxedFilter::new_true();
+
+        if let Some(entity_types) = entity_types {
+            filter = filter.and_condition(t::entity_type.eq_any(entity_types));
+        }
+
+        // Events for entities without a lab never match
+        if let Some(labs) = labs {
+            filter = filter.and_condition(t::lab_id.assume_not_null().eq_any(labs));
+        }
+
+        filter
+    }
+}
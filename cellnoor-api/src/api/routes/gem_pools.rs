@@ -1,13 +1,10 @@
-use axum::Router;
-use axum_extra::routing::RouterExt;
-
-use crate::state::AppState;
+use crate::api::openapi::ApiRouter;
 
 mod fetch;
 mod list;
 
-pub(super) fn router() -> Router<AppState> {
-    Router::new()
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
         .typed_get(list::list_gems)
         .typed_get(fetch::fetch_gem_pool)
 }
@@ -1,12 +1,15 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::chromium_run::{GemPool, GemPoolId};
+use axum::extract::State;
+use cellnoor_models::{
+    chromium_run::{GemPool, GemPoolId},
+    read_options::{NoInclude, ReadOptions},
+};
 use cellnoor_schema::gem_pools;
 use diesel::{PgConnection, prelude::*};
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{FetchResponse, fetch_handler},
     },
     db,
     state::AppState,
@@ -16,9 +19,9 @@ pub(super) async fn fetch_gem_pool(
     request: GemPoolId,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<GemPool> {
-    let item = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, item))
+    QsQuery(options): QsQuery<ReadOptions<NoInclude>>,
+) -> FetchResponse<GemPool> {
+    fetch_handler(state, user, request, options).await
 }
 
 impl db::Operation<GemPool> for GemPoolId {
@@ -1,13 +1,17 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::chromium_run::{GemPoolFilter, GemPoolQuery, GemPoolSummary};
+use axum::extract::State;
+use cellnoor_models::{
+    chromium_run::{GemPoolFilter, GemPoolQuery, GemPoolSummary},
+    generic_query::Keyset,
+};
 use cellnoor_schema::gem_pools;
 use diesel::prelude::*;
-use serde_qs::axum::QsQuery;
+use uuid::Uuid;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, Root, inner_handler},
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, Root, list_handler},
     },
     db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter},
     state::AppState,
@@ -17,32 +21,51 @@ pub(super) async fn list_gems(
     _: Root,
     state: State<AppState>,
     user: AuthenticatedUser,
+    format: Option<ExportFormat>,
     QsQuery(request): QsQuery<GemPoolQuery>,
-) -> ApiResponse<Vec<GemPoolSummary>> {
-    let items = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, items))
+) -> ListResponse<GemPoolSummary> {
+    list_handler(state, user, request, format).await
 }
 
-impl db::Operation<Vec<GemPoolSummary>> for GemPoolQuery {
-    fn execute(self, db_conn: &mut PgConnection) -> Result<Vec<GemPoolSummary>, db::Error> {
-        let Self {
+impl db::List for GemPoolQuery {
+    type Row = GemPoolSummary;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, GemPoolSummary> {
+        let GemPoolQuery {
             filter,
             limit,
             offset,
             order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
         } = self;
 
         let mut stmt = GemPoolSummary::query()
-            .limit(limit)
-            .offset(offset)
             .filter(filter.to_boxed_filter())
             .into_boxed();
 
-        for ordering in order_by.as_ref() {
-            stmt = stmt.then_order_by(ordering);
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
         }
 
-        Ok(stmt.load(db_conn)?)
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(gem_pools::id);
+        }
+
+        stmt
+    }
+
+    fn id(row: &GemPoolSummary) -> Uuid {
+        row.id()
     }
 }
 
@@ -1,17 +1,16 @@
-use axum::Router;
-use axum_extra::routing::RouterExt;
-
-use crate::state::AppState;
+use crate::api::openapi::ApiRouter;
 
 mod create;
 mod fetch;
 mod list;
 mod members;
+mod update;
 
-pub(super) fn router() -> Router<AppState> {
-    Router::new()
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
         .typed_post(create::create_institution)
         .typed_get(fetch::fetch_institution)
         .typed_get(list::list_institutions)
+        .typed_patch(update::update_institution)
         .typed_get(members::list::list_members)
 }
@@ -1,12 +1,15 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::institution::{Institution, InstitutionId};
+use axum::extract::State;
+use cellnoor_models::{
+    institution::{Institution, InstitutionId},
+    read_options::{NoInclude, ReadOptions},
+};
 use cellnoor_schema::institutions::dsl::id;
 use diesel::{PgConnection, prelude::*};
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{FetchResponse, fetch_handler},
     },
     db::{self},
     state::AppState,
@@ -16,9 +19,9 @@ pub(super) async fn fetch_institution(
     request: InstitutionId,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Institution> {
-    let item = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, item))
+    QsQuery(options): QsQuery<ReadOptions<NoInclude>>,
+) -> FetchResponse<Institution> {
+    fetch_handler(state, user, request, options).await
 }
 
 impl db::Operation<Institution> for InstitutionId {
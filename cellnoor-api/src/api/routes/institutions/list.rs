@@ -1,13 +1,17 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::institution::{Institution, InstitutionFilter, InstitutionQuery};
+use axum::extract::State;
+use cellnoor_models::{
+    generic_query::Keyset,
+    institution::{Institution, InstitutionFilter, InstitutionQuery},
+};
 use cellnoor_schema::institutions::dsl::{id, name};
 use diesel::{SelectableExpression, prelude::*};
-use serde_qs::axum::QsQuery;
+use uuid::Uuid;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, Root, inner_handler},
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, Root, list_handler},
     },
     db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter, utils::like_any},
     state::AppState,
@@ -17,32 +21,51 @@ pub(super) async fn list_institutions(
     _: Root,
     state: State<AppState>,
     user: AuthenticatedUser,
+    format: Option<ExportFormat>,
     QsQuery(request): QsQuery<InstitutionQuery>,
-) -> ApiResponse<Vec<Institution>> {
-    let items = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, items))
+) -> ListResponse<Institution> {
+    list_handler(state, user, request, format).await
 }
 
-impl db::Operation<Vec<Institution>> for InstitutionQuery {
-    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<Vec<Institution>, db::Error> {
-        let Self {
+impl db::List for InstitutionQuery {
+    type Row = Institution;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, Institution> {
+        let InstitutionQuery {
             filter,
             limit,
             offset,
             order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
         } = self;
 
         let mut stmt = Institution::query()
-            .limit(limit)
-            .offset(offset)
             .filter(filter.to_boxed_filter())
             .into_boxed();
 
-        for ordering in order_by.as_ref() {
-            stmt = stmt.then_order_by(ordering);
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
         }
 
-        Ok(stmt.load(db_conn)?)
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(id);
+        }
+
+        stmt
+    }
+
+    fn id(row: &Institution) -> Uuid {
+        row.id()
     }
 }
 
@@ -1,14 +1,14 @@
-use axum::{extract::State, http::StatusCode};
+use axum::extract::State;
 use cellnoor_models::{
     institution::{self, InstitutionIdMembers},
-    person::{self, PersonFilter, PersonQuery},
+    person::{self, PersonFilter, PersonQuery, PersonSummary},
 };
-use serde_qs::axum::QsQuery;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, list_handler},
     },
     db::{self},
     state::AppState,
@@ -18,29 +18,45 @@ pub async fn list_members(
     institution_id: institution::InstitutionIdMembers,
     state: State<AppState>,
     user: AuthenticatedUser,
+    format: Option<ExportFormat>,
     QsQuery(request): QsQuery<person::PersonQuery>,
-) -> ApiResponse<Vec<person::PersonSummary>> {
-    let items = inner_handler(state, user, (institution_id, request)).await?;
-    Ok((StatusCode::OK, items))
+) -> ListResponse<PersonSummary> {
+    list_handler(state, user, (institution_id, request), format).await
 }
 
-impl db::Operation<Vec<person::PersonSummary>> for (InstitutionIdMembers, PersonQuery) {
-    fn execute(
-        self,
-        db_conn: &mut diesel::PgConnection,
-    ) -> Result<Vec<person::PersonSummary>, db::Error> {
-        let (InstitutionIdMembers(institution_id), mut person_query) = self;
+fn members_query(
+    (InstitutionIdMembers(institution_id), mut person_query): (InstitutionIdMembers, PersonQuery),
+) -> PersonQuery {
+    let institution_ids = Some(vec![institution_id]);
+    if let Some(q) = &mut person_query.filter {
+        q.institution_ids = institution_ids;
+    } else {
+        person_query.filter = Some(PersonFilter {
+            institution_ids,
+            ..Default::default()
+        });
+    }
+
+    person_query
+}
 
-        let institution_ids = Some(vec![institution_id]);
-        if let Some(q) = &mut person_query.filter {
-            q.institution_ids = institution_ids;
-        } else {
-            person_query.filter = Some(PersonFilter {
-                institution_ids,
-                ..Default::default()
-            });
-        }
+impl<T> db::Operation<T> for (InstitutionIdMembers, PersonQuery)
+where
+    PersonQuery: db::Operation<T>,
+{
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<T, db::Error> {
+        members_query(self).execute(db_conn)
+    }
+}
+
+impl db::Export for (InstitutionIdMembers, PersonQuery) {
+    type Row = person::PersonSummary;
 
-        person_query.execute(db_conn)
+    fn export(
+        self,
+        db_conn: &mut diesel::PgConnection,
+        on_row: &mut dyn FnMut(person::PersonSummary) -> bool,
+    ) -> Result<(), db::Error> {
+        members_query(self).export(db_conn, on_row)
     }
 }
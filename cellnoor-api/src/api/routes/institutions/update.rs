@@ -0,0 +1,33 @@
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::institution::{Institution, InstitutionId, InstitutionUpdate};
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::{ValidJson, auth::AuthenticatedUser},
+        routes::{ApiResponse, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn update_institution(
+    id: InstitutionId,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    ValidJson(request): ValidJson<InstitutionUpdate>,
+) -> ApiResponse<Institution> {
+    let item = inner_handler(state, user, (id, request)).await?;
+    Ok((StatusCode::OK, item))
+}
+
+impl db::Operation<Institution> for (InstitutionId, InstitutionUpdate) {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<Institution, db::Error> {
+        let (id, mut update) = self;
+        update.set_id(id.0);
+
+        diesel::update(&update).set(&update).execute(db_conn)?;
+
+        id.execute(db_conn)
+    }
+}
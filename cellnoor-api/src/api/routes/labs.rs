@@ -1,14 +1,11 @@
-use axum::Router;
-use axum_extra::routing::RouterExt;
-
-use crate::state::AppState;
+use crate::api::openapi::ApiRouter;
 
 mod create;
 mod list;
 mod members;
 
-pub(super) fn router() -> Router<AppState> {
-    Router::new()
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
         .typed_post(create::create_lab)
         .typed_get(list::list_labs)
 }
@@ -1,22 +1,21 @@
-use axum::{Router, routing::post};
-use axum_extra::routing::{RouterExt, TypedPath};
+use axum_extra::routing::TypedPath;
 use cellnoor_models::library::LibraryIdMeasurements;
 
-use crate::state::AppState;
+use crate::api::openapi::ApiRouter;
 
 mod create;
 mod fetch;
 mod list;
 mod measurements;
 
-pub(super) fn router() -> Router<AppState> {
-    Router::new()
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
         .typed_post(create::create_library)
         .typed_get(fetch::fetch_library)
         .typed_get(list::list_libraries)
-        .route(
+        .post(
             LibraryIdMeasurements::PATH,
-            post(measurements::create::create_measurement),
+            measurements::create::create_measurement,
         )
         .typed_get(measurements::list::list_measurements)
 }
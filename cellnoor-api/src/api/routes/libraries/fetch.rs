@@ -1,12 +1,15 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::library::{Library, LibraryId};
+use axum::extract::State;
+use cellnoor_models::{
+    library::{Library, LibraryId},
+    read_options::{NoInclude, ReadOptions},
+};
 use cellnoor_schema::libraries;
 use diesel::{PgConnection, prelude::*};
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{FetchResponse, fetch_handler},
     },
     db,
     state::AppState,
@@ -16,9 +19,9 @@ pub(super) async fn fetch_library(
     request: LibraryId,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Library> {
-    let item = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, item))
+    QsQuery(options): QsQuery<ReadOptions<NoInclude>>,
+) -> FetchResponse<Library> {
+    fetch_handler(state, user, request, options).await
 }
 
 impl db::Operation<Library> for LibraryId {
@@ -1,13 +1,17 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::library::{LibraryFilter, LibraryQuery, LibrarySummary};
-use cellnoor_schema::libraries::id;
+use axum::extract::State;
+use cellnoor_models::{
+    generic_query::Keyset,
+    library::{LibraryFilter, LibraryQuery, LibrarySummary},
+};
+use cellnoor_schema::libraries::{additional_data, id, readable_id};
 use diesel::{SelectableExpression, prelude::*};
-use serde_qs::axum::QsQuery;
+use uuid::Uuid;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, Root, inner_handler},
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, Root, list_handler},
     },
     db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter},
     state::AppState,
@@ -17,47 +21,80 @@ pub(super) async fn list_libraries(
     _: Root,
     state: State<AppState>,
     user: AuthenticatedUser,
+    format: Option<ExportFormat>,
     QsQuery(request): QsQuery<LibraryQuery>,
-) -> ApiResponse<Vec<LibrarySummary>> {
-    let items = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, items))
+) -> ListResponse<LibrarySummary> {
+    list_handler(state, user, request, format).await
 }
 
-impl db::Operation<Vec<LibrarySummary>> for LibraryQuery {
-    fn execute(self, db_conn: &mut PgConnection) -> Result<Vec<LibrarySummary>, db::Error> {
-        let Self {
+impl db::List for LibraryQuery {
+    type Row = LibrarySummary;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, LibrarySummary> {
+        let LibraryQuery {
             filter,
             limit,
             offset,
             order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
         } = self;
 
         let mut stmt = LibrarySummary::query()
-            .limit(limit)
-            .offset(offset)
             .filter(filter.to_boxed_filter())
             .into_boxed();
 
-        for ordering in order_by.as_ref() {
-            stmt = stmt.then_order_by(ordering);
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
         }
 
-        Ok(stmt.load(db_conn)?)
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(id);
+        }
+
+        stmt
+    }
+
+    fn id(row: &LibrarySummary) -> Uuid {
+        row.id()
     }
 }
 
 impl<'a, QS: 'a> ToBoxedFilter<'a, QS> for LibraryFilter
 where
     id: SelectableExpression<QS>,
+    readable_id: SelectableExpression<QS>,
+    additional_data: SelectableExpression<QS>,
 {
     fn to_boxed_filter(&'a self) -> BoxedFilter<'a, QS> {
-        let Self { ids } = self;
+        let Self {
+            ids,
+            readable_ids,
+            additional_data_predicates,
+        } = self;
         let mut filter = BoxedFilter::new_true();
 
         if let Some(ids) = ids {
             filter = filter.and_condition(id.eq_any(ids));
         }
 
+        if let Some(readable_ids) = readable_ids {
+            filter = filter.and_condition(readable_id.eq_any(readable_ids));
+        }
+
+        for predicate in additional_data_predicates.iter().flatten() {
+            filter = filter.and_condition(predicate.matches(additional_data));
+        }
+
         filter
     }
 }
@@ -2,24 +2,25 @@ use axum::extract::State;
 use cellnoor_models::library::{LibraryIdMeasurements, measurement::LibraryMeasurement};
 use cellnoor_schema::library_measurements;
 use diesel::prelude::*;
-use reqwest::StatusCode;
 
 use crate::{
     api::{
+        export::ExportFormat,
         extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        routes::{UnpagedListResponse, unpaged_list_handler},
     },
     db::{self},
     state::AppState,
 };
 
+// Not paged, since each library is only QC'd a handful of times
 pub async fn list_measurements(
     library_id: LibraryIdMeasurements,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Vec<LibraryMeasurement>> {
-    let item = inner_handler(state, user, library_id).await?;
-    Ok((StatusCode::OK, item))
+    format: Option<ExportFormat>,
+) -> UnpagedListResponse<LibraryMeasurement> {
+    unpaged_list_handler(state, user, library_id, format).await
 }
 
 impl db::Operation<Vec<LibraryMeasurement>> for LibraryIdMeasurements {
@@ -0,0 +1,116 @@
+use axum::{
+    Json,
+    extract::State,
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use cellnoor_models::lineage::{Lineage, LineageFormat, LineageNodeType, LineageQuery};
+use cellnoor_schema::{sequencing_runs, sequencing_submissions};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        extract::auth::AuthenticatedUser,
+        routes::{FetchResponse, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+const DOT_CONTENT_TYPE: &str = "text/vnd.graphviz";
+
+pub(super) async fn lineage_response<Request>(
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    request: Request,
+    LineageQuery { format }: LineageQuery,
+) -> FetchResponse<Lineage>
+where
+    Request: std::fmt::Debug + db::Operation<Lineage> + Send + 'static,
+{
+    let Json(lineage) = inner_handler(state, user, request).await?;
+
+    let response = match format {
+        LineageFormat::Json => (StatusCode::OK, Json(lineage)).into_response(),
+        LineageFormat::Dot => (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, DOT_CONTENT_TYPE)],
+            lineage.to_dot(),
+        )
+            .into_response(),
+    };
+
+    Ok(response.into())
+}
+
+/// Sequencing runs are a sibling of Chromium datasets rather than a step
+/// between libraries and datasets, so both directions of the walk attach them
+/// to libraries in the same way
+pub(super) fn add_sequencing_runs(
+    lineage: &mut Lineage,
+    library_ids: &[Uuid],
+    db_conn: &mut PgConnection,
+) -> Result<(), db::Error> {
+    let submissions: Vec<(Uuid, Uuid, String)> = sequencing_submissions::table
+        .inner_join(sequencing_runs::table)
+        .select((
+            sequencing_submissions::library_id,
+            sequencing_runs::id,
+            sequencing_runs::readable_id,
+        ))
+        .filter(sequencing_submissions::library_id.eq_any(library_ids))
+        .load(db_conn)?;
+
+    for (library_id, sequencing_run_id, readable_id) in submissions {
+        lineage.add_node(
+            LineageNodeType::SequencingRun,
+            sequencing_run_id,
+            readable_id,
+        );
+        lineage.add_edge(library_id, sequencing_run_id);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::{Body, to_bytes},
+        http::{Request, StatusCode, header},
+    };
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::DOT_CONTENT_TYPE;
+    use crate::{
+        test_app::{TestApp, app},
+        test_state::{Database, database},
+    };
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn specimen_lineage_as_dot(
+        #[future] app: TestApp,
+        #[future] database: &'static Database,
+    ) {
+        let specimen_id = database.specimens[0].id();
+        let request = Request::get(format!("/api/specimens/{specimen_id}/lineage?format=dot"))
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.send(request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], DOT_CONTENT_TYPE);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.starts_with("digraph"), "not a DOT graph: {body}");
+        assert!(
+            body.contains(&specimen_id.to_string()),
+            "specimen missing from lineage: {body}"
+        );
+    }
+}
@@ -1,24 +1,27 @@
-use axum::{extract::State, http::StatusCode};
+use axum::extract::State;
 use cellnoor_models::multiplexing_tag::MultiplexingTag;
 use cellnoor_schema::multiplexing_tags::dsl::*;
 use diesel::prelude::*;
 
 use crate::{
     api::{
+        export::ExportFormat,
         extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, Root, inner_handler},
+        routes::{Root, UnpagedListResponse, unpaged_list_handler},
     },
     db,
     state::AppState,
 };
 
+// Not paged, since this is the fixed catalogue of barcodes from the kits we
+// use rather than data that grows over time
 pub(super) async fn list_multiplexing_tags(
     _: Root,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Vec<MultiplexingTag>> {
-    let items = inner_handler(state, user, ()).await?;
-    Ok((StatusCode::OK, items))
+    format: Option<ExportFormat>,
+) -> UnpagedListResponse<MultiplexingTag> {
+    unpaged_list_handler(state, user, (), format).await
 }
 
 impl db::Operation<Vec<MultiplexingTag>> for () {
@@ -1,18 +1,27 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::person::{Person, PersonId, PersonSummaryWithParents};
+use axum::extract::State;
+use cellnoor_models::{
+    person::{Person, PersonId, PersonSummaryWithParents},
+    read_options::{NoInclude, ReadOptions},
+};
 use cellnoor_schema::people::dsl::id;
 use diesel::{PgConnection, prelude::*, sql_types::Text};
 
-use super::{ApiResponse, inner_handler};
-use crate::{api::extract::auth::AuthenticatedUser, db, state::AppState};
+use crate::{
+    api::{
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{FetchResponse, fetch_handler},
+    },
+    db,
+    state::AppState,
+};
 
 pub(super) async fn fetch_person(
     request: PersonId,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Person> {
-    let item = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, item))
+    QsQuery(options): QsQuery<ReadOptions<NoInclude>>,
+) -> FetchResponse<Person> {
+    fetch_handler(state, user, request, options).await
 }
 
 impl db::Operation<Person> for PersonId {
@@ -1,13 +1,17 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::person::{PersonFilter, PersonQuery, PersonSummary};
+use axum::extract::State;
+use cellnoor_models::{
+    generic_query::Keyset,
+    person::{PersonFilter, PersonQuery, PersonSummary},
+};
 use cellnoor_schema::people::dsl::{email, id, institution_id, microsoft_entra_oid, name, orcid};
 use diesel::{dsl::AssumeNotNull, prelude::*};
-use serde_qs::axum::QsQuery;
+use uuid::Uuid;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, Root, inner_handler},
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, Root, list_handler},
     },
     db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter, utils::like_any},
     state::AppState,
@@ -17,10 +21,10 @@ pub(super) async fn list_people(
     _: Root,
     state: State<AppState>,
     user: AuthenticatedUser,
+    format: Option<ExportFormat>,
     QsQuery(request): QsQuery<PersonQuery>,
-) -> ApiResponse<Vec<PersonSummary>> {
-    let items = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, items))
+) -> ListResponse<PersonSummary> {
+    list_handler(state, user, request, format).await
 }
 
 impl<'a, QS: 'a> ToBoxedFilter<'a, QS> for PersonFilter
@@ -76,26 +80,45 @@ where
     }
 }
 
-impl db::Operation<Vec<PersonSummary>> for PersonQuery {
-    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<Vec<PersonSummary>, db::Error> {
-        let Self {
+impl db::List for PersonQuery {
+    type Row = PersonSummary;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, PersonSummary> {
+        let PersonQuery {
             filter,
             limit,
             offset,
             order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
         } = self;
 
         let mut stmt = PersonSummary::query()
-            .limit(limit)
-            .offset(offset)
             .filter(filter.to_boxed_filter())
             .into_boxed();
 
-        for ordering in order_by.as_ref() {
-            stmt = stmt.then_order_by(ordering);
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
         }
 
-        Ok(stmt.load(db_conn)?)
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(id);
+        }
+
+        stmt
+    }
+
+    fn id(row: &PersonSummary) -> Uuid {
+        row.id()
     }
 }
 
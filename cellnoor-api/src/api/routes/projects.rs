@@ -0,0 +1,182 @@
+use axum_extra::routing::TypedPath;
+use cellnoor_models::project::{ProjectIdChromiumDatasets, ProjectIdSpecimens};
+
+use crate::api::openapi::ApiRouter;
+
+mod chromium_datasets;
+mod create;
+mod fetch;
+mod list;
+mod specimens;
+mod update;
+
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
+        .typed_post(create::create_project)
+        .typed_get(fetch::fetch_project)
+        .typed_get(list::list_projects)
+        .typed_patch(update::update_project)
+        .post(
+            ProjectIdSpecimens::PATH,
+            specimens::add::add_specimens_to_project,
+        )
+        .typed_get(specimens::list::list_specimens)
+        .post(
+            ProjectIdChromiumDatasets::PATH,
+            chromium_datasets::add::add_chromium_datasets_to_project,
+        )
+        .typed_get(chromium_datasets::list::list_chromium_datasets)
+}
+
+#[cfg(test)]
+mod tests {
+    use cellnoor_models::{
+        chromium_dataset::{ChromiumDatasetQuery, ChromiumDatasetSummary},
+        project::{ProjectCreation, ProjectIdChromiumDatasets, ProjectIdSpecimens},
+        specimen::{SpecimenQuery, SpecimenSummary},
+    };
+    use deadpool_diesel::postgres::Connection;
+    use diesel::{Connection as _, PgConnection};
+    use rstest::rstest;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use crate::{
+        db::{self, Operation},
+        test_state::{Database, database, root_db_conn},
+    };
+
+    /// A new project, which is rolled back after `f` runs
+    async fn with_project<F, T>(root_db_conn: &Connection, database: &Database, f: F) -> T
+    where
+        F: FnOnce(Uuid, &mut PgConnection) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let project: ProjectCreation = serde_json::from_value(json!({
+            "name": "project-test",
+            "lab_id": database.labs[0].id(),
+            "started_at": "2025-01-01T00:00:00Z",
+        }))
+        .unwrap();
+
+        root_db_conn
+            .interact(move |db_conn| {
+                db_conn.test_transaction::<_, db::Error, _>(|tx| {
+                    let project_id = project.execute(tx)?.id();
+                    Ok(f(project_id, tx))
+                })
+            })
+            .await
+            .unwrap()
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn add_specimens_to_project(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let mut specimen_ids: Vec<_> = database.specimens.iter().map(SpecimenSummary::id).collect();
+        specimen_ids.sort();
+
+        let mut listed = with_project(&root_db_conn, database, move |project_id, tx| {
+            let project_id = ProjectIdSpecimens(project_id);
+
+            // Adding the same specimens twice is not an error
+            (project_id, specimen_ids.clone()).execute(tx).unwrap();
+            (project_id, specimen_ids.clone()).execute(tx).unwrap();
+
+            let specimens: Vec<SpecimenSummary> =
+                (project_id, SpecimenQuery::default_with_no_limit())
+                    .execute(tx)
+                    .unwrap();
+            let listed: Vec<_> = specimens.iter().map(SpecimenSummary::id).collect();
+
+            (listed, specimen_ids)
+        })
+        .await;
+        listed.0.sort();
+
+        assert_eq!(listed.0, listed.1);
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn add_chromium_datasets_to_project(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let mut dataset_ids: Vec<_> = database
+            .chromium_datasets
+            .iter()
+            .map(ChromiumDatasetSummary::id)
+            .collect();
+        dataset_ids.sort();
+
+        let mut listed = with_project(&root_db_conn, database, move |project_id, tx| {
+            let project_id = ProjectIdChromiumDatasets(project_id);
+
+            (project_id, dataset_ids.clone()).execute(tx).unwrap();
+            (project_id, dataset_ids.clone()).execute(tx).unwrap();
+
+            let datasets: Vec<ChromiumDatasetSummary> =
+                (project_id, ChromiumDatasetQuery::default_with_no_limit())
+                    .execute(tx)
+                    .unwrap();
+            let listed: Vec<_> = datasets.iter().map(ChromiumDatasetSummary::id).collect();
+
+            (listed, dataset_ids)
+        })
+        .await;
+        listed.0.sort();
+
+        assert_eq!(listed.0, listed.1);
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn unknown_ids_are_invalid_references(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let specimen_id = database.specimens[0].id();
+        let dataset_id = database.chromium_datasets[0].id();
+
+        let results = with_project(&root_db_conn, database, move |project_id, tx| {
+            let unknown_specimen = tx.transaction(|tx| {
+                (
+                    ProjectIdSpecimens(project_id),
+                    vec![specimen_id, Uuid::now_v7()],
+                )
+                    .execute(tx)
+            });
+            let unknown_dataset = tx.transaction(|tx| {
+                (ProjectIdChromiumDatasets(project_id), vec![Uuid::now_v7()]).execute(tx)
+            });
+            let unknown_project = tx.transaction(|tx| {
+                (ProjectIdSpecimens(Uuid::now_v7()), vec![specimen_id]).execute(tx)
+            });
+            let unknown_project_for_dataset = tx.transaction(|tx| {
+                (ProjectIdChromiumDatasets(Uuid::now_v7()), vec![dataset_id]).execute(tx)
+            });
+
+            [
+                unknown_specimen,
+                unknown_dataset,
+                unknown_project,
+                unknown_project_for_dataset,
+            ]
+        })
+        .await;
+
+        for result in results {
+            assert!(
+                matches!(result, Err(db::Error::InvalidReference { .. })),
+                "{result:?}"
+            );
+        }
+    }
+}
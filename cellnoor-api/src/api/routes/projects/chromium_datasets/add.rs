@@ -0,0 +1,48 @@
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::project::ProjectIdChromiumDatasets;
+use cellnoor_schema::project_chromium_datasets;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        extract::{ValidPathJson, auth::AuthenticatedUser},
+        routes::{ApiResponse, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub async fn add_chromium_datasets_to_project(
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    ValidPathJson(project_id, dataset_ids): ValidPathJson<ProjectIdChromiumDatasets, Vec<Uuid>>,
+) -> ApiResponse<()> {
+    Ok((
+        StatusCode::OK,
+        inner_handler(state, user, (project_id, dataset_ids)).await?,
+    ))
+}
+
+impl db::Operation<()> for (ProjectIdChromiumDatasets, Vec<Uuid>) {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<(), db::Error> {
+        let (project_id, dataset_ids) = self;
+
+        let project_dataset_map: Vec<_> = dataset_ids
+            .iter()
+            .map(|d| {
+                (
+                    project_chromium_datasets::project_id.eq(project_id),
+                    project_chromium_datasets::dataset_id.eq(d),
+                )
+            })
+            .collect();
+
+        diesel::insert_into(project_chromium_datasets::table)
+            .values(&project_dataset_map)
+            .on_conflict_do_nothing()
+            .execute(db_conn)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use axum::extract::State;
+use cellnoor_models::{
+    chromium_dataset::{
+        ChromiumDatasetFilter, ChromiumDatasetInclude, ChromiumDatasetQuery, ChromiumDatasetSummary,
+    },
+    project::ProjectIdChromiumDatasets,
+};
+
+use crate::{
+    api::{
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, list_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub async fn list_chromium_datasets(
+    project_id: ProjectIdChromiumDatasets,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    format: Option<ExportFormat>,
+    QsQuery(request): QsQuery<ChromiumDatasetQuery>,
+) -> ListResponse<ChromiumDatasetSummary, ChromiumDatasetInclude> {
+    list_handler(state, user, (project_id, request), format).await
+}
+
+fn project_datasets_query(
+    (ProjectIdChromiumDatasets(project_id), mut dataset_query): (
+        ProjectIdChromiumDatasets,
+        ChromiumDatasetQuery,
+    ),
+) -> ChromiumDatasetQuery {
+    let project_ids = Some(vec![project_id]);
+    if let Some(filter) = &mut dataset_query.filter {
+        filter.project_ids = project_ids;
+    } else {
+        dataset_query.filter = Some(ChromiumDatasetFilter {
+            project_ids,
+            ..Default::default()
+        });
+    }
+
+    dataset_query
+}
+
+impl<T> db::Operation<T> for (ProjectIdChromiumDatasets, ChromiumDatasetQuery)
+where
+    ChromiumDatasetQuery: db::Operation<T>,
+{
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<T, db::Error> {
+        project_datasets_query(self).execute(db_conn)
+    }
+}
+
+impl db::Export for (ProjectIdChromiumDatasets, ChromiumDatasetQuery) {
+    type Row = ChromiumDatasetSummary;
+
+    fn export(
+        self,
+        db_conn: &mut diesel::PgConnection,
+        on_row: &mut dyn FnMut(ChromiumDatasetSummary) -> bool,
+    ) -> Result<(), db::Error> {
+        project_datasets_query(self).export(db_conn, on_row)
+    }
+}
@@ -0,0 +1,34 @@
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::project::{Project, ProjectCreation, ProjectId};
+use cellnoor_schema::projects::dsl::{id, projects};
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::{ValidJson, auth::AuthenticatedUser},
+        routes::{ApiResponse, Root, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn create_project(
+    _: Root,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    ValidJson(request): ValidJson<ProjectCreation>,
+) -> ApiResponse<Project> {
+    let item = inner_handler(state, user, request).await?;
+    Ok((StatusCode::CREATED, item))
+}
+
+impl db::Operation<Project> for ProjectCreation {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<Project, db::Error> {
+        let created_id = diesel::insert_into(projects)
+            .values(self)
+            .returning(id)
+            .get_result(db_conn)?;
+
+        ProjectId(created_id).execute(db_conn)
+    }
+}
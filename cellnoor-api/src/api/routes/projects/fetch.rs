@@ -0,0 +1,31 @@
+use axum::extract::State;
+use cellnoor_models::{
+    project::{Project, ProjectId},
+    read_options::{NoInclude, ReadOptions},
+};
+use cellnoor_schema::projects::dsl::id;
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{FetchResponse, fetch_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn fetch_project(
+    project_id: ProjectId,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    QsQuery(options): QsQuery<ReadOptions<NoInclude>>,
+) -> FetchResponse<Project> {
+    fetch_handler(state, user, project_id, options).await
+}
+
+impl db::Operation<Project> for ProjectId {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<Project, db::Error> {
+        Ok(Project::query().filter(id.eq(self)).first(db_conn)?)
+    }
+}
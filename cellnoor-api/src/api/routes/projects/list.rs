@@ -0,0 +1,96 @@
+use axum::extract::State;
+use cellnoor_models::{
+    generic_query::Keyset,
+    project::{ProjectFilter, ProjectQuery, ProjectSummary},
+};
+use cellnoor_schema::projects as t;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, Root, list_handler},
+    },
+    db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter, utils::like_any},
+    state::AppState,
+};
+
+pub(super) async fn list_projects(
+    _: Root,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    format: Option<ExportFormat>,
+    QsQuery(request): QsQuery<ProjectQuery>,
+) -> ListResponse<ProjectSummary> {
+    list_handler(state, user, request, format).await
+}
+
+impl db::List for ProjectQuery {
+    type Row = ProjectSummary;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, ProjectSummary> {
+        let ProjectQuery {
+            filter,
+            limit,
+            offset,
+            order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
+        } = self;
+
+        let mut stmt = ProjectSummary::query()
+            .filter(filter.to_boxed_filter())
+            .into_boxed();
+
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
+        }
+
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(t::id);
+        }
+
+        stmt
+    }
+
+    fn id(row: &ProjectSummary) -> Uuid {
+        row.id()
+    }
+}
+
+impl<'a, QS: 'a> ToBoxedFilter<'a, QS> for ProjectFilter
+where
+    t::id: SelectableExpression<QS>,
+    t::name: SelectableExpression<QS>,
+    t::lab_id: SelectableExpression<QS>,
+{
+    fn to_boxed_filter(&'a self) -> BoxedFilter<'a, QS> {
+        let Self { ids, names, labs } = self;
+        let mut filter = BoxedFilter::new_true();
+
+        if let Some(ids) = ids {
+            filter = filter.and_condition(t::id.eq_any(ids));
+        }
+
+        if let Some(names) = names {
+            filter = filter.and_condition(like_any(t::name, names));
+        }
+
+        if let Some(labs) = labs {
+            filter = filter.and_condition(t::lab_id.eq_any(labs));
+        }
+
+        filter
+    }
+}
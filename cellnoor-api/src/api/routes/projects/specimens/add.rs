@@ -0,0 +1,48 @@
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::project::ProjectIdSpecimens;
+use cellnoor_schema::project_specimens;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        extract::{ValidPathJson, auth::AuthenticatedUser},
+        routes::{ApiResponse, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub async fn add_specimens_to_project(
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    ValidPathJson(project_id, specimen_ids): ValidPathJson<ProjectIdSpecimens, Vec<Uuid>>,
+) -> ApiResponse<()> {
+    Ok((
+        StatusCode::OK,
+        inner_handler(state, user, (project_id, specimen_ids)).await?,
+    ))
+}
+
+impl db::Operation<()> for (ProjectIdSpecimens, Vec<Uuid>) {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<(), db::Error> {
+        let (project_id, specimen_ids) = self;
+
+        let project_specimen_map: Vec<_> = specimen_ids
+            .iter()
+            .map(|s| {
+                (
+                    project_specimens::project_id.eq(project_id),
+                    project_specimens::specimen_id.eq(s),
+                )
+            })
+            .collect();
+
+        diesel::insert_into(project_specimens::table)
+            .values(&project_specimen_map)
+            .on_conflict_do_nothing()
+            .execute(db_conn)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use axum::extract::State;
+use cellnoor_models::{
+    project::ProjectIdSpecimens,
+    specimen::{SpecimenFilter, SpecimenInclude, SpecimenQuery, SpecimenSummary},
+};
+
+use crate::{
+    api::{
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, list_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub async fn list_specimens(
+    project_id: ProjectIdSpecimens,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    format: Option<ExportFormat>,
+    QsQuery(request): QsQuery<SpecimenQuery>,
+) -> ListResponse<SpecimenSummary, SpecimenInclude> {
+    list_handler(state, user, (project_id, request), format).await
+}
+
+fn project_specimens_query(
+    (ProjectIdSpecimens(project_id), mut specimen_query): (ProjectIdSpecimens, SpecimenQuery),
+) -> SpecimenQuery {
+    let project_ids = Some(vec![project_id]);
+    if let Some(filter) = &mut specimen_query.filter {
+        filter.project_ids = project_ids;
+    } else {
+        specimen_query.filter = Some(SpecimenFilter {
+            project_ids,
+            ..Default::default()
+        });
+    }
+
+    specimen_query
+}
+
+impl<T> db::Operation<T> for (ProjectIdSpecimens, SpecimenQuery)
+where
+    SpecimenQuery: db::Operation<T>,
+{
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<T, db::Error> {
+        project_specimens_query(self).execute(db_conn)
+    }
+}
+
+impl db::Export for (ProjectIdSpecimens, SpecimenQuery) {
+    type Row = SpecimenSummary;
+
+    fn export(
+        self,
+        db_conn: &mut diesel::PgConnection,
+        on_row: &mut dyn FnMut(SpecimenSummary) -> bool,
+    ) -> Result<(), db::Error> {
+        project_specimens_query(self).export(db_conn, on_row)
+    }
+}
@@ -0,0 +1,35 @@
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::project::{Project, ProjectId, ProjectUpdate};
+use cellnoor_schema::projects;
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::{ValidJson, auth::AuthenticatedUser},
+        routes::{ApiResponse, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn update_project(
+    id: ProjectId,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    ValidJson(request): ValidJson<ProjectUpdate>,
+) -> ApiResponse<Project> {
+    let item = inner_handler(state, user, (id, request)).await?;
+    Ok((StatusCode::OK, item))
+}
+
+impl db::Operation<Project> for (ProjectId, ProjectUpdate) {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<Project, db::Error> {
+        let (id, update) = self;
+
+        diesel::update(projects::table.find(id))
+            .set(update)
+            .execute(db_conn)?;
+
+        id.execute(db_conn)
+    }
+}
@@ -0,0 +1,109 @@
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::search::{SearchEntityType, SearchHit, SearchQuery, SearchResults};
+use cellnoor_schema::search_documents as t;
+use diesel::{
+    dsl::sql,
+    prelude::*,
+    sql_types::{Bool, Float, Text},
+};
+
+use crate::{
+    api::{
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        openapi::ApiRouter,
+        routes::{ApiResponse, Root, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new().typed_get(search)
+}
+
+async fn search(
+    _: Root,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    QsQuery(query): QsQuery<SearchQuery>,
+) -> ApiResponse<SearchResults> {
+    Ok((StatusCode::OK, inner_handler(state, user, query).await?))
+}
+
+impl db::Operation<SearchResults> for SearchQuery {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<SearchResults, db::Error> {
+        let Self { q, limit } = self;
+        let q: &str = q.as_ref();
+
+        // `document` isn't in the Rust-side schema because diesel has no `tsvector`
+        // type. Full-text search finds whole words anywhere in the content, while
+        // trigram word similarity finds partial names like "PBMC-0" or typos
+        let matches = sql::<Bool>("(document @@ websearch_to_tsquery('simple', ")
+            .bind::<Text, _>(q)
+            .sql(") or ")
+            .bind::<Text, _>(q)
+            .sql(" <% content)");
+        let rank = sql::<Float>("greatest(ts_rank(document, websearch_to_tsquery('simple', ")
+            .bind::<Text, _>(q)
+            .sql(")), word_similarity(")
+            .bind::<Text, _>(q)
+            .sql(", content))");
+
+        let hits: Vec<(SearchEntityType, SearchHit)> = t::table
+            .select((t::entity_type, (t::id, t::label, t::links, rank.clone())))
+            .filter(matches)
+            .order((rank.desc(), t::label))
+            .limit(limit)
+            .load(db_conn)?;
+
+        let mut results = SearchResults::default();
+        for (entity_type, hit) in hits {
+            results.push(entity_type, hit);
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cellnoor_models::search::{SearchEntityType, SearchQuery};
+    use deadpool_diesel::postgres::Connection;
+    use non_empty::NonEmptyString;
+    use rstest::rstest;
+
+    use crate::{
+        db::Operation,
+        test_state::{Database, database, root_db_conn},
+    };
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn partial_specimen_name(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let specimen = &database.specimens[0];
+        let name = specimen.name();
+        let partial_name = &name[..name.len() - 2];
+
+        let query = SearchQuery {
+            q: NonEmptyString::new(partial_name).unwrap(),
+            limit: 100,
+        };
+
+        let results = root_db_conn
+            .interact(|db_conn| query.execute(db_conn).unwrap())
+            .await
+            .unwrap();
+
+        assert!(
+            results
+                .get(SearchEntityType::Specimen)
+                .iter()
+                .any(|hit| hit.id() == specimen.id()),
+            "specimen not found when searching for part of its name"
+        );
+    }
+}
@@ -1,16 +1,16 @@
-use axum::{Router, routing::post};
-use axum_extra::routing::RouterExt;
+use axum_extra::routing::TypedPath;
+use cellnoor_models::sequencing_run::SequencingRunIdLibraries;
 
-use crate::state::AppState;
+use crate::api::openapi::ApiRouter;
 
 mod create;
 mod libraries;
 
-pub(super) fn router() -> Router<AppState> {
-    Router::new()
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
         .typed_post(create::create_sequencing_run)
-        .route(
-            "/libraries",
-            post(libraries::add_to_sequencing_run::add_libraries_to_sequencing_run),
+        .post(
+            SequencingRunIdLibraries::PATH,
+            libraries::add_to_sequencing_run::add_libraries_to_sequencing_run,
         )
 }
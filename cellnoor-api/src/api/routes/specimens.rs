@@ -1,25 +1,30 @@
-use axum::{Router, routing::post};
-use axum_extra::routing::{RouterExt, TypedPath};
+use axum_extra::routing::TypedPath;
 use cellnoor_models::specimen::SpecimenIdMeasurements;
 
-use crate::state::AppState;
+use crate::api::openapi::ApiRouter;
 
 mod chromium_datasets;
 mod create;
 mod fetch;
+mod include;
+mod lineage;
 mod list;
 mod measurements;
+mod progress;
 mod update;
 
-pub(super) fn router() -> Router<AppState> {
-    Router::new()
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
         .typed_post(create::create_specimen)
         .typed_get(fetch::fetch_specimen)
         .typed_get(list::list_specimens)
-        .route(
+        .post(
             SpecimenIdMeasurements::PATH,
-            post(measurements::create::create_measurement),
+            measurements::create::create_measurement,
         )
         .typed_get(measurements::list::list_measurements)
         .typed_get(chromium_datasets::list::list_chromium_datasets)
+        .typed_get(lineage::fetch_lineage)
+        .typed_get(progress::fetch_progress)
+        .typed_get(progress::list_stalled)
 }
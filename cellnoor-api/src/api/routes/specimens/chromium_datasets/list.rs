@@ -1,16 +1,16 @@
-use axum::{extract::State, http::StatusCode};
+use axum::extract::State;
 use cellnoor_models::{
-    chromium_dataset::ChromiumDatasetSummary, specimen::SpecimenIdChromiumDatasets,
+    chromium_dataset::{
+        ChromiumDatasetFilter, ChromiumDatasetInclude, ChromiumDatasetQuery, ChromiumDatasetSummary,
+    },
+    specimen::{SpecimenFilter, SpecimenIdChromiumDatasets},
 };
-use cellnoor_schema::specimens;
-use diesel::prelude::*;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{
-            ApiResponse, chromium_datasets::chromium_datasets_to_all_specimens, inner_handler,
-        },
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, list_handler},
     },
     db,
     state::AppState,
@@ -20,21 +20,46 @@ pub async fn list_chromium_datasets(
     specimen_id: SpecimenIdChromiumDatasets,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Vec<ChromiumDatasetSummary>> {
-    Ok((
-        StatusCode::OK,
-        inner_handler(state, user, specimen_id).await?,
-    ))
+    format: Option<ExportFormat>,
+    QsQuery(request): QsQuery<ChromiumDatasetQuery>,
+) -> ListResponse<ChromiumDatasetSummary, ChromiumDatasetInclude> {
+    list_handler(state, user, (specimen_id, request), format).await
+}
+
+fn specimen_datasets_query(
+    (SpecimenIdChromiumDatasets(specimen_id), mut dataset_query): (
+        SpecimenIdChromiumDatasets,
+        ChromiumDatasetQuery,
+    ),
+) -> ChromiumDatasetQuery {
+    let filter = dataset_query
+        .filter
+        .get_or_insert_with(ChromiumDatasetFilter::default);
+    filter
+        .specimen
+        .get_or_insert_with(SpecimenFilter::default)
+        .ids = Some(vec![specimen_id]);
+
+    dataset_query
 }
 
-impl db::Operation<Vec<ChromiumDatasetSummary>> for SpecimenIdChromiumDatasets {
-    fn execute(
+impl<T> db::Operation<T> for (SpecimenIdChromiumDatasets, ChromiumDatasetQuery)
+where
+    ChromiumDatasetQuery: db::Operation<T>,
+{
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<T, db::Error> {
+        specimen_datasets_query(self).execute(db_conn)
+    }
+}
+
+impl db::Export for (SpecimenIdChromiumDatasets, ChromiumDatasetQuery) {
+    type Row = ChromiumDatasetSummary;
+
+    fn export(
         self,
         db_conn: &mut diesel::PgConnection,
-    ) -> Result<Vec<ChromiumDatasetSummary>, db::Error> {
-        Ok(chromium_datasets_to_all_specimens()
-            .select(ChromiumDatasetSummary::as_select())
-            .filter(specimens::id.eq(self))
-            .load(db_conn)?)
+        on_row: &mut dyn FnMut(ChromiumDatasetSummary) -> bool,
+    ) -> Result<(), db::Error> {
+        specimen_datasets_query(self).export(db_conn, on_row)
     }
 }
@@ -1,6 +1,9 @@
 use axum::extract::State;
 use cellnoor_models::specimen::{Specimen, SpecimenCreation, SpecimenId};
-use cellnoor_schema::specimens::dsl::{id, specimens};
+use cellnoor_schema::{
+    specimen_committee_approvals,
+    specimens::dsl::{id, specimens},
+};
 use diesel::prelude::*;
 use reqwest::StatusCode;
 
@@ -25,6 +28,8 @@ pub(super) async fn create_specimen(
 
 impl db::Operation<Specimen> for SpecimenCreation {
     fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<Specimen, db::Error> {
+        let committee_approval_ids = self.committee_approval_ids().to_vec();
+
         let split = match self {
             Self::FixedBlock(s) => s.split_for_insertion(),
             Self::FrozenBlock(s) => s.split_for_insertion(),
@@ -42,6 +47,20 @@ impl db::Operation<Specimen> for SpecimenCreation {
             .returning(id)
             .get_result(db_conn)?;
 
+        let committee_approvals: Vec<_> = committee_approval_ids
+            .iter()
+            .map(|a| {
+                (
+                    specimen_committee_approvals::specimen_id.eq(created_id),
+                    specimen_committee_approvals::approval_id.eq(a),
+                )
+            })
+            .collect();
+
+        diesel::insert_into(specimen_committee_approvals::table)
+            .values(&committee_approvals)
+            .execute(db_conn)?;
+
         SpecimenId(created_id).execute(db_conn)
     }
 }
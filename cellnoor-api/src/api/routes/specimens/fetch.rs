@@ -1,13 +1,15 @@
 use axum::extract::State;
-use cellnoor_models::specimen::{Specimen, SpecimenId};
+use cellnoor_models::{
+    read_options::ReadOptions,
+    specimen::{Specimen, SpecimenId, SpecimenInclude},
+};
 use cellnoor_schema::specimens::dsl::id;
 use diesel::prelude::*;
-use reqwest::StatusCode;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{FetchResponse, fetch_handler},
     },
     db,
     state::AppState,
@@ -17,9 +19,9 @@ pub(super) async fn fetch_specimen(
     specimen_id: SpecimenId,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Specimen> {
-    let item = inner_handler(state, user, specimen_id).await?;
-    Ok((StatusCode::OK, item))
+    QsQuery(options): QsQuery<ReadOptions<SpecimenInclude>>,
+) -> FetchResponse<Specimen, SpecimenInclude> {
+    fetch_handler(state, user, specimen_id, options).await
 }
 
 impl db::Operation<Specimen> for SpecimenId {
@@ -0,0 +1,32 @@
+use cellnoor_models::{
+    donor::DonorSummary, lab::LabSummary, person::PersonSummary, specimen::SpecimenInclude,
+};
+use cellnoor_schema::{donors, labs, people};
+use diesel::prelude::*;
+
+use crate::{
+    api::read_options::{Object, Relation, embed_foreign_key},
+    db,
+};
+
+impl Relation for SpecimenInclude {
+    fn embed(self, items: &mut [Object], db_conn: &mut PgConnection) -> Result<(), db::Error> {
+        match self {
+            Self::Lab => embed_foreign_key(items, "lab_id", "lab", |ids| {
+                Ok(LabSummary::query()
+                    .filter(labs::id.eq_any(ids))
+                    .load::<LabSummary>(db_conn)?)
+            }),
+            Self::SubmittedBy => embed_foreign_key(items, "submitted_by", "submitted_by", |ids| {
+                Ok(PersonSummary::query()
+                    .filter(people::id.eq_any(ids))
+                    .load::<PersonSummary>(db_conn)?)
+            }),
+            Self::Donor => embed_foreign_key(items, "donor_id", "donor", |ids| {
+                Ok(DonorSummary::query()
+                    .filter(donors::id.eq_any(ids))
+                    .load::<DonorSummary>(db_conn)?)
+            }),
+        }
+    }
+}
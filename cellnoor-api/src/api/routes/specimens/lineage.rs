@@ -0,0 +1,163 @@
+use axum::extract::State;
+use cellnoor_models::{
+    lineage::{Lineage, LineageNodeType, LineageQuery},
+    specimen::SpecimenIdLineage,
+};
+use cellnoor_schema::{
+    cdna, chip_loadings, chromium_dataset_libraries, chromium_datasets, gem_pools, libraries,
+    specimens, suspension_pools, suspension_tagging, suspensions,
+};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{
+            FetchResponse,
+            lineage::{add_sequencing_runs, lineage_response},
+        },
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn fetch_lineage(
+    specimen_id: SpecimenIdLineage,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    QsQuery(query): QsQuery<LineageQuery>,
+) -> FetchResponse<Lineage> {
+    lineage_response(state, user, specimen_id, query).await
+}
+
+// Walk downstream from the specimen, one table at a time. Each step only
+// follows edges out of nodes already in the graph, so material that was pooled
+// with this specimen's suspensions doesn't show up unless it's a descendant
+impl db::Operation<Lineage> for SpecimenIdLineage {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<Lineage, db::Error> {
+        use LineageNodeType::{ChipLoading, Specimen, Suspension, SuspensionPool};
+
+        let mut lineage = Lineage::default();
+
+        let (specimen_id, specimen_readable_id): (Uuid, String) = specimens::table
+            .select((specimens::id, specimens::readable_id))
+            .filter(specimens::id.eq(self))
+            .first(db_conn)?;
+        lineage.add_node(Specimen, specimen_id, specimen_readable_id);
+
+        let suspension_ids: Vec<(Uuid, String)> = suspensions::table
+            .select((suspensions::id, suspensions::readable_id))
+            .filter(suspensions::parent_specimen_id.eq(specimen_id))
+            .load(db_conn)?;
+        let suspension_ids: Vec<Uuid> = suspension_ids
+            .into_iter()
+            .map(|(suspension_id, readable_id)| {
+                lineage.add_node(Suspension, suspension_id, readable_id);
+                lineage.add_edge(specimen_id, suspension_id);
+                suspension_id
+            })
+            .collect();
+
+        let taggings: Vec<(Uuid, Uuid, String)> = suspension_tagging::table
+            .inner_join(suspension_pools::table)
+            .select((
+                suspension_tagging::suspension_id,
+                suspension_pools::id,
+                suspension_pools::readable_id,
+            ))
+            .filter(suspension_tagging::suspension_id.eq_any(&suspension_ids))
+            .load(db_conn)?;
+        let mut pool_ids = Vec::with_capacity(taggings.len());
+        for (suspension_id, pool_id, readable_id) in taggings {
+            lineage.add_node(SuspensionPool, pool_id, readable_id);
+            lineage.add_edge(suspension_id, pool_id);
+            pool_ids.push(pool_id);
+        }
+
+        let loadings: Vec<(Uuid, Uuid, Option<Uuid>, Option<Uuid>)> = chip_loadings::table
+            .select((
+                chip_loadings::id,
+                chip_loadings::gem_pool_id,
+                chip_loadings::suspension_id,
+                chip_loadings::suspension_pool_id,
+            ))
+            .filter(
+                chip_loadings::suspension_id
+                    .eq_any(&suspension_ids)
+                    .or(chip_loadings::suspension_pool_id.eq_any(&pool_ids)),
+            )
+            .load(db_conn)?;
+        let mut gem_pool_ids = Vec::with_capacity(loadings.len());
+        for (loading_id, gem_pool_id, suspension_id, pool_id) in loadings {
+            lineage.add_node(ChipLoading, loading_id, loading_id.to_string());
+            if let Some(loaded_from) = suspension_id.or(pool_id) {
+                lineage.add_edge(loaded_from, loading_id);
+            }
+            lineage.add_edge(loading_id, gem_pool_id);
+            gem_pool_ids.push(gem_pool_id);
+        }
+
+        add_descendants_of_gem_pools(&mut lineage, &gem_pool_ids, db_conn)?;
+
+        Ok(lineage)
+    }
+}
+
+fn add_descendants_of_gem_pools(
+    lineage: &mut Lineage,
+    gem_pool_ids: &[Uuid],
+    db_conn: &mut PgConnection,
+) -> Result<(), db::Error> {
+    use LineageNodeType::{Cdna, ChromiumDataset, GemPool, Library};
+
+    let gem_pools: Vec<(Uuid, String)> = gem_pools::table
+        .select((gem_pools::id, gem_pools::readable_id))
+        .filter(gem_pools::id.eq_any(gem_pool_ids))
+        .load(db_conn)?;
+    for (gem_pool_id, readable_id) in gem_pools {
+        lineage.add_node(GemPool, gem_pool_id, readable_id);
+    }
+
+    let cdna: Vec<(Uuid, String, Option<Uuid>)> = cdna::table
+        .select((cdna::id, cdna::readable_id, cdna::gem_pool_id))
+        .filter(cdna::gem_pool_id.eq_any(gem_pool_ids))
+        .load(db_conn)?;
+    let mut cdna_ids = Vec::with_capacity(cdna.len());
+    for (cdna_id, readable_id, gem_pool_id) in cdna {
+        lineage.add_node(Cdna, cdna_id, readable_id);
+        if let Some(gem_pool_id) = gem_pool_id {
+            lineage.add_edge(gem_pool_id, cdna_id);
+        }
+        cdna_ids.push(cdna_id);
+    }
+
+    let libraries: Vec<(Uuid, String, Uuid)> = libraries::table
+        .select((libraries::id, libraries::readable_id, libraries::cdna_id))
+        .filter(libraries::cdna_id.eq_any(&cdna_ids))
+        .load(db_conn)?;
+    let mut library_ids = Vec::with_capacity(libraries.len());
+    for (library_id, readable_id, cdna_id) in libraries {
+        lineage.add_node(Library, library_id, readable_id);
+        lineage.add_edge(cdna_id, library_id);
+        library_ids.push(library_id);
+    }
+
+    add_sequencing_runs(lineage, &library_ids, db_conn)?;
+
+    let datasets: Vec<(Uuid, Uuid, String)> = chromium_dataset_libraries::table
+        .inner_join(chromium_datasets::table)
+        .select((
+            chromium_dataset_libraries::library_id,
+            chromium_datasets::id,
+            chromium_datasets::name,
+        ))
+        .filter(chromium_dataset_libraries::library_id.eq_any(&library_ids))
+        .load(db_conn)?;
+    for (library_id, dataset_id, name) in datasets {
+        lineage.add_node(ChromiumDataset, dataset_id, name);
+        lineage.add_edge(library_id, dataset_id);
+    }
+
+    Ok(())
+}
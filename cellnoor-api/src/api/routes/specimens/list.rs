@@ -1,15 +1,18 @@
 use axum::extract::State;
-use cellnoor_models::specimen::{SpecimenFilter, SpecimenQuery, SpecimenSummary};
-use cellnoor_schema::specimens as t;
+use cellnoor_models::{
+    generic_query::Keyset,
+    specimen::{SpecimenFilter, SpecimenInclude, SpecimenQuery, SpecimenSummary},
+};
+use cellnoor_schema::{project_specimens, specimen_progress, specimens as t};
 use diesel::{dsl::AssumeNotNull, prelude::*};
 use jiff_diesel::ToDiesel;
-use reqwest::StatusCode;
-use serde_qs::axum::QsQuery;
+use uuid::Uuid;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, Root, inner_handler},
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, Root, list_handler},
     },
     db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter, utils::like_any},
     state::AppState,
@@ -19,35 +22,51 @@ pub(super) async fn list_specimens(
     _: Root,
     state: State<AppState>,
     user: AuthenticatedUser,
+    format: Option<ExportFormat>,
     QsQuery(request): QsQuery<SpecimenQuery>,
-) -> ApiResponse<Vec<SpecimenSummary>> {
-    let item = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, item))
+) -> ListResponse<SpecimenSummary, SpecimenInclude> {
+    list_handler(state, user, request, format).await
 }
 
-impl db::Operation<Vec<SpecimenSummary>> for SpecimenQuery {
-    fn execute(
-        self,
-        db_conn: &mut diesel::PgConnection,
-    ) -> Result<Vec<SpecimenSummary>, db::Error> {
-        let Self {
+impl db::List for SpecimenQuery {
+    type Row = SpecimenSummary;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, SpecimenSummary> {
+        let SpecimenQuery {
             filter,
             limit,
             offset,
             order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
         } = self;
 
         let mut stmt = SpecimenSummary::query()
-            .limit(limit)
-            .offset(offset)
             .filter(filter.to_boxed_filter())
             .into_boxed();
 
-        for ordering in order_by.as_ref() {
-            stmt = stmt.then_order_by(ordering);
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
+        }
+
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(t::id);
         }
 
-        Ok(stmt.load(db_conn)?)
+        stmt
+    }
+
+    fn id(row: &SpecimenSummary) -> Uuid {
+        row.id()
     }
 }
 
@@ -59,6 +78,7 @@ where
     AssumeNotNull<t::name>: SelectableExpression<QS>,
     AssumeNotNull<t::submitted_by>: SelectableExpression<QS>,
     AssumeNotNull<t::lab_id>: SelectableExpression<QS>,
+    AssumeNotNull<t::donor_id>: SelectableExpression<QS>,
     AssumeNotNull<t::received_at>: SelectableExpression<QS>,
     AssumeNotNull<t::species>: SelectableExpression<QS>,
     AssumeNotNull<t::host_species>: SelectableExpression<QS>,
@@ -72,6 +92,7 @@ where
     AssumeNotNull<t::returned_at>: SelectableExpression<QS>,
     AssumeNotNull<t::additional_data>: SelectableExpression<QS>,
 {
+    #[allow(clippy::too_many_lines)]
     fn to_boxed_filter(&'a self) -> db::BoxedFilter<'a, QS> {
         let mut filter = BoxedFilter::new_true();
 
@@ -80,6 +101,8 @@ where
             names,
             submitted_by,
             labs,
+            donor_ids,
+            project_ids,
             received_before,
             received_after,
             species,
@@ -94,6 +117,8 @@ where
             returned_after,
             returned_by,
             additional_data,
+            additional_data_predicates,
+            stage,
         } = self;
 
         if let Some(ids) = ids {
@@ -112,6 +137,18 @@ where
             filter = filter.and_condition(t::lab_id.assume_not_null().eq_any(labs));
         }
 
+        if let Some(donor_ids) = donor_ids {
+            filter = filter.and_condition(t::donor_id.assume_not_null().eq_any(donor_ids));
+        }
+
+        if let Some(project_ids) = project_ids {
+            let project_specimens = project_specimens::table
+                .select(project_specimens::specimen_id)
+                .filter(project_specimens::project_id.eq_any(project_ids))
+                .into_boxed();
+            filter = filter.and_condition(t::id.assume_not_null().eq_any(project_specimens));
+        }
+
         if let Some(received_before) = received_before.map(ToDiesel::to_diesel) {
             filter = filter.and_condition(t::received_at.assume_not_null().lt(received_before));
         }
@@ -174,6 +211,18 @@ where
             );
         }
 
+        for predicate in additional_data_predicates.iter().flatten() {
+            filter = filter.and_condition(predicate.matches(t::additional_data.assume_not_null()));
+        }
+
+        if let Some(stage) = stage {
+            let specimens_in_stage = specimen_progress::table
+                .select(specimen_progress::specimen_id)
+                .filter(specimen_progress::stage.eq_any(stage))
+                .into_boxed();
+            filter = filter.and_condition(t::id.assume_not_null().eq_any(specimens_in_stage));
+        }
+
         filter
     }
 }
@@ -182,11 +231,18 @@ where
 mod tests {
     use std::cmp::Ordering;
 
-    use cellnoor_models::specimen::*;
+    use cellnoor_models::{
+        generic_query::{Page, TotalMode},
+        specimen::*,
+    };
     use deadpool_diesel::postgres::Connection;
+    use diesel::{Connection as _, PgConnection};
+    use pretty_assertions::assert_eq;
     use rstest::rstest;
+    use uuid::Uuid;
 
     use crate::{
+        db::{self, Operation},
         test_state::{Database, database, root_db_conn},
         test_util::test_query,
     };
@@ -250,4 +306,106 @@ mod tests {
             .run(root_db_conn)
             .await;
     }
+
+    fn page(query: SpecimenQuery, db_conn: &mut PgConnection) -> Page<SpecimenSummary> {
+        query.execute(db_conn).unwrap()
+    }
+
+    #[rstest]
+    #[case(SpecimenOrderBy::species { descending: Some(false) })]
+    #[case(SpecimenOrderBy::returned_at { descending: Some(true) })]
+    #[case(SpecimenOrderBy::returned_at { descending: Some(false) })]
+    #[case(SpecimenOrderBy::lab_id { descending: Some(true) })]
+    #[awt]
+    #[tokio::test]
+    async fn cursor_pages_through_ties(
+        #[future] root_db_conn: Connection,
+        #[case] order_by: SpecimenOrderBy,
+    ) {
+        // Most specimens share a species, lab and (null) returned_at with
+        // others, so the cursor has to fall back to the ID to make progress
+        let query = move |cursor: Option<Uuid>, limit: i64| {
+            SpecimenQuery::builder()
+                .order_by(order_by.clone())
+                .limit(limit)
+                .maybe_cursor(cursor)
+                .build()
+        };
+
+        let (paged, all) = root_db_conn
+            .interact(move |db_conn| {
+                db_conn.test_transaction::<_, db::Error, _>(|tx| {
+                    let mut paged = Vec::new();
+                    let mut cursor = None;
+
+                    loop {
+                        let page = page(query(cursor, 4), tx);
+                        paged.extend(page.items.iter().map(SpecimenSummary::id));
+
+                        let Some(next_cursor) = page.next_cursor else {
+                            break;
+                        };
+                        cursor = Some(next_cursor);
+                    }
+
+                    let all: Vec<SpecimenSummary> = query(None, i64::MAX).execute(tx)?;
+                    let all: Vec<_> = all.iter().map(SpecimenSummary::id).collect();
+
+                    Ok((paged, all))
+                })
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(paged, all);
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn page_totals(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let submitted_by = database.specimens[0].submitted_by();
+        let n_submitted = database
+            .specimens
+            .iter()
+            .filter(|s| s.submitted_by() == submitted_by)
+            .count();
+        let n_submitted = i64::try_from(n_submitted).unwrap();
+
+        let query = move |total: Option<TotalMode>| {
+            SpecimenQuery::builder()
+                .filter(
+                    SpecimenFilter::builder()
+                        .submitted_by(vec![submitted_by])
+                        .build(),
+                )
+                .limit(1)
+                .offset(1)
+                .maybe_total(total)
+                .build()
+        };
+
+        let [default, exact, estimated, skipped] = root_db_conn
+            .interact(move |db_conn| {
+                db_conn.test_transaction::<_, db::Error, _>(|tx| {
+                    Ok([
+                        None,
+                        Some(TotalMode::Exact),
+                        Some(TotalMode::Estimated),
+                        Some(TotalMode::Skip),
+                    ]
+                    .map(|total| page(query(total), tx).total))
+                })
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(default, None);
+        assert_eq!(exact, Some(n_submitted));
+        assert!(estimated.is_some_and(|n| n >= 0), "{estimated:?}");
+        assert_eq!(skipped, None);
+    }
 }
@@ -2,24 +2,26 @@ use axum::extract::State;
 use cellnoor_models::specimen::{SpecimenIdMeasurements, measurement::SpecimenMeasurement};
 use cellnoor_schema::specimen_measurements;
 use diesel::prelude::*;
-use reqwest::StatusCode;
 
 use crate::{
     api::{
+        export::ExportFormat,
         extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        routes::{UnpagedListResponse, unpaged_list_handler},
     },
     db::{self},
     state::AppState,
 };
 
+// Not paged, since a specimen is only measured a handful of times before
+// it's used up
 pub async fn list_measurements(
     specimen_id: SpecimenIdMeasurements,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Vec<SpecimenMeasurement>> {
-    let item = inner_handler(state, user, specimen_id).await?;
-    Ok((StatusCode::OK, item))
+    format: Option<ExportFormat>,
+) -> UnpagedListResponse<SpecimenMeasurement> {
+    unpaged_list_handler(state, user, specimen_id, format).await
 }
 
 impl db::Operation<Vec<SpecimenMeasurement>> for SpecimenIdMeasurements {
@@ -0,0 +1,280 @@
+use axum::{extract::State, http::StatusCode};
+use axum_extra::routing::TypedPath;
+use cellnoor_models::specimen::{
+    SpecimenIdProgress, SpecimenProgress, SpecimenStage, SpecimenStageTimes, StalledSpecimenQuery,
+};
+use cellnoor_schema::{specimen_progress as t, specimens};
+use diesel::prelude::*;
+use jiff::{SignedDuration, Timestamp};
+use jiff_diesel::ToDiesel;
+
+use crate::{
+    api::{
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ApiResponse, inner_handler},
+    },
+    db::{self, ToBoxedFilter},
+    state::AppState,
+};
+
+#[derive(TypedPath)]
+#[typed_path("/stalled")]
+pub(super) struct StalledSpecimens;
+
+pub(super) async fn fetch_progress(
+    specimen_id: SpecimenIdProgress,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+) -> ApiResponse<SpecimenProgress> {
+    Ok((
+        StatusCode::OK,
+        inner_handler(state, user, specimen_id).await?,
+    ))
+}
+
+pub(super) async fn list_stalled(
+    _: StalledSpecimens,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    QsQuery(query): QsQuery<StalledSpecimenQuery>,
+) -> ApiResponse<Vec<SpecimenProgress>> {
+    Ok((StatusCode::OK, inner_handler(state, user, query).await?))
+}
+
+impl db::Operation<SpecimenProgress> for SpecimenIdProgress {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<SpecimenProgress, db::Error> {
+        let times = SpecimenStageTimes::query()
+            .filter(t::specimen_id.eq(self))
+            .first(db_conn)?;
+
+        Ok(SpecimenProgress::new(times, Timestamp::now()))
+    }
+}
+
+// Ordered from the longest stalled
+impl db::Operation<Vec<SpecimenProgress>> for StalledSpecimenQuery {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<Vec<SpecimenProgress>, db::Error> {
+        let Self { sla_days, filter } = &self;
+
+        let now = Timestamp::now();
+        let cutoff = SignedDuration::try_from_secs_f64(sla_days * 86_400.0)
+            .ok()
+            .and_then(|sla| now.checked_sub(sla).ok())
+            .ok_or_else(|| db::Error::Data {
+                message: format!("invalid SLA: {sla_days} days"),
+            })?;
+
+        let matching_specimens = specimens::table
+            .select(specimens::id)
+            .filter(filter.to_boxed_filter())
+            .into_boxed();
+
+        let stalled: Vec<SpecimenStageTimes> = SpecimenStageTimes::query()
+            .filter(t::stage.ne(SpecimenStage::Delivered))
+            .filter(t::stage_entered_at.lt(cutoff.to_diesel()))
+            .filter(t::specimen_id.eq_any(matching_specimens))
+            .order_by(t::stage_entered_at)
+            .load(db_conn)?;
+
+        Ok(stalled
+            .into_iter()
+            .map(|times| SpecimenProgress::new(times, now))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use cellnoor_models::{
+        chromium_dataset::{ChromiumDatasetIdSpecimens, ChromiumDatasetSummary},
+        specimen::{
+            Species, SpecimenFilter, SpecimenIdProgress, SpecimenQuery, SpecimenStage,
+            SpecimenSummary,
+        },
+        suspension::{SuspensionContent, SuspensionCreation, SuspensionFields},
+    };
+    use cellnoor_schema::{chromium_datasets, suspension_tagging, suspensions};
+    use deadpool_diesel::postgres::Connection;
+    use diesel::{Connection as _, prelude::*};
+    use jiff::Timestamp;
+    use non_empty::NonEmptyString;
+    use pretty_assertions::assert_eq;
+    use ranged::RangedU32;
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    use crate::{
+        db::{self, Operation},
+        test_state::{Database, database, root_db_conn},
+        test_util::specimen,
+    };
+
+    fn specimens_in_stages(
+        stages: Vec<SpecimenStage>,
+        ids: Vec<Uuid>,
+        db_conn: &mut PgConnection,
+    ) -> Result<HashSet<Uuid>, db::Error> {
+        let mut query = SpecimenQuery::default_with_no_limit();
+        query.filter = Some(SpecimenFilter {
+            ids: Some(ids),
+            stage: Some(stages),
+            ..Default::default()
+        });
+
+        let specimens: Vec<SpecimenSummary> = query.execute(db_conn)?;
+        Ok(specimens.iter().map(SpecimenSummary::id).collect())
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn delivered_directly_and_through_pools(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let dataset_ids: Vec<_> = database
+            .chromium_datasets
+            .iter()
+            .map(ChromiumDatasetSummary::id)
+            .collect();
+        let specimen_ids: Vec<_> = database.specimens.iter().map(SpecimenSummary::id).collect();
+
+        root_db_conn
+            .interact(move |db_conn| {
+                db_conn.test_transaction::<_, db::Error, _>(|tx| {
+                    // A specimen is delivered with the first dataset derived from it
+                    let mut delivered: HashMap<Uuid, Timestamp> = HashMap::new();
+                    for &id in &dataset_ids {
+                        let delivered_at: jiff_diesel::Timestamp = chromium_datasets::table
+                            .find(id)
+                            .select(chromium_datasets::delivered_at)
+                            .first(tx)?;
+                        let delivered_at = delivered_at.to_jiff();
+
+                        let specimens: Vec<SpecimenSummary> = (
+                            ChromiumDatasetIdSpecimens(id),
+                            SpecimenQuery::default_with_no_limit(),
+                        )
+                            .execute(tx)?;
+                        for specimen in specimens {
+                            delivered
+                                .entry(specimen.id())
+                                .and_modify(|t| *t = (*t).min(delivered_at))
+                                .or_insert(delivered_at);
+                        }
+                    }
+
+                    let pooled: HashSet<Uuid> = suspensions::table
+                        .inner_join(suspension_tagging::table)
+                        .select(suspensions::parent_specimen_id)
+                        .load(tx)?
+                        .into_iter()
+                        .collect();
+                    assert!(delivered.keys().any(|id| pooled.contains(id)));
+                    assert!(delivered.keys().any(|id| !pooled.contains(id)));
+
+                    for (&specimen_id, &delivered_at) in &delivered {
+                        let progress = SpecimenIdProgress(specimen_id).execute(tx)?;
+
+                        assert_eq!(progress.times().stage(), SpecimenStage::Delivered);
+                        assert_eq!(progress.times().stage_entered_at(), delivered_at);
+                    }
+
+                    let delivered_ids: HashSet<Uuid> = delivered.keys().copied().collect();
+                    assert_eq!(
+                        specimens_in_stages(
+                            vec![SpecimenStage::Delivered],
+                            specimen_ids.clone(),
+                            tx
+                        )?,
+                        delivered_ids
+                    );
+
+                    let undelivered = specimens_in_stages(
+                        vec![
+                            SpecimenStage::Received,
+                            SpecimenStage::SuspensionPrepared,
+                            SpecimenStage::ChipLoaded,
+                            SpecimenStage::CdnaPrepared,
+                            SpecimenStage::LibraryPrepared,
+                            SpecimenStage::Sequenced,
+                        ],
+                        specimen_ids.clone(),
+                        tx,
+                    )?;
+                    assert!(undelivered.is_disjoint(&delivered_ids));
+                    assert_eq!(undelivered.len() + delivered_ids.len(), specimen_ids.len());
+
+                    Ok(())
+                });
+            })
+            .await
+            .unwrap();
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn stage_of_new_specimen(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let lab_id = database.labs[0].id();
+        let submitted_by = database.people[0].id();
+
+        root_db_conn
+            .interact(move |db_conn| {
+                db_conn.test_transaction::<_, db::Error, _>(|tx| {
+                    let specimen =
+                        specimen(lab_id, submitted_by, Species::HomoSapiens, None, vec![])
+                            .execute(tx)?;
+
+                    let progress = SpecimenIdProgress(specimen.id()).execute(tx)?;
+                    assert_eq!(progress.times().stage(), SpecimenStage::Received);
+                    assert_eq!(progress.times().stage_entered_at(), specimen.received_at());
+                    assert_eq!(
+                        specimens_in_stages(
+                            vec![SpecimenStage::Received],
+                            vec![specimen.id()],
+                            tx
+                        )?,
+                        HashSet::from([specimen.id()])
+                    );
+
+                    // A suspension without a creation time still moves the specimen on,
+                    // but the stage is dated from when the specimen was received
+                    let suspension = SuspensionCreation::builder()
+                        .inner(
+                            SuspensionFields::builder()
+                                .readable_id(
+                                    NonEmptyString::new(Uuid::now_v7().to_string()).unwrap(),
+                                )
+                                .parent_specimen_id(specimen.id())
+                                .build(),
+                        )
+                        .target_cell_recovery(RangedU32::new(10_000).unwrap())
+                        .preparer_ids(submitted_by)
+                        .build();
+                    (suspension, SuspensionContent::Cells).execute(tx)?;
+
+                    let progress = SpecimenIdProgress(specimen.id()).execute(tx)?;
+                    assert_eq!(progress.times().stage(), SpecimenStage::SuspensionPrepared);
+                    assert_eq!(progress.times().stage_entered_at(), specimen.received_at());
+                    assert_eq!(
+                        specimens_in_stages(
+                            vec![SpecimenStage::Received],
+                            vec![specimen.id()],
+                            tx
+                        )?,
+                        HashSet::new()
+                    );
+
+                    Ok(())
+                });
+            })
+            .await
+            .unwrap();
+    }
+}
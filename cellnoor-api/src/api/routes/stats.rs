@@ -0,0 +1,543 @@
+use axum::{extract::State, http::StatusCode};
+use axum_extra::routing::TypedPath;
+use cellnoor_models::stats::{
+    ChromiumDatasetGroupBy, ChromiumDatasetStatsQuery, SpecimenGroupBy, SpecimenStatsQuery,
+    StatsPoint, StatsQuery, StatsSeries, TimeBucket,
+};
+use cellnoor_schema::{specimens, suspension_measurements, suspensions};
+use diesel::{
+    dsl::sql,
+    expression::SqlLiteral,
+    prelude::*,
+    sql_types::{BigInt, Bool, Double, Nullable, Text, Timestamptz},
+};
+use jiff::Timestamp;
+
+use crate::{
+    api::{
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        openapi::ApiRouter,
+        routes::{
+            ApiResponse, chromium_datasets::chromium_datasets_to_all_specimens, inner_handler,
+        },
+    },
+    db::{self, ToBoxedFilter},
+    state::AppState,
+};
+
+#[derive(TypedPath)]
+#[typed_path("/specimens")]
+struct SpecimenStats;
+
+#[derive(TypedPath)]
+#[typed_path("/chromium-datasets")]
+struct ChromiumDatasetStats;
+
+#[derive(TypedPath)]
+#[typed_path("/viability")]
+struct ViabilityStats;
+
+#[derive(TypedPath)]
+#[typed_path("/turnaround")]
+struct TurnaroundStats;
+
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
+        .typed_get(specimen_stats)
+        .typed_get(chromium_dataset_stats)
+        .typed_get(viability_stats)
+        .typed_get(turnaround_stats)
+}
+
+/// The number of specimens received
+async fn specimen_stats(
+    _: SpecimenStats,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    QsQuery(query): QsQuery<SpecimenStatsQuery>,
+) -> ApiResponse<Vec<StatsSeries>> {
+    Ok((StatusCode::OK, inner_handler(state, user, query).await?))
+}
+
+/// The number of Chromium datasets delivered
+async fn chromium_dataset_stats(
+    _: ChromiumDatasetStats,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    QsQuery(query): QsQuery<ChromiumDatasetStatsQuery>,
+) -> ApiResponse<Vec<StatsSeries>> {
+    Ok((StatusCode::OK, inner_handler(state, user, query).await?))
+}
+
+/// The mean viability of the suspensions derived from specimens, from 0 to 1
+async fn viability_stats(
+    _: ViabilityStats,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    QsQuery(query): QsQuery<SpecimenStatsQuery>,
+) -> ApiResponse<Vec<StatsSeries>> {
+    Ok((
+        StatusCode::OK,
+        inner_handler(state, user, Viability(query)).await?,
+    ))
+}
+
+/// The mean number of days between receiving the specimens of a Chromium
+/// dataset and delivering it
+async fn turnaround_stats(
+    _: TurnaroundStats,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    QsQuery(query): QsQuery<ChromiumDatasetStatsQuery>,
+) -> ApiResponse<Vec<StatsSeries>> {
+    Ok((
+        StatusCode::OK,
+        inner_handler(state, user, Turnaround(query)).await?,
+    ))
+}
+
+#[derive(Debug)]
+struct Viability(SpecimenStatsQuery);
+
+#[derive(Debug)]
+struct Turnaround(ChromiumDatasetStatsQuery);
+
+type StatsSqlType = (
+    Nullable<Text>,
+    Nullable<Timestamptz>,
+    BigInt,
+    Nullable<Double>,
+);
+type StatsRow = (
+    Option<String>,
+    Option<jiff_diesel::Timestamp>,
+    i64,
+    Option<f64>,
+);
+
+// Statistics are always grouped and ordered by the first two columns of
+// `stats_select`
+const GROUP_AND_BUCKET: &str = "1, 2";
+
+/// Select the group, the bucket of `timestamp` and `aggregates`, which must be
+/// a count followed by a mean
+fn stats_select(
+    group: Option<&str>,
+    bucket: Option<TimeBucket>,
+    timestamp: &str,
+    aggregates: &str,
+) -> SqlLiteral<StatsSqlType> {
+    let group = group.unwrap_or("null::text");
+    // `TimeBucket` is a closed set of `date_trunc` units, so it's safe to inline
+    let bucket = bucket.map_or_else(
+        || "null::timestamptz".to_owned(),
+        |b| {
+            let unit: &str = b.into();
+            format!("date_trunc('{unit}', {timestamp}, 'UTC')")
+        },
+    );
+
+    sql(&format!("{group}, {bucket}, {aggregates}"))
+}
+
+fn to_series(rows: Vec<StatsRow>) -> Vec<StatsSeries> {
+    StatsSeries::collect(rows.into_iter().map(|(group, bucket, count, mean)| {
+        let bucket = bucket.map(jiff_diesel::Timestamp::to_jiff);
+        (group, StatsPoint::new(bucket, count, mean))
+    }))
+}
+
+fn specimen_group(group_by: Option<SpecimenGroupBy>) -> Option<&'static str> {
+    group_by.map(|g| match g {
+        SpecimenGroupBy::Lab => {
+            "(select labs.name::text from labs where labs.id = specimens.lab_id)"
+        }
+        SpecimenGroupBy::Species => "specimens.species",
+        SpecimenGroupBy::Type => "specimens.type",
+        SpecimenGroupBy::Tissue => "specimens.tissue",
+    })
+}
+
+fn chromium_dataset_group(group_by: Option<ChromiumDatasetGroupBy>) -> Option<&'static str> {
+    group_by.map(|g| match g {
+        ChromiumDatasetGroupBy::Lab => {
+            "(select labs.name::text from labs where labs.id = chromium_datasets.lab_id)"
+        }
+        ChromiumDatasetGroupBy::Assay => "tenx_assays.name::text",
+    })
+}
+
+impl db::Operation<Vec<StatsSeries>> for SpecimenStatsQuery {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<Vec<StatsSeries>, db::Error> {
+        let Self {
+            filter,
+            group_by,
+            bucket,
+        } = &self;
+
+        let rows: Vec<StatsRow> = specimens::table
+            .filter(filter.to_boxed_filter())
+            .group_by(sql::<Text>(GROUP_AND_BUCKET))
+            .select(stats_select(
+                specimen_group(*group_by),
+                *bucket,
+                "specimens.received_at",
+                "count(*), null::float8",
+            ))
+            .order_by(sql::<Text>(GROUP_AND_BUCKET))
+            .load(db_conn)?;
+
+        Ok(to_series(rows))
+    }
+}
+
+impl db::Operation<Vec<StatsSeries>> for ChromiumDatasetStatsQuery {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<Vec<StatsSeries>, db::Error> {
+        let Self {
+            filter,
+            group_by,
+            bucket,
+        } = &self;
+
+        // A dataset appears once for every specimen it was derived from
+        let rows: Vec<StatsRow> = chromium_datasets_to_all_specimens()
+            .filter(filter.to_boxed_filter())
+            .group_by(sql::<Text>(GROUP_AND_BUCKET))
+            .select(stats_select(
+                chromium_dataset_group(*group_by),
+                *bucket,
+                "chromium_datasets.delivered_at",
+                "count(distinct chromium_datasets.id), null::float8",
+            ))
+            .order_by(sql::<Text>(GROUP_AND_BUCKET))
+            .load(db_conn)?;
+
+        Ok(to_series(rows))
+    }
+}
+
+impl db::Operation<Vec<StatsSeries>> for Viability {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<Vec<StatsSeries>, db::Error> {
+        let StatsQuery {
+            filter,
+            group_by,
+            bucket,
+        } = &self.0;
+
+        // Viability is measured again after hybridization, but that's a property
+        // of the protocol rather than the specimen
+        let is_viability = sql::<Bool>(
+            "suspension_measurements.data ->> 'quantity' = 'viability' and \
+             (suspension_measurements.data ->> 'post_hybridization')::boolean is not true",
+        );
+
+        let rows: Vec<StatsRow> = suspension_measurements::table
+            .inner_join(suspensions::table.inner_join(specimens::table))
+            .filter(is_viability)
+            .filter(filter.to_boxed_filter())
+            .group_by(sql::<Text>(GROUP_AND_BUCKET))
+            .select(stats_select(
+                specimen_group(*group_by),
+                *bucket,
+                "suspension_measurements.measured_at",
+                "count(*), avg((suspension_measurements.data ->> 'value')::float8)",
+            ))
+            .order_by(sql::<Text>(GROUP_AND_BUCKET))
+            .load(db_conn)?;
+
+        Ok(to_series(rows))
+    }
+}
+
+impl db::Operation<Vec<StatsSeries>> for Turnaround {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<Vec<StatsSeries>, db::Error> {
+        let StatsQuery {
+            filter,
+            group_by,
+            bucket,
+        } = &self.0;
+
+        // The turnaround of a dataset is measured from its earliest specimen. This
+        // can't be averaged in the same query, so we load one row per dataset and
+        // the `count` column is unused
+        let rows: Vec<StatsRow> = chromium_datasets_to_all_specimens()
+            .filter(filter.to_boxed_filter())
+            .group_by(sql::<Text>("1, 2, chromium_datasets.id"))
+            .select(stats_select(
+                chromium_dataset_group(*group_by),
+                *bucket,
+                "chromium_datasets.delivered_at",
+                "1::bigint, extract(epoch from chromium_datasets.delivered_at - \
+                 min(coalesce(specimens.received_at, pooled_specimens.received_at)))::float8 / \
+                 86400",
+            ))
+            .order_by(sql::<Text>(GROUP_AND_BUCKET))
+            .load(db_conn)?;
+
+        let mut totals: Vec<(Option<String>, Option<Timestamp>, i64, f64)> = Vec::new();
+        for (group, bucket, _, days) in rows {
+            let Some(days) = days else {
+                continue;
+            };
+            let bucket = bucket.map(jiff_diesel::Timestamp::to_jiff);
+
+            match totals.last_mut() {
+                Some((g, b, n, sum)) if *g == group && *b == bucket => {
+                    *n += 1;
+                    *sum += days;
+                }
+                _ => totals.push((group, bucket, 1, days)),
+            }
+        }
+
+        Ok(StatsSeries::collect(totals.into_iter().map(
+            |(group, bucket, n, sum)| (group, StatsPoint::new(bucket, n, Some(sum / n as f64))),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cellnoor_models::{
+        chromium_dataset::{
+            ChromiumDatasetFilter, ChromiumDatasetIdSpecimens, ChromiumDatasetSummary,
+        },
+        specimen::{SpecimenFilter, SpecimenQuery, SpecimenSummary},
+        stats::{
+            ChromiumDatasetGroupBy, ChromiumDatasetStatsQuery, SpecimenGroupBy, SpecimenStatsQuery,
+            StatsPoint, StatsSeries, TimeBucket,
+        },
+    };
+    use cellnoor_schema::{chromium_datasets, suspension_measurements, suspensions};
+    use deadpool_diesel::postgres::Connection;
+    use diesel::{Connection as _, prelude::*};
+    use jiff::Timestamp;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::{Turnaround, Viability};
+    use crate::{
+        db::{self, Operation},
+        test_state::{Database, database, root_db_conn},
+    };
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.unwrap();
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn specimens_per_lab_per_month(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let query = SpecimenStatsQuery {
+            filter: Some(SpecimenFilter {
+                ids: Some(database.specimens.iter().map(SpecimenSummary::id).collect()),
+                ..Default::default()
+            }),
+            group_by: Some(SpecimenGroupBy::Lab),
+            bucket: Some(TimeBucket::Month),
+        };
+
+        let series = root_db_conn
+            .interact(|db_conn| query.execute(db_conn).unwrap())
+            .await
+            .unwrap();
+
+        let total: i64 = series
+            .iter()
+            .flat_map(StatsSeries::points)
+            .map(StatsPoint::count)
+            .sum();
+        assert_eq!(usize::try_from(total).unwrap(), database.specimens.len());
+        assert!(series.iter().all(|s| s.group().is_some()));
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn mean_viability_per_year(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let specimen_ids = [database.specimens[0].id(), database.specimens[1].id()];
+        let measured_by = database.people[0].id();
+
+        root_db_conn
+            .interact(move |db_conn| {
+                db_conn.test_transaction::<_, db::Error, _>(|tx| {
+                    let mut measurements = Vec::new();
+                    for (specimen_id, data) in [
+                        (
+                            specimen_ids[0],
+                            [
+                                ("2001-03-01T00:00:00Z", "viability", 0.8, false),
+                                ("2001-06-01T00:00:00Z", "viability", 0.6, false),
+                                // Neither of these is the viability of the specimen
+                                ("2001-06-01T00:00:00Z", "viability", 0.1, true),
+                                ("2001-06-01T00:00:00Z", "volume", 50.0, false),
+                            ]
+                            .as_slice(),
+                        ),
+                        (
+                            specimen_ids[1],
+                            [("2002-01-01T00:00:00Z", "viability", 0.9, false)].as_slice(),
+                        ),
+                    ] {
+                        let suspension_id: uuid::Uuid = suspensions::table
+                            .filter(suspensions::parent_specimen_id.eq(specimen_id))
+                            .select(suspensions::id)
+                            .first(tx)?;
+
+                        for &(measured_at, quantity, value, post_hybridization) in data {
+                            let measured_at: Timestamp = measured_at.parse().unwrap();
+                            measurements.push((
+                                suspension_measurements::suspension_id.eq(suspension_id),
+                                suspension_measurements::measured_by.eq(measured_by),
+                                suspension_measurements::measured_at
+                                    .eq(jiff_diesel::Timestamp::from(measured_at)),
+                                suspension_measurements::data.eq(json!({
+                                    "quantity": quantity,
+                                    "value": value,
+                                    "post_hybridization": post_hybridization,
+                                })),
+                            ));
+                        }
+                    }
+                    diesel::insert_into(suspension_measurements::table)
+                        .values(measurements)
+                        .execute(tx)?;
+
+                    let query = SpecimenStatsQuery {
+                        filter: Some(SpecimenFilter {
+                            ids: Some(specimen_ids.to_vec()),
+                            ..Default::default()
+                        }),
+                        group_by: None,
+                        bucket: Some(TimeBucket::Year),
+                    };
+                    let series = Viability(query).execute(tx)?;
+
+                    let [series] = series.as_slice() else {
+                        panic!("expected a single series, got {series:?}");
+                    };
+                    assert_eq!(series.group(), None);
+
+                    let [first, second] = series.points() else {
+                        panic!("expected a point per year, got {series:?}");
+                    };
+                    assert_eq!(
+                        first.bucket(),
+                        Some("2001-01-01T00:00:00Z".parse().unwrap())
+                    );
+                    assert_eq!(first.count(), 2);
+                    assert_close(first.mean(), 0.7);
+
+                    assert_eq!(
+                        second.bucket(),
+                        Some("2002-01-01T00:00:00Z".parse().unwrap())
+                    );
+                    assert_eq!(second.count(), 1);
+                    assert_close(second.mean(), 0.9);
+
+                    Ok(())
+                });
+            })
+            .await
+            .unwrap();
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn turnaround_is_measured_from_earliest_specimen(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let dataset_ids: Vec<_> = database
+            .chromium_datasets
+            .iter()
+            .map(ChromiumDatasetSummary::id)
+            .collect();
+
+        root_db_conn
+            .interact(move |db_conn| {
+                db_conn.test_transaction::<_, db::Error, _>(|tx| {
+                    // Datasets are reached through pooled and unpooled suspensions
+                    // alike, which `ChromiumDatasetIdSpecimens` handles separately
+                    let mut total_days = 0.0;
+                    for &id in &dataset_ids {
+                        let delivered_at: jiff_diesel::Timestamp = chromium_datasets::table
+                            .find(id)
+                            .select(chromium_datasets::delivered_at)
+                            .first(tx)?;
+
+                        let specimens: Vec<SpecimenSummary> = (
+                            ChromiumDatasetIdSpecimens(id),
+                            SpecimenQuery::default_with_no_limit(),
+                        )
+                            .execute(tx)?;
+                        let earliest_received_at = specimens
+                            .iter()
+                            .map(SpecimenSummary::received_at)
+                            .min()
+                            .unwrap();
+
+                        total_days += delivered_at
+                            .to_jiff()
+                            .duration_since(earliest_received_at)
+                            .as_secs_f64()
+                            / 86400.0;
+                    }
+                    let expected_mean = total_days / dataset_ids.len() as f64;
+
+                    let filter = ChromiumDatasetFilter {
+                        ids: Some(dataset_ids.clone()),
+                        ..Default::default()
+                    };
+
+                    let series = Turnaround(ChromiumDatasetStatsQuery {
+                        filter: Some(filter.clone()),
+                        group_by: None,
+                        bucket: None,
+                    })
+                    .execute(tx)?;
+
+                    let [series] = series.as_slice() else {
+                        panic!("expected a single series, got {series:?}");
+                    };
+                    let [point] = series.points() else {
+                        panic!("expected a single point, got {series:?}");
+                    };
+                    assert_eq!(usize::try_from(point.count()).unwrap(), dataset_ids.len());
+                    assert_close(point.mean(), expected_mean);
+
+                    // Each dataset is counted once however it's grouped
+                    let series = Turnaround(ChromiumDatasetStatsQuery {
+                        filter: Some(filter),
+                        group_by: Some(ChromiumDatasetGroupBy::Lab),
+                        bucket: Some(TimeBucket::Month),
+                    })
+                    .execute(tx)?;
+
+                    let total: i64 = series
+                        .iter()
+                        .flat_map(StatsSeries::points)
+                        .map(StatsPoint::count)
+                        .sum();
+                    assert_eq!(usize::try_from(total).unwrap(), dataset_ids.len());
+
+                    Ok(())
+                });
+            })
+            .await
+            .unwrap();
+    }
+}
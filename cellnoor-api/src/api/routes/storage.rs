@@ -0,0 +1,21 @@
+use axum_extra::routing::TypedPath;
+use cellnoor_models::storage::{StorageUnitIdCheckIn, StorageUnitIdCheckOut};
+
+use crate::api::openapi::ApiRouter;
+
+mod check_in;
+mod check_out;
+mod create;
+mod fetch;
+mod list;
+mod occupied_positions;
+
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
+        .typed_post(create::create_storage_unit)
+        .typed_get(fetch::fetch_storage_unit)
+        .typed_get(list::list_storage_units)
+        .typed_get(occupied_positions::list_occupied_positions)
+        .post(StorageUnitIdCheckIn::PATH, check_in::check_in)
+        .post(StorageUnitIdCheckOut::PATH, check_out::check_out)
+}
@@ -0,0 +1,41 @@
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::storage::{
+    OccupiedPosition, StorageCheckIn, StorageUnitIdCheckIn, StorageUnitIdOccupiedPositions,
+};
+use cellnoor_schema::storage_events;
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::{ValidPathJson, auth::AuthenticatedUser},
+        routes::{ApiResponse, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn check_in(
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    ValidPathJson(box_id, request): ValidPathJson<StorageUnitIdCheckIn, StorageCheckIn>,
+) -> ApiResponse<Vec<OccupiedPosition>> {
+    Ok((
+        StatusCode::CREATED,
+        inner_handler(state, user, (box_id, request)).await?,
+    ))
+}
+
+impl db::Operation<Vec<OccupiedPosition>> for (StorageUnitIdCheckIn, StorageCheckIn) {
+    fn execute(
+        self,
+        db_conn: &mut diesel::PgConnection,
+    ) -> Result<Vec<OccupiedPosition>, db::Error> {
+        let (StorageUnitIdCheckIn(box_id), check_in) = self;
+
+        diesel::insert_into(storage_events::table)
+            .values(check_in.into_event(box_id))
+            .execute(db_conn)?;
+
+        StorageUnitIdOccupiedPositions(box_id).execute(db_conn)
+    }
+}
@@ -0,0 +1,45 @@
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::storage::{
+    OccupiedPosition, StorageCheckOut, StorageUnitIdCheckOut, StorageUnitIdOccupiedPositions,
+};
+use cellnoor_schema::storage_events;
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::{ValidPathJson, auth::AuthenticatedUser},
+        routes::{ApiResponse, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn check_out(
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    ValidPathJson(box_id, request): ValidPathJson<StorageUnitIdCheckOut, StorageCheckOut>,
+) -> ApiResponse<Vec<OccupiedPosition>> {
+    Ok((
+        StatusCode::CREATED,
+        inner_handler(state, user, (box_id, request)).await?,
+    ))
+}
+
+impl db::Operation<Vec<OccupiedPosition>> for (StorageUnitIdCheckOut, StorageCheckOut) {
+    fn execute(
+        self,
+        db_conn: &mut diesel::PgConnection,
+    ) -> Result<Vec<OccupiedPosition>, db::Error> {
+        let (StorageUnitIdCheckOut(box_id), check_out) = self;
+
+        // Validation ensures that the item is in this box
+        let current: Option<OccupiedPosition> = check_out.item().execute(db_conn)?;
+        let position = current.ok_or(diesel::result::Error::NotFound)?.position();
+
+        diesel::insert_into(storage_events::table)
+            .values(check_out.into_event(box_id, position))
+            .execute(db_conn)?;
+
+        StorageUnitIdOccupiedPositions(box_id).execute(db_conn)
+    }
+}
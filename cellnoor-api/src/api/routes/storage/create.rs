@@ -0,0 +1,34 @@
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::storage::{StorageUnit, StorageUnitCreation, StorageUnitId};
+use cellnoor_schema::storage_units::dsl::{id, storage_units};
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::{ValidJson, auth::AuthenticatedUser},
+        routes::{ApiResponse, Root, inner_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn create_storage_unit(
+    _: Root,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    ValidJson(request): ValidJson<StorageUnitCreation>,
+) -> ApiResponse<StorageUnit> {
+    let item = inner_handler(state, user, request).await?;
+    Ok((StatusCode::CREATED, item))
+}
+
+impl db::Operation<StorageUnit> for StorageUnitCreation {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<StorageUnit, db::Error> {
+        let created_id = diesel::insert_into(storage_units)
+            .values(self)
+            .returning(id)
+            .get_result(db_conn)?;
+
+        StorageUnitId(created_id).execute(db_conn)
+    }
+}
@@ -0,0 +1,31 @@
+use axum::extract::State;
+use cellnoor_models::{
+    read_options::{NoInclude, ReadOptions},
+    storage::{StorageUnit, StorageUnitId},
+};
+use cellnoor_schema::storage_units::dsl::id;
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{FetchResponse, fetch_handler},
+    },
+    db,
+    state::AppState,
+};
+
+pub(super) async fn fetch_storage_unit(
+    unit_id: StorageUnitId,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    QsQuery(options): QsQuery<ReadOptions<NoInclude>>,
+) -> FetchResponse<StorageUnit> {
+    fetch_handler(state, user, unit_id, options).await
+}
+
+impl db::Operation<StorageUnit> for StorageUnitId {
+    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<StorageUnit, db::Error> {
+        Ok(StorageUnit::query().filter(id.eq(self)).first(db_conn)?)
+    }
+}
@@ -0,0 +1,107 @@
+use axum::extract::State;
+use cellnoor_models::{
+    generic_query::Keyset,
+    storage::{StorageUnit, StorageUnitFilter, StorageUnitQuery},
+};
+use cellnoor_schema::storage_units as t;
+use diesel::{dsl::AssumeNotNull, prelude::*};
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, Root, list_handler},
+    },
+    db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter, utils::like_any},
+    state::AppState,
+};
+
+pub(super) async fn list_storage_units(
+    _: Root,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    format: Option<ExportFormat>,
+    QsQuery(request): QsQuery<StorageUnitQuery>,
+) -> ListResponse<StorageUnit> {
+    list_handler(state, user, request, format).await
+}
+
+impl db::List for StorageUnitQuery {
+    type Row = StorageUnit;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, StorageUnit> {
+        let StorageUnitQuery {
+            filter,
+            limit,
+            offset,
+            order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
+        } = self;
+
+        let mut stmt = StorageUnit::query()
+            .filter(filter.to_boxed_filter())
+            .into_boxed();
+
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
+        }
+
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(t::id);
+        }
+
+        stmt
+    }
+
+    fn id(row: &StorageUnit) -> Uuid {
+        row.id()
+    }
+}
+
+impl<'a, QS: 'a> ToBoxedFilter<'a, QS> for StorageUnitFilter
+where
+    t::id: SelectableExpression<QS>,
+    t::name: SelectableExpression<QS>,
+    t::kind: SelectableExpression<QS>,
+    AssumeNotNull<t::parent_id>: SelectableExpression<QS>,
+{
+    fn to_boxed_filter(&'a self) -> BoxedFilter<'a, QS> {
+        let Self {
+            ids,
+            names,
+            kinds,
+            parent_ids,
+        } = self;
+
+        let mut filter = BoxedFilter::new_true();
+
+        if let Some(ids) = ids {
+            filter = filter.and_condition(t::id.eq_any(ids));
+        }
+
+        if let Some(names) = names {
+            filter = filter.and_condition(like_any(t::name, names));
+        }
+
+        if let Some(kinds) = kinds {
+            filter = filter.and_condition(t::kind.eq_any(kinds));
+        }
+
+        if let Some(parent_ids) = parent_ids {
+            filter = filter.and_condition(t::parent_id.assume_not_null().eq_any(parent_ids));
+        }
+
+        filter
+    }
+}
@@ -0,0 +1,55 @@
+use axum::extract::State;
+use cellnoor_models::storage::{OccupiedPosition, StorageUnitIdOccupiedPositions, StoredItem};
+use cellnoor_schema::storage_occupancy as t;
+use diesel::prelude::*;
+
+use crate::{
+    api::{
+        export::ExportFormat,
+        extract::auth::AuthenticatedUser,
+        routes::{UnpagedListResponse, unpaged_list_handler},
+    },
+    db,
+    state::AppState,
+};
+
+// Not paged, since a box can't hold more items than it has positions
+pub(super) async fn list_occupied_positions(
+    box_id: StorageUnitIdOccupiedPositions,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    format: Option<ExportFormat>,
+) -> UnpagedListResponse<OccupiedPosition> {
+    unpaged_list_handler(state, user, box_id, format).await
+}
+
+impl db::Operation<Vec<OccupiedPosition>> for StorageUnitIdOccupiedPositions {
+    fn execute(
+        self,
+        db_conn: &mut diesel::PgConnection,
+    ) -> Result<Vec<OccupiedPosition>, db::Error> {
+        Ok(OccupiedPosition::query()
+            .filter(t::box_id.eq(self))
+            .order_by(t::position)
+            .load(db_conn)?)
+    }
+}
+
+// Where an item currently lives, if anywhere
+impl db::Operation<Option<OccupiedPosition>> for StoredItem {
+    fn execute(
+        self,
+        db_conn: &mut diesel::PgConnection,
+    ) -> Result<Option<OccupiedPosition>, db::Error> {
+        let stmt = OccupiedPosition::query();
+
+        let location = match self {
+            Self::Specimen(id) => stmt.filter(t::specimen_id.eq(id)).first(db_conn),
+            Self::Suspension(id) => stmt.filter(t::suspension_id.eq(id)).first(db_conn),
+            Self::Cdna(id) => stmt.filter(t::cdna_id.eq(id)).first(db_conn),
+            Self::Library(id) => stmt.filter(t::library_id.eq(id)).first(db_conn),
+        };
+
+        Ok(location.optional()?)
+    }
+}
@@ -1,7 +1,4 @@
-use axum::Router;
-use axum_extra::routing::RouterExt;
-
-use crate::state::AppState;
+use crate::api::openapi::ApiRouter;
 
 mod cells;
 mod create;
@@ -11,8 +8,8 @@ mod measurements;
 mod nuclei;
 mod suspensions;
 
-pub(super) fn router() -> Router<AppState> {
-    Router::new()
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
         .typed_post(create::create_suspension_pool)
         .nest("/cells", cells::router())
         .nest("/nuclei", nuclei::router())
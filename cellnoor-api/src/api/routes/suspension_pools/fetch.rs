@@ -1,13 +1,15 @@
 use axum::extract::State;
-use cellnoor_models::suspension_pool::{SuspensionPool, SuspensionPoolId};
+use cellnoor_models::{
+    read_options::{NoInclude, ReadOptions},
+    suspension_pool::{SuspensionPool, SuspensionPoolId},
+};
 use cellnoor_schema::suspension_pools::id;
 use diesel::prelude::*;
-use reqwest::StatusCode;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{FetchResponse, fetch_handler},
     },
     db,
     state::AppState,
@@ -17,9 +19,9 @@ pub(super) async fn fetch_suspension_pool(
     suspension_id: SuspensionPoolId,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<SuspensionPool> {
-    let item = inner_handler(state, user, suspension_id).await?;
-    Ok((StatusCode::OK, item))
+    QsQuery(options): QsQuery<ReadOptions<NoInclude>>,
+) -> FetchResponse<SuspensionPool> {
+    fetch_handler(state, user, suspension_id, options).await
 }
 
 impl db::Operation<SuspensionPool> for SuspensionPoolId {
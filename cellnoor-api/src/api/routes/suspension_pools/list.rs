@@ -1,14 +1,17 @@
 use axum::extract::State;
-use cellnoor_models::suspension_pool::{SuspensionPool, SuspensionPoolFilter, SuspensionPoolQuery};
-use cellnoor_schema::suspension_pools::id;
+use cellnoor_models::{
+    generic_query::Keyset,
+    suspension_pool::{SuspensionPool, SuspensionPoolFilter, SuspensionPoolQuery},
+};
+use cellnoor_schema::suspension_pools::{additional_data, id};
 use diesel::prelude::*;
-use reqwest::StatusCode;
-use serde_qs::axum::QsQuery;
+use uuid::Uuid;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, Root, inner_handler},
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, Root, list_handler},
     },
     db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter},
     state::AppState,
@@ -18,47 +21,74 @@ pub(super) async fn list_suspension_pools(
     _: Root,
     state: State<AppState>,
     user: AuthenticatedUser,
+    format: Option<ExportFormat>,
     QsQuery(request): QsQuery<SuspensionPoolQuery>,
-) -> ApiResponse<Vec<SuspensionPool>> {
-    let item = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, item))
+) -> ListResponse<SuspensionPool> {
+    list_handler(state, user, request, format).await
 }
 
-impl db::Operation<Vec<SuspensionPool>> for SuspensionPoolQuery {
-    fn execute(self, db_conn: &mut PgConnection) -> Result<Vec<SuspensionPool>, db::Error> {
-        let Self {
+impl db::List for SuspensionPoolQuery {
+    type Row = SuspensionPool;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, SuspensionPool> {
+        let SuspensionPoolQuery {
             filter,
             limit,
             offset,
             order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
         } = self;
 
         let mut stmt = SuspensionPool::query()
-            .limit(limit)
-            .offset(offset)
             .filter(filter.to_boxed_filter())
             .into_boxed();
 
-        for ordering in order_by.as_ref() {
-            stmt = stmt.then_order_by(ordering);
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
+        }
+
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(id);
         }
 
-        Ok(stmt.load(db_conn)?)
+        stmt
+    }
+
+    fn id(row: &SuspensionPool) -> Uuid {
+        row.id()
     }
 }
 
 impl<'a, QS: 'a> ToBoxedFilter<'a, QS> for SuspensionPoolFilter
 where
     id: SelectableExpression<QS>,
+    additional_data: SelectableExpression<QS>,
 {
     fn to_boxed_filter(&'a self) -> BoxedFilter<'a, QS> {
-        let Self { ids } = self;
+        let Self {
+            ids,
+            additional_data_predicates,
+        } = self;
         let mut filter = BoxedFilter::new_true();
 
         if let Some(ids) = ids {
             filter = filter.and_condition(id.eq_any(ids));
         }
 
+        for predicate in additional_data_predicates.iter().flatten() {
+            filter = filter.and_condition(predicate.matches(additional_data));
+        }
+
         filter
     }
 }
@@ -1,4 +1,4 @@
-use axum::{extract::State, http::StatusCode};
+use axum::extract::State;
 use cellnoor_models::suspension_pool::{
     SuspensionPoolIdMeasurements, measurement::SuspensionPoolMeasurement,
 };
@@ -6,20 +6,23 @@ use diesel::prelude::*;
 
 use crate::{
     api::{
+        export::ExportFormat,
         extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        routes::{UnpagedListResponse, unpaged_list_handler},
     },
     db::{self},
     state::AppState,
 };
 
+// Not paged, since a pool is only measured a handful of times before it's
+// loaded
 pub async fn list_measurements(
     pool_id: SuspensionPoolIdMeasurements,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Vec<SuspensionPoolMeasurement>> {
-    let items = inner_handler(state, user, pool_id).await?;
-    Ok((StatusCode::OK, items))
+    format: Option<ExportFormat>,
+) -> UnpagedListResponse<SuspensionPoolMeasurement> {
+    unpaged_list_handler(state, user, pool_id, format).await
 }
 
 impl db::Operation<Vec<SuspensionPoolMeasurement>> for SuspensionPoolIdMeasurements {
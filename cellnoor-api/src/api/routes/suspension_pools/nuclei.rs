@@ -1,14 +1,13 @@
-use axum::{Router, routing::post};
 use axum_extra::routing::TypedPath;
 use cellnoor_models::suspension_pool::SuspensionPoolIdMeasurements;
 
-use crate::state::AppState;
+use crate::api::openapi::ApiRouter;
 
 mod measurements;
 
-pub(super) fn router() -> Router<AppState> {
-    Router::new().route(
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new().post(
         SuspensionPoolIdMeasurements::PATH,
-        post(measurements::create_nucleus_suspension_pool_measurement),
+        measurements::create_nucleus_suspension_pool_measurement,
     )
 }
@@ -1,16 +1,18 @@
-use axum::{extract::State, http::StatusCode};
+use axum::extract::State;
 use cellnoor_models::{
+    generic_query::Keyset,
     suspension::{SuspensionQuery, SuspensionSummary},
     suspension_pool::SuspensionPoolIdSuspensions,
 };
 use cellnoor_schema::{suspension_tagging, suspensions};
 use diesel::prelude::*;
-use serde_qs::axum::QsQuery;
+use uuid::Uuid;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, list_handler},
     },
     db::{self, ToBoxedFilter},
     state::AppState,
@@ -20,48 +22,66 @@ pub async fn list_suspensions(
     pool_id: SuspensionPoolIdSuspensions,
     state: State<AppState>,
     user: AuthenticatedUser,
+    format: Option<ExportFormat>,
     QsQuery(request): QsQuery<SuspensionQuery>,
-) -> ApiResponse<Vec<SuspensionSummary>> {
-    let items = inner_handler(state, user, (pool_id, request)).await?;
-    Ok((StatusCode::OK, items))
+) -> ListResponse<SuspensionSummary> {
+    list_handler(state, user, (pool_id, request), format).await
 }
 
-impl db::Operation<Vec<SuspensionSummary>> for (SuspensionPoolIdSuspensions, SuspensionQuery) {
-    fn execute(
-        self,
-        db_conn: &mut diesel::PgConnection,
-    ) -> Result<Vec<SuspensionSummary>, db::Error> {
-        let (
-            pool_id,
-            SuspensionQuery {
-                limit,
-                offset,
-                filter,
-                order_by,
-            },
-        ) = self;
+impl db::List for (SuspensionPoolIdSuspensions, SuspensionQuery) {
+    type Row = SuspensionSummary;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, SuspensionSummary> {
+        let (pool_id, query) = self;
+
+        let SuspensionQuery {
+            limit,
+            offset,
+            filter,
+            order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
+        } = query;
 
         let mut stmt = suspension_tagging::table
             .filter(suspension_tagging::pool_id.eq(pool_id))
             .inner_join(suspensions::table)
-            .limit(limit)
-            .offset(offset)
             .select(SuspensionSummary::as_select())
             .into_boxed();
 
         stmt = stmt.filter(filter.to_boxed_filter());
 
-        for ordering in order_by.as_ref() {
-            stmt = stmt.then_order_by(ordering);
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
+        }
+
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(suspensions::id);
         }
 
-        Ok(stmt.load(db_conn)?)
+        stmt
+    }
+
+    fn id(row: &SuspensionSummary) -> Uuid {
+        row.id()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use cellnoor_models::{suspension::SuspensionQuery, suspension_pool::*};
+    use cellnoor_models::{
+        suspension::{SuspensionQuery, SuspensionSummary},
+        suspension_pool::*,
+    };
     use deadpool_diesel::postgres::Connection;
     use rstest::rstest;
 
@@ -84,7 +104,7 @@ mod tests {
             SuspensionQuery::default_with_no_limit(),
         );
 
-        let suspensions = root_db_conn
+        let suspensions: Vec<SuspensionSummary> = root_db_conn
             .interact(|db_conn| query.execute(db_conn).unwrap())
             .await
             .unwrap();
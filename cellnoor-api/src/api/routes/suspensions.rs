@@ -1,7 +1,4 @@
-use axum::Router;
-use axum_extra::routing::RouterExt;
-
-use crate::state::AppState;
+use crate::api::openapi::ApiRouter;
 
 mod cells;
 mod create;
@@ -10,8 +7,8 @@ mod list;
 mod measurements;
 mod nuclei;
 
-pub(super) fn router() -> Router<AppState> {
-    Router::new()
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
         .nest("/cells", cells::router())
         .nest("/nuclei", nuclei::router())
         .typed_get(fetch::fetch_suspension)
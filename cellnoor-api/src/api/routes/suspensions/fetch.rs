@@ -1,13 +1,15 @@
 use axum::extract::State;
-use cellnoor_models::suspension::{Suspension, SuspensionId};
+use cellnoor_models::{
+    read_options::{NoInclude, ReadOptions},
+    suspension::{Suspension, SuspensionId},
+};
 use cellnoor_schema::suspensions::id;
 use diesel::prelude::*;
-use reqwest::StatusCode;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{FetchResponse, fetch_handler},
     },
     db,
     state::AppState,
@@ -17,9 +19,9 @@ pub(super) async fn fetch_suspension(
     suspension_id: SuspensionId,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Suspension> {
-    let item = inner_handler(state, user, suspension_id).await?;
-    Ok((StatusCode::OK, item))
+    QsQuery(options): QsQuery<ReadOptions<NoInclude>>,
+) -> FetchResponse<Suspension> {
+    fetch_handler(state, user, suspension_id, options).await
 }
 
 impl db::Operation<Suspension> for SuspensionId {
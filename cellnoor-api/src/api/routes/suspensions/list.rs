@@ -1,14 +1,17 @@
 use axum::extract::State;
-use cellnoor_models::suspension::{SuspensionFilter, SuspensionQuery, SuspensionSummary};
-use cellnoor_schema::suspensions::id;
+use cellnoor_models::{
+    generic_query::Keyset,
+    suspension::{SuspensionFilter, SuspensionQuery, SuspensionSummary},
+};
+use cellnoor_schema::suspensions::{additional_data, id};
 use diesel::prelude::*;
-use reqwest::StatusCode;
-use serde_qs::axum::QsQuery;
+use uuid::Uuid;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, Root, inner_handler},
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, Root, list_handler},
     },
     db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter},
     state::AppState,
@@ -18,47 +21,74 @@ pub(super) async fn list_suspensions(
     _: Root,
     state: State<AppState>,
     user: AuthenticatedUser,
+    format: Option<ExportFormat>,
     QsQuery(request): QsQuery<SuspensionQuery>,
-) -> ApiResponse<Vec<SuspensionSummary>> {
-    let item = inner_handler(state, user, request).await?;
-    Ok((StatusCode::OK, item))
+) -> ListResponse<SuspensionSummary> {
+    list_handler(state, user, request, format).await
 }
 
-impl db::Operation<Vec<SuspensionSummary>> for SuspensionQuery {
-    fn execute(self, db_conn: &mut PgConnection) -> Result<Vec<SuspensionSummary>, db::Error> {
-        let Self {
+impl db::List for SuspensionQuery {
+    type Row = SuspensionSummary;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, SuspensionSummary> {
+        let SuspensionQuery {
             filter,
             limit,
             offset,
             order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
         } = self;
 
         let mut stmt = SuspensionSummary::query()
-            .limit(limit)
-            .offset(offset)
             .filter(filter.to_boxed_filter())
             .into_boxed();
 
-        for ordering in order_by.as_ref() {
-            stmt = stmt.then_order_by(ordering);
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
+        }
+
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(id);
         }
 
-        Ok(stmt.load(db_conn)?)
+        stmt
+    }
+
+    fn id(row: &SuspensionSummary) -> Uuid {
+        row.id()
     }
 }
 
 impl<'a, QS: 'a> ToBoxedFilter<'a, QS> for SuspensionFilter
 where
     id: SelectableExpression<QS>,
+    additional_data: SelectableExpression<QS>,
 {
     fn to_boxed_filter(&'a self) -> BoxedFilter<'a, QS> {
-        let Self { ids } = self;
+        let Self {
+            ids,
+            additional_data_predicates,
+        } = self;
         let mut filter = BoxedFilter::new_true();
 
         if let Some(ids) = ids {
             filter = filter.and_condition(id.eq_any(ids));
         }
 
+        for predicate in additional_data_predicates.iter().flatten() {
+            filter = filter.and_condition(predicate.matches(additional_data));
+        }
+
         filter
     }
 }
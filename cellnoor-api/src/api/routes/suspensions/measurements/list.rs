@@ -1,23 +1,26 @@
-use axum::{extract::State, http::StatusCode};
+use axum::extract::State;
 use cellnoor_models::suspension::{SuspensionIdMeasurements, measurement::SuspensionMeasurement};
 use diesel::prelude::*;
 
 use crate::{
     api::{
+        export::ExportFormat,
         extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, inner_handler},
+        routes::{UnpagedListResponse, unpaged_list_handler},
     },
     db::{self},
     state::AppState,
 };
 
+// Not paged, since a suspension is only measured a handful of times between
+// preparation and loading
 pub async fn list_measurements(
     suspension_id: SuspensionIdMeasurements,
     state: State<AppState>,
     user: AuthenticatedUser,
-) -> ApiResponse<Vec<SuspensionMeasurement>> {
-    let items = inner_handler(state, user, suspension_id).await?;
-    Ok((StatusCode::OK, items))
+    format: Option<ExportFormat>,
+) -> UnpagedListResponse<SuspensionMeasurement> {
+    unpaged_list_handler(state, user, suspension_id, format).await
 }
 
 impl db::Operation<Vec<SuspensionMeasurement>> for SuspensionIdMeasurements {
@@ -1,17 +1,16 @@
-use axum::{Router, routing::post};
-use axum_extra::routing::{RouterExt, TypedPath};
+use axum_extra::routing::TypedPath;
 use cellnoor_models::suspension::SuspensionIdMeasurements;
 
-use crate::state::AppState;
+use crate::api::openapi::ApiRouter;
 
 mod create;
 mod measurements;
 
-pub(super) fn router() -> Router<AppState> {
-    Router::new()
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
         .typed_post(create::create_nucleus_suspension)
-        .route(
+        .post(
             SuspensionIdMeasurements::PATH,
-            post(measurements::create_nucleus_suspension_measurement),
+            measurements::create_nucleus_suspension_measurement,
         )
 }
@@ -1,10 +1,7 @@
-use axum::Router;
-use axum_extra::routing::RouterExt;
-
-use crate::state::AppState;
+use crate::api::openapi::ApiRouter;
 
 mod list;
 
-pub(super) fn router() -> Router<AppState> {
-    Router::new().typed_get(list::list_tenx_assays)
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new().typed_get(list::list_tenx_assays)
 }
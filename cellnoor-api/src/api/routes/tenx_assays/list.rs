@@ -1,13 +1,17 @@
-use axum::{extract::State, http::StatusCode};
-use cellnoor_models::tenx_assay::{TenxAssay, TenxAssayFilter, TenxAssayQuery};
+use axum::extract::State;
+use cellnoor_models::{
+    generic_query::Keyset,
+    tenx_assay::{TenxAssay, TenxAssayFilter, TenxAssayQuery},
+};
 use cellnoor_schema::tenx_assays::dsl::*;
 use diesel::{dsl::AssumeNotNull, prelude::*};
-use serde_qs::axum::QsQuery;
+use uuid::Uuid;
 
 use crate::{
     api::{
-        extract::auth::AuthenticatedUser,
-        routes::{ApiResponse, Root, inner_handler},
+        export::ExportFormat,
+        extract::{auth::AuthenticatedUser, query::QsQuery},
+        routes::{ListResponse, Root, list_handler},
     },
     db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter, utils::like_any},
     state::AppState,
@@ -17,31 +21,51 @@ pub async fn list_tenx_assays(
     _: Root,
     state: State<AppState>,
     user: AuthenticatedUser,
+    format: Option<ExportFormat>,
     QsQuery(request): QsQuery<TenxAssayQuery>,
-) -> ApiResponse<Vec<TenxAssay>> {
-    Ok((StatusCode::OK, inner_handler(state, user, request).await?))
+) -> ListResponse<TenxAssay> {
+    list_handler(state, user, request, format).await
 }
 
-impl db::Operation<Vec<TenxAssay>> for TenxAssayQuery {
-    fn execute(self, db_conn: &mut diesel::PgConnection) -> Result<Vec<TenxAssay>, db::Error> {
-        let Self {
+impl db::List for TenxAssayQuery {
+    type Row = TenxAssay;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, TenxAssay> {
+        let TenxAssayQuery {
             filter,
             limit,
             offset,
             order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
         } = self;
 
         let mut stmt = TenxAssay::query()
-            .limit(limit)
-            .offset(offset)
             .filter(filter.to_boxed_filter())
             .into_boxed();
 
-        for ordering in order_by.as_ref() {
-            stmt = stmt.then_order_by(ordering);
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
+        }
+
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(id);
         }
 
-        Ok(stmt.load(db_conn)?)
+        stmt
+    }
+
+    fn id(row: &TenxAssay) -> Uuid {
+        row.id()
     }
 }
 
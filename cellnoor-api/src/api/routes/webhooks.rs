@@ -0,0 +1,214 @@
+use std::fmt::Debug;
+
+use axum::{extract::State, http::StatusCode};
+use cellnoor_models::{
+    generic_query::Keyset,
+    webhook::{
+        WebhookDelivery, WebhookDeliveryFilter, WebhookDeliveryQuery, WebhookEventType,
+        WebhookSubscription, WebhookSubscriptionCreation, WebhookSubscriptionId,
+        WebhookSubscriptionIdDeliveries,
+    },
+};
+use cellnoor_schema::{webhook_deliveries as d, webhook_subscriptions as s};
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        export::ExportFormat,
+        extract::{ValidJson, auth::AuthenticatedUser, query::QsQuery},
+        openapi::ApiRouter,
+        routes::{
+            ApiResponse, ListResponse, Root, UnpagedListResponse, inner_handler, list_handler,
+            unpaged_list_handler,
+        },
+    },
+    db::{self, BoxedFilter, BoxedFilterExt, ToBoxedFilter},
+    state::AppState,
+};
+
+pub(super) fn router() -> ApiRouter {
+    ApiRouter::new()
+        .typed_post(create_webhook_subscription)
+        .typed_get(list_webhook_subscriptions)
+        .typed_delete(delete_webhook_subscription)
+        .typed_get(list_webhook_deliveries)
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = s, check_for_backend(diesel::pg::Pg))]
+struct NewWebhookSubscription {
+    lab_id: Uuid,
+    url: String,
+    secret: String,
+    event_types: Vec<WebhookEventType>,
+}
+
+// The secret is never logged
+impl Debug for NewWebhookSubscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NewWebhookSubscription")
+            .field("lab_id", &self.lab_id)
+            .field("url", &self.url)
+            .field("event_types", &self.event_types)
+            .finish_non_exhaustive()
+    }
+}
+
+impl From<WebhookSubscriptionCreation> for NewWebhookSubscription {
+    fn from(request: WebhookSubscriptionCreation) -> Self {
+        let WebhookSubscriptionCreation {
+            lab_id,
+            url,
+            secret,
+            event_types,
+        } = request;
+
+        Self {
+            lab_id,
+            url: url.into(),
+            secret: secret.into(),
+            event_types: event_types.into(),
+        }
+    }
+}
+
+// Row-level security means that subscriptions can only be created for the
+// labs a person belongs to
+async fn create_webhook_subscription(
+    _: Root,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    ValidJson(request): ValidJson<WebhookSubscriptionCreation>,
+) -> ApiResponse<WebhookSubscription> {
+    let request = NewWebhookSubscription::from(request);
+    Ok((
+        StatusCode::CREATED,
+        inner_handler(state, user, request).await?,
+    ))
+}
+
+impl db::Operation<WebhookSubscription> for NewWebhookSubscription {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<WebhookSubscription, db::Error> {
+        let created_id: Uuid = diesel::insert_into(s::table)
+            .values(self)
+            .returning(s::id)
+            .get_result(db_conn)?;
+
+        Ok(WebhookSubscription::query()
+            .filter(s::id.eq(created_id))
+            .first(db_conn)?)
+    }
+}
+
+#[derive(Debug)]
+struct VisibleWebhookSubscriptions;
+
+// Not paged, since a lab only has a handful of subscriptions
+async fn list_webhook_subscriptions(
+    _: Root,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    format: Option<ExportFormat>,
+) -> UnpagedListResponse<WebhookSubscription> {
+    unpaged_list_handler(state, user, VisibleWebhookSubscriptions, format).await
+}
+
+impl db::Operation<Vec<WebhookSubscription>> for VisibleWebhookSubscriptions {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<Vec<WebhookSubscription>, db::Error> {
+        Ok(WebhookSubscription::query()
+            .order_by(s::created_at)
+            .load(db_conn)?)
+    }
+}
+
+async fn delete_webhook_subscription(
+    subscription_id: WebhookSubscriptionId,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+) -> ApiResponse<()> {
+    Ok((
+        StatusCode::OK,
+        inner_handler(state, user, subscription_id).await?,
+    ))
+}
+
+impl db::Operation<()> for WebhookSubscriptionId {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<(), db::Error> {
+        diesel::delete(s::table.filter(s::id.eq(self.0))).execute(db_conn)?;
+
+        Ok(())
+    }
+}
+
+async fn list_webhook_deliveries(
+    subscription_id: WebhookSubscriptionIdDeliveries,
+    state: State<AppState>,
+    user: AuthenticatedUser,
+    format: Option<ExportFormat>,
+    QsQuery(request): QsQuery<WebhookDeliveryQuery>,
+) -> ListResponse<WebhookDelivery> {
+    list_handler(state, user, (subscription_id, request), format).await
+}
+
+impl db::List for (WebhookSubscriptionIdDeliveries, WebhookDeliveryQuery) {
+    type Row = WebhookDelivery;
+
+    fn list_stmt(&self, rows: db::Rows) -> impl db::ListStatement<'_, WebhookDelivery> {
+        let (subscription_id, query) = self;
+
+        let WebhookDeliveryQuery {
+            limit,
+            offset,
+            filter,
+            order_by,
+            cursor,
+            total: _,
+            fields: _,
+            include: _,
+        } = query;
+
+        let mut stmt = WebhookDelivery::query()
+            .filter(d::subscription_id.eq(subscription_id))
+            .into_boxed();
+
+        stmt = stmt.filter(filter.to_boxed_filter());
+
+        if rows == db::Rows::Page {
+            stmt = stmt.limit(*limit).offset(*offset);
+
+            if let Some(cursor) = cursor {
+                stmt = stmt.filter(Keyset::new(order_by.as_ref(), *cursor));
+            }
+        }
+
+        if rows != db::Rows::Unordered {
+            for ordering in order_by.as_ref() {
+                stmt = stmt.then_order_by(ordering);
+            }
+            stmt = stmt.then_order_by(d::id);
+        }
+
+        stmt
+    }
+
+    fn id(row: &WebhookDelivery) -> Uuid {
+        row.id()
+    }
+}
+
+impl<'a, QS: 'a> ToBoxedFilter<'a, QS> for WebhookDeliveryFilter
+where
+    d::status: SelectableExpression<QS>,
+{
+    fn to_boxed_filter(&'a self) -> BoxedFilter<'a, QS> {
+        let Self { statuses } = self;
+        let mut filter = BoxedFilter::new_true();
+
+        if let Some(statuses) = statuses {
+            filter = filter.and_condition(d::status.eq_any(statuses));
+        }
+
+        filter
+    }
+}
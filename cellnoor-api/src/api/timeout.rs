@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use axum::{
+    extract::{Request, State},
+    http::{StatusCode, header::CONTENT_TYPE},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::api::error::{Error, ErrorResponse};
+
+#[derive(Clone, Copy)]
+pub(super) struct Timeouts {
+    pub(super) request: Duration,
+    /// Multipart uploads spend most of their time receiving the body, which can
+    /// take much longer than handling any other request
+    pub(super) upload: Duration,
+}
+
+/// Give up on a request that takes longer than its timeout. Any query it was
+/// running keeps going until the database's `statement_timeout` cancels it.
+pub(super) async fn enforce(
+    State(timeouts): State<Timeouts>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let is_upload = request
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .is_some_and(|content_type| content_type.starts_with("multipart/form-data"));
+    let timeout = if is_upload {
+        timeouts.upload
+    } else {
+        timeouts.request
+    };
+
+    let method = request.method().clone();
+    let uri = request.uri().clone();
+
+    if let Ok(response) = tokio::time::timeout(timeout, next.run(request)).await {
+        response
+    } else {
+        tracing::warn!("{method} {uri} timed out after {timeout:?}");
+
+        ErrorResponse {
+            status: StatusCode::SERVICE_UNAVAILABLE.as_u16(),
+            public_error: Error::Other,
+            internal_error: None,
+            request_id: None,
+        }
+        .into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::{
+        Router,
+        body::Body,
+        extract::Request,
+        http::{StatusCode, header::CONTENT_TYPE},
+        middleware,
+        routing::post,
+    };
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use tower::ServiceExt;
+
+    use super::{Timeouts, enforce};
+
+    #[rstest]
+    #[case::request("application/json", StatusCode::SERVICE_UNAVAILABLE)]
+    #[case::upload("multipart/form-data; boundary=x", StatusCode::OK)]
+    #[tokio::test]
+    async fn uploads_have_their_own_timeout(
+        #[case] content_type: &str,
+        #[case] expected_status: StatusCode,
+    ) {
+        let timeouts = Timeouts {
+            request: Duration::from_millis(10),
+            upload: Duration::from_secs(1),
+        };
+        let router = Router::new()
+            .route(
+                "/",
+                post(async || tokio::time::sleep(Duration::from_millis(100)).await),
+            )
+            .route_layer(middleware::from_fn_with_state(timeouts, enforce));
+
+        let request = Request::post("/")
+            .header(CONTENT_TYPE, content_type)
+            .body(Body::empty())
+            .unwrap();
+        let response = router.oneshot(request).await.unwrap();
+
+        assert_eq!(response.status(), expected_status);
+    }
+}
@@ -0,0 +1,363 @@
+use std::{
+    fmt::Display,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use cellnoor_models::change_event::ChangeEvent;
+use cellnoor_schema::change_events;
+use diesel::{
+    dsl::{IntervalDsl, now, sql},
+    prelude::*,
+    sql_types::{BigInt, Timestamptz},
+};
+use tokio::sync::{
+    broadcast::{self, error::RecvError},
+    watch,
+};
+
+use crate::db::{BoxedFilter, BoxedFilterExt};
+
+// Must match the channel notified by `record_change_event()` in the migrations
+const CHANNEL: &str = "change_events";
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(5);
+const PRUNE_INTERVAL: Duration = Duration::from_hours(1);
+const RETENTION_DAYS: i32 = 7;
+// Subscribers that fall further behind than this are disconnected
+const CAPACITY: usize = 1024;
+
+/// Inserts and updates announced by the database with `NOTIFY`, read back in
+/// cursor order and fanned out to every open event stream. Events are also kept
+/// in the `change_events` table for a week so that clients can catch up after
+/// reconnecting.
+#[derive(Clone)]
+pub(crate) struct ChangeFeed {
+    events: broadcast::Sender<(EventCursor, ChangeEvent)>,
+    closed: Arc<watch::Sender<bool>>,
+}
+
+impl ChangeFeed {
+    /// Listen for changes on a dedicated connection in a background thread,
+    /// reconnecting whenever the connection is lost
+    pub(crate) fn start(db_url: String) -> anyhow::Result<Self> {
+        let (events, _) = broadcast::channel(CAPACITY);
+        let (closed, _) = watch::channel(false);
+        let feed = Self {
+            events,
+            closed: Arc::new(closed),
+        };
+
+        let listener = feed.clone();
+        std::thread::Builder::new()
+            .name("change-feed".to_owned())
+            .spawn(move || listener.listen(&db_url))
+            .context("failed to spawn change feed listener")?;
+
+        Ok(feed)
+    }
+
+    /// End every open event stream, so that the server can shut down without
+    /// waiting for clients to disconnect
+    pub(crate) fn close(&self) {
+        self.closed.send_replace(true);
+    }
+
+    pub(crate) fn subscribe(&self) -> Subscription {
+        Subscription {
+            events: self.events.subscribe(),
+            closed: self.closed.subscribe(),
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        *self.closed.borrow()
+    }
+
+    fn listen(&self, db_url: &str) {
+        let mut pruned_at = None;
+        // Kept across reconnections so that nothing recorded in between is missed
+        let mut cursor = None;
+
+        while !self.is_closed() {
+            if let Err(err) = self.listen_until_closed(db_url, &mut pruned_at, &mut cursor) {
+                tracing::error!("change feed listener failed, reconnecting: {err:#}");
+                std::thread::sleep(RECONNECT_INTERVAL);
+            }
+        }
+    }
+
+    fn listen_until_closed(
+        &self,
+        db_url: &str,
+        pruned_at: &mut Option<Instant>,
+        cursor: &mut Option<EventCursor>,
+    ) -> anyhow::Result<()> {
+        let mut db_conn = PgConnection::establish(db_url)?;
+        diesel::sql_query(format!("listen {CHANNEL}")).execute(&mut db_conn)?;
+        tracing::info!("listening for change events");
+
+        // Everything below the horizon was recorded before the feed started
+        let cursor = match cursor {
+            Some(cursor) => cursor,
+            None => cursor.insert(EventCursor {
+                xact_id: diesel::select(horizon()).get_result(&mut db_conn)?,
+                id: 0,
+            }),
+        };
+        // The newest event announced, which may not be readable yet because an
+        // earlier transaction is still running
+        let mut announced = *cursor;
+
+        while !self.is_closed() {
+            if pruned_at.is_none_or(|t| t.elapsed() >= PRUNE_INTERVAL) {
+                prune(&mut db_conn).context("failed to prune change events")?;
+                *pruned_at = Some(Instant::now());
+            }
+
+            // This only reads what the server has already sent, so it has to be
+            // polled
+            for notification in db_conn.notifications_iter() {
+                let payload = notification?.payload;
+
+                match serde_json::from_str(&payload) {
+                    Ok(notified) => announced = announced.max(notified),
+                    Err(err) => tracing::error!("malformed change event {payload}: {err}"),
+                }
+            }
+
+            // Notifications arrive in commit order, which isn't cursor order, so
+            // the events themselves are read back from the table
+            if announced > *cursor {
+                for (event_cursor, event) in
+                    events_after(*cursor, BoxedFilter::new_true(), &mut db_conn)?
+                {
+                    *cursor = event_cursor;
+                    // An error just means that nobody is subscribed
+                    self.events.send((event_cursor, event)).ok();
+                }
+            }
+
+            std::thread::sleep(POLL_INTERVAL);
+        }
+
+        Ok(())
+    }
+}
+
+fn prune(db_conn: &mut PgConnection) -> QueryResult<usize> {
+    diesel::delete(change_events::table)
+        .filter(
+            change_events::occurred_at.lt(now.into_sql::<Timestamptz>() - RETENTION_DAYS.days()),
+        )
+        .execute(db_conn)
+}
+
+/// A position in the feed, sent to clients as the ID of each event.
+///
+/// Identity values are handed out when events are recorded rather than when
+/// they're committed, so ordering by ID alone would let a slow transaction
+/// commit an event behind a cursor that a client has already passed. Events are
+/// instead ordered by the transaction that recorded them, and only read once
+/// every earlier transaction has finished.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize)]
+pub(crate) struct EventCursor {
+    xact_id: i64,
+    id: i64,
+}
+
+impl Display for EventCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.xact_id, self.id)
+    }
+}
+
+impl FromStr for EventCursor {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (xact_id, id) = s
+            .split_once('-')
+            .context("event cursor should be a transaction ID and an event ID")?;
+
+        Ok(Self {
+            xact_id: xact_id.parse()?,
+            id: id.parse()?,
+        })
+    }
+}
+
+/// The oldest transaction that may still be running. Anything recorded by an
+/// earlier transaction has either been committed or rolled back.
+fn horizon() -> diesel::expression::SqlLiteral<BigInt> {
+    sql("pg_snapshot_xmin(pg_current_snapshot())::text::bigint")
+}
+
+/// The events after `cursor` that match `filter`, in cursor order, leaving out
+/// those that an unfinished transaction could still be followed by
+pub(crate) fn events_after(
+    cursor: EventCursor,
+    filter: BoxedFilter<'_, change_events::table>,
+    db_conn: &mut PgConnection,
+) -> QueryResult<Vec<(EventCursor, ChangeEvent)>> {
+    use change_events as t;
+
+    let rows: Vec<(i64, i64, ChangeEvent)> = t::table
+        .filter(
+            t::xact_id
+                .gt(cursor.xact_id)
+                .or(t::xact_id.eq(cursor.xact_id).and(t::id.gt(cursor.id))),
+        )
+        .filter(t::xact_id.lt(horizon()))
+        .filter(filter)
+        .order_by((t::xact_id, t::id))
+        .select((t::xact_id, t::id, ChangeEvent::as_select()))
+        .load(db_conn)?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(xact_id, id, event)| (EventCursor { xact_id, id }, event))
+        .collect())
+}
+
+pub(crate) struct Subscription {
+    events: broadcast::Receiver<(EventCursor, ChangeEvent)>,
+    closed: watch::Receiver<bool>,
+}
+
+impl Subscription {
+    /// The next change, or `None` once the server starts shutting down or if
+    /// this subscriber fell so far behind that changes were dropped. Either
+    /// way, the client can reconnect and resume from the last event it saw.
+    pub(crate) async fn next(&mut self) -> Option<(EventCursor, ChangeEvent)> {
+        tokio::select! {
+            _ = self.closed.wait_for(|closed| *closed) => None,
+            event = self.events.recv() => match event {
+                Ok(event) => Some(event),
+                Err(RecvError::Lagged(n_dropped)) => {
+                    tracing::warn!("event stream fell behind by {n_dropped} events");
+                    None
+                }
+                Err(RecvError::Closed) => None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use cellnoor_schema::change_events;
+    use deadpool_diesel::postgres::Connection;
+    use diesel::prelude::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    use super::{EventCursor, events_after};
+    use crate::test_state::root_db_conn;
+
+    fn record_event(lab_id: Uuid, db_conn: &mut PgConnection) -> i64 {
+        diesel::insert_into(change_events::table)
+            .values((
+                change_events::entity_type.eq("specimen"),
+                change_events::operation.eq("insert"),
+                change_events::entity_id.eq(Uuid::now_v7()),
+                change_events::lab_id.eq(lab_id),
+            ))
+            .returning(change_events::id)
+            .get_result(db_conn)
+            .unwrap()
+    }
+
+    async fn read_after(cursor: EventCursor, lab_id: Uuid, db_conn: &Connection) -> Vec<i64> {
+        db_conn
+            .interact(move |db_conn| {
+                let filter = Box::new(change_events::lab_id.assume_not_null().eq(lab_id));
+
+                events_after(cursor, filter, db_conn)
+                    .unwrap()
+                    .into_iter()
+                    .map(|(_, event)| event.id())
+                    .collect()
+            })
+            .await
+            .unwrap()
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn late_commits_are_not_skipped(
+        #[future] root_db_conn: Connection,
+        #[future]
+        #[from(root_db_conn)]
+        slow_db_conn: Connection,
+    ) {
+        let lab_id = Uuid::now_v7();
+        let start = EventCursor { xact_id: 0, id: 0 };
+
+        // The slow transaction records its event first but commits last, so its
+        // event has the lower ID
+        let slow_id = slow_db_conn
+            .interact(move |db_conn| {
+                diesel::sql_query("begin").execute(db_conn).unwrap();
+                record_event(lab_id, db_conn)
+            })
+            .await
+            .unwrap();
+        let fast_id = root_db_conn
+            .interact(move |db_conn| record_event(lab_id, db_conn))
+            .await
+            .unwrap();
+        assert!(slow_id < fast_id);
+
+        // Streaming the fast event now would move clients past the slow one
+        assert!(read_after(start, lab_id, &root_db_conn).await.is_empty());
+
+        slow_db_conn
+            .interact(|db_conn| diesel::sql_query("commit").execute(db_conn))
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Other tests' transactions can hold the horizon back for a while, but
+        // the events only ever become readable in order
+        let mut read = Vec::new();
+        for _ in 0..100 {
+            read = read_after(start, lab_id, &root_db_conn).await;
+            assert_eq!(read, [slow_id, fast_id][..read.len()]);
+
+            if read.len() == 2 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert_eq!(read, [slow_id, fast_id]);
+
+        root_db_conn
+            .interact(move |db_conn| {
+                diesel::delete(change_events::table)
+                    .filter(change_events::lab_id.eq(lab_id))
+                    .execute(db_conn)
+            })
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    #[rstest]
+    fn cursor_round_trips() {
+        let cursor = EventCursor {
+            xact_id: 1234,
+            id: 5,
+        };
+
+        assert_eq!(cursor.to_string(), "1234-5");
+        assert_eq!(cursor.to_string().parse::<EventCursor>().unwrap(), cursor);
+        assert!("5".parse::<EventCursor>().is_err());
+    }
+}
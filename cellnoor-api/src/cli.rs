@@ -0,0 +1,96 @@
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+
+use crate::{
+    api,
+    cli::{
+        config::ConfigCommand, create_person::CreatePerson, issue_api_key::IssueApiKey,
+        migrate::Migrate,
+    },
+    config::{Config, ConfigArgs},
+    setup, telemetry,
+};
+
+mod config;
+mod create_person;
+mod issue_api_key;
+mod migrate;
+
+/// Serve the cellnoor API, or run one of the administrative tasks it depends
+/// on as a one-off job
+#[derive(Parser)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(flatten)]
+    config: ConfigArgs,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Serve the API over HTTP
+    Serve {
+        /// Run migrations, set the database users' passwords and insert the
+        /// initial data before serving. With more than one instance of the API,
+        /// run those as separate jobs instead.
+        #[arg(long)]
+        setup: bool,
+    },
+    /// Run pending database migrations
+    Migrate(Migrate),
+    /// Insert or update the initial data
+    Seed,
+    /// Create a person and the database user that carries their roles
+    CreatePerson(CreatePerson),
+    /// Issue an API key for a person, printing it to standard output
+    IssueApiKey(IssueApiKey),
+    /// Set the passwords of the `cellnoor_api` and `cellnoor_ui` database users
+    /// to the configured values
+    RotateDbPasswords,
+    /// Inspect the configuration without doing anything else
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+}
+
+impl Cli {
+    pub async fn run(self) -> anyhow::Result<()> {
+        let Self { config, command } = self;
+
+        let config = Config::from_args(config).context(
+            "failed to read configuration from command-line, environment, configuration directory \
+             and configuration file",
+        )?;
+        let _telemetry = telemetry::initialize(&config)?;
+
+        match command {
+            Command::Serve { setup } => api::serve(config, setup).await,
+            Command::Migrate(args) => args.run(&config),
+            Command::Seed => setup::seed(&config).await,
+            Command::CreatePerson(args) => args.run(&config),
+            Command::IssueApiKey(args) => args.run(&config),
+            Command::RotateDbPasswords => {
+                setup::set_db_user_passwords(&config, &mut setup::root_db_conn(&config)?)
+            }
+            Command::Config { command } => {
+                command.run(&config);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+    use rstest::rstest;
+
+    use super::Cli;
+
+    #[rstest]
+    fn cli() {
+        Cli::command().debug_assert();
+    }
+}
@@ -0,0 +1,21 @@
+use clap::Subcommand;
+
+use crate::config::Config;
+
+#[derive(Subcommand)]
+pub(super) enum ConfigCommand {
+    /// Print every setting, with secrets redacted, and where it came from
+    Check,
+}
+
+impl ConfigCommand {
+    pub(super) fn run(&self, config: &Config) {
+        match self {
+            Self::Check => {
+                for setting in config.effective_settings() {
+                    println!("{setting}");
+                }
+            }
+        }
+    }
+}
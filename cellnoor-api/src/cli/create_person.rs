@@ -0,0 +1,63 @@
+use cellnoor_models::person::{Person, PersonCreation, PersonFields, UserRole};
+use diesel::Connection;
+use non_empty::NonEmptyString;
+use uuid::Uuid;
+
+use crate::{config::Config, db::Operation, setup::root_db_conn, validate::Validate};
+
+#[derive(clap::Args)]
+pub(super) struct CreatePerson {
+    #[arg(long, value_parser = non_empty)]
+    name: NonEmptyString,
+    #[arg(long, value_parser = non_empty)]
+    email: NonEmptyString,
+    #[arg(long)]
+    institution_id: Uuid,
+    #[arg(long, value_parser = non_empty)]
+    orcid: Option<NonEmptyString>,
+    #[arg(long)]
+    microsoft_entra_oid: Option<Uuid>,
+    /// A comma-separated list of roles, such as `app_admin,biology_staff`
+    #[arg(long, value_delimiter = ',')]
+    roles: Vec<UserRole>,
+}
+
+impl CreatePerson {
+    pub(super) fn run(self, config: &Config) -> anyhow::Result<()> {
+        let Self {
+            name,
+            email,
+            institution_id,
+            orcid,
+            microsoft_entra_oid,
+            roles,
+        } = self;
+
+        let request = PersonCreation::builder()
+            .inner(
+                PersonFields::builder()
+                    .name(name)
+                    .maybe_orcid(orcid)
+                    .institution_id(institution_id)
+                    .maybe_microsoft_entra_oid(microsoft_entra_oid)
+                    .build(),
+            )
+            .email(email)
+            .roles(roles)
+            .build();
+
+        let mut db_conn = root_db_conn(config)?;
+        request.validate(&mut db_conn)?;
+
+        // Creating the person and their database user should succeed or fail
+        // together
+        let person: Person = db_conn.transaction(|tx| request.execute(tx))?;
+        println!("{}", serde_json::to_string_pretty(&person)?);
+
+        Ok(())
+    }
+}
+
+fn non_empty(s: &str) -> Result<NonEmptyString, &'static str> {
+    NonEmptyString::new(s).ok_or("must not be empty")
+}
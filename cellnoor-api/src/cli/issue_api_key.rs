@@ -0,0 +1,32 @@
+use cellnoor_schema::api_keys;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::{api::generate_api_key, config::Config, setup::root_db_conn};
+
+#[derive(clap::Args)]
+pub(super) struct IssueApiKey {
+    /// The ID of the person who will own the key
+    #[arg(long)]
+    person_id: Uuid,
+}
+
+impl IssueApiKey {
+    pub(super) fn run(self, config: &Config) -> anyhow::Result<()> {
+        let (api_key, hash) = generate_api_key();
+        let prefix = &api_key[..config.api_key_prefix_length()];
+
+        diesel::insert_into(api_keys::table)
+            .values((
+                api_keys::prefix.eq(prefix),
+                api_keys::hash.eq(hash),
+                api_keys::user_id.eq(self.person_id),
+            ))
+            .execute(&mut root_db_conn(config)?)?;
+
+        // This is the only time the key itself is available
+        println!("{}", base16ct::lower::encode_string(&api_key));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use anyhow::anyhow;
+use diesel_migrations::MigrationHarness;
+
+use crate::{
+    config::Config,
+    setup::{MIGRATIONS, root_db_conn, run_migrations},
+};
+
+#[derive(clap::Args)]
+#[group(multiple = false)]
+pub(super) struct Migrate {
+    /// Print the names of pending migrations without running them
+    #[arg(long)]
+    dry_run: bool,
+    /// Revert the last N migrations instead of running pending ones
+    #[arg(long, value_name = "N")]
+    revert: Option<usize>,
+}
+
+impl Migrate {
+    pub(super) fn run(self, config: &Config) -> anyhow::Result<()> {
+        let Self { dry_run, revert } = self;
+        let mut db_conn = root_db_conn(config)?;
+
+        if dry_run {
+            for migration in db_conn
+                .pending_migrations(MIGRATIONS)
+                .map_err(|e| anyhow!(e))?
+            {
+                println!("{}", migration.name());
+            }
+
+            return Ok(());
+        }
+
+        let Some(n_migrations) = revert else {
+            return run_migrations(&mut db_conn);
+        };
+
+        for _ in 0..n_migrations {
+            let version = db_conn
+                .revert_last_migration(MIGRATIONS)
+                .map_err(|e| anyhow!(e))?;
+            println!("reverted {version}");
+        }
+
+        Ok(())
+    }
+}
@@ -1,14 +1,32 @@
 // The three fields not zeroized in `Config` cause a linting error
 #![allow(unused)]
-use std::{path::Path, str::FromStr};
+use std::{collections::HashSet, fmt::Display, str::FromStr, time::Duration};
 
-use anyhow::Context;
+use anyhow::{Context, anyhow, bail};
 use camino::{Utf8Path, Utf8PathBuf};
-use clap::Parser;
+use clap::{ArgMatches, Args, CommandFactory, FromArgMatches, Parser, parser::ValueSource};
+use jiff::SignedDuration;
+use url::Url;
 use zeroize::Zeroize;
 
 use crate::initial_data::InitialData;
 
+const CONFIG_FILE_NAME: &str = "cellnoor.toml";
+const SECRETS: [&str; 3] = [
+    "db_root_password",
+    "cellnoor_api_db_password",
+    "cellnoor_ui_db_password",
+];
+
+const DEFAULT_DB_POOL_TIMEOUT: SignedDuration = SignedDuration::from_secs(30);
+const DEFAULT_DB_STATEMENT_TIMEOUT: SignedDuration = SignedDuration::from_secs(30);
+const DEFAULT_REQUEST_TIMEOUT: SignedDuration = SignedDuration::from_secs(120);
+const DEFAULT_UPLOAD_TIMEOUT: SignedDuration = SignedDuration::from_secs(600);
+const DEFAULT_READ_RATE_LIMIT: u32 = 600;
+const DEFAULT_WRITE_RATE_LIMIT: u32 = 60;
+const DEFAULT_MAX_METRICS_FILES_SIZE: usize = 2usize.pow(21);
+const DEFAULT_MAX_WEB_SUMMARY_SIZE: usize = 2usize.pow(24);
+
 #[derive(Debug, Zeroize)]
 pub struct Config {
     #[zeroize(skip)]
@@ -27,12 +45,43 @@ pub struct Config {
     initial_data: InitialData,
     #[zeroize(skip)]
     log_dir: Option<Utf8PathBuf>,
+    metrics_port: Option<u16>,
+    #[zeroize(skip)]
+    otlp_endpoint: Option<Url>,
+    db_pool_size: Option<usize>,
+    #[zeroize(skip)]
+    db_pool_timeout: Duration,
+    #[zeroize(skip)]
+    db_statement_timeout: Duration,
+    #[zeroize(skip)]
+    request_timeout: Duration,
+    #[zeroize(skip)]
+    upload_timeout: Duration,
+    read_rate_limit: u32,
+    write_rate_limit: u32,
+    #[zeroize(skip)]
+    upload_limits: UploadLimits,
+    #[zeroize(skip)]
+    effective_settings: Vec<EffectiveSetting>,
 }
 
 impl Config {
     pub fn read() -> anyhow::Result<Self> {
-        let Cli {
+        Self::from_args(Cli::parse().args)
+    }
+
+    /// Each setting is taken from the first of these that has it: the
+    /// command-line, the environment, a file named after the setting in
+    /// `config_dir`, and `cellnoor.toml`
+    pub fn from_args(args: ConfigArgs) -> anyhow::Result<Self> {
+        let ConfigArgs {
+            arguments,
+            from_command_line,
+        } = args;
+
+        let Arguments {
             config_dir,
+            config_file,
             mode,
             db_root_user,
             db_root_password,
@@ -44,29 +93,97 @@ impl Config {
             api_key_prefix_length,
             host,
             port,
+            initial_data_path,
             log_dir,
-        } = Cli::parse();
+            metrics_port,
+            otlp_endpoint,
+            db_pool_size,
+            db_pool_timeout,
+            db_statement_timeout,
+            request_timeout,
+            upload_timeout,
+            read_rate_limit,
+            write_rate_limit,
+            max_metrics_files_size,
+            max_web_summary_size,
+        } = arguments;
+
+        let mut layers = Layers::new(config_dir, config_file, from_command_line)?;
+
+        let initial_data = layers.initial_data(initial_data_path)?;
 
         Ok(Self {
-            mode: mode.or_load(config_dir.join("mode")).unwrap_or_default(),
-            db_root_user: db_root_user.or_load(config_dir.join("db_root_user"))?,
-            db_root_password: db_root_password.or_load(config_dir.join("db_root_password"))?,
-            cellnoor_api_db_password: cellnoor_api_db_password
-                .or_load(config_dir.join("cellnoor_api_db_password"))?,
-            cellnoor_ui_db_password: cellnoor_ui_db_password
-                .or_load(config_dir.join("cellnoor_ui_db_password"))?,
-            db_host: db_host.or_load(config_dir.join("db_host"))?,
-            db_port: db_port.or_load(config_dir.join("db_port"))?,
-            db_name: db_name.or_load(config_dir.join("db_name"))?,
-            api_key_prefix_length: api_key_prefix_length
-                .or_load(config_dir.join("api_key_prefix_length"))?,
-            host: host.or_load(config_dir.join("host"))?,
-            port: port.or_load(config_dir.join("port"))?,
-            initial_data: None.or_load(config_dir.join("initial_data"))?,
-            log_dir: log_dir.or_load(config_dir.join("log_dir")).ok(),
+            mode: layers.or_default("mode", mode, AppMode::default())?,
+            db_root_user: layers.required("db_root_user", db_root_user)?,
+            db_root_password: layers.required("db_root_password", db_root_password)?,
+            cellnoor_api_db_password: layers
+                .required("cellnoor_api_db_password", cellnoor_api_db_password)?,
+            cellnoor_ui_db_password: layers
+                .required("cellnoor_ui_db_password", cellnoor_ui_db_password)?,
+            db_host: layers.required("db_host", db_host)?,
+            db_port: layers.required("db_port", db_port)?,
+            db_name: layers.required("db_name", db_name)?,
+            api_key_prefix_length: layers
+                .required("api_key_prefix_length", api_key_prefix_length)?,
+            host: layers.required("host", host)?,
+            port: layers.required("port", port)?,
+            initial_data,
+            log_dir: layers.optional("log_dir", log_dir)?,
+            metrics_port: layers.optional("metrics_port", metrics_port)?,
+            otlp_endpoint: layers.optional("otlp_endpoint", otlp_endpoint)?,
+            db_pool_size: layers.optional("db_pool_size", db_pool_size)?,
+            db_pool_timeout: layers.timeout(
+                "db_pool_timeout",
+                db_pool_timeout,
+                DEFAULT_DB_POOL_TIMEOUT,
+            )?,
+            db_statement_timeout: layers.timeout(
+                "db_statement_timeout",
+                db_statement_timeout,
+                DEFAULT_DB_STATEMENT_TIMEOUT,
+            )?,
+            request_timeout: layers.timeout(
+                "request_timeout",
+                request_timeout,
+                DEFAULT_REQUEST_TIMEOUT,
+            )?,
+            upload_timeout: layers.timeout(
+                "upload_timeout",
+                upload_timeout,
+                DEFAULT_UPLOAD_TIMEOUT,
+            )?,
+            read_rate_limit: layers.or_default(
+                "read_rate_limit",
+                read_rate_limit,
+                DEFAULT_READ_RATE_LIMIT,
+            )?,
+            write_rate_limit: layers.or_default(
+                "write_rate_limit",
+                write_rate_limit,
+                DEFAULT_WRITE_RATE_LIMIT,
+            )?,
+            upload_limits: UploadLimits {
+                metrics_files: layers.or_default(
+                    "max_metrics_files_size",
+                    max_metrics_files_size,
+                    DEFAULT_MAX_METRICS_FILES_SIZE,
+                )?,
+                web_summary: layers.or_default(
+                    "max_web_summary_size",
+                    max_web_summary_size,
+                    DEFAULT_MAX_WEB_SUMMARY_SIZE,
+                )?,
+            },
+            effective_settings: layers.effective_settings,
         })
     }
 
+    /// Every setting, where it came from, and its value unless it's a secret
+    #[must_use]
+    pub fn effective_settings(&self) -> &[EffectiveSetting] {
+        &self.effective_settings
+    }
+
     #[must_use]
     pub fn cellnoor_api_db_password(&self) -> &str {
         &self.cellnoor_api_db_password
@@ -92,6 +209,17 @@ impl Config {
             port: _,
             initial_data: _,
             log_dir: _,
+            metrics_port: _,
+            otlp_endpoint: _,
+            db_pool_size: _,
+            db_pool_timeout: _,
+            db_statement_timeout: _,
+            request_timeout: _,
+            upload_timeout: _,
+            read_rate_limit: _,
+            write_rate_limit: _,
+            upload_limits: _,
+            effective_settings: _,
         } = self;
 
         let base = "postgres://";
@@ -148,15 +276,116 @@ impl Config {
             api_key_prefix_length: _,
             initial_data: _,
             log_dir: _,
+            metrics_port: _,
+            otlp_endpoint: _,
+            db_pool_size: _,
+            db_pool_timeout: _,
+            db_statement_timeout: _,
+            request_timeout: _,
+            upload_timeout: _,
+            read_rate_limit: _,
+            write_rate_limit: _,
+            upload_limits: _,
+            effective_settings: _,
         } = self;
 
         format!("{host}:{port}")
     }
 
+    /// The port on which to serve `/metrics` separately from the API. If not
+    /// given, `/metrics` is served alongside `/api`.
+    #[must_use]
+    pub fn metrics_address(&self) -> Option<String> {
+        self.metrics_port
+            .map(|metrics_port| format!("{}:{metrics_port}", self.host))
+    }
+
+    /// Where to export traces with the OTLP HTTP protocol, such as
+    /// `http://otel-collector:4318/v1/traces`
+    #[must_use]
+    pub fn otlp_endpoint(&self) -> Option<&Url> {
+        self.otlp_endpoint.as_ref()
+    }
+
     #[must_use]
     pub fn api_key_prefix_length(&self) -> usize {
         self.api_key_prefix_length
     }
+
+    /// The maximum number of connections to the database. If not given,
+    /// four times the number of CPUs.
+    #[must_use]
+    pub fn db_pool_size(&self) -> Option<usize> {
+        self.db_pool_size
+    }
+
+    /// How long to wait for a connection from the pool before giving up
+    #[must_use]
+    pub fn db_pool_timeout(&self) -> Duration {
+        self.db_pool_timeout
+    }
+
+    /// The Postgres `statement_timeout` of every pooled connection
+    #[must_use]
+    pub fn db_statement_timeout(&self) -> Duration {
+        self.db_statement_timeout
+    }
+
+    #[must_use]
+    pub fn request_timeout(&self) -> Duration {
+        self.request_timeout
+    }
+
+    /// Like [`Config::request_timeout`], but for multipart file uploads, which
+    /// can take much longer over a slow connection
+    #[must_use]
+    pub fn upload_timeout(&self) -> Duration {
+        self.upload_timeout
+    }
+
+    /// Requests per minute per user to routes that only read, where 0 means
+    /// unlimited
+    #[must_use]
+    pub fn read_rate_limit(&self) -> u32 {
+        self.read_rate_limit
+    }
+
+    /// Requests per minute per user to routes that write, where 0 means
+    /// unlimited
+    #[must_use]
+    pub fn write_rate_limit(&self) -> u32 {
+        self.write_rate_limit
+    }
+
+    #[must_use]
+    pub fn upload_limits(&self) -> UploadLimits {
+        self.upload_limits
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_rate_limits(self, read_rate_limit: u32, write_rate_limit: u32) -> Self {
+        Self {
+            read_rate_limit,
+            write_rate_limit,
+            ..self
+        }
+    }
+}
+
+/// The maximum sizes, in bytes, of the bodies of the multipart file routes
+#[derive(Clone, Copy, Debug)]
+pub struct UploadLimits {
+    pub metrics_files: usize,
+    pub web_summary: usize,
+}
+
+impl Default for UploadLimits {
+    fn default() -> Self {
+        Self {
+            metrics_files: DEFAULT_MAX_METRICS_FILES_SIZE,
+            web_summary: DEFAULT_MAX_WEB_SUMMARY_SIZE,
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -165,10 +394,21 @@ enum DatabaseUser {
     CellnoorApi,
 }
 
-#[derive(Clone, Debug, Parser)]
+#[derive(Parser)]
 struct Cli {
+    #[command(flatten)]
+    args: ConfigArgs,
+}
+
+#[derive(Clone, Debug, clap::Args)]
+struct Arguments {
+    /// A directory containing a file per setting, named after the setting, such
+    /// as a directory of Docker secrets
     #[arg(long, env = "CELLNOOR_CONFIG_DIR")]
-    config_dir: Utf8PathBuf,
+    config_dir: Option<Utf8PathBuf>,
+    /// Defaults to `cellnoor.toml` in the configuration directory, if it exists
+    #[arg(long, env = "CELLNOOR_CONFIG_FILE")]
+    config_file: Option<Utf8PathBuf>,
     #[arg(long, env = "CELLNOOR_MODE")]
     mode: Option<AppMode>,
     #[arg(long, env = "CELLNOOR_DB_ROOT_USER")]
@@ -191,39 +431,369 @@ struct Cli {
     host: Option<String>,
     #[arg(long, env = "CELLNOOR_API_PORT")]
     port: Option<u16>,
+    /// A JSON file of the initial data. Defaults to the file `initial_data` in
+    /// the configuration directory.
+    #[arg(long, env = "CELLNOOR_INITIAL_DATA_PATH")]
+    initial_data_path: Option<Utf8PathBuf>,
     #[arg(long, env = "CELLNOOR_LOG_DIR")]
     log_dir: Option<Utf8PathBuf>,
+    #[arg(long, env = "CELLNOOR_METRICS_PORT")]
+    metrics_port: Option<u16>,
+    #[arg(long, env = "CELLNOOR_OTLP_ENDPOINT")]
+    otlp_endpoint: Option<Url>,
+    #[arg(long, env = "CELLNOOR_DB_POOL_SIZE")]
+    db_pool_size: Option<usize>,
+    /// For example, "30s" or "PT30S"
+    #[arg(long, env = "CELLNOOR_DB_POOL_TIMEOUT")]
+    db_pool_timeout: Option<SignedDuration>,
+    #[arg(long, env = "CELLNOOR_DB_STATEMENT_TIMEOUT")]
+    db_statement_timeout: Option<SignedDuration>,
+    #[arg(long, env = "CELLNOOR_REQUEST_TIMEOUT")]
+    request_timeout: Option<SignedDuration>,
+    #[arg(long, env = "CELLNOOR_UPLOAD_TIMEOUT")]
+    upload_timeout: Option<SignedDuration>,
+    /// Requests per minute per user to routes that only read. 0 means
+    /// unlimited.
+    #[arg(long, env = "CELLNOOR_READ_RATE_LIMIT")]
+    read_rate_limit: Option<u32>,
+    /// Requests per minute per user to routes that write. 0 means unlimited.
+    #[arg(long, env = "CELLNOOR_WRITE_RATE_LIMIT")]
+    write_rate_limit: Option<u32>,
+    /// The largest upload of metrics files, in bytes
+    #[arg(long, env = "CELLNOOR_MAX_METRICS_FILES_SIZE")]
+    max_metrics_files_size: Option<usize>,
+    /// The largest upload of a web summary, in bytes
+    #[arg(long, env = "CELLNOOR_MAX_WEB_SUMMARY_SIZE")]
+    max_web_summary_size: Option<usize>,
 }
 
-trait OptionExt<T> {
-    fn or_load<P>(self, path: P) -> anyhow::Result<T>
-    where
-        T: FromStr,
-        T::Err: Send + Sync + std::error::Error + std::fmt::Display + 'static,
-        P: std::fmt::Display + AsRef<Path>;
+/// The configuration given on the command-line or in the environment, along
+/// with which of the two each setting came from
+#[derive(Clone, Debug)]
+pub struct ConfigArgs {
+    arguments: Arguments,
+    from_command_line: HashSet<String>,
+}
+
+impl FromArgMatches for ConfigArgs {
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, clap::Error> {
+        let from_command_line = matches
+            .ids()
+            .filter(|id| matches.value_source(id.as_str()) == Some(ValueSource::CommandLine))
+            .map(|id| id.as_str().to_owned())
+            .collect();
+
+        Ok(Self {
+            arguments: Arguments::from_arg_matches(matches)?,
+            from_command_line,
+        })
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> Result<(), clap::Error> {
+        *self = Self::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+impl Args for ConfigArgs {
+    fn augment_args(cmd: clap::Command) -> clap::Command {
+        Arguments::augment_args(cmd)
+    }
+
+    fn augment_args_for_update(cmd: clap::Command) -> clap::Command {
+        Arguments::augment_args_for_update(cmd)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Source {
+    CommandLine,
+    Environment(String),
+    ConfigDir(Utf8PathBuf),
+    ConfigFile(Utf8PathBuf),
+    Default,
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CommandLine => write!(f, "the command-line"),
+            Self::Environment(var) => write!(f, "environment variable {var}"),
+            Self::ConfigDir(path) => write!(f, "file {path}"),
+            Self::ConfigFile(path) => write!(f, "{path}"),
+            Self::Default => write!(f, "the default"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EffectiveSetting {
+    pub name: &'static str,
+    /// `None` for secrets and settings that aren't set
+    pub value: Option<String>,
+    /// `None` for settings that aren't set
+    pub source: Option<Source>,
+}
+
+// Formatted as TOML so that the output can be used as a starting point for
+// `cellnoor.toml`
+impl Display for EffectiveSetting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self {
+            name,
+            value,
+            source,
+        } = self;
+
+        let Some(source) = source else {
+            return write!(f, "# {name} is not set");
+        };
+
+        let value = value.as_deref().unwrap_or("<redacted>");
+        write!(f, "{name} = {}  # from {source}", toml::Value::from(value))
+    }
+}
+
+struct Layers {
+    arguments: clap::Command,
+    from_command_line: HashSet<String>,
+    config_dir: Option<Utf8PathBuf>,
+    config_file: Option<(Utf8PathBuf, toml::Table)>,
+    effective_settings: Vec<EffectiveSetting>,
 }
 
-impl<T> OptionExt<T> for Option<T> {
-    fn or_load<P>(self, path: P) -> anyhow::Result<T>
+impl Layers {
+    fn new(
+        config_dir: Option<Utf8PathBuf>,
+        config_file: Option<Utf8PathBuf>,
+        from_command_line: HashSet<String>,
+    ) -> anyhow::Result<Self> {
+        let arguments = Arguments::augment_args(clap::Command::new("cellnoor-api"));
+
+        let config_file = config_file.or_else(|| {
+            config_dir
+                .as_ref()
+                .map(|dir| dir.join(CONFIG_FILE_NAME))
+                .filter(|path| path.exists())
+        });
+
+        let config_file = match config_file {
+            Some(path) => {
+                let contents = std::fs::read_to_string(&path)
+                    .context(format!("failed to read configuration file {path}"))?;
+                let table: toml::Table = toml::from_str(&contents)
+                    .context(format!("failed to parse configuration file {path}"))?;
+
+                for key in table.keys() {
+                    let known = arguments
+                        .get_arguments()
+                        .any(|arg| arg.get_id() == key.as_str())
+                        && !["config_dir", "config_file"].contains(&key.as_str());
+                    if !known {
+                        bail!("unknown setting `{key}` in {path}");
+                    }
+                }
+
+                Some((path, table))
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            arguments,
+            from_command_line,
+            config_dir,
+            config_file,
+            effective_settings: Vec::new(),
+        })
+    }
+
+    fn find<T>(&self, name: &str, value: Option<T>) -> anyhow::Result<Option<(T, Source)>>
     where
         T: FromStr,
         T::Err: Send + Sync + std::error::Error + 'static,
-        P: std::fmt::Display + AsRef<Path>,
     {
-        if let Some(value) = self {
-            return Ok(value);
+        if let Some(value) = value {
+            return Ok(Some((value, self.argument_source(name))));
+        }
+
+        if let Some(path) = self.config_dir_file(name) {
+            let contents =
+                std::fs::read_to_string(&path).context(format!("failed to read {path}"))?;
+            // Files written by editors and `echo` end with a newline
+            let contents = contents.strip_suffix('\n').unwrap_or(&contents);
+            return parse(name, contents, Source::ConfigDir(path)).map(Some);
+        }
+
+        let Some((path, table)) = &self.config_file else {
+            return Ok(None);
+        };
+        let Some(value) = table.get(name) else {
+            return Ok(None);
+        };
+
+        let source = Source::ConfigFile(path.clone());
+        let value = match value {
+            toml::Value::String(s) => s.clone(),
+            toml::Value::Integer(_) | toml::Value::Float(_) | toml::Value::Boolean(_) => {
+                value.to_string()
+            }
+            _ => bail!("`{name}` in {source} must be a string or a number"),
+        };
+
+        parse(name, &value, source).map(Some)
+    }
+
+    fn optional<T>(&mut self, name: &'static str, value: Option<T>) -> anyhow::Result<Option<T>>
+    where
+        T: FromStr + Display,
+        T::Err: Send + Sync + std::error::Error + 'static,
+    {
+        let found = self.find(name, value)?;
+
+        match &found {
+            Some((value, source)) => self.record(name, Some(value), Some(source.clone())),
+            None => self.record(name, None, None),
+        }
+
+        Ok(found.map(|(value, _)| value))
+    }
+
+    fn required<T>(&mut self, name: &'static str, value: Option<T>) -> anyhow::Result<T>
+    where
+        T: FromStr + Display,
+        T::Err: Send + Sync + std::error::Error + 'static,
+    {
+        self.optional(name, value)?
+            .ok_or_else(|| self.missing(name, name))
+    }
+
+    fn or_default<T>(
+        &mut self,
+        name: &'static str,
+        value: Option<T>,
+        default: T,
+    ) -> anyhow::Result<T>
+    where
+        T: FromStr + Display,
+        T::Err: Send + Sync + std::error::Error + 'static,
+    {
+        let (value, source) = self
+            .find(name, value)?
+            .unwrap_or((default, Source::Default));
+        self.record(name, Some(&value), Some(source));
+
+        Ok(value)
+    }
+
+    /// The initial data is read from `initial_data_path` or, failing that, the
+    /// file `initial_data` in the configuration directory
+    fn initial_data(&mut self, path: Option<Utf8PathBuf>) -> anyhow::Result<InitialData> {
+        let (path, source) = self
+            .find("initial_data_path", path)?
+            .or_else(|| {
+                self.config_dir_file("initial_data")
+                    .map(|path| (path.clone(), Source::ConfigDir(path)))
+            })
+            .ok_or_else(|| self.missing("initial_data_path", "initial_data"))?;
+        self.record("initial_data_path", Some(&path), Some(source));
+
+        std::fs::read_to_string(&path)
+            .context(format!("failed to read initial data from {path}"))?
+            .parse()
+            .context(format!("failed to parse initial data in {path}"))
+    }
+
+    fn timeout(
+        &mut self,
+        name: &'static str,
+        value: Option<SignedDuration>,
+        default: SignedDuration,
+    ) -> anyhow::Result<Duration> {
+        let duration = self.or_default(name, value, default)?;
+
+        duration
+            .try_into()
+            .context(format!("`{name}` must not be negative"))
+    }
+
+    fn config_dir_file(&self, name: &str) -> Option<Utf8PathBuf> {
+        self.config_dir
+            .as_ref()
+            .map(|dir| dir.join(name))
+            .filter(|path| path.exists())
+    }
+
+    fn argument_source(&self, name: &str) -> Source {
+        if self.from_command_line.contains(name) {
+            return Source::CommandLine;
         }
 
-        let contents = std::fs::read_to_string(&path)
-            .context(format!("failed to read contents of file {path}"))?;
+        let env = self
+            .arguments
+            .get_arguments()
+            .find(|arg| arg.get_id() == name)
+            .and_then(|arg| arg.get_env())
+            .map(|env| env.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Source::Environment(env)
+    }
+
+    fn record(&mut self, name: &'static str, value: Option<&dyn Display>, source: Option<Source>) {
+        let value = value
+            .filter(|_| !SECRETS.contains(&name))
+            .map(ToString::to_string);
 
-        contents.parse().context(format!(
-            "failed to parse contents of {path} as {}",
-            std::any::type_name::<T>()
-        ))
+        self.effective_settings.push(EffectiveSetting {
+            name,
+            value,
+            source,
+        });
+    }
+
+    fn missing(&self, name: &str, config_dir_file: &str) -> anyhow::Error {
+        let mut places = Vec::new();
+
+        if let Some(arg) = self
+            .arguments
+            .get_arguments()
+            .find(|arg| arg.get_id() == name)
+        {
+            if let Some(long) = arg.get_long() {
+                places.push(format!("pass --{long}"));
+            }
+            if let Some(env) = arg.get_env() {
+                places.push(format!("set {}", env.to_string_lossy()));
+            }
+        }
+
+        match &self.config_dir {
+            Some(dir) => places.push(format!("write it to {dir}/{config_dir_file}")),
+            None => places.push("write it to a file in the configuration directory".to_owned()),
+        }
+
+        match &self.config_file {
+            Some((path, _)) => places.push(format!("add it to {path}")),
+            None => places.push(format!("add it to {CONFIG_FILE_NAME}")),
+        }
+
+        anyhow!("`{name}` is not set: {}", places.join(", or "))
     }
 }
 
+fn parse<T>(name: &str, value: &str, source: Source) -> anyhow::Result<(T, Source)>
+where
+    T: FromStr,
+    T::Err: Send + Sync + std::error::Error + 'static,
+{
+    let value = value
+        .parse()
+        .context(format!("invalid value for `{name}` in {source}"))?;
+
+    Ok((value, source))
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub enum AppMode {
     Development,
@@ -255,3 +825,95 @@ impl std::fmt::Display for AppMode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use camino::Utf8PathBuf;
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use uuid::Uuid;
+
+    use super::{CONFIG_FILE_NAME, Layers};
+
+    #[fixture]
+    fn config_dir() -> Utf8PathBuf {
+        let dir = Utf8PathBuf::try_from(std::env::temp_dir())
+            .unwrap()
+            .join(format!("cellnoor-config-{}", Uuid::now_v7()));
+        std::fs::create_dir(&dir).unwrap();
+
+        std::fs::write(dir.join("db_port"), "5433\n").unwrap();
+        std::fs::write(
+            dir.join(CONFIG_FILE_NAME),
+            "db_port = 5434\ndb_host = \"db.example.com\"\n",
+        )
+        .unwrap();
+
+        dir
+    }
+
+    fn layers(config_dir: Utf8PathBuf) -> Layers {
+        Layers::new(Some(config_dir), None, HashSet::from(["port".to_owned()])).unwrap()
+    }
+
+    #[rstest]
+    fn settings_are_layered(config_dir: Utf8PathBuf) {
+        let mut layers = layers(config_dir.clone());
+
+        assert_eq!(layers.required("port", Some(8000u16)).unwrap(), 8000);
+        assert_eq!(layers.required::<u16>("db_port", None).unwrap(), 5433);
+        assert_eq!(
+            layers.required::<String>("db_host", None).unwrap(),
+            "db.example.com"
+        );
+        assert_eq!(layers.optional::<String>("db_name", None).unwrap(), None);
+
+        let report: Vec<_> = layers
+            .effective_settings
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            report,
+            [
+                "port = \"8000\"  # from the command-line".to_owned(),
+                format!("db_port = \"5433\"  # from file {config_dir}/db_port"),
+                format!("db_host = \"db.example.com\"  # from {config_dir}/{CONFIG_FILE_NAME}"),
+                "# db_name is not set".to_owned(),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn secrets_are_redacted(config_dir: Utf8PathBuf) {
+        let mut layers = layers(config_dir);
+        layers
+            .required("db_root_password", Some("hunter2".to_owned()))
+            .unwrap();
+
+        assert_eq!(layers.effective_settings[0].value, None);
+    }
+
+    #[rstest]
+    fn missing_setting_is_named(config_dir: Utf8PathBuf) {
+        let mut layers = layers(config_dir);
+        let err = layers.required::<String>("db_name", None).unwrap_err();
+
+        assert!(
+            err.to_string()
+                .starts_with("`db_name` is not set: pass --db-name, or set CELLNOOR_DB_NAME")
+        );
+    }
+
+    #[rstest]
+    fn unknown_setting_in_config_file_is_rejected(config_dir: Utf8PathBuf) {
+        std::fs::write(config_dir.join(CONFIG_FILE_NAME), "db_prot = 5432\n").unwrap();
+        let err = Layers::new(Some(config_dir), None, HashSet::new())
+            .err()
+            .unwrap();
+
+        assert!(err.to_string().starts_with("unknown setting `db_prot`"));
+    }
+}
@@ -1,8 +1,21 @@
 mod boxed_filter;
 mod error;
+mod export;
+mod list;
 mod operation;
+mod total;
 pub mod utils;
 
 pub use boxed_filter::{BoxedFilter, BoxedFilterExt, ToBoxedFilter};
 pub use error::Error;
+pub use export::{Export, for_each_row};
+pub use list::{List, ListStatement, Rows};
 pub use operation::Operation;
+pub use total::total;
+
+/// A span for running `Op` inside `interact`. Spans don't follow work onto the
+/// blocking thread, so this should be created before calling `interact` and
+/// entered inside the closure.
+pub(crate) fn operation_span<Op>() -> tracing::Span {
+    tracing::info_span!("db_operation", operation = std::any::type_name::<Op>())
+}
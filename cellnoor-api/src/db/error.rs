@@ -3,6 +3,8 @@ use uuid::Uuid;
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "DatabaseError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "DatabaseError")]
 #[serde(rename_all = "snake_case", tag = "type", content = "info")]
 pub enum Error {
     #[error("failed to find {resource} with ID {resource_id}")]
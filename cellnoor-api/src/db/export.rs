@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+use diesel::{Connection, PgConnection, RunQueryDsl, pg::PgRowByRowLoadingMode};
+
+use super::ListStatement;
+
+/// Like [`super::Operation`], but rows are handed to `on_row` one at a time as
+/// they come out of Postgres. `on_row` returns `false` to stop early
+pub trait Export: Sized {
+    type Row;
+
+    fn export(
+        self,
+        db_conn: &mut PgConnection,
+        on_row: &mut dyn FnMut(Self::Row) -> bool,
+    ) -> Result<(), super::Error>;
+
+    fn export_as_user<UserId>(
+        self,
+        user_id: UserId,
+        db_conn: &mut PgConnection,
+        on_row: &mut dyn FnMut(Self::Row) -> bool,
+    ) -> Result<(), super::Error>
+    where
+        UserId: Display,
+    {
+        db_conn.transaction(|tx| {
+            diesel::sql_query(format!(r#"set local role "{user_id}""#)).execute(tx)?;
+
+            // An export is one statement that runs for as long as it takes to
+            // stream every row, so the connection's `statement_timeout` would
+            // cut large ones short. It stops early when the client goes away
+            // instead.
+            diesel::sql_query("set local statement_timeout = 0").execute(tx)?;
+
+            self.export(tx, on_row)
+        })
+    }
+}
+
+pub fn for_each_row<'a, T>(
+    stmt: impl ListStatement<'a, T>,
+    db_conn: &mut PgConnection,
+    on_row: &mut dyn FnMut(T) -> bool,
+) -> Result<(), super::Error>
+where
+    T: 'static,
+{
+    for row in stmt.load_iter::<T, PgRowByRowLoadingMode>(db_conn)? {
+        if !on_row(row?) {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use deadpool_diesel::postgres::Connection;
+    use diesel::{Connection as _, PgConnection, dsl::sql, prelude::*, sql_types::Text};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::Export;
+    use crate::{
+        db,
+        test_state::{Database, database, root_db_conn},
+    };
+
+    struct StatementTimeout;
+
+    impl Export for StatementTimeout {
+        type Row = String;
+
+        fn export(
+            self,
+            db_conn: &mut PgConnection,
+            on_row: &mut dyn FnMut(Self::Row) -> bool,
+        ) -> Result<(), db::Error> {
+            let statement_timeout =
+                diesel::select(sql::<Text>("current_setting('statement_timeout')"))
+                    .get_result(db_conn)?;
+            on_row(statement_timeout);
+
+            Ok(())
+        }
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn exports_are_not_bounded_by_statement_timeout(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let user_id = database.people[0].id();
+
+        let statement_timeout = root_db_conn
+            .interact(move |db_conn| {
+                db_conn.test_transaction::<_, db::Error, _>(|tx| {
+                    diesel::sql_query("set local statement_timeout = 1000").execute(tx)?;
+
+                    let mut rows = Vec::new();
+                    StatementTimeout.export_as_user(user_id, tx, &mut |row| {
+                        rows.push(row);
+                        true
+                    })?;
+
+                    Ok(rows)
+                })
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(statement_timeout, ["0"]);
+    }
+}
@@ -0,0 +1,112 @@
+use cellnoor_models::generic_query::{Page, Query, TotalMode};
+use diesel::{
+    PgConnection, RunQueryDsl,
+    pg::{Pg, PgRowByRowLoadingMode},
+    query_builder::{QueryFragment, QueryId},
+    query_dsl::LoadQuery,
+};
+use uuid::Uuid;
+
+use super::{Export, Operation, for_each_row};
+
+/// Which of the rows matching a list query its statement returns
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rows {
+    /// The page selected by the query's `limit`, `offset` and `cursor`
+    Page,
+    /// Every matching row, for exports
+    All,
+    /// Every matching row in no particular order, for counting
+    Unordered,
+}
+
+/// A statement that can either be loaded all at once, streamed row by row or
+/// counted
+pub trait ListStatement<'a, T>:
+    LoadQuery<'a, PgConnection, T>
+    + LoadQuery<'a, PgConnection, T, PgRowByRowLoadingMode>
+    + QueryFragment<Pg>
+    + QueryId
+    + 'a
+{
+}
+
+impl<'a, T, Q> ListStatement<'a, T> for Q where
+    Q: LoadQuery<'a, PgConnection, T>
+        + LoadQuery<'a, PgConnection, T, PgRowByRowLoadingMode>
+        + QueryFragment<Pg>
+        + QueryId
+        + 'a
+{
+}
+
+/// The parts of a list request that control how its [`Page`] is built
+pub trait Paging {
+    fn limit(&self) -> i64;
+    fn total_mode(&self) -> TotalMode;
+}
+
+impl<F, O, I> Paging for Query<F, O, I>
+where
+    O: Default,
+{
+    fn limit(&self) -> i64 {
+        self.limit
+    }
+
+    fn total_mode(&self) -> TotalMode {
+        self.total
+    }
+}
+
+// Nested list routes pair the parent ID with the query
+impl<P, Q> Paging for (P, Q)
+where
+    Q: Paging,
+{
+    fn limit(&self) -> i64 {
+        self.1.limit()
+    }
+
+    fn total_mode(&self) -> TotalMode {
+        self.1.total_mode()
+    }
+}
+
+/// A list request whose rows all come from one statement. Loading them, paging
+/// through them and exporting them are implemented in terms of
+/// [`List::list_stmt`]
+pub trait List: Paging + Sized {
+    type Row: 'static;
+
+    fn list_stmt(&self, rows: Rows) -> impl ListStatement<'_, Self::Row>;
+
+    fn id(row: &Self::Row) -> Uuid;
+}
+
+impl<L: List> Operation<Vec<L::Row>> for L {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<Vec<L::Row>, super::Error> {
+        Ok(self.list_stmt(Rows::Page).load(db_conn)?)
+    }
+}
+
+impl<L: List> Operation<Page<L::Row>> for L {
+    fn execute(self, db_conn: &mut PgConnection) -> Result<Page<L::Row>, super::Error> {
+        let total = super::total(self.list_stmt(Rows::Unordered), self.total_mode(), db_conn)?;
+        let items = self.list_stmt(Rows::Page).load(db_conn)?;
+
+        Ok(Page::new(items, total, self.limit(), L::id))
+    }
+}
+
+impl<L: List> Export for L {
+    type Row = L::Row;
+
+    fn export(
+        self,
+        db_conn: &mut PgConnection,
+        on_row: &mut dyn FnMut(L::Row) -> bool,
+    ) -> Result<(), super::Error> {
+        for_each_row(self.list_stmt(Rows::All), db_conn, on_row)
+    }
+}
@@ -0,0 +1,73 @@
+use cellnoor_models::generic_query::TotalMode;
+use diesel::{
+    pg::Pg,
+    prelude::*,
+    query_builder::{AstPass, Query, QueryFragment, QueryId},
+    sql_types::{BigInt, Json},
+};
+
+#[derive(Debug, Clone, Copy, QueryId)]
+struct Count<Q>(Q);
+
+impl<Q> Query for Count<Q> {
+    type SqlType = BigInt;
+}
+
+impl<Q> RunQueryDsl<PgConnection> for Count<Q> {}
+
+impl<Q: QueryFragment<Pg>> QueryFragment<Pg> for Count<Q> {
+    fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        pass.push_sql("select count(*) from (");
+        self.0.walk_ast(pass.reborrow())?;
+        pass.push_sql(") as counted");
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, QueryId)]
+struct Explain<Q>(Q);
+
+impl<Q> Query for Explain<Q> {
+    type SqlType = Json;
+}
+
+impl<Q> RunQueryDsl<PgConnection> for Explain<Q> {}
+
+impl<Q: QueryFragment<Pg>> QueryFragment<Pg> for Explain<Q> {
+    fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        pass.push_sql("explain (format json) ");
+        self.0.walk_ast(pass.reborrow())
+    }
+}
+
+/// Count the number of rows `stmt` would return. `stmt` should not have a limit
+/// or offset
+pub fn total<Q>(
+    stmt: Q,
+    mode: TotalMode,
+    db_conn: &mut PgConnection,
+) -> Result<Option<i64>, super::Error>
+where
+    Q: QueryFragment<Pg> + QueryId,
+{
+    let total = match mode {
+        TotalMode::Exact => Count(stmt).get_result(db_conn)?,
+        TotalMode::Estimated => {
+            let plan: serde_json::Value = Explain(stmt).get_result(db_conn)?;
+            let estimate = plan[0]["Plan"]["Plan Rows"]
+                .as_f64()
+                .ok_or(super::Error::Other {
+                    message: "failed to read row estimate from query plan".to_owned(),
+                })?;
+
+            #[allow(clippy::cast_possible_truncation)]
+            let estimate = estimate as i64;
+
+            estimate
+        }
+        TotalMode::Skip => return Ok(None),
+    };
+
+    Ok(Some(total))
+}
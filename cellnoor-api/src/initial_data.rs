@@ -10,7 +10,7 @@ use url::Url;
 
 use crate::{
     initial_data::index_sets::{
-        download_and_insert_dual_index_sets, download_and_insert_single_index_sets,
+        load_and_insert_dual_index_sets, load_and_insert_single_index_sets,
     },
     validate::Validate,
 };
@@ -87,9 +87,8 @@ pub async fn insert_initial_data(
         Ok(())
     };
 
-    download_and_insert_single_index_sets(single_index_set_urls, http_client.clone(), &db_conn)
-        .await?;
-    download_and_insert_dual_index_sets(dual_index_set_urls, http_client, &db_conn).await?;
+    load_and_insert_single_index_sets(single_index_set_urls, http_client.clone(), &db_conn).await?;
+    load_and_insert_dual_index_sets(dual_index_set_urls, http_client, &db_conn).await?;
 
     db_conn.interact(simple_operations).await.unwrap()?;
 
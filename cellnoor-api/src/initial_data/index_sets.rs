@@ -1,7 +1,11 @@
 use std::collections::HashMap;
 
+use anyhow::{Context, anyhow, bail};
+use cellnoor_schema::index_set_files;
 pub(crate) use common::IndexSetName;
+use diesel::{PgConnection, prelude::*};
 use serde::de::DeserializeOwned;
+use sha2::{Digest, Sha256};
 use tokio::task::JoinSet;
 use url::Url;
 
@@ -14,24 +18,28 @@ mod common;
 mod dual;
 mod single;
 
-pub(super) async fn download_and_insert_dual_index_sets(
+mod bundle {
+    include!(concat!(env!("OUT_DIR"), "/index_set_bundle.rs"));
+}
+
+pub(super) async fn load_and_insert_dual_index_sets(
     file_urls: Vec<Url>,
     http_client: reqwest::Client,
     db_conn: &deadpool_diesel::postgres::Connection,
 ) -> anyhow::Result<()> {
-    download_and_insert_index_sets::<HashMap<String, DualIndexSet>>(file_urls, http_client, db_conn)
+    load_and_insert_index_sets::<HashMap<String, DualIndexSet>>(file_urls, http_client, db_conn)
         .await
 }
 
-pub(super) async fn download_and_insert_single_index_sets(
+pub(super) async fn load_and_insert_single_index_sets(
     file_urls: Vec<Url>,
     http_client: reqwest::Client,
     db_conn: &deadpool_diesel::postgres::Connection,
 ) -> anyhow::Result<()> {
-    download_and_insert_index_sets::<Vec<SingleIndexSet>>(file_urls, http_client, db_conn).await
+    load_and_insert_index_sets::<Vec<SingleIndexSet>>(file_urls, http_client, db_conn).await
 }
 
-async fn download_and_insert_index_sets<T>(
+async fn load_and_insert_index_sets<T>(
     file_urls: Vec<Url>,
     http_client: reqwest::Client,
     db_conn: &deadpool_diesel::postgres::Connection,
@@ -39,26 +47,41 @@ async fn download_and_insert_index_sets<T>(
 where
     T: 'static + DeserializeOwned + Send + Upsert,
 {
-    // let downloads = JoinSet::new();
-    // for url in file_urls {
-    //     downloads.spawn(download_json::<T>(http_client.clone(), url));
-    // }
-    let downloads: JoinSet<_> = file_urls
+    let fetches: JoinSet<_> = file_urls
         .into_iter()
-        .map(|url| download_json::<T>(http_client.clone(), url))
+        .map(|url| {
+            let http_client = http_client.clone();
+            async move {
+                let contents = fetch(&url, &http_client).await;
+                (url, contents)
+            }
+        })
         .collect();
 
-    let index_sets = downloads
-        .join_all()
-        .await
-        .into_iter()
-        .collect::<anyhow::Result<Vec<_>>>()?;
-
     // A for-loop is fine because this is like 10 URLs max, and each of these is a
     // bulk insert
-    for sets in index_sets {
+    for (url, contents) in fetches.join_all().await {
+        let contents = match contents {
+            Ok(contents) => contents,
+            Err(err) => {
+                let url_str = url.to_string();
+                let previously_loaded = db_conn
+                    .interact(move |db_conn| stored_checksum(&url_str, db_conn))
+                    .await
+                    .unwrap()?
+                    .is_some();
+
+                if !previously_loaded {
+                    return Err(err);
+                }
+
+                tracing::warn!("{err:#}, so the index sets already in the database will be used");
+                continue;
+            }
+        };
+
         db_conn
-            .interact(|db_conn| sets.upsert(db_conn))
+            .interact(move |db_conn| insert_if_changed::<T>(&url, &contents, db_conn))
             .await
             .unwrap()?;
     }
@@ -66,9 +89,201 @@ where
     Ok(())
 }
 
-async fn download_json<T: DeserializeOwned>(
-    http_client: reqwest::Client,
-    url: Url,
-) -> anyhow::Result<T> {
-    Ok(http_client.get(url).send().await?.json().await?)
+/// `bundle:` URLs, such as `bundle:2022-06/Dual_Index_Kit_TT_Set_A.json`, refer
+/// to the files in `cellnoor-api/index-sets` that are compiled into the binary
+async fn fetch(url: &Url, http_client: &reqwest::Client) -> anyhow::Result<Vec<u8>> {
+    match url.scheme() {
+        "http" | "https" => async {
+            let response = http_client.get(url.clone()).send().await?;
+            anyhow::Ok(response.error_for_status()?.bytes().await?.to_vec())
+        }
+        .await
+        .context(format!("failed to download {url}")),
+        "file" => {
+            let path = url
+                .to_file_path()
+                .map_err(|()| anyhow!("{url} is not a valid file path"))?;
+            std::fs::read(&path).context(format!("failed to read {}", path.display()))
+        }
+        "bundle" => bundle::BUNDLE
+            .iter()
+            .find(|(name, _)| *name == url.path())
+            .map(|(_, contents)| contents.to_vec())
+            .ok_or_else(|| anyhow!("{url} is not in the bundle of index sets")),
+        scheme => bail!("unsupported scheme '{scheme}' in {url}"),
+    }
+}
+
+fn stored_checksum(url: &str, db_conn: &mut PgConnection) -> anyhow::Result<Option<String>> {
+    Ok(index_set_files::table
+        .find(url)
+        .select(index_set_files::sha256)
+        .first(db_conn)
+        .optional()?)
+}
+
+fn insert_if_changed<T>(
+    url: &Url,
+    contents: &[u8],
+    db_conn: &mut PgConnection,
+) -> anyhow::Result<()>
+where
+    T: DeserializeOwned + Upsert,
+{
+    let url = url.as_str();
+    let checksum = base16ct::lower::encode_string(&Sha256::digest(contents));
+
+    if stored_checksum(url, db_conn)?.as_ref() == Some(&checksum) {
+        tracing::debug!("index sets from {url} are unchanged");
+        return Ok(());
+    }
+
+    let index_sets: T = serde_json::from_slice(contents)
+        .context(format!("failed to parse index sets from {url}"))?;
+
+    db_conn.transaction(|db_conn| {
+        index_sets.upsert(db_conn)?;
+
+        diesel::insert_into(index_set_files::table)
+            .values((
+                index_set_files::url.eq(url),
+                index_set_files::sha256.eq(&checksum),
+            ))
+            .on_conflict(index_set_files::url)
+            .do_update()
+            .set((
+                index_set_files::sha256.eq(&checksum),
+                index_set_files::loaded_at.eq(diesel::dsl::now),
+            ))
+            .execute(db_conn)?;
+
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use cellnoor_schema::index_set_files;
+    use deadpool_diesel::postgres::Connection;
+    use diesel::prelude::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use url::Url;
+    use uuid::Uuid;
+
+    use super::{
+        bundle::BUNDLE, fetch, load_and_insert_dual_index_sets, load_and_insert_single_index_sets,
+    };
+    use crate::test_state::root_db_conn;
+
+    fn temp_file() -> (PathBuf, Url) {
+        let path = std::env::temp_dir().join(format!("{}.json", Uuid::now_v7()));
+        let url = Url::from_file_path(&path).unwrap();
+
+        (path, url)
+    }
+
+    async fn load(url: &Url, db_conn: &Connection) -> anyhow::Result<()> {
+        load_and_insert_single_index_sets(vec![url.clone()], reqwest::Client::new(), db_conn).await
+    }
+
+    async fn loaded(url: &Url, db_conn: &Connection) -> Option<(String, jiff::Timestamp)> {
+        let url = url.to_string();
+
+        db_conn
+            .interact(move |db_conn| {
+                index_set_files::table
+                    .find(url)
+                    .select((index_set_files::sha256, index_set_files::loaded_at))
+                    .first::<(String, jiff_diesel::Timestamp)>(db_conn)
+                    .optional()
+                    .unwrap()
+                    .map(|(checksum, loaded_at)| (checksum, loaded_at.to_jiff()))
+            })
+            .await
+            .unwrap()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn fetch_local_file() {
+        let (path, url) = temp_file();
+        std::fs::write(&path, "[]").unwrap();
+
+        let contents = fetch(&url, &reqwest::Client::new()).await.unwrap();
+
+        assert_eq!(contents, b"[]");
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn fetch_missing_bundle_file() {
+        let url = Url::parse("bundle:0000-00/missing.json").unwrap();
+
+        assert!(fetch(&url, &reqwest::Client::new()).await.is_err());
+    }
+
+    // A vendored file that doesn't parse would otherwise only be noticed when a
+    // deployment without internet access starts up
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn bundled_files_load(#[future] root_db_conn: Connection) {
+        for (name, _) in BUNDLE {
+            let url = Url::parse(&format!("bundle:{name}")).unwrap();
+            let file_urls = vec![url.clone()];
+            let http_client = reqwest::Client::new();
+
+            if name.contains("/Single_") {
+                load_and_insert_single_index_sets(file_urls, http_client, &root_db_conn).await
+            } else {
+                load_and_insert_dual_index_sets(file_urls, http_client, &root_db_conn).await
+            }
+            .unwrap();
+
+            assert!(loaded(&url, &root_db_conn).await.is_some(), "{url}");
+        }
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn only_changed_files_are_reloaded(#[future] root_db_conn: Connection) {
+        let (path, url) = temp_file();
+
+        std::fs::write(&path, "[]").unwrap();
+        load(&url, &root_db_conn).await.unwrap();
+        let first = loaded(&url, &root_db_conn).await.unwrap();
+
+        load(&url, &root_db_conn).await.unwrap();
+        assert_eq!(loaded(&url, &root_db_conn).await.unwrap(), first);
+
+        std::fs::write(&path, "[ ]").unwrap();
+        load(&url, &root_db_conn).await.unwrap();
+        let (checksum, loaded_at) = loaded(&url, &root_db_conn).await.unwrap();
+
+        assert_ne!(checksum, first.0);
+        assert!(loaded_at > first.1);
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn unreachable_files_fall_back_to_last_loaded(#[future] root_db_conn: Connection) {
+        let (path, url) = temp_file();
+
+        std::fs::write(&path, "[]").unwrap();
+        load(&url, &root_db_conn).await.unwrap();
+        let before = loaded(&url, &root_db_conn).await.unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+        load(&url, &root_db_conn).await.unwrap();
+        assert_eq!(loaded(&url, &root_db_conn).await.unwrap(), before);
+
+        let (_, never_loaded) = temp_file();
+        assert!(load(&never_loaded, &root_db_conn).await.is_err());
+        assert_eq!(loaded(&never_loaded, &root_db_conn).await, None);
+    }
 }
@@ -1,12 +1,20 @@
 #![allow(uncommon_codepoints)]
 
 pub mod api;
+mod change_feed;
+pub mod cli;
 pub mod config;
 mod db;
 mod initial_data;
+mod metrics;
+mod setup;
 mod state;
+mod telemetry;
+#[cfg(test)]
+mod test_app;
 #[cfg(any(feature = "dummy-data", test))]
 mod test_state;
 #[cfg(test)]
 mod test_util;
 mod validate;
+mod webhooks;
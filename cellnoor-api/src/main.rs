@@ -1,14 +1,9 @@
-use anyhow::Context;
-use cellnoor_api::{api, config::Config};
+use cellnoor_api::cli::Cli;
+use clap::Parser;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     dotenvy::dotenv().unwrap_or_default();
 
-    api::serve(Config::read().context(
-        "failed to read configuration from command-line, environment, and configuration directory",
-    )?)
-    .await?;
-
-    Ok(())
+    Cli::parse().run().await
 }
@@ -0,0 +1,147 @@
+use std::time::{Duration, Instant};
+
+use axum::{
+    Extension,
+    extract::{MatchedPath, Request, State},
+    middleware::Next,
+    response::Response,
+};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+
+use crate::{state::AppState, validate};
+
+const HTTP_REQUESTS: &str = "cellnoor_http_requests_total";
+const HTTP_REQUEST_DURATION: &str = "cellnoor_http_request_duration_seconds";
+const DB_POOL_WAIT: &str = "cellnoor_db_pool_wait_seconds";
+const DB_INTERACT_WAIT: &str = "cellnoor_db_interact_wait_seconds";
+const DB_POOL_CONNECTIONS: &str = "cellnoor_db_pool_connections";
+const DB_POOL_WAITING: &str = "cellnoor_db_pool_waiting";
+const VALIDATION_FAILURES: &str = "cellnoor_validation_failures_total";
+
+const DURATION_BUCKETS: [f64; 12] = [
+    0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0,
+];
+const UPKEEP_INTERVAL: Duration = Duration::from_secs(5);
+
+pub(crate) fn install_recorder() -> anyhow::Result<PrometheusHandle> {
+    let handle = PrometheusBuilder::new()
+        .set_buckets_for_metric(Matcher::Suffix("_seconds".to_owned()), &DURATION_BUCKETS)?
+        .install_recorder()?;
+
+    // Without upkeep, histogram samples accumulate between scrapes
+    let upkeep_handle = handle.clone();
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(UPKEEP_INTERVAL).await;
+            upkeep_handle.run_upkeep();
+        }
+    });
+
+    Ok(handle)
+}
+
+// The pool's status is only interesting at the moment of a scrape, so it's
+// recorded here rather than every time a connection is checked out
+pub(crate) async fn render(
+    State(state): State<AppState>,
+    Extension(handle): Extension<PrometheusHandle>,
+) -> String {
+    let status = state.db_pool_status();
+
+    let in_use = status.size - status.available;
+    for (label, n_connections) in [
+        ("in_use", in_use),
+        ("available", status.available),
+        ("max", status.max_size),
+    ] {
+        metrics::gauge!(DB_POOL_CONNECTIONS, "state" => label).set(n_connections as f64);
+    }
+    metrics::gauge!(DB_POOL_WAITING).set(status.waiting as f64);
+
+    handle.render()
+}
+
+/// Count and time every request that matched a route, labelled by the route's
+/// path template rather than the actual path so that IDs don't end up in
+/// labels
+pub(crate) async fn track_requests(request: Request, next: Next) -> Response {
+    let start = Instant::now();
+
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_owned())
+        .unwrap_or_default();
+
+    let response = next.run(request).await;
+
+    let labels = [
+        ("method", method),
+        ("route", route),
+        ("status", response.status().as_u16().to_string()),
+    ];
+    metrics::counter!(HTTP_REQUESTS, &labels).increment(1);
+    metrics::histogram!(HTTP_REQUEST_DURATION, &labels).record(start.elapsed());
+
+    response
+}
+
+pub(crate) fn record_db_pool_wait(wait: Duration) {
+    metrics::histogram!(DB_POOL_WAIT).record(wait);
+}
+
+/// How long a closure passed to `interact` waited for a blocking thread
+pub(crate) fn record_interact_wait(queued_at: Instant) {
+    metrics::histogram!(DB_INTERACT_WAIT).record(queued_at.elapsed());
+}
+
+/// Database errors raised while validating are the server's fault rather than
+/// the request's, so they aren't counted
+pub(crate) fn record_validation_failure(err: &validate::Error) {
+    if let validate::Error::Database(_) = err {
+        return;
+    }
+
+    let kind: &'static str = err.into();
+    metrics::counter!(VALIDATION_FAILURES, "kind" => kind).increment(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Router, body::Body, extract::Request, middleware, routing::get};
+    use metrics_exporter_prometheus::PrometheusBuilder;
+    use rstest::rstest;
+    use tower::ServiceExt;
+
+    use super::{HTTP_REQUESTS, track_requests};
+
+    #[rstest]
+    #[tokio::test]
+    async fn requests_are_labelled_by_route_template() {
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        let router = Router::new()
+            .route("/specimens/{id}", get(async || "OK"))
+            .route_layer(middleware::from_fn(track_requests));
+        let request = Request::builder()
+            .uri("/specimens/00000000-0000-0000-0000-000000000001")
+            .body(Body::empty())
+            .unwrap();
+        router.oneshot(request).await.unwrap();
+
+        let rendered = handle.render();
+        let requests = rendered
+            .lines()
+            .find(|line| line.starts_with(HTTP_REQUESTS))
+            .unwrap();
+
+        assert!(
+            requests.contains(r#"route="/specimens/{id}""#),
+            "{requests}"
+        );
+        assert!(!rendered.contains("00000000-0000-0000-0000-000000000001"));
+    }
+}
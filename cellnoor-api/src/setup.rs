@@ -0,0 +1,75 @@
+use anyhow::{Context, anyhow};
+use diesel::{PgConnection, prelude::*};
+use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
+
+use crate::{
+    config::Config,
+    initial_data::insert_initial_data,
+    state::{DbPoolSettings, create_db_pool},
+};
+
+pub(crate) const MIGRATIONS: EmbeddedMigrations =
+    embed_migrations!("../crates/cellnoor-schema/migrations");
+
+/// Run migrations, set the passwords of the database users and insert the
+/// initial data, in that order
+pub(crate) async fn run_all(config: &Config) -> anyhow::Result<()> {
+    let mut root_db_conn = root_db_conn(config)?;
+
+    run_migrations(&mut root_db_conn)?;
+    set_db_user_passwords(config, &mut root_db_conn)?;
+    seed(config).await
+}
+
+pub(crate) fn root_db_conn(config: &Config) -> anyhow::Result<PgConnection> {
+    PgConnection::establish(&config.db_root_url()).context("failed to connect to db as root")
+}
+
+pub(crate) fn run_migrations(db_conn: &mut PgConnection) -> anyhow::Result<()> {
+    let versions = db_conn
+        .run_pending_migrations(MIGRATIONS)
+        .map_err(|e| anyhow!(e))?;
+
+    tracing::info!("ran {} database migrations", versions.len());
+
+    Ok(())
+}
+
+pub(crate) fn set_db_user_passwords(
+    config: &Config,
+    db_conn: &mut PgConnection,
+) -> anyhow::Result<()> {
+    let db_users = [
+        ("cellnoor_api", config.cellnoor_api_db_password()),
+        ("cellnoor_ui", config.cellnoor_ui_db_password()),
+    ];
+
+    for (username, password) in db_users {
+        diesel::sql_query(format!(
+            r#"alter user "{username}" with password '{password}'"#
+        ))
+        .execute(db_conn)?;
+        tracing::info!("set password for database user '{username}'");
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn seed(config: &Config) -> anyhow::Result<()> {
+    // Get a connection pool as the root user so as to insert the initial data. We
+    // only need one connection here
+    let root_db_pool = create_db_pool(
+        &config.db_root_url(),
+        DbPoolSettings {
+            max_size: Some(1),
+            ..Default::default()
+        },
+    )?;
+
+    insert_initial_data(config.initial_data(), reqwest::Client::new(), root_db_pool)
+        .await
+        .context("failed to insert initial data")?;
+    tracing::info!("inserted initial data");
+
+    Ok(())
+}
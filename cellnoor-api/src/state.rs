@@ -1,16 +1,16 @@
-use anyhow::{Context, anyhow};
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
 use deadpool_diesel::{
     Runtime,
-    postgres::{Manager as PoolManager, Pool},
+    postgres::{Hook, HookError, Manager as PoolManager, Pool},
 };
 use diesel::{PgConnection, prelude::*};
-use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
 use uuid::Uuid;
 
 use crate::{
     config::{AppMode, Config},
-    db,
-    initial_data::insert_initial_data,
+    db, metrics,
 };
 
 #[derive(Clone)]
@@ -25,22 +25,79 @@ pub enum AppState {
     },
 }
 
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct DbPoolSettings {
+    pub max_size: Option<usize>,
+    /// How long to wait for a connection, which is forever if `None`
+    pub timeout: Option<Duration>,
+    pub statement_timeout: Option<Duration>,
+}
+
+impl DbPoolSettings {
+    fn from_config(config: &Config) -> Self {
+        Self {
+            max_size: config.db_pool_size(),
+            timeout: Some(config.db_pool_timeout()),
+            statement_timeout: Some(config.db_statement_timeout()),
+        }
+    }
+}
+
 #[cfg(any(feature = "dummy-data", test))]
 pub fn create_test_db_pool(db_url: &str) -> anyhow::Result<Pool> {
-    create_db_pool(db_url, None)
+    create_db_pool(db_url, DbPoolSettings::default())
 }
 
-fn create_db_pool(db_url: &str, max_size: Option<usize>) -> anyhow::Result<Pool> {
+pub(crate) fn create_db_pool(db_url: &str, settings: DbPoolSettings) -> anyhow::Result<Pool> {
+    let DbPoolSettings {
+        max_size,
+        timeout,
+        statement_timeout,
+    } = settings;
+
     let manager = PoolManager::new(db_url, Runtime::Tokio1);
-    let mut builder = Pool::builder(manager);
+    let mut builder = Pool::builder(manager)
+        .runtime(Runtime::Tokio1)
+        .wait_timeout(timeout)
+        .create_timeout(timeout);
 
     if let Some(max_size) = max_size {
         builder = builder.max_size(max_size);
     }
 
+    // Setting this once per connection rather than per transaction means that it
+    // also bounds the queries run outside of `Operation::execute_as_user`, such as
+    // those of the readiness checks
+    if let Some(statement_timeout) = statement_timeout {
+        let statement = format!("set statement_timeout = {}", statement_timeout.as_millis());
+
+        builder = builder.post_create(Hook::async_fn(move |db_conn, _| {
+            let statement = statement.clone();
+            Box::pin(async move {
+                db_conn
+                    .interact(move |db_conn| diesel::sql_query(statement).execute(db_conn))
+                    .await
+                    .map_err(|e| HookError::message(e.to_string()))?
+                    .map_err(|e| {
+                        HookError::message(format!("failed to set statement timeout: {e}"))
+                    })?;
+
+                Ok(())
+            })
+        }));
+    }
+
     Ok(builder.build()?)
 }
 
+/// The URL of the database as the user that serves requests
+pub(crate) fn app_db_url(config: &Config) -> String {
+    match config.mode() {
+        AppMode::Development => config.db_root_url(),
+        AppMode::Production => config.cellnoor_api_db_url(),
+    }
+}
+
 fn create_dev_superuser(db_conn: &mut PgConnection) -> anyhow::Result<Uuid> {
     let user_id = Uuid::now_v7();
 
@@ -51,62 +108,9 @@ fn create_dev_superuser(db_conn: &mut PgConnection) -> anyhow::Result<Uuid> {
     Ok(user_id)
 }
 
-fn run_migrations(db_conn: &mut PgConnection) -> anyhow::Result<()> {
-    const MIGRATIONS: EmbeddedMigrations =
-        embed_migrations!("../crates/cellnoor-schema/migrations");
-
-    db_conn
-        .run_pending_migrations(MIGRATIONS)
-        .map_err(|e| anyhow!(e))?;
-
-    Ok(())
-}
-
-fn set_db_user_password(
-    username: &str,
-    password: &str,
-    db_conn: &mut PgConnection,
-) -> anyhow::Result<()> {
-    diesel::sql_query(format!(
-        r#"alter user "{username}" with password '{password}'"#
-    ))
-    .execute(db_conn)?;
-
-    Ok(())
-}
-
 impl AppState {
-    pub async fn initialize(config: &Config) -> anyhow::Result<Self> {
-        let mut root_db_conn = PgConnection::establish(&config.db_root_url())
-            .context("failed to connect to db as root to run migrations")?;
-
-        run_migrations(&mut root_db_conn)?;
-        tracing::info!("ran database migrations");
-
-        let db_users = [
-            ("cellnoor_api", config.cellnoor_api_db_password()),
-            ("cellnoor_ui", config.cellnoor_ui_db_password()),
-        ];
-        for (username, password) in db_users {
-            set_db_user_password(username, password, &mut root_db_conn)?;
-            tracing::info!("set password for database user '{username}'");
-        }
-
-        // Get a connection pool as the root user so as to insert the initial data. We
-        // only need one connection here
-        let root_db_pool = create_db_pool(&config.db_root_url(), Some(1))?;
-        let initial_data = config.initial_data();
-        insert_initial_data(initial_data, reqwest::Client::new(), root_db_pool.clone())
-            .await
-            .context("failed to insert initial data")?;
-        tracing::info!("inserted initial data");
-
-        let db_url = match config.mode() {
-            AppMode::Development => config.db_root_url(),
-            AppMode::Production => config.cellnoor_api_db_url(),
-        };
-
-        let db_pool = create_db_pool(&db_url, None)?;
+    pub fn initialize(config: &Config) -> anyhow::Result<Self> {
+        let db_pool = create_db_pool(&app_db_url(config), DbPoolSettings::from_config(config))?;
 
         let state = match config.mode() {
             AppMode::Development => {
@@ -123,6 +127,33 @@ impl AppState {
         Ok(state)
     }
 
+    // API keys aren't checked in development, so any prefix length will do
+    pub fn api_key_prefix_length(&self) -> usize {
+        match self {
+            Self::Development {
+                db_pool: _,
+                user_id: _,
+            } => 8,
+            Self::Production {
+                api_key_prefix_length,
+                db_pool: _,
+            } => *api_key_prefix_length,
+        }
+    }
+
+    pub fn db_pool_status(&self) -> deadpool_diesel::Status {
+        match self {
+            Self::Development {
+                db_pool,
+                user_id: _,
+            }
+            | Self::Production {
+                db_pool,
+                api_key_prefix_length: _,
+            } => db_pool.status(),
+        }
+    }
+
     pub async fn db_conn(&self) -> Result<deadpool_diesel::postgres::Connection, db::Error> {
         match self {
             Self::Development {
@@ -132,7 +163,13 @@ impl AppState {
             | Self::Production {
                 db_pool,
                 api_key_prefix_length: _,
-            } => Ok(db_pool.get().await?),
+            } => {
+                let start = Instant::now();
+                let db_conn = db_pool.get().await?;
+                metrics::record_db_pool_wait(start.elapsed());
+
+                Ok(db_conn)
+            }
         }
     }
 }
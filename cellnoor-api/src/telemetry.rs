@@ -0,0 +1,137 @@
+use anyhow::Context;
+use diesel::connection::{InstrumentationEvent, set_default_instrumentation};
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{Resource, trace::SdkTracerProvider};
+use tracing::Level;
+use tracing_subscriber::{Layer, Registry, filter::Targets, prelude::*};
+
+use crate::config::Config;
+
+type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync>;
+
+/// Flushes exported spans when dropped, so it should live until the process
+/// exits
+pub(crate) struct TelemetryGuard(Option<SdkTracerProvider>);
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        if let Some(provider) = self.0.take()
+            && let Err(err) = provider.shutdown()
+        {
+            eprintln!("failed to flush traces: {err}");
+        }
+    }
+}
+
+pub(crate) fn initialize(config: &Config) -> anyhow::Result<TelemetryGuard> {
+    let log_layer = tracing_subscriber::fmt::layer();
+
+    let log_layer: BoxedLayer = match config.log_dir() {
+        None => {
+            let dev_test_log_filter = Targets::new().with_target("cellnoor", Level::DEBUG);
+            log_layer.pretty().with_filter(dev_test_log_filter).boxed()
+        }
+        Some(path) => {
+            let log_writer = tracing_appender::rolling::daily(path, "cellnoor.log");
+            let prod_log_filter = Targets::new().with_target("cellnoor", Level::INFO);
+            log_layer
+                .json()
+                .with_writer(log_writer)
+                .with_filter(prod_log_filter)
+                .boxed()
+        }
+    };
+
+    let mut layers = vec![log_layer];
+
+    let tracer_provider = match config.otlp_endpoint() {
+        Some(endpoint) => {
+            let exporter = SpanExporter::builder()
+                .with_http()
+                .with_endpoint(endpoint.as_str())
+                .build()
+                .context(format!("failed to build OTLP exporter for {endpoint}"))?;
+
+            let provider = SdkTracerProvider::builder()
+                .with_batch_exporter(exporter)
+                .with_resource(
+                    Resource::builder()
+                        .with_service_name("cellnoor-api")
+                        .build(),
+                )
+                .build();
+
+            // Traces include the SQL of each query, which is too noisy for logs
+            let trace_filter = Targets::new().with_target("cellnoor", Level::DEBUG);
+            layers.push(
+                tracing_opentelemetry::layer()
+                    .with_tracer(provider.tracer("cellnoor-api"))
+                    .with_filter(trace_filter)
+                    .boxed(),
+            );
+
+            Some(provider)
+        }
+        None => None,
+    };
+
+    tracing_subscriber::registry().with(layers).init();
+
+    Ok(TelemetryGuard(tracer_provider))
+}
+
+/// Emit an event for every query run by connections established from now on,
+/// so that queries show up under the span of the request that ran them. This
+/// should be called after any query that embeds a secret, like setting a
+/// password, has been run.
+pub(crate) fn record_queries() {
+    let instrumentation =
+        || Some(Box::new(|event: InstrumentationEvent<'_>| log_query(&event)) as Box<_>);
+
+    if let Err(err) = set_default_instrumentation(instrumentation) {
+        tracing::warn!("failed to instrument database connections: {err}");
+    }
+}
+
+// Bind parameters hold the values being written, such as API key hashes and
+// webhook secrets, so only the SQL is recorded
+fn log_query(event: &InstrumentationEvent<'_>) {
+    if let InstrumentationEvent::FinishQuery { query, error, .. } = event {
+        let query = query.to_string();
+        let query = sql_text(&query);
+
+        match error {
+            None => tracing::debug!(query, "ran query"),
+            Some(err) => tracing::debug!(query, %err, "query failed"),
+        }
+    }
+}
+
+fn sql_text(query: &str) -> &str {
+    query
+        .split_once(" -- binds: ")
+        .map_or(query, |(sql, _)| sql)
+}
+
+#[cfg(test)]
+mod tests {
+    use cellnoor_schema::api_keys;
+    use diesel::{debug_query, pg::Pg, prelude::*};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::sql_text;
+
+    #[rstest]
+    fn binds_are_not_recorded() {
+        let query = diesel::insert_into(api_keys::table).values(api_keys::hash.eq("secret"));
+        let query = debug_query::<Pg, _>(&query).to_string();
+
+        assert!(query.contains("secret"));
+        assert_eq!(
+            sql_text(&query),
+            r#"INSERT INTO "api_keys" ("hash") VALUES ($1)"#
+        );
+    }
+}
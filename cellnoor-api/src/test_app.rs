@@ -0,0 +1,129 @@
+use axum::{
+    Router,
+    body::{Body, to_bytes},
+    http::{Method, Request, StatusCode, header::CONTENT_TYPE},
+    response::Response,
+};
+use cellnoor_schema::{api_keys, people};
+use diesel::prelude::*;
+use rstest::fixture;
+use serde_json::Value;
+use tokio::sync::OnceCell;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::{
+    api::{self, API_KEY_HEADER},
+    change_feed::ChangeFeed,
+    config::Config,
+    setup,
+    state::{self, AppState},
+    test_state::root_db_conn,
+};
+
+static TEST_APP: OnceCell<TestApp> = OnceCell::const_new();
+
+#[fixture]
+pub async fn app() -> TestApp {
+    TEST_APP.get_or_init(TestApp::new).await.clone()
+}
+
+/// The app as it's served, for tests that go through routing and middleware.
+/// Requests are sent as the app admin.
+#[derive(Clone)]
+pub struct TestApp {
+    router: Router,
+    api_key: String,
+}
+
+impl TestApp {
+    async fn new() -> Self {
+        // Getting a connection waits for the database to be set up
+        drop(root_db_conn().await);
+
+        let config = Config::read()
+            .expect("test configuration should be readable from environment variables");
+
+        Self::with_config(&config)
+    }
+
+    /// An app of its own, for tests that need a configuration other than the
+    /// one in the environment
+    pub fn with_config(config: &Config) -> Self {
+        let app_state =
+            AppState::initialize(config).expect("should be able to initialize app state");
+        let change_feed = ChangeFeed::start(state::app_db_url(config))
+            .expect("should be able to start change feed");
+
+        Self {
+            router: api::app(app_state, change_feed, config),
+            api_key: issue_app_admin_api_key(config),
+        }
+    }
+
+    pub async fn send(&self, request: Request<Body>) -> Response {
+        self.send_with_api_key(request, &self.api_key).await
+    }
+
+    pub async fn send_with_api_key(&self, mut request: Request<Body>, api_key: &str) -> Response {
+        request
+            .headers_mut()
+            .insert(API_KEY_HEADER, api_key.parse().unwrap());
+
+        self.router.clone().oneshot(request).await.unwrap()
+    }
+
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    /// Send `body` as JSON, returning the status and the JSON response body,
+    /// which is `null` if there isn't one
+    pub async fn send_json(
+        &self,
+        method: Method,
+        uri: &str,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let request = Request::builder().method(method).uri(uri);
+        let request = match body {
+            Some(body) => request
+                .header(CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string())),
+            None => request.body(Body::empty()),
+        };
+
+        let response = self.send(request.unwrap()).await;
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = if body.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_slice(&body).unwrap()
+        };
+
+        (status, body)
+    }
+}
+
+fn issue_app_admin_api_key(config: &Config) -> String {
+    let mut db_conn = setup::root_db_conn(config).unwrap();
+
+    let app_admin_id: Uuid = people::table
+        .filter(people::email.eq(config.initial_data().app_admin().email()))
+        .select(people::id)
+        .first(&mut db_conn)
+        .unwrap();
+
+    let (api_key, hash) = api::generate_api_key();
+    diesel::insert_into(api_keys::table)
+        .values((
+            api_keys::prefix.eq(&api_key[..config.api_key_prefix_length()]),
+            api_keys::hash.eq(hash),
+            api_keys::user_id.eq(app_admin_id),
+        ))
+        .execute(&mut db_conn)
+        .unwrap();
+
+    base16ct::lower::encode_string(&api_key)
+}
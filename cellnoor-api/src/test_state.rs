@@ -29,7 +29,7 @@ use cellnoor_models::{
         SuspensionPool, SuspensionPoolCreation, SuspensionPoolFields, SuspensionPoolQuery,
         SuspensionTagging,
     },
-    tenx_assay::{LibraryType, SampleMultiplexing, TenxAssayFilter, TenxAssayQuery},
+    tenx_assay::{LibraryType, SampleMultiplexing, TenxAssay, TenxAssayFilter, TenxAssayQuery},
 };
 use deadpool_diesel::postgres::{Connection, Pool};
 use diesel::prelude::*;
@@ -52,6 +52,7 @@ use crate::{
     config::Config,
     db,
     db::Operation,
+    setup,
     state::{AppState, create_test_db_pool},
 };
 
@@ -80,10 +81,12 @@ impl TestState {
         let config = Config::read()
             .expect("test configuration should be readable from environment variables");
 
+        setup::run_all(&config)
+            .await
+            .expect("should be able to set up database");
+
         Self {
-            _inner: AppState::initialize(&config)
-                .await
-                .expect("should be able to initialize app state"),
+            _inner: AppState::initialize(&config).expect("should be able to initialize app state"),
             root_db_pool: create_test_db_pool(&config.db_root_url()).unwrap(),
         }
     }
@@ -386,7 +389,7 @@ impl TestState {
 
         let db_conn = self.root_db_conn().await;
 
-        let three_prime_gex_assay_id = db_conn
+        let three_prime_gex_assay_id: Vec<TenxAssay> = db_conn
             .interact(|db_conn| three_prime_gex_query.execute(db_conn).unwrap())
             .await
             .unwrap();
@@ -457,7 +460,7 @@ impl TestState {
 
         let db_conn = self.root_db_conn().await;
 
-        let ocm_assay_id = db_conn
+        let ocm_assay_id: Vec<TenxAssay> = db_conn
             .interact(|db_conn| ocm_gex_query.execute(db_conn).unwrap())
             .await
             .unwrap();
@@ -543,7 +546,7 @@ impl TestState {
 
         let db_conn = self.root_db_conn().await;
 
-        let flex_assay_id = db_conn
+        let flex_assay_id: Vec<TenxAssay> = db_conn
             .interact(|db_conn| flex_query.execute(db_conn).unwrap())
             .await
             .unwrap();
@@ -760,7 +763,7 @@ pub trait DefaultWithNoLimit {
     fn default_with_no_limit() -> Self;
 }
 
-impl<F, O> DefaultWithNoLimit for generic_query::Query<F, O>
+impl<F, O, I> DefaultWithNoLimit for generic_query::Query<F, O, I>
 where
     O: Default,
 {
@@ -773,7 +776,7 @@ impl DefaultWithNoLimit for () {
     fn default_with_no_limit() -> Self {}
 }
 
-impl<U, F, O> DefaultWithNoLimit for (U, generic_query::Query<F, O>)
+impl<U, F, O, I> DefaultWithNoLimit for (U, generic_query::Query<F, O, I>)
 where
     U: From<Uuid>,
     O: Default,
@@ -781,7 +784,7 @@ where
     fn default_with_no_limit() -> Self {
         (
             Uuid::default().into(),
-            generic_query::Query::<F, O>::default_with_no_limit(),
+            generic_query::Query::<F, O, I>::default_with_no_limit(),
         )
     }
 }
@@ -798,7 +801,7 @@ pub struct Database {
     pub _gem_pools: Vec<GemPoolSummary>,
     pub _cdna: Vec<CdnaSummary>,
     pub _libraries: Vec<LibrarySummary>,
-    pub _chromium_datasets: Vec<ChromiumDatasetSummary>,
+    pub chromium_datasets: Vec<ChromiumDatasetSummary>,
 }
 
 impl Database {
@@ -839,7 +842,7 @@ impl Database {
             _gem_pools: gem_pools,
             _cdna: cdna,
             _libraries: libraries,
-            _chromium_datasets: chromium_datasets,
+            chromium_datasets,
         }
     }
 }
@@ -1,7 +1,13 @@
 use std::{cmp::Ordering, fmt::Debug};
 
+use cellnoor_models::specimen::{
+    FrozenTissueCreation, Species, SpecimenCommonFields, SpecimenCreation,
+};
 use diesel::{Connection, PgConnection};
+use jiff::Timestamp;
+use non_empty::NonEmptyString;
 use pretty_assertions::assert_eq;
+use uuid::Uuid;
 
 use crate::{db, test_state::DefaultWithNoLimit};
 
@@ -93,3 +99,32 @@ pub async fn test_query<Query, Record>(
 
     pooled_db_conn.interact(perform_test).await.unwrap();
 }
+
+/// A frozen tissue specimen, which is the simplest kind
+pub fn specimen(
+    lab_id: Uuid,
+    submitted_by: Uuid,
+    species: Species,
+    donor_id: Option<Uuid>,
+    committee_approval_ids: Vec<Uuid>,
+) -> SpecimenCreation {
+    let readable_id = NonEmptyString::new(Uuid::now_v7().to_string()).unwrap();
+
+    let inner = SpecimenCommonFields::builder()
+        .readable_id(readable_id.clone())
+        .name(readable_id)
+        .submitted_by(submitted_by)
+        .lab_id(lab_id)
+        .received_at(Timestamp::now())
+        .species(species)
+        .tissue(NonEmptyString::new("liver").unwrap())
+        .maybe_donor_id(donor_id)
+        .build();
+
+    SpecimenCreation::FrozenTissue(
+        FrozenTissueCreation::builder()
+            .inner(inner)
+            .committee_approval_ids(committee_approval_ids)
+            .build(),
+    )
+}
@@ -5,35 +5,47 @@ use crate::{db, validate::common::TimestampError};
 mod cdna;
 mod chromium_dataset;
 mod chromium_run;
+mod committee_approval;
 mod common;
+mod donor;
 mod initial_data;
 mod institution;
 mod lab;
 mod library;
 mod nucleic_acid_measurement;
 mod person;
+mod project;
 mod sequencing_run;
 mod specimen;
+mod storage;
 mod suspension;
 mod suspension_pool;
 mod tenx_assay;
+mod webhook;
 
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "DataValidationError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "DataValidationError")]
 #[serde(rename_all = "snake_case", tag = "type", content = "info")]
+#[derive(strum::IntoStaticStr)]
+#[strum(serialize_all = "snake_case")]
 #[error(transparent)]
 pub enum Error {
     InsertInitialData(#[from] initial_data::Error),
     CreatePerson(#[from] person::Error),
     CreateLab(#[from] lab::Error),
+    CreateDonor(#[from] donor::Error),
     CreateSpecimen(#[from] specimen::Error),
+    Storage(#[from] storage::Error),
     CreateSuspension(#[from] suspension::Error),
     CreateSuspensionPool(#[from] suspension_pool::Error),
     CreateCdna(#[from] cdna::Error),
     CreateLibrary(#[from] library::Error),
     CreateNucleicAcidMeasurement(#[from] nucleic_acid_measurement::Error),
     CreateChromiumDataset(#[from] chromium_dataset::Error),
+    CreateWebhookSubscription(#[from] webhook::Error),
     Timestamp(#[from] TimestampError),
     Database(#[from] db::Error),
 }
@@ -16,6 +16,8 @@ pub mod measurement;
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "CdnaValidationError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "CdnaValidationError")]
 #[serde(rename_all = "snake_case", tag = "type", content = "info")]
 pub enum Error {
     #[error("library type does not exist in assay {assay_id}")]
@@ -12,6 +12,8 @@ use crate::{
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "ChromiumDatasetValidationError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "ChromiumDatasetValidationError")]
 #[serde(rename_all = "snake_case", tag = "type", content = "info")]
 pub enum Error {
     #[error("all libraries must come from same GEM pool")]
@@ -64,9 +64,10 @@ fn validate_suspension_pools_created_before_chromium_run(
     let mut q = SuspensionPoolQuery::default_with_no_limit();
     q.filter = Some(SuspensionPoolFilter {
         ids: Some(suspension_pool_ids),
+        ..Default::default()
     });
 
-    let suspension_pools = q.execute(db_conn)?;
+    let suspension_pools: Vec<SuspensionPool> = q.execute(db_conn)?;
     for pooled_at in suspension_pools.iter().map(SuspensionPool::pooled_at) {
         validate_timestamps(pooled_at, chromium_run_at, "run_at")?;
     }
@@ -0,0 +1,84 @@
+use cellnoor_models::committee_approval::{
+    CommitteeApproval, CommitteeApprovalCreation, CommitteeApprovalId, CommitteeApprovalUpdate,
+};
+
+use crate::{
+    db::Operation,
+    validate::{Validate, common::validate_strictly_ordered_timestamps},
+};
+
+impl Validate for CommitteeApprovalCreation {
+    fn validate(&self, _db_conn: &mut diesel::PgConnection) -> Result<(), super::Error> {
+        if let Some(valid_until) = self.valid_until() {
+            validate_strictly_ordered_timestamps(self.valid_from(), valid_until, "valid_until")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for (CommitteeApprovalId, CommitteeApprovalUpdate) {
+    fn validate(&self, db_conn: &mut diesel::PgConnection) -> Result<(), super::Error> {
+        let (approval_id, update) = self;
+
+        let existing: CommitteeApproval = approval_id.execute(db_conn)?;
+
+        let valid_from = update.valid_from().unwrap_or(existing.valid_from());
+        let Some(valid_until) = update.valid_until().or(existing.valid_until()) else {
+            return Ok(());
+        };
+
+        validate_strictly_ordered_timestamps(valid_from, valid_until, "valid_until")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cellnoor_models::committee_approval::{CommitteeApprovalCreation, CommitteeType};
+    use deadpool_diesel::postgres::Connection;
+    use rstest::rstest;
+    use serde_json::json;
+
+    use crate::{
+        test_state::{Database, database, root_db_conn},
+        validate::{self, Validate},
+    };
+
+    #[rstest]
+    #[case("2025-01-01T00:00:00Z", true)]
+    #[case("2024-01-01T00:00:00Z", false)]
+    #[case("2023-01-01T00:00:00Z", false)]
+    #[awt]
+    #[tokio::test]
+    async fn validity_window_cannot_be_empty(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+        #[case] valid_until: &'static str,
+        #[case] is_valid: bool,
+    ) {
+        let approval: CommitteeApprovalCreation = serde_json::from_value(json!({
+            "institution_id": database.institutions[0].id(),
+            "committee_type": CommitteeType::Irb,
+            "compliance_identifier": "validity-window",
+            "valid_from": "2024-01-01T00:00:00Z",
+            "valid_until": valid_until,
+        }))
+        .unwrap();
+
+        let result = root_db_conn
+            .interact(move |db_conn| approval.validate(db_conn))
+            .await
+            .unwrap();
+
+        if is_valid {
+            assert!(result.is_ok(), "{result:?}");
+        } else {
+            assert!(
+                matches!(result, Err(validate::Error::Timestamp(_))),
+                "{result:?}"
+            );
+        }
+    }
+}
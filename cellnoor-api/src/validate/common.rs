@@ -3,6 +3,8 @@ use jiff::Timestamp;
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "TimestampError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "TimestampError")]
 #[error("t1 must be before t2")]
 pub struct TimestampError {
     #[cfg_attr(feature = "typescript", ts(as = "String"))]
@@ -27,3 +29,21 @@ pub(super) fn validate_timestamps(
 
     Ok(())
 }
+
+/// Like [`validate_timestamps`], but for intervals that must not be empty, so
+/// the timestamps cannot be equal
+pub(super) fn validate_strictly_ordered_timestamps(
+    first: Timestamp,
+    second: Timestamp,
+    field: &'static str,
+) -> Result<(), TimestampError> {
+    if first >= second {
+        return Err(TimestampError {
+            t1: first,
+            t2: second,
+            field,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use cellnoor_models::{
+    donor::{DonorCreation, DonorId, DonorUpdate},
+    specimen::Species,
+};
+use cellnoor_schema::specimens;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::validate::Validate;
+
+#[derive(Debug, thiserror::Error, serde::Serialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(rename = "DonorValidationError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "DonorValidationError")]
+#[serde(rename_all = "snake_case", tag = "type", content = "info")]
+pub enum Error {
+    #[error(
+        "cannot change species to {species:?} because specimens {specimen_ids:?} are of a \
+         different species"
+    )]
+    SpeciesConflictsWithSpecimens {
+        species: Species,
+        specimen_ids: Vec<Uuid>,
+    },
+}
+
+// The database will ensure that at most one of `donor.born_at` and
+// `donor.age_in_days` is set
+impl Validate for DonorCreation {}
+
+impl Validate for (DonorId, DonorUpdate) {
+    fn validate(&self, db_conn: &mut diesel::PgConnection) -> Result<(), super::Error> {
+        let (DonorId(donor_id), update) = self;
+
+        let Some(species) = update.species() else {
+            return Ok(());
+        };
+
+        let specimen_ids: Vec<Uuid> = specimens::table
+            .select(specimens::id)
+            .filter(specimens::donor_id.eq(donor_id))
+            .filter(specimens::species.ne(species))
+            .load(db_conn)?;
+
+        if !specimen_ids.is_empty() {
+            return Err(Error::SpeciesConflictsWithSpecimens {
+                species,
+                specimen_ids,
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cellnoor_models::{
+        donor::{DonorCreation, DonorId, DonorUpdate},
+        specimen::Species,
+    };
+    use cellnoor_schema::donors;
+    use deadpool_diesel::postgres::Connection;
+    use diesel::{Connection as _, prelude::*};
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::Error;
+    use crate::{
+        db::{self, Operation},
+        test_state::{Database, database, root_db_conn},
+        test_util::specimen,
+        validate::{self, Validate},
+    };
+
+    /// A mouse donor with one specimen, which is rolled back after `f` runs
+    async fn with_donor_and_specimen<F, T>(
+        root_db_conn: &Connection,
+        database: &Database,
+        f: F,
+    ) -> T
+    where
+        F: FnOnce(DonorId, &mut PgConnection) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let lab_id = database.labs[0].id();
+        let submitted_by = database.people[0].id();
+        let donor: DonorCreation = serde_json::from_value(json!({
+            "external_id": "donor-with-specimen",
+            "lab_id": lab_id,
+            "species": Species::MusMusculus,
+        }))
+        .unwrap();
+
+        root_db_conn
+            .interact(move |db_conn| {
+                db_conn.test_transaction::<_, db::Error, _>(|tx| {
+                    let donor_id = donor.execute(tx)?.id();
+                    specimen(
+                        lab_id,
+                        submitted_by,
+                        Species::MusMusculus,
+                        Some(donor_id),
+                        vec![],
+                    )
+                    .execute(tx)?;
+
+                    Ok(f(DonorId(donor_id), tx))
+                })
+            })
+            .await
+            .unwrap()
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn species_cannot_change_away_from_specimens(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let result = with_donor_and_specimen(&root_db_conn, database, |donor_id, tx| {
+            let update = DonorUpdate::builder()
+                .species(Species::RattusNorvegicus)
+                .build();
+            (donor_id, update).validate(tx)
+        })
+        .await;
+
+        assert!(
+            matches!(
+                result,
+                Err(validate::Error::CreateDonor(
+                    Error::SpeciesConflictsWithSpecimens { .. }
+                ))
+            ),
+            "{result:?}"
+        );
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn donor_with_specimens_cannot_be_deleted(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let result = with_donor_and_specimen(&root_db_conn, database, |DonorId(donor_id), tx| {
+            diesel::delete(donors::table.find(donor_id))
+                .execute(tx)
+                .map_err(db::Error::from)
+        })
+        .await;
+
+        assert!(
+            matches!(result, Err(db::Error::InvalidReference { .. })),
+            "{result:?}"
+        );
+    }
+}
@@ -7,12 +7,16 @@ use crate::{initial_data::InitialData, validate::Validate};
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "InitialDataValidationError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "InitialDataValidationError")]
 #[serde(rename_all = "snake_case", tag = "type", content = "info")]
 pub enum Error {
     #[error("app_admin must have Microsoft Entra OID")]
     AppAdminWithoutMicrosoftEntraOid,
     #[error("URL '{0}' does not have domain '10xgenomics.com'")]
     Non10xGenomicsUrl(String),
+    #[error("index set URL '{0}' must have scheme 'https', 'file', or 'bundle'")]
+    UnsupportedIndexSetUrl(String),
 }
 
 impl Validate for InitialData {
@@ -24,10 +28,8 @@ impl Validate for InitialData {
         }
         self.single_index_set_urls()
             .iter()
-            .try_for_each(validate_10x_genomics_url)?;
-        self.dual_index_set_urls()
-            .iter()
-            .try_for_each(validate_10x_genomics_url)?;
+            .chain(self.dual_index_set_urls())
+            .try_for_each(validate_index_set_url)?;
         self.tenx_assays()
             .iter()
             .try_for_each(|a| a.validate(db_conn))?;
@@ -36,6 +38,16 @@ impl Validate for InitialData {
     }
 }
 
+// Local files and the bundle compiled into the binary are trusted, but
+// downloads must come from 10x Genomics
+fn validate_index_set_url(url: &Url) -> Result<(), super::Error> {
+    match url.scheme() {
+        "https" => validate_10x_genomics_url(url),
+        "file" | "bundle" => Ok(()),
+        _ => Err(Error::UnsupportedIndexSetUrl(url.to_string()))?,
+    }
+}
+
 pub(super) fn validate_10x_genomics_url<S: AsRef<str> + Display>(
     url: &S,
 ) -> Result<(), super::Error> {
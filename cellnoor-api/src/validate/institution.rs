@@ -3,3 +3,5 @@ use cellnoor_models::institution;
 use crate::validate::Validate;
 
 impl Validate for institution::InstitutionCreation {}
+
+impl Validate for institution::InstitutionUpdate {}
@@ -5,6 +5,8 @@ use crate::validate::Validate;
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "LabValidationError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "LabValidationError")]
 #[serde(rename_all = "snake_case", tag = "type", content = "info")]
 pub enum Error {
     #[error("{delivery_dir} invalid: {message}")]
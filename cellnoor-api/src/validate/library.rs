@@ -15,6 +15,8 @@ pub mod measurement;
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "LibraryValidationError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "LibraryValidationError")]
 #[serde(rename_all = "snake_case", tag = "type", content = "info")]
 pub enum Error {
     #[error("wrong volume found")]
@@ -112,7 +114,7 @@ fn fetch_cdna_data(
 #[cfg(test)]
 mod tests {
     use cellnoor_models::tenx_assay::{
-        LibraryType, SampleMultiplexing, TenxAssayFilter, TenxAssayQuery,
+        LibraryType, SampleMultiplexing, TenxAssay, TenxAssayFilter, TenxAssayQuery,
     };
     use cellnoor_schema::{cdna, tenx_assays};
     use deadpool_diesel::postgres::Connection;
@@ -146,7 +148,7 @@ mod tests {
             )
             .build();
 
-        let three_prime_gex_assay_id = root_db_conn
+        let three_prime_gex_assay_id: Vec<TenxAssay> = root_db_conn
             .interact(|db_conn| three_prime_gex_query.execute(db_conn).unwrap())
             .await
             .unwrap();
@@ -13,6 +13,8 @@ static EMAIL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "PersonValidationError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "PersonValidationError")]
 #[serde(rename_all = "snake_case", tag = "type", content = "info")]
 #[error("{email} invalid: {message}")]
 pub enum Error {
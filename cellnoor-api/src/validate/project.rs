@@ -0,0 +1,33 @@
+use cellnoor_models::project::{
+    ProjectCreation, ProjectIdChromiumDatasets, ProjectIdSpecimens, ProjectUpdate,
+};
+use uuid::Uuid;
+
+use crate::validate::{Validate, common::validate_timestamps};
+
+impl Validate for ProjectCreation {
+    fn validate(&self, _db_conn: &mut diesel::PgConnection) -> Result<(), super::Error> {
+        if let Some(ended_at) = self.ended_at() {
+            validate_timestamps(self.started_at(), ended_at, "ended_at")?;
+        }
+
+        Ok(())
+    }
+}
+
+// If only one of `started_at` and `ended_at` is being changed, the database
+// will ensure that the project still starts before it ends
+impl Validate for ProjectUpdate {
+    fn validate(&self, _db_conn: &mut diesel::PgConnection) -> Result<(), super::Error> {
+        if let (Some(started_at), Some(ended_at)) = (self.started_at(), self.ended_at()) {
+            validate_timestamps(started_at, ended_at, "ended_at")?;
+        }
+
+        Ok(())
+    }
+}
+
+// The database will ensure that the project and its members exist
+impl Validate for (ProjectIdSpecimens, Vec<Uuid>) {}
+
+impl Validate for (ProjectIdChromiumDatasets, Vec<Uuid>) {}
@@ -1,5 +1,5 @@
 use cellnoor_models::{
-    library::{LibraryFilter, LibraryQuery},
+    library::{LibraryFilter, LibraryQuery, LibrarySummary},
     sequencing_run::SequencingRunIdLibraries,
 };
 use cellnoor_schema::sequencing_runs;
@@ -18,8 +18,9 @@ impl Validate for (SequencingRunIdLibraries, Vec<Uuid>) {
         let mut library_query = LibraryQuery::default_with_no_limit();
         library_query.filter = Some(LibraryFilter {
             ids: Some(library_ids.clone()),
+            ..Default::default()
         });
-        let libraries = library_query.execute(db_conn)?;
+        let libraries: Vec<LibrarySummary> = library_query.execute(db_conn)?;
 
         let sequencing_run_begun_at = sequencing_runs::table
             .select(sequencing_runs::begun_at)
@@ -1,17 +1,55 @@
-use cellnoor_models::specimen::{Species, SpecimenCreation};
+use cellnoor_models::{
+    committee_approval::{CommitteeApprovalSummary, CommitteeType},
+    donor::DonorId,
+    specimen::{Species, SpecimenCreation},
+};
+use cellnoor_schema::{committee_approvals, institutions, people};
+use diesel::prelude::*;
 use jiff::Timestamp;
+use uuid::Uuid;
 
-use crate::validate::{Validate, common::validate_timestamps};
+use crate::{
+    db::Operation,
+    validate::{Validate, common::validate_timestamps},
+};
 
 pub(super) mod measurement;
 
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "SpecimenValidationError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "SpecimenValidationError")]
 #[serde(rename_all = "snake_case", tag = "type", content = "info")]
 pub enum Error {
     #[error("donor and host species cannot be the same")]
     SameDonorAndHostSpecies { species: Species },
+    #[error(
+        "specimen species ({specimen_species:?}) must match species of donor {donor_id} \
+         ({donor_species:?})"
+    )]
+    DonorSpeciesMismatch {
+        donor_id: Uuid,
+        donor_species: Species,
+        specimen_species: Species,
+    },
+    #[error("specimen lab {specimen_lab_id} must match lab of donor {donor_id} ({donor_lab_id})")]
+    DonorLabMismatch {
+        donor_id: Uuid,
+        donor_lab_id: Uuid,
+        specimen_lab_id: Uuid,
+    },
+    #[error(
+        "{species:?} specimens require an approval from the {committee_type:?} committee of \
+         institution {institution_id} that is valid at {received_at}"
+    )]
+    MissingCommitteeApproval {
+        species: Species,
+        committee_type: CommitteeType,
+        institution_id: Uuid,
+        #[cfg_attr(feature = "typescript", ts(as = "String"))]
+        received_at: Timestamp,
+    },
     #[error("received at ({received_at}) cannot be after returned at ({returned_at})")]
     ReturnedBeforeReceived {
         #[cfg_attr(feature = "typescript", ts(as = "String"))]
@@ -22,15 +60,21 @@ pub enum Error {
 }
 
 impl Validate for SpecimenCreation {
-    fn validate(&self, _db_conn: &mut diesel::PgConnection) -> Result<(), super::Error> {
+    fn validate(&self, db_conn: &mut diesel::PgConnection) -> Result<(), super::Error> {
         if let Some(host_species) = self.host_species() {
             validate_species(self.species(), host_species)?;
         }
 
+        if let Some(donor_id) = self.donor_id() {
+            validate_donor(donor_id, self, db_conn)?;
+        }
+
         if let Some(returned_at) = self.returned_at() {
             validate_received_before_returned(self.received_at(), returned_at)?;
         }
 
+        validate_committee_approvals(self, db_conn)?;
+
         Ok(())
     }
 }
@@ -45,6 +89,82 @@ fn validate_species(donor_species: Species, host_species: Species) -> Result<(),
     Ok(())
 }
 
+fn validate_donor(
+    donor_id: Uuid,
+    specimen: &SpecimenCreation,
+    db_conn: &mut diesel::PgConnection,
+) -> Result<(), super::Error> {
+    let donor = DonorId(donor_id).execute(db_conn)?;
+
+    let (donor_species, specimen_species) = (donor.species(), specimen.species());
+    if donor_species != specimen_species {
+        return Err(Error::DonorSpeciesMismatch {
+            donor_id,
+            donor_species,
+            specimen_species,
+        }
+        .into());
+    }
+
+    let (donor_lab_id, specimen_lab_id) = (donor.lab_id(), specimen.lab_id());
+    if donor_lab_id != specimen_lab_id {
+        return Err(Error::DonorLabMismatch {
+            donor_id,
+            donor_lab_id,
+            specimen_lab_id,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+fn validate_committee_approvals(
+    specimen: &SpecimenCreation,
+    db_conn: &mut diesel::PgConnection,
+) -> Result<(), super::Error> {
+    let species = specimen.species();
+    let Some(committee_type) = CommitteeType::required_for(species) else {
+        return Ok(());
+    };
+
+    // The requirement is configured by the institution of the person submitting
+    // the specimen, and only that institution's committees can satisfy it
+    let (institution_id, requires_approval): (Uuid, bool) = people::table
+        .inner_join(institutions::table)
+        .filter(people::id.eq(specimen.submitted_by()))
+        .select((institutions::id, institutions::requires_committee_approval))
+        .first(db_conn)?;
+
+    if !requires_approval {
+        return Ok(());
+    }
+
+    let received_at = specimen.received_at();
+
+    let approvals: Vec<CommitteeApprovalSummary> = CommitteeApprovalSummary::query()
+        .filter(committee_approvals::id.eq_any(specimen.committee_approval_ids()))
+        .load(db_conn)?;
+
+    let is_approved = approvals.iter().any(|a| {
+        a.institution_id() == institution_id
+            && a.committee_type() == committee_type
+            && a.is_valid_at(received_at)
+    });
+
+    if !is_approved {
+        return Err(Error::MissingCommitteeApproval {
+            species,
+            committee_type,
+            institution_id,
+            received_at,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
 fn validate_received_before_returned(
     received_at: Timestamp,
     returned_at: Timestamp,
@@ -53,3 +173,221 @@ fn validate_received_before_returned(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use cellnoor_models::{
+        committee_approval::{CommitteeApprovalCreation, CommitteeType},
+        donor::DonorCreation,
+        specimen::Species,
+    };
+    use cellnoor_schema::{institutions, people};
+    use deadpool_diesel::postgres::Connection;
+    use diesel::{Connection as _, prelude::*};
+    use rstest::rstest;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::Error;
+    use crate::{
+        db::{self, Operation},
+        test_state::{Database, database, root_db_conn},
+        test_util::specimen,
+        validate::{self, Validate},
+    };
+
+    /// Validate a specimen from `specimen_lab_id` against a rat donor from the
+    /// first lab, rolling back the donor afterwards
+    async fn validate_against_donor(
+        root_db_conn: &Connection,
+        database: &Database,
+        specimen_species: Species,
+        specimen_lab_id: Uuid,
+    ) -> Result<(), validate::Error> {
+        let donor: DonorCreation = serde_json::from_value(json!({
+            "external_id": "validation-donor",
+            "lab_id": database.labs[0].id(),
+            "species": Species::RattusNorvegicus,
+        }))
+        .unwrap();
+        let submitted_by = database.people[0].id();
+
+        root_db_conn
+            .interact(move |db_conn| {
+                db_conn.test_transaction::<_, db::Error, _>(|tx| {
+                    let donor_id = donor.execute(tx)?.id();
+                    let specimen = specimen(
+                        specimen_lab_id,
+                        submitted_by,
+                        specimen_species,
+                        Some(donor_id),
+                        vec![],
+                    );
+
+                    Ok(specimen.validate(tx))
+                })
+            })
+            .await
+            .unwrap()
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn specimen_matches_donor(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let result = validate_against_donor(
+            &root_db_conn,
+            database,
+            Species::RattusNorvegicus,
+            database.labs[0].id(),
+        )
+        .await;
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn specimen_species_differs_from_donor(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let result = validate_against_donor(
+            &root_db_conn,
+            database,
+            Species::CanisFamiliaris,
+            database.labs[0].id(),
+        )
+        .await;
+
+        assert!(
+            matches!(
+                result,
+                Err(validate::Error::CreateSpecimen(
+                    Error::DonorSpeciesMismatch { .. }
+                ))
+            ),
+            "{result:?}"
+        );
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn specimen_lab_differs_from_donor(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let result = validate_against_donor(
+            &root_db_conn,
+            database,
+            Species::RattusNorvegicus,
+            database.labs[1].id(),
+        )
+        .await;
+
+        assert!(
+            matches!(
+                result,
+                Err(validate::Error::CreateSpecimen(
+                    Error::DonorLabMismatch { .. }
+                ))
+            ),
+            "{result:?}"
+        );
+    }
+
+    /// Validate a mouse specimen that cites an IACUC approval from either the
+    /// submitter's institution or another one, with the submitter's institution
+    /// requiring approval. Everything is rolled back afterwards.
+    async fn validate_with_approval(
+        root_db_conn: &Connection,
+        database: &'static Database,
+        from_submitters_institution: bool,
+    ) -> Result<(), validate::Error> {
+        let lab_id = database.labs[0].id();
+        let submitted_by = database.people[0].id();
+
+        root_db_conn
+            .interact(move |db_conn| {
+                db_conn.test_transaction::<_, db::Error, _>(|tx| {
+                    let submitter_institution_id: Uuid = people::table
+                        .find(submitted_by)
+                        .select(people::institution_id)
+                        .first(tx)?;
+
+                    diesel::update(institutions::table.find(submitter_institution_id))
+                        .set(institutions::requires_committee_approval.eq(true))
+                        .execute(tx)?;
+
+                    let approval_institution_id = if from_submitters_institution {
+                        submitter_institution_id
+                    } else {
+                        database
+                            .institutions
+                            .iter()
+                            .find(|i| i.id() != submitter_institution_id)
+                            .unwrap()
+                            .id()
+                    };
+
+                    let approval: CommitteeApprovalCreation = serde_json::from_value(json!({
+                        "institution_id": approval_institution_id,
+                        "committee_type": CommitteeType::Iacuc,
+                        "compliance_identifier": "validation-approval",
+                        "valid_from": "2000-01-01T00:00:00Z",
+                    }))
+                    .unwrap();
+                    let approval_id = approval.execute(tx)?.id();
+
+                    let specimen = specimen(
+                        lab_id,
+                        submitted_by,
+                        Species::MusMusculus,
+                        None,
+                        vec![approval_id],
+                    );
+
+                    Ok(specimen.validate(tx))
+                })
+            })
+            .await
+            .unwrap()
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn approval_from_submitters_institution(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let result = validate_with_approval(&root_db_conn, database, true).await;
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn approval_from_other_institution(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let result = validate_with_approval(&root_db_conn, database, false).await;
+
+        assert!(
+            matches!(
+                result,
+                Err(validate::Error::CreateSpecimen(
+                    Error::MissingCommitteeApproval { .. }
+                ))
+            ),
+            "{result:?}"
+        );
+    }
+}
@@ -0,0 +1,438 @@
+use cellnoor_models::storage::{
+    OccupiedPosition, StorageCheckIn, StorageCheckOut, StorageUnit, StorageUnitCreation,
+    StorageUnitId, StorageUnitIdCheckIn, StorageUnitIdCheckOut, StorageUnitKind, StoredItem,
+};
+use cellnoor_schema::{storage_events, storage_occupancy};
+use diesel::{dsl::max, prelude::*};
+use jiff::Timestamp;
+use uuid::Uuid;
+
+use crate::{db::Operation, validate::Validate};
+
+#[derive(Debug, thiserror::Error, serde::Serialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(rename = "StorageValidationError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "StorageValidationError")]
+#[serde(rename_all = "snake_case", tag = "type", content = "info")]
+pub enum Error {
+    #[error("a {kind:?} cannot be placed in a {parent_kind:?}")]
+    InvalidParent {
+        kind: StorageUnitKind,
+        parent_kind: Option<StorageUnitKind>,
+    },
+    #[error("storage unit {unit_id} is not a box")]
+    NotABox { unit_id: Uuid },
+    #[error("position {position} is outside of box {box_id}, which has {capacity} positions")]
+    PositionOutOfRange {
+        box_id: Uuid,
+        position: i32,
+        capacity: i32,
+    },
+    #[error("position {position} of box {box_id} is already occupied")]
+    PositionOccupied {
+        box_id: Uuid,
+        position: i32,
+        occupied_by: StoredItem,
+    },
+    #[error("item is not in box {box_id}")]
+    NotInBox { box_id: Uuid, item: StoredItem },
+    #[error("item was last moved at {latest_at}, which is after {performed_at}")]
+    EarlierThanLatestEvent {
+        item: StoredItem,
+        #[cfg_attr(feature = "typescript", ts(as = "String"))]
+        latest_at: Timestamp,
+        #[cfg_attr(feature = "typescript", ts(as = "String"))]
+        performed_at: Timestamp,
+    },
+}
+
+impl Validate for StorageUnitCreation {
+    fn validate(&self, db_conn: &mut diesel::PgConnection) -> Result<(), super::Error> {
+        let parent_kind = match self.parent_id() {
+            Some(parent_id) => {
+                let parent: StorageUnit = StorageUnitId(parent_id).execute(db_conn)?;
+                Some(parent.kind())
+            }
+            None => None,
+        };
+
+        if !self.kind().can_be_placed_in(parent_kind) {
+            return Err(Error::InvalidParent {
+                kind: self.kind(),
+                parent_kind,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for (StorageUnitIdCheckIn, StorageCheckIn) {
+    fn validate(&self, db_conn: &mut diesel::PgConnection) -> Result<(), super::Error> {
+        let (StorageUnitIdCheckIn(box_id), check_in) = self;
+        let (box_id, position) = (*box_id, check_in.position());
+
+        validate_after_latest_event(check_in.item(), check_in.performed_at(), db_conn)?;
+
+        let storage_unit: StorageUnit = StorageUnitId(box_id).execute(db_conn)?;
+        let Some(capacity) = storage_unit.capacity() else {
+            return Err(Error::NotABox { unit_id: box_id }.into());
+        };
+
+        if !(1..=capacity).contains(&position) {
+            return Err(Error::PositionOutOfRange {
+                box_id,
+                position,
+                capacity,
+            }
+            .into());
+        }
+
+        let occupant: Option<OccupiedPosition> = OccupiedPosition::query()
+            .filter(storage_occupancy::box_id.eq(box_id))
+            .filter(storage_occupancy::position.eq(position))
+            .first(db_conn)
+            .optional()?;
+
+        let Some(occupied_by) = occupant.and_then(|o| o.item()) else {
+            return Ok(());
+        };
+
+        if occupied_by != check_in.item() {
+            return Err(Error::PositionOccupied {
+                box_id,
+                position,
+                occupied_by,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for (StorageUnitIdCheckOut, StorageCheckOut) {
+    fn validate(&self, db_conn: &mut diesel::PgConnection) -> Result<(), super::Error> {
+        let (StorageUnitIdCheckOut(box_id), check_out) = self;
+        let item = check_out.item();
+
+        validate_after_latest_event(item, check_out.performed_at(), db_conn)?;
+
+        let location: Option<OccupiedPosition> = item.execute(db_conn)?;
+
+        if location.is_none_or(|l| l.box_id() != *box_id) {
+            return Err(Error::NotInBox {
+                box_id: *box_id,
+                item,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+// Occupancy is derived from each item's most recent event, so an event that
+// predates it would be silently ignored
+fn validate_after_latest_event(
+    item: StoredItem,
+    performed_at: Timestamp,
+    db_conn: &mut diesel::PgConnection,
+) -> Result<(), super::Error> {
+    use storage_events as t;
+
+    let stmt = t::table.select(max(t::performed_at));
+
+    let latest_at: Option<jiff_diesel::Timestamp> = match item {
+        StoredItem::Specimen(id) => stmt.filter(t::specimen_id.eq(id)).first(db_conn),
+        StoredItem::Suspension(id) => stmt.filter(t::suspension_id.eq(id)).first(db_conn),
+        StoredItem::Cdna(id) => stmt.filter(t::cdna_id.eq(id)).first(db_conn),
+        StoredItem::Library(id) => stmt.filter(t::library_id.eq(id)).first(db_conn),
+    }?;
+
+    let Some(latest_at) = latest_at.map(jiff_diesel::Timestamp::to_jiff) else {
+        return Ok(());
+    };
+
+    if performed_at < latest_at {
+        return Err(Error::EarlierThanLatestEvent {
+            item,
+            latest_at,
+            performed_at,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cellnoor_models::storage::{
+        StorageCheckIn, StorageCheckOut, StorageUnit, StorageUnitCreation, StorageUnitIdCheckIn,
+        StorageUnitIdCheckOut, StorageUnitIdOccupiedPositions, StoredItem,
+    };
+    use deadpool_diesel::postgres::Connection;
+    use diesel::{Connection as _, PgConnection, RunQueryDsl};
+    use rstest::rstest;
+    use serde_json::{Value, json};
+    use uuid::Uuid;
+
+    use super::Error;
+    use crate::{
+        db::{self, Operation},
+        test_state::{Database, database, root_db_conn},
+        validate::{self, Validate},
+    };
+
+    struct TestBox {
+        id: Uuid,
+        performed_by: Uuid,
+        items: [StoredItem; 2],
+    }
+
+    impl TestBox {
+        fn check_in(
+            &self,
+            item: StoredItem,
+            position: i32,
+            performed_at: &str,
+        ) -> (StorageUnitIdCheckIn, StorageCheckIn) {
+            let check_in = serde_json::from_value(json!({
+                "item": item,
+                "position": position,
+                "performed_by": self.performed_by,
+                "performed_at": performed_at,
+            }))
+            .unwrap();
+
+            (StorageUnitIdCheckIn(self.id), check_in)
+        }
+
+        fn check_out(
+            &self,
+            item: StoredItem,
+            performed_at: &str,
+        ) -> (StorageUnitIdCheckOut, StorageCheckOut) {
+            let check_out = serde_json::from_value(json!({
+                "item": item,
+                "performed_by": self.performed_by,
+                "performed_at": performed_at,
+            }))
+            .unwrap();
+
+            (StorageUnitIdCheckOut(self.id), check_out)
+        }
+
+        fn occupants(&self, db_conn: &mut PgConnection) -> Vec<(i32, StoredItem)> {
+            StorageUnitIdOccupiedPositions(self.id)
+                .execute(db_conn)
+                .unwrap()
+                .iter()
+                .map(|o| (o.position(), o.item().unwrap()))
+                .collect()
+        }
+    }
+
+    /// An empty box with two positions inside a new freezer
+    fn create_box(
+        performed_by: Uuid,
+        items: [StoredItem; 2],
+        db_conn: &mut PgConnection,
+    ) -> Result<TestBox, db::Error> {
+        let unit = |value: Value| -> StorageUnitCreation { serde_json::from_value(value).unwrap() };
+
+        let freezer: StorageUnit =
+            unit(json!({ "name": Uuid::now_v7(), "kind": "freezer" })).execute(db_conn)?;
+        let test_box: StorageUnit =
+            unit(json!({ "name": "box", "kind": "box", "parent_id": freezer.id(), "capacity": 2 }))
+                .execute(db_conn)?;
+
+        Ok(TestBox {
+            id: test_box.id(),
+            performed_by,
+            items,
+        })
+    }
+
+    /// A box from `create_box`, which is rolled back after `f` runs
+    async fn with_box<F>(root_db_conn: &Connection, database: &Database, f: F)
+    where
+        F: FnOnce(&TestBox, &mut PgConnection) + Send + 'static,
+    {
+        let performed_by = database.people[0].id();
+        let items = [
+            StoredItem::Specimen(database.specimens[0].id()),
+            StoredItem::Specimen(database.specimens[1].id()),
+        ];
+
+        root_db_conn
+            .interact(move |db_conn| {
+                db_conn.test_transaction::<_, db::Error, _>(|tx| {
+                    f(&create_box(performed_by, items, tx)?, tx);
+
+                    Ok(())
+                });
+            })
+            .await
+            .unwrap();
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn check_in_and_check_out(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        with_box(&root_db_conn, database, |test_box, tx| {
+            let [first, second] = test_box.items;
+
+            let check_in = test_box.check_in(first, 1, "2025-01-01T00:00:00Z");
+            check_in.validate(tx).unwrap();
+            let _: Vec<_> = check_in.execute(tx).unwrap();
+            assert_eq!(test_box.occupants(tx), [(1, first)]);
+
+            let occupied = test_box.check_in(second, 1, "2025-01-02T00:00:00Z");
+            assert!(matches!(
+                occupied.validate(tx),
+                Err(validate::Error::Storage(Error::PositionOccupied { occupied_by, .. }))
+                    if occupied_by == first
+            ));
+
+            let out_of_range = test_box.check_in(second, 3, "2025-01-02T00:00:00Z");
+            assert!(matches!(
+                out_of_range.validate(tx),
+                Err(validate::Error::Storage(Error::PositionOutOfRange {
+                    capacity: 2,
+                    ..
+                }))
+            ));
+
+            let check_out = test_box.check_out(first, "2025-01-03T00:00:00Z");
+            check_out.validate(tx).unwrap();
+            let _: Vec<_> = check_out.execute(tx).unwrap();
+            assert_eq!(test_box.occupants(tx), []);
+
+            let not_in_box = test_box.check_out(first, "2025-01-04T00:00:00Z");
+            assert!(matches!(
+                not_in_box.validate(tx),
+                Err(validate::Error::Storage(Error::NotInBox { .. }))
+            ));
+        })
+        .await;
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn events_cannot_predate_latest_event(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        with_box(&root_db_conn, database, |test_box, tx| {
+            let [item, _] = test_box.items;
+
+            let _: Vec<_> = test_box
+                .check_in(item, 1, "2025-01-02T00:00:00Z")
+                .execute(tx)
+                .unwrap();
+
+            let check_out = test_box.check_out(item, "2025-01-01T00:00:00Z");
+            assert!(matches!(
+                check_out.validate(tx),
+                Err(validate::Error::Storage(
+                    Error::EarlierThanLatestEvent { .. }
+                ))
+            ));
+
+            // The database rejects it even if validation is skipped
+            let result: Result<Vec<_>, _> = tx.transaction(|tx| check_out.execute(tx));
+            assert!(matches!(result, Err(db::Error::Data { .. })), "{result:?}");
+        })
+        .await;
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn database_rejects_occupied_position(
+        #[future] root_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        with_box(&root_db_conn, database, |test_box, tx| {
+            let [first, second] = test_box.items;
+
+            let _: Vec<_> = test_box
+                .check_in(first, 1, "2025-01-01T00:00:00Z")
+                .execute(tx)
+                .unwrap();
+
+            // Validation happens before the inserting transaction, so this is what a
+            // concurrent check-in that raced past it would do
+            let result: Result<Vec<_>, _> = tx.transaction(|tx| {
+                test_box
+                    .check_in(second, 1, "2025-01-02T00:00:00Z")
+                    .execute(tx)
+            });
+            assert!(matches!(result, Err(db::Error::Data { .. })), "{result:?}");
+            assert_eq!(test_box.occupants(tx), [(1, first)]);
+        })
+        .await;
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn concurrent_events_for_an_item_wait_for_each_other(
+        #[future] root_db_conn: Connection,
+        #[future]
+        #[from(root_db_conn)]
+        other_db_conn: Connection,
+        #[future] database: &'static Database,
+    ) {
+        let performed_by = database.people[0].id();
+        let item = StoredItem::Specimen(database.specimens[2].id());
+        let items = [item, StoredItem::Specimen(database.specimens[3].id())];
+
+        // Another transaction checks the item into a different box and stays open
+        other_db_conn
+            .interact(move |db_conn| {
+                diesel::sql_query("begin").execute(db_conn)?;
+                let other_box = create_box(performed_by, items, db_conn)?;
+                let _: Vec<_> = other_box
+                    .check_in(item, 1, "2025-01-02T00:00:00Z")
+                    .execute(db_conn)?;
+
+                Ok::<_, db::Error>(())
+            })
+            .await
+            .unwrap()
+            .unwrap();
+
+        with_box(&root_db_conn, database, move |test_box, tx| {
+            diesel::sql_query("set local lock_timeout = '1s'")
+                .execute(tx)
+                .unwrap();
+
+            let result: Result<Vec<_>, _> = tx.transaction(|tx| {
+                test_box
+                    .check_in(item, 1, "2025-01-01T00:00:00Z")
+                    .execute(tx)
+            });
+            assert!(
+                matches!(&result, Err(db::Error::Other { message }) if message.contains("lock timeout")),
+                "{result:?}"
+            );
+        })
+        .await;
+
+        other_db_conn
+            .interact(|db_conn| diesel::sql_query("rollback").execute(db_conn))
+            .await
+            .unwrap()
+            .unwrap();
+    }
+}
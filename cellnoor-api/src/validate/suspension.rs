@@ -12,6 +12,8 @@ pub mod measurement;
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "SuspensionValidationError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "SuspensionValidationError")]
 #[serde(rename_all = "snake_case", tag = "type", content = "info")]
 pub enum Error {
     #[error("suspension cannot be created before its parent specimen is received")]
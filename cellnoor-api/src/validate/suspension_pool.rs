@@ -16,6 +16,8 @@ pub mod measurement;
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 #[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
 #[cfg_attr(feature = "typescript", ts(rename = "SuspensionPoolValidationError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "SuspensionPoolValidationError")]
 #[serde(rename_all = "snake_case", tag = "type", content = "info")]
 pub enum Error {
     #[error("differing suspension contents")]
@@ -0,0 +1,86 @@
+use cellnoor_models::webhook::WebhookSubscriptionCreation;
+use url::{Host, Url};
+
+use crate::{validate::Validate, webhooks::is_public};
+
+#[derive(Debug, thiserror::Error, serde::Serialize)]
+#[cfg_attr(feature = "typescript", derive(ts_rs::TS))]
+#[cfg_attr(feature = "typescript", ts(rename = "WebhookValidationError"))]
+#[derive(schemars::JsonSchema)]
+#[schemars(rename = "WebhookValidationError")]
+#[serde(rename_all = "snake_case", tag = "type", content = "info")]
+pub enum Error {
+    #[error("{url} invalid: {message}")]
+    Url { url: String, message: String },
+}
+
+impl Validate for WebhookSubscriptionCreation {
+    fn validate(&self, _db_conn: &mut diesel::PgConnection) -> Result<(), super::Error> {
+        let url = self.url.as_ref();
+
+        check_url(url).map_err(|message| Error::Url {
+            url: url.to_owned(),
+            message,
+        })?;
+
+        Ok(())
+    }
+}
+
+// Deliveries are sent from inside our network, so they can only go to public
+// hosts. Hostnames are checked again when they're resolved for each delivery
+fn check_url(url: &str) -> Result<(), String> {
+    let parsed = Url::parse(url).map_err(|e| e.to_string())?;
+    if parsed.scheme() != "https" {
+        return Err("'url' must be an HTTPS URL".to_owned());
+    }
+
+    let is_public_host = match parsed.host() {
+        Some(Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.');
+            domain != "localhost" && !domain.ends_with(".localhost")
+        }
+        Some(Host::Ipv4(ip)) => is_public(ip.into()),
+        Some(Host::Ipv6(ip)) => is_public(ip.into()),
+        None => false,
+    };
+    if !is_public_host {
+        return Err("'url' must point to a public host".to_owned());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::check_url;
+
+    #[rstest]
+    #[case("https://example.com/hook")]
+    #[case("https://93.184.215.14/hook")]
+    fn public_https_url(#[case] url: &str) {
+        assert!(check_url(url).is_ok());
+    }
+
+    #[rstest]
+    #[case("http://example.com/hook")]
+    #[case("ftp://example.com/hook")]
+    #[case("https://localhost/hook")]
+    #[case("https://api.localhost./hook")]
+    #[case("https://127.0.0.1/hook")]
+    #[case("https://2130706433/hook")]
+    #[case("https://169.254.169.254/latest/meta-data")]
+    #[case("https://10.0.0.1/hook")]
+    #[case("https://192.168.0.1/hook")]
+    #[case("https://[::1]/hook")]
+    #[case("https://[fd12::1]/hook")]
+    #[case("https://[::ffff:10.0.0.1]/hook")]
+    #[case("https://[64:ff9b::a9fe:a9fe]/hook")]
+    #[case("https://[2002:a9fe:a9fe::1]/hook")]
+    #[case("https://198.18.0.1/hook")]
+    fn non_public_or_insecure_url(#[case] url: &str) {
+        assert!(check_url(url).is_err());
+    }
+}
@@ -0,0 +1,500 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    time::Duration,
+};
+
+use anyhow::Context;
+use cellnoor_models::webhook::{WebhookDeliveryStatus, WebhookEventType};
+use cellnoor_schema::{webhook_deliveries as d, webhook_subscriptions as s};
+use diesel::{
+    dsl::{IntervalDsl, now},
+    prelude::*,
+    sql_types::Timestamptz,
+};
+use hmac::{Hmac, Mac};
+use reqwest::{
+    Client,
+    dns::{Addrs, Name, Resolve, Resolving},
+    header::CONTENT_TYPE,
+    redirect,
+};
+use serde_json::{Value, json};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::{db, state::AppState};
+
+pub(crate) const SIGNATURE_HEADER: &str = "X-Cellnoor-Signature";
+pub(crate) const TIMESTAMP_HEADER: &str = "X-Cellnoor-Timestamp";
+const EVENT_HEADER: &str = "X-Cellnoor-Event";
+const DELIVERY_HEADER: &str = "X-Cellnoor-Delivery";
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const BATCH_SIZE: u8 = 20;
+// A claimed delivery is only picked up again after this long, which covers a
+// replica dying halfway through sending it
+const CLAIM_MINUTES: i32 = 5;
+// The first retry is after 30 seconds and the last is after about 4 hours
+const MAX_ATTEMPTS: i32 = 10;
+const INITIAL_BACKOFF_SECONDS: i32 = 30;
+
+/// Send due deliveries from the `webhook_deliveries` outbox in the background.
+/// Deliveries are queued by triggers in the same transaction as the change that
+/// caused them, and sent at least once: receivers should use the
+/// `X-Cellnoor-Delivery` header to ignore duplicates.
+pub(crate) fn start(state: AppState) -> anyhow::Result<()> {
+    let client = Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .https_only(true)
+        .redirect(redirect::Policy::none())
+        .dns_resolver(PublicResolver)
+        .build()
+        .context("failed to build webhook HTTP client")?;
+
+    tokio::spawn(async move {
+        loop {
+            match send_due(&state, &client).await {
+                // A full batch probably means that more are due
+                Ok(n_sent) if n_sent == usize::from(BATCH_SIZE) => continue,
+                Ok(_) => {}
+                Err(err) => tracing::error!("failed to send webhook deliveries: {err}"),
+            }
+
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    });
+
+    Ok(())
+}
+
+/// Whether deliveries can be sent to `ip`. Receivers have to be on the public
+/// internet, so that a subscription can't be used to reach cellnoor-api's own
+/// network.
+pub(crate) fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, c, _] = ip.octets();
+            let is_this_network = a == 0;
+            let is_shared = a == 100 && (b & 0b1100_0000) == 64;
+            let is_protocol_assignment = a == 192 && b == 0 && c == 0;
+            let is_benchmarking = a == 198 && (b & 0b1111_1110) == 18;
+            // Includes the broadcast address
+            let is_reserved = a >= 240;
+
+            !(is_this_network
+                || is_shared
+                || is_protocol_assignment
+                || is_benchmarking
+                || is_reserved
+                || ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_documentation()
+                || ip.is_multicast())
+        }
+        IpAddr::V6(ip) => {
+            // Requests to these are forwarded to the IPv4 address they embed, so
+            // that address is the one that has to be public
+            let embedded = match ip.segments() {
+                // NAT64, where a.b.c.d is 64:ff9b::a.b.c.d
+                [0x64, 0xff9b, 0, 0, 0, 0, high, low]
+                // 6to4, where a.b.c.d is 2002:aabb:ccdd::/48
+                | [0x2002, high, low, ..] => {
+                    Some(Ipv4Addr::from((u32::from(high) << 16) | u32::from(low)))
+                }
+                _ => ip.to_ipv4_mapped(),
+            };
+            if let Some(ip) = embedded {
+                return is_public(IpAddr::V4(ip));
+            }
+
+            let [first, second, third, ..] = ip.segments();
+            let is_local_nat64 = first == 0x64 && second == 0xff9b && third == 1;
+            let is_documentation = first == 0x2001 && second == 0xdb8;
+
+            !(is_local_nat64
+                || is_documentation
+                || ip.is_unspecified()
+                || ip.is_loopback()
+                || ip.is_unique_local()
+                || ip.is_unicast_link_local()
+                || ip.is_multicast())
+        }
+    }
+}
+
+/// Leaves out the addresses of a receiver's host that aren't public, since the
+/// host can resolve to something else by the time a delivery is sent than it
+/// did when its subscription was validated
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| is_public(addr.ip()))
+                .collect();
+
+            if addrs.is_empty() {
+                return Err(format!("{} has no public addresses", name.as_str()).into());
+            }
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+#[derive(Queryable)]
+struct DueDelivery {
+    id: Uuid,
+    event_type: WebhookEventType,
+    payload: Value,
+    created_at: jiff_diesel::Timestamp,
+    n_attempts: i32,
+    url: String,
+    secret: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Attempt {
+    response_status: Option<i32>,
+    error: Option<String>,
+}
+
+impl Attempt {
+    fn succeeded(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+async fn send_due(state: &AppState, client: &Client) -> Result<usize, db::Error> {
+    let due = state.db_conn().await?.interact(claim_due).await??;
+    let n_due = due.len();
+
+    for delivery in due {
+        let attempt = send(client, &delivery).await;
+        if let Some(error) = &attempt.error {
+            tracing::warn!("webhook delivery {} failed: {error}", delivery.id);
+        }
+
+        state
+            .db_conn()
+            .await?
+            .interact(move |db_conn| record_attempt(db_conn, &delivery, &attempt))
+            .await??;
+    }
+
+    Ok(n_due)
+}
+
+fn claim_due(db_conn: &mut PgConnection) -> QueryResult<Vec<DueDelivery>> {
+    db_conn.transaction(|tx| {
+        let ids: Vec<Uuid> = d::table
+            .select(d::id)
+            .filter(d::status.eq(WebhookDeliveryStatus::Pending))
+            .filter(d::next_attempt_at.le(now))
+            .order_by(d::next_attempt_at)
+            .limit(i64::from(BATCH_SIZE))
+            .for_update()
+            .skip_locked()
+            .load(tx)?;
+
+        diesel::update(d::table.filter(d::id.eq_any(&ids)))
+            .set(d::next_attempt_at.eq(now.into_sql::<Timestamptz>() + CLAIM_MINUTES.minutes()))
+            .execute(tx)?;
+
+        d::table
+            .inner_join(s::table)
+            .select((
+                d::id,
+                d::event_type,
+                d::payload,
+                d::created_at,
+                d::n_attempts,
+                s::url,
+                s::secret,
+            ))
+            .filter(d::id.eq_any(&ids))
+            .load(tx)
+    })
+}
+
+async fn send(client: &Client, delivery: &DueDelivery) -> Attempt {
+    let event_type: &str = delivery.event_type.into();
+    let body = json!({
+        "id": delivery.id,
+        "event_type": event_type,
+        "created_at": delivery.created_at.to_jiff(),
+        "data": delivery.payload,
+    })
+    .to_string();
+    let timestamp = jiff::Timestamp::now().as_second();
+    let signature = sign(delivery.secret.as_bytes(), timestamp, body.as_bytes());
+
+    let response = client
+        .post(&delivery.url)
+        .header(CONTENT_TYPE, "application/json")
+        .header(EVENT_HEADER, event_type)
+        .header(DELIVERY_HEADER, delivery.id.to_string())
+        .header(TIMESTAMP_HEADER, timestamp)
+        .header(SIGNATURE_HEADER, format!("sha256={signature}"))
+        .body(body)
+        .send()
+        .await;
+
+    match response {
+        Ok(response) => {
+            let status = response.status();
+            Attempt {
+                response_status: Some(i32::from(status.as_u16())),
+                error: (!status.is_success()).then(|| format!("receiver responded with {status}")),
+            }
+        }
+        Err(err) => Attempt {
+            response_status: None,
+            error: Some(err.without_url().to_string()),
+        },
+    }
+}
+
+fn record_attempt(
+    db_conn: &mut PgConnection,
+    delivery: &DueDelivery,
+    attempt: &Attempt,
+) -> QueryResult<usize> {
+    let n_attempts = delivery.n_attempts + 1;
+    let status = if attempt.succeeded() {
+        WebhookDeliveryStatus::Delivered
+    } else if n_attempts >= MAX_ATTEMPTS {
+        WebhookDeliveryStatus::Failed
+    } else {
+        WebhookDeliveryStatus::Pending
+    };
+
+    diesel::update(d::table.filter(d::id.eq(delivery.id)))
+        .set((
+            d::status.eq(status),
+            d::n_attempts.eq(n_attempts),
+            d::last_attempted_at.eq(now),
+            d::next_attempt_at
+                .eq(now.into_sql::<Timestamptz>() + backoff_seconds(n_attempts).seconds()),
+            d::response_status.eq(attempt.response_status),
+            d::error.eq(&attempt.error),
+        ))
+        .execute(db_conn)
+}
+
+fn backoff_seconds(n_attempts: i32) -> i32 {
+    INITIAL_BACKOFF_SECONDS << (n_attempts - 1).clamp(0, MAX_ATTEMPTS)
+}
+
+/// The hex-encoded HMAC-SHA256 of `{timestamp}.{body}` keyed by `secret`. The
+/// timestamp is signed too, so that receivers can reject old deliveries that
+/// are replayed.
+pub(crate) fn sign(secret: &[u8], timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret).expect("HMAC should accept keys of any length");
+    mac.update(format!("{timestamp}.").as_bytes());
+    mac.update(body);
+
+    base16ct::lower::encode_string(&mac.finalize().into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use axum::{
+        Router,
+        http::{HeaderMap, StatusCode},
+        routing::post,
+    };
+    use cellnoor_models::webhook::WebhookEventType;
+    use cellnoor_schema::{
+        chip_loadings, chromium_runs, gem_pools, specimens, suspensions, webhook_deliveries as d,
+        webhook_subscriptions as s,
+    };
+    use deadpool_diesel::postgres::Connection;
+    use diesel::{Connection as _, prelude::*};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::{Value, json};
+    use tokio::net::TcpListener;
+    use uuid::Uuid;
+
+    use super::{
+        Attempt, DueDelivery, SIGNATURE_HEADER, TIMESTAMP_HEADER, backoff_seconds, is_public, send,
+        sign,
+    };
+    use crate::test_state::root_db_conn;
+
+    #[rstest]
+    fn timestamp_is_signed() {
+        // HMAC-SHA256 of "1700000000.{}" keyed by "secret"
+        assert_eq!(
+            sign(b"secret", 1_700_000_000, b"{}"),
+            "b8569b78799ff9e3cbff0fc2d63a33a2b57f3282abd07c37ae5e8e7d79a5f163"
+        );
+        assert_ne!(
+            sign(b"secret", 1_700_000_001, b"{}"),
+            sign(b"secret", 1_700_000_000, b"{}")
+        );
+    }
+
+    #[rstest]
+    #[case("93.184.215.14", true)]
+    #[case("2606:2800:21f:cb07:6820:80da:af6b:8b2c", true)]
+    #[case("127.0.0.1", false)]
+    #[case("10.1.2.3", false)]
+    #[case("172.16.0.1", false)]
+    #[case("192.168.1.1", false)]
+    #[case("169.254.169.254", false)]
+    #[case("100.64.0.1", false)]
+    #[case("0.0.0.0", false)]
+    #[case("::1", false)]
+    #[case("fd00::1", false)]
+    #[case("fe80::1", false)]
+    #[case("::ffff:127.0.0.1", false)]
+    #[case("198.18.0.1", false)]
+    #[case("198.19.255.255", false)]
+    #[case("192.0.0.1", false)]
+    #[case("240.0.0.1", false)]
+    #[case("255.255.255.255", false)]
+    #[case("64:ff9b::a9fe:a9fe", false)]
+    #[case("64:ff9b::7f00:1", false)]
+    #[case("64:ff9b::5db8:d70e", true)]
+    #[case("64:ff9b:1::1", false)]
+    #[case("2002:a9fe:a9fe::1", false)]
+    #[case("2002:0a00:0001::1", false)]
+    #[case("2002:5db8:d70e::1", true)]
+    #[case("2001:db8::1", false)]
+    fn only_public_addresses_are_allowed(#[case] ip: &str, #[case] expected: bool) {
+        assert_eq!(is_public(ip.parse().unwrap()), expected);
+    }
+
+    #[rstest]
+    fn backoff_doubles() {
+        let schedule: Vec<_> = (1..=4).map(backoff_seconds).collect();
+        assert_eq!(schedule, [30, 60, 120, 240]);
+    }
+
+    type Received = Arc<Mutex<Vec<(HeaderMap, String)>>>;
+
+    /// Stands in for a receiver, responding to every request with `status`
+    async fn receiver(status: StatusCode) -> (String, Received) {
+        let received = Received::default();
+        let app = Router::new().route(
+            "/hook",
+            post({
+                let received = received.clone();
+                async move |headers: HeaderMap, body: String| {
+                    received.lock().unwrap().push((headers, body));
+                    status
+                }
+            }),
+        );
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        (url, received)
+    }
+
+    fn delivery(url: String) -> DueDelivery {
+        DueDelivery {
+            id: Uuid::now_v7(),
+            event_type: WebhookEventType::ChromiumDatasetDelivered,
+            payload: json!({"name": "dataset"}),
+            created_at: jiff::Timestamp::UNIX_EPOCH.into(),
+            n_attempts: 0,
+            url,
+            secret: "secret".to_owned(),
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn delivery_is_signed() {
+        let (url, received) = receiver(StatusCode::NO_CONTENT).await;
+        let delivery = delivery(url);
+
+        let attempt = send(&reqwest::Client::new(), &delivery).await;
+        assert_eq!(
+            attempt,
+            Attempt {
+                response_status: Some(204),
+                error: None
+            }
+        );
+
+        let (headers, body) = received.lock().unwrap().pop().unwrap();
+        let timestamp: i64 = headers[TIMESTAMP_HEADER].to_str().unwrap().parse().unwrap();
+        assert_eq!(
+            headers[SIGNATURE_HEADER].to_str().unwrap(),
+            format!("sha256={}", sign(b"secret", timestamp, body.as_bytes()))
+        );
+
+        let body: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(body["id"], json!(delivery.id));
+        assert_eq!(body["event_type"], "chromium_dataset_delivered");
+        assert_eq!(body["data"], delivery.payload);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn error_response_is_a_failed_attempt() {
+        let (url, _) = receiver(StatusCode::INTERNAL_SERVER_ERROR).await;
+
+        let attempt = send(&reqwest::Client::new(), &delivery(url)).await;
+        assert_eq!(attempt.response_status, Some(500));
+        assert!(!attempt.succeeded());
+    }
+
+    #[rstest]
+    #[awt]
+    #[tokio::test]
+    async fn failed_run_is_sent_to_loaded_labs(#[future] root_db_conn: Connection) {
+        root_db_conn
+            .interact(|db_conn| {
+                db_conn.test_transaction::<_, diesel::result::Error, _>(|tx| {
+                    let (run_id, lab_id): (Uuid, Uuid) = chip_loadings::table
+                        .inner_join(gem_pools::table)
+                        .inner_join(suspensions::table.inner_join(specimens::table))
+                        .select((gem_pools::chromium_run_id, specimens::lab_id))
+                        .first(tx)?;
+
+                    let subscription_id: Uuid = diesel::insert_into(s::table)
+                        .values((
+                            s::lab_id.eq(lab_id),
+                            s::url.eq("https://example.com/hook"),
+                            s::secret.eq("secret"),
+                            s::event_types.eq(vec![WebhookEventType::ChromiumRunFailed]),
+                        ))
+                        .returning(s::id)
+                        .get_result(tx)?;
+
+                    diesel::update(chromium_runs::table.filter(chromium_runs::id.eq(run_id)))
+                        .set(chromium_runs::succeeded.eq(false))
+                        .execute(tx)?;
+
+                    // The trigger would otherwise wait for a commit that never comes
+                    diesel::sql_query("set constraints all immediate").execute(tx)?;
+
+                    let payloads: Vec<Value> = d::table
+                        .select(d::payload)
+                        .filter(d::subscription_id.eq(subscription_id))
+                        .load(tx)?;
+                    assert_eq!(payloads.len(), 1);
+                    assert_eq!(payloads[0]["id"], json!(run_id));
+                    assert_eq!(payloads[0]["lab_id"], json!(lab_id));
+
+                    Ok(())
+                });
+            })
+            .await
+            .unwrap();
+    }
+}
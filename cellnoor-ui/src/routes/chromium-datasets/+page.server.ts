@@ -1,20 +1,56 @@
 import { ApiClient } from "$lib/server/cellnoor-client";
+import { isSuccess } from "$lib/cellnoor-typeguard";
+import type { ServerLoadEvent } from "@sveltejs/kit";
+import type { ApiErrorResponse } from "cellnoor-types/ApiErrorResponse";
 import type { ChromiumDatasetSummary } from "cellnoor-types/ChromiumDatasetSummary";
+import type { Page } from "cellnoor-types/Page";
 import type { TenxAssay } from "cellnoor-types/TenxAssay";
 
+// The filter sidebar needs every assay, not just the first page of them
+async function getAllAssays(
+  apiClient: ApiClient,
+  event: ServerLoadEvent,
+): Promise<TenxAssay[] | ApiErrorResponse> {
+  const assays: TenxAssay[] = [];
+  let cursor: string | null = null;
+
+  do {
+    const page: Page<TenxAssay> | ApiErrorResponse = await apiClient.getJson<
+      Page<TenxAssay>
+    >(event, undefined, {
+      endpoint: "/10x-assays",
+      queryString: cursor ? `?${new URLSearchParams({ cursor })}` : "",
+    });
+
+    if (!isSuccess(page)) {
+      return page;
+    }
+
+    assays.push(...page.items);
+    cursor = page.next_cursor;
+  } while (cursor !== null);
+
+  return assays;
+}
+
 export async function load(event) {
   const apiClient = await ApiClient.new();
 
   const [chromiumDatasets, assays] = await Promise.all([
-    apiClient.getJson<ChromiumDatasetSummary[]>(event),
-    apiClient.getJson<TenxAssay[]>(event, undefined, {
-      endpoint: "/10x-assays",
-      queryString: "",
-    }),
+    apiClient.getJson<Page<ChromiumDatasetSummary>>(event),
+    getAllAssays(apiClient, event),
   ]);
 
+  let nextPage: string | null = null;
+  if (isSuccess(chromiumDatasets) && chromiumDatasets.next_cursor !== null) {
+    const searchParams = new URLSearchParams(event.url.searchParams);
+    searchParams.set("cursor", chromiumDatasets.next_cursor);
+    nextPage = `?${searchParams}`;
+  }
+
   return {
     chromiumDatasets,
     assays,
+    nextPage,
   };
 }
@@ -0,0 +1,37 @@
+use anyhow::bail;
+use cellnoor_client::{
+    Client,
+    models::library::{LibraryFilter, LibraryQuery, LibrarySummary},
+};
+use uuid::Uuid;
+
+pub(crate) mod api_keys;
+pub(crate) mod create_dataset;
+pub(crate) mod export;
+pub(crate) mod register_sequencing_run;
+pub(crate) mod upload_outs;
+
+/// Look up the IDs of libraries by their readable IDs, failing if any of them
+/// don't exist
+async fn library_ids(client: &Client, readable_ids: &[String]) -> anyhow::Result<Vec<Uuid>> {
+    let query = LibraryQuery::builder()
+        .filter(
+            LibraryFilter::builder()
+                .readable_ids(readable_ids.to_vec())
+                .build(),
+        )
+        .limit(i64::try_from(readable_ids.len())?)
+        .build();
+    let libraries = client.list_libraries(&query).await?.items;
+
+    let missing: Vec<&str> = readable_ids
+        .iter()
+        .filter(|readable_id| !libraries.iter().any(|l| l.readable_id() == *readable_id))
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        bail!("failed to find libraries with readable IDs {missing:?}");
+    }
+
+    Ok(libraries.iter().map(LibrarySummary::id).collect())
+}
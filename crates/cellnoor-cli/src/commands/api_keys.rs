@@ -0,0 +1,24 @@
+use cellnoor_client::Client;
+
+use crate::output::OutputFormat;
+
+/// Manage your own API keys
+#[derive(clap::Subcommand)]
+pub(crate) enum ApiKeyCommand {
+    List,
+    /// Create an API key, which is only ever shown once
+    Create,
+    Delete {
+        prefix: String,
+    },
+}
+
+impl ApiKeyCommand {
+    pub(crate) async fn run(self, client: &Client, output: OutputFormat) -> anyhow::Result<()> {
+        match self {
+            Self::List => output.print(&client.list_api_keys().await?),
+            Self::Create => output.print(&client.create_api_key().await?),
+            Self::Delete { prefix } => Ok(client.delete_api_key(&prefix).await?),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use anyhow::Context;
+use cellnoor_client::{
+    Client,
+    models::chromium_dataset::{
+        ChromiumDataset, ChromiumDatasetCmdline, ChromiumDatasetCreation, ChromiumDatasetFields,
+    },
+};
+use jiff::Timestamp;
+use non_empty::NonEmptyString;
+use uuid::Uuid;
+
+use super::library_ids;
+
+/// Create a dataset from the readable IDs of the libraries it was produced from
+#[derive(clap::Args)]
+pub(crate) struct CreateDataset {
+    #[arg(long)]
+    name: String,
+    #[arg(long)]
+    lab_id: Uuid,
+    /// The pipeline command, such as "cellranger multi"
+    #[arg(long)]
+    cmdline: ChromiumDatasetCmdline,
+    /// Defaults to now
+    #[arg(long)]
+    delivered_at: Option<Timestamp>,
+    #[arg(required = true)]
+    libraries: Vec<String>,
+}
+
+impl CreateDataset {
+    pub(crate) async fn run(self, client: &Client) -> anyhow::Result<ChromiumDataset> {
+        let Self {
+            name,
+            lab_id,
+            cmdline,
+            delivered_at,
+            libraries,
+        } = self;
+
+        let dataset = ChromiumDatasetCreation::builder()
+            .inner(
+                ChromiumDatasetFields::builder()
+                    .name(NonEmptyString::new(name).context("dataset name must not be empty")?)
+                    .lab_id(lab_id)
+                    .build(),
+            )
+            .delivered_at(delivered_at.unwrap_or_else(Timestamp::now))
+            .library_ids(library_ids(client, &libraries).await?)
+            .cmdline(cmdline)
+            .build();
+
+        Ok(client.create_chromium_dataset(&dataset).await?)
+    }
+}
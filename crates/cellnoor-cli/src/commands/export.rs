@@ -0,0 +1,114 @@
+use std::io::Write;
+
+use anyhow::Context;
+use camino::Utf8PathBuf;
+use cellnoor_client::{Client, ExportFormat};
+use serde_json::{Map, Value};
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum Table {
+    Institutions,
+    People,
+    Labs,
+    Projects,
+    CommitteeApprovals,
+    Donors,
+    Specimens,
+    Storage,
+    TenxAssays,
+    Suspensions,
+    SuspensionPools,
+    ChromiumRuns,
+    GemPools,
+    Cdna,
+    Libraries,
+    ChromiumDatasets,
+}
+
+impl Table {
+    fn path(self) -> &'static str {
+        match self {
+            Self::Institutions => "/institutions",
+            Self::People => "/people",
+            Self::Labs => "/labs",
+            Self::Projects => "/projects",
+            Self::CommitteeApprovals => "/committee-approvals",
+            Self::Donors => "/donors",
+            Self::Specimens => "/specimens",
+            Self::Storage => "/storage",
+            Self::TenxAssays => "/10x-assays",
+            Self::Suspensions => "/suspensions",
+            Self::SuspensionPools => "/suspension-pools",
+            Self::ChromiumRuns => "/chromium-runs",
+            Self::GemPools => "/gem-pools",
+            Self::Cdna => "/cdna",
+            Self::Libraries => "/libraries",
+            Self::ChromiumDatasets => "/chromium-datasets",
+        }
+    }
+}
+
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum Format {
+    Csv,
+    Tsv,
+    Ndjson,
+}
+
+impl From<Format> for ExportFormat {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Csv => Self::Csv,
+            Format::Tsv => Self::Tsv,
+            Format::Ndjson => Self::Ndjson,
+        }
+    }
+}
+
+/// Export every row of a table that matches a query
+#[derive(clap::Args)]
+pub(crate) struct Export {
+    #[arg(value_enum)]
+    table: Table,
+    #[arg(long, value_enum, default_value = "csv")]
+    format: Format,
+    /// A query string as accepted by the API, such as
+    /// `filter[tissues][0]=blood`
+    #[arg(long)]
+    query: Option<String>,
+    /// Defaults to standard output
+    #[arg(long)]
+    file: Option<Utf8PathBuf>,
+}
+
+impl Export {
+    pub(crate) async fn run(self, client: &Client) -> anyhow::Result<()> {
+        let Self {
+            table,
+            format,
+            query,
+            file,
+        } = self;
+
+        // Parsing the query here gives a better error than the API would
+        let query: Map<String, Value> = match &query {
+            Some(query) => serde_qs::from_str(query).context("failed to parse query")?,
+            None => Map::new(),
+        };
+
+        let mut writer: Box<dyn Write> = match &file {
+            Some(path) => {
+                Box::new(std::fs::File::create(path).context(format!("failed to create {path}"))?)
+            }
+            None => Box::new(std::io::stdout().lock()),
+        };
+
+        let mut response = client.export(table.path(), &query, format.into()).await?;
+        while let Some(chunk) = response.chunk().await? {
+            writer.write_all(&chunk)?;
+        }
+        writer.flush()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,180 @@
+use anyhow::{Context, bail};
+use camino::Utf8PathBuf;
+use cellnoor_client::{
+    Client,
+    models::sequencing_run::{SequencingRun, SequencingRunCreation, SequencingRunFields},
+};
+use jiff::{
+    Timestamp,
+    civil::{Date, DateTime},
+    tz::TimeZone,
+};
+use non_empty::NonEmptyString;
+use serde_json::json;
+
+use super::library_ids;
+
+/// Register a sequencing run from the `RunInfo.xml` written by the sequencer.
+/// The run ID becomes the readable ID, and the run number, flowcell and
+/// instrument are stored as additional data.
+#[derive(clap::Args)]
+pub(crate) struct RegisterSequencingRun {
+    run_info: Utf8PathBuf,
+    /// Defaults to the date in `RunInfo.xml`, interpreted in the local time
+    /// zone
+    #[arg(long)]
+    begun_at: Option<Timestamp>,
+    #[arg(long)]
+    finished_at: Option<Timestamp>,
+    /// The readable IDs of the libraries that were sequenced
+    #[arg(long, num_args = 1..)]
+    libraries: Vec<String>,
+}
+
+impl RegisterSequencingRun {
+    pub(crate) async fn run(self, client: &Client) -> anyhow::Result<SequencingRun> {
+        let Self {
+            run_info,
+            begun_at,
+            finished_at,
+            libraries,
+        } = self;
+
+        let contents = std::fs::read_to_string(&run_info)
+            .context(format!("failed to read contents of file {run_info}"))?;
+        let run_info = RunInfo::parse(&contents, &TimeZone::system())?;
+
+        let Some(begun_at) = begun_at.or(run_info.date) else {
+            bail!("RunInfo.xml has no date that can be parsed, so --begun-at must be given");
+        };
+
+        let run = SequencingRunCreation::builder()
+            .inner(
+                SequencingRunFields::builder()
+                    .readable_id(NonEmptyString::new(&run_info.id).context("run ID is empty")?)
+                    .additional_data(json!({
+                        "run_number": run_info.number,
+                        "flowcell": run_info.flowcell,
+                        "instrument": run_info.instrument,
+                    }))
+                    .build(),
+            )
+            .begun_at(begun_at)
+            .maybe_finished_at(finished_at)
+            .build();
+
+        let run = client.create_sequencing_run(&run).await?;
+
+        if !libraries.is_empty() {
+            let library_ids = library_ids(client, &libraries).await?;
+            client
+                .add_libraries_to_sequencing_run(run.id(), &library_ids)
+                .await?;
+        }
+
+        Ok(run)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct RunInfo {
+    id: String,
+    number: Option<u32>,
+    flowcell: Option<String>,
+    instrument: Option<String>,
+    date: Option<Timestamp>,
+}
+
+impl RunInfo {
+    fn parse(contents: &str, tz: &TimeZone) -> anyhow::Result<Self> {
+        let document =
+            roxmltree::Document::parse(contents).context("failed to parse RunInfo.xml")?;
+
+        let Some(run) = document.descendants().find(|n| n.has_tag_name("Run")) else {
+            bail!("failed to find run in RunInfo.xml");
+        };
+        let Some(id) = run.attribute("Id") else {
+            bail!("failed to find run ID in RunInfo.xml");
+        };
+
+        let element = |name: &str| {
+            run.children()
+                .find(|n| n.has_tag_name(name))
+                .and_then(|n| n.text())
+                .map(str::trim)
+                .filter(|text| !text.is_empty())
+                .map(str::to_owned)
+        };
+
+        Ok(Self {
+            id: id.to_owned(),
+            number: run.attribute("Number").and_then(|n| n.parse().ok()),
+            flowcell: element("Flowcell"),
+            instrument: element("Instrument"),
+            date: element("Date").and_then(|d| parse_run_date(&d, tz)),
+        })
+    }
+}
+
+/// Older sequencers write `YYMMDD`, while newer ones write a date and time
+/// such as `1/15/2025 3:45:12 PM` or `2025-01-15T15:45:12Z`
+fn parse_run_date(date: &str, tz: &TimeZone) -> Option<Timestamp> {
+    if let Ok(timestamp) = date.parse() {
+        return Some(timestamp);
+    }
+
+    let datetime = date
+        .parse::<DateTime>()
+        .or_else(|_| DateTime::strptime("%m/%d/%Y %I:%M:%S %p", date))
+        .or_else(|_| Date::strptime("%y%m%d", date).map(|d| d.at(0, 0, 0, 0)))
+        .ok()?;
+
+    datetime.to_zoned(tz.clone()).ok().map(|z| z.timestamp())
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::{Timestamp, tz::TimeZone};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::{RunInfo, parse_run_date};
+
+    #[rstest]
+    #[case("250115", "2025-01-15T00:00:00Z")]
+    #[case("1/15/2025 3:45:12 PM", "2025-01-15T15:45:12Z")]
+    #[case("2025-01-15T15:45:12", "2025-01-15T15:45:12Z")]
+    #[case("2025-01-15T15:45:12+01:00", "2025-01-15T14:45:12Z")]
+    fn run_date(#[case] date: &str, #[case] expected: Timestamp) {
+        assert_eq!(parse_run_date(date, &TimeZone::UTC), Some(expected));
+    }
+
+    #[rstest]
+    #[case(r#"<Run Id="250115_A00123_0042_BHXXXXDSXC" Number="42">"#)]
+    #[case(r#"<Run Number="42" Id="250115_A00123_0042_BHXXXXDSXC">"#)]
+    fn run_info(#[case] run: &str) {
+        let contents = format!(
+            r#"<?xml version="1.0"?>
+<RunInfo Version="6">
+  {run}
+    <Flowcell>HXXXXDSXC</Flowcell>
+    <Instrument>A00123</Instrument>
+    <Date>1/15/2025 3:45:12 PM</Date>
+    <Reads>
+      <Read Number="1" NumCycles="28" IsIndexedRead="N" />
+    </Reads>
+  </Run>
+</RunInfo>"#
+        );
+
+        let expected = RunInfo {
+            id: "250115_A00123_0042_BHXXXXDSXC".to_owned(),
+            number: Some(42),
+            flowcell: Some("HXXXXDSXC".to_owned()),
+            instrument: Some("A00123".to_owned()),
+            date: Some("2025-01-15T15:45:12Z".parse().unwrap()),
+        };
+
+        assert_eq!(RunInfo::parse(&contents, &TimeZone::UTC).unwrap(), expected);
+    }
+}
@@ -0,0 +1,100 @@
+use anyhow::{Context, bail};
+use camino::{Utf8Path, Utf8PathBuf};
+use cellnoor_client::{Client, DatasetFile};
+use uuid::Uuid;
+
+const WEB_SUMMARY_FILENAME: &str = "web_summary.html";
+
+// `cellranger count` and `cellranger multi` write `metrics_summary.csv`, while
+// `cellranger-atac` and `cellranger-arc` write `summary.csv` and `summary.json`
+const METRICS_FILENAMES: &[&str] = &["metrics_summary.csv", "summary.csv", "summary.json"];
+
+/// Upload the web summaries and metrics files in a Cell Ranger `outs/`
+/// directory to a dataset. Each file is recorded under the name of the
+/// directory containing it, such as `outs` or the sample name in
+/// `outs/per_sample_outs`.
+#[derive(clap::Args)]
+pub(crate) struct UploadOuts {
+    dataset_id: Uuid,
+    outs_dir: Utf8PathBuf,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub(crate) struct UploadedFiles {
+    web_summaries: Vec<Utf8PathBuf>,
+    metrics_files: Vec<Utf8PathBuf>,
+}
+
+impl UploadOuts {
+    pub(crate) async fn run(self, client: &Client) -> anyhow::Result<UploadedFiles> {
+        let Self {
+            dataset_id,
+            outs_dir,
+        } = self;
+
+        let uploaded = find_files(&outs_dir)?;
+        if uploaded.web_summaries.is_empty() && uploaded.metrics_files.is_empty() {
+            bail!("no web summaries or metrics files found in {outs_dir}");
+        }
+
+        let read_all = |paths: &[Utf8PathBuf]| -> anyhow::Result<Vec<DatasetFile>> {
+            paths.iter().map(|p| dataset_file(p)).collect()
+        };
+
+        if !uploaded.web_summaries.is_empty() {
+            client
+                .upload_web_summaries(dataset_id, read_all(&uploaded.web_summaries)?)
+                .await?;
+        }
+        if !uploaded.metrics_files.is_empty() {
+            client
+                .upload_metrics_files(dataset_id, read_all(&uploaded.metrics_files)?)
+                .await?;
+        }
+
+        Ok(uploaded)
+    }
+}
+
+fn find_files(dir: &Utf8Path) -> anyhow::Result<UploadedFiles> {
+    let mut found = UploadedFiles::default();
+    let mut dirs = vec![dir.to_owned()];
+
+    while let Some(dir) = dirs.pop() {
+        for entry in dir
+            .read_dir_utf8()
+            .context(format!("failed to read directory {dir}"))?
+        {
+            let entry = entry?;
+            let path = entry.path().to_owned();
+
+            if entry.file_type()?.is_dir() {
+                dirs.push(path);
+            } else if entry.file_name() == WEB_SUMMARY_FILENAME {
+                found.web_summaries.push(path);
+            } else if METRICS_FILENAMES.contains(&entry.file_name()) {
+                found.metrics_files.push(path);
+            }
+        }
+    }
+
+    found.web_summaries.sort();
+    found.metrics_files.sort();
+
+    Ok(found)
+}
+
+fn dataset_file(path: &Utf8Path) -> anyhow::Result<DatasetFile> {
+    let (Some(directory), Some(filename)) = (
+        path.parent().and_then(Utf8Path::file_name),
+        path.file_name(),
+    ) else {
+        bail!("{path} must be inside a directory");
+    };
+
+    Ok(DatasetFile {
+        directory: directory.to_owned(),
+        filename: filename.to_owned(),
+        content: std::fs::read(path).context(format!("failed to read {path}"))?,
+    })
+}
@@ -0,0 +1,53 @@
+use anyhow::{Context, bail};
+use camino::{Utf8Path, Utf8PathBuf};
+use cellnoor_client::Client;
+use url::Url;
+
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    api_url: Option<Url>,
+    api_key: Option<String>,
+}
+
+impl ConfigFile {
+    fn read(path: &Utf8Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .context(format!("failed to read contents of file {path}"))?;
+
+        toml::from_str(&contents).context(format!("failed to parse {path} as TOML"))
+    }
+}
+
+fn default_config_path() -> Option<Utf8PathBuf> {
+    let config_dir = std::env::var("XDG_CONFIG_HOME")
+        .map(Utf8PathBuf::from)
+        .or_else(|_| std::env::var("HOME").map(|home| Utf8PathBuf::from(home).join(".config")))
+        .ok()?;
+
+    Some(config_dir.join("cellnoor").join("config.toml"))
+}
+
+/// Values given on the command-line or in the environment take precedence
+/// over those in the configuration file. A missing configuration file is only
+/// an error if its path was given explicitly.
+pub(crate) fn client(
+    api_url: Option<Url>,
+    api_key: Option<String>,
+    config_path: Option<&Utf8Path>,
+) -> anyhow::Result<Client> {
+    let config_file = match (config_path, default_config_path()) {
+        (Some(path), _) => ConfigFile::read(path)?,
+        (None, Some(path)) if path.exists() => ConfigFile::read(&path)?,
+        (None, _) => ConfigFile::default(),
+    };
+
+    let Some(api_url) = api_url.or(config_file.api_url) else {
+        bail!("API URL must be given by --api-url, CELLNOOR_API_URL, or the configuration file");
+    };
+    let Some(api_key) = api_key.or(config_file.api_key) else {
+        bail!("API key must be given by --api-key, CELLNOOR_API_KEY, or the configuration file");
+    };
+
+    Ok(Client::builder().base_url(api_url).api_key(api_key).build())
+}
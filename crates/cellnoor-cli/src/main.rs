@@ -0,0 +1,66 @@
+use camino::Utf8PathBuf;
+use clap::{Parser, Subcommand};
+use url::Url;
+
+use crate::{
+    commands::{
+        api_keys::ApiKeyCommand, create_dataset::CreateDataset, export::Export,
+        register_sequencing_run::RegisterSequencingRun, upload_outs::UploadOuts,
+    },
+    output::OutputFormat,
+};
+
+mod commands;
+mod config;
+mod output;
+
+/// Run common pipeline and administrative tasks against the cellnoor API
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// The root of the API, such as `https://cellnoor.example.org/api`
+    #[arg(long, env = "CELLNOOR_API_URL", global = true)]
+    api_url: Option<Url>,
+    #[arg(long, env = "CELLNOOR_API_KEY", global = true, hide_env_values = true)]
+    api_key: Option<String>,
+    /// A TOML file with `api_url` and `api_key`, which are overridden by the
+    /// corresponding flags or environment variables. Defaults to
+    /// `$XDG_CONFIG_HOME/cellnoor/config.toml`.
+    #[arg(long, env = "CELLNOOR_CLI_CONFIG", global = true)]
+    config: Option<Utf8PathBuf>,
+    #[arg(short, long, value_enum, default_value_t, global = true)]
+    output: OutputFormat,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    UploadOuts(UploadOuts),
+    CreateDataset(CreateDataset),
+    RegisterSequencingRun(RegisterSequencingRun),
+    Export(Export),
+    #[command(subcommand)]
+    ApiKeys(ApiKeyCommand),
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let Cli {
+        api_url,
+        api_key,
+        config,
+        output,
+        command,
+    } = Cli::parse();
+
+    let client = config::client(api_url, api_key, config.as_deref())?;
+
+    match command {
+        Command::UploadOuts(args) => output.print(&args.run(&client).await?),
+        Command::CreateDataset(args) => output.print(&args.run(&client).await?),
+        Command::RegisterSequencingRun(args) => output.print(&args.run(&client).await?),
+        Command::Export(args) => args.run(&client).await,
+        Command::ApiKeys(command) => command.run(&client, output).await,
+    }
+}
@@ -0,0 +1,119 @@
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Clone, Copy, Debug, Default, clap::ValueEnum)]
+pub(crate) enum OutputFormat {
+    #[default]
+    Json,
+    Table,
+}
+
+impl OutputFormat {
+    pub(crate) fn print<T: Serialize>(self, value: &T) -> anyhow::Result<()> {
+        let value = serde_json::to_value(value)?;
+
+        match self {
+            Self::Json => println!("{}", serde_json::to_string_pretty(&value)?),
+            Self::Table => print!("{}", table(&value)),
+        }
+
+        Ok(())
+    }
+}
+
+/// Arrays of objects, including the `items` of a page, become one row per
+/// object. A single object becomes a row per field. Nested values are written
+/// as JSON.
+fn table(value: &Value) -> String {
+    let items = match value {
+        Value::Array(items) => items.as_slice(),
+        Value::Object(object) => {
+            if let Some(Value::Array(items)) = object.get("items") {
+                items.as_slice()
+            } else {
+                let rows = object
+                    .iter()
+                    .map(|(key, value)| vec![key.clone(), cell(value)])
+                    .collect();
+                return render(&["field".to_owned(), "value".to_owned()], rows);
+            }
+        }
+        value => return format!("{}\n", cell(value)),
+    };
+
+    let Some(Value::Object(first)) = items.first() else {
+        let rows = items.iter().map(|item| vec![cell(item)]).collect();
+        return render(&["value".to_owned()], rows);
+    };
+
+    let columns: Vec<String> = first.keys().cloned().collect();
+    let rows = items
+        .iter()
+        .map(|item| {
+            columns
+                .iter()
+                .map(|column| item.get(column).map(cell).unwrap_or_default())
+                .collect()
+        })
+        .collect();
+
+    render(&columns, rows)
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn render(columns: &[String], rows: Vec<Vec<String>>) -> String {
+    let mut widths: Vec<usize> = columns.iter().map(|c| c.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut rendered = String::new();
+    for row in std::iter::once(columns.to_vec()).chain(rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect();
+        rendered.push_str(line.join("  ").trim_end());
+        rendered.push('\n');
+    }
+
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::table;
+
+    #[rstest]
+    fn page_table() {
+        let page = json!({
+            "items": [
+                { "name": "PBMC-01", "tissue": "blood", "links": { "self": "/specimens/1" } },
+                { "name": "LN-2", "tissue": null, "links": { "self": "/specimens/2" } },
+            ],
+            "next_cursor": null,
+        });
+
+        let expected = "\
+links                    name     tissue
+{\"self\":\"/specimens/1\"}  PBMC-01  blood
+{\"self\":\"/specimens/2\"}  LN-2
+";
+
+        assert_eq!(table(&page), expected);
+    }
+}
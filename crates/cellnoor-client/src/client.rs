@@ -0,0 +1,288 @@
+use reqwest::{Method, RequestBuilder, Response, header::ACCEPT, multipart::Form};
+use serde::{Serialize, de::DeserializeOwned};
+use url::Url;
+
+use crate::error::{ApiErrorResponse, Error};
+
+pub const API_KEY_HEADER: &str = "X-API-Key";
+const LAST_EVENT_ID_HEADER: &str = "Last-Event-ID";
+
+/// A format that list endpoints can stream every matching row in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Tsv,
+    Ndjson,
+}
+
+impl ExportFormat {
+    #[must_use]
+    pub fn media_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Tsv => "text/tab-separated-values",
+            Self::Ndjson => "application/x-ndjson",
+        }
+    }
+}
+
+/// A client for the cellnoor API. Cloning is cheap, since the underlying
+/// [`reqwest::Client`] is reference-counted.
+#[derive(Clone, Debug)]
+pub struct Client {
+    http: reqwest::Client,
+    base_url: Url,
+    api_key: String,
+}
+
+#[bon::bon]
+impl Client {
+    /// `base_url` is the root of the API, such as
+    /// `https://cellnoor.example.org/api`
+    #[builder(on(String, into))]
+    pub fn new(
+        base_url: Url,
+        api_key: String,
+        #[builder(default)] http_client: reqwest::Client,
+    ) -> Self {
+        Self {
+            http: http_client,
+            base_url,
+            api_key,
+        }
+    }
+}
+
+impl Client {
+    fn url(&self, path: &str) -> Url {
+        let mut url = self.base_url.clone();
+        let base_path = url.path().trim_end_matches('/').to_owned();
+        url.set_path(&format!("{base_path}{path}"));
+
+        url
+    }
+
+    fn request(&self, method: Method, url: Url) -> RequestBuilder {
+        self.http
+            .request(method, url)
+            .header(API_KEY_HEADER, &self.api_key)
+    }
+
+    /// The API parses query strings with `serde_qs`, so nested filters must be
+    /// encoded as `filter[names][0]=...` rather than by `reqwest`'s
+    /// `serde_urlencoded`
+    fn url_with_query<Q: Serialize>(&self, path: &str, query: &Q) -> Result<Url, Error> {
+        let mut url = self.url(path);
+        let query_string = query_string(query)?;
+        if !query_string.is_empty() {
+            url.set_query(Some(&query_string));
+        }
+
+        Ok(url)
+    }
+
+    /// `GET` an arbitrary path. This is useful when `fields` or `include` are
+    /// set, since they change the shape of the response - deserialize into a
+    /// [`serde_json::Value`] or a type of your own.
+    pub async fn get<Q, T>(&self, path: &str, query: &Q) -> Result<T, Error>
+    where
+        Q: Serialize,
+        T: DeserializeOwned,
+    {
+        let url = self.url_with_query(path, query)?;
+        send(self.request(Method::GET, url)).await
+    }
+
+    /// Export every row of a list endpoint, such as `/specimens`, that matches
+    /// `query`. The body is streamed, so read it with [`Response::chunk`]
+    /// rather than buffering it.
+    pub async fn export<Q: Serialize>(
+        &self,
+        path: &str,
+        query: &Q,
+        format: ExportFormat,
+    ) -> Result<Response, Error> {
+        let url = self.url_with_query(path, query)?;
+        check_status(
+            self.request(Method::GET, url)
+                .header(ACCEPT, format.media_type()),
+        )
+        .await
+    }
+
+    pub(crate) async fn get_event_stream<Q: Serialize>(
+        &self,
+        path: &str,
+        query: &Q,
+        last_event_id: Option<&str>,
+    ) -> Result<Response, Error> {
+        let url = self.url_with_query(path, query)?;
+        let mut request = self
+            .request(Method::GET, url)
+            .header(ACCEPT, "text/event-stream");
+        if let Some(last_event_id) = last_event_id {
+            request = request.header(LAST_EVENT_ID_HEADER, last_event_id);
+        }
+
+        check_status(request).await
+    }
+
+    pub(crate) async fn get_without_query<T: DeserializeOwned>(
+        &self,
+        path: &str,
+    ) -> Result<T, Error> {
+        send(self.request(Method::GET, self.url(path))).await
+    }
+
+    pub(crate) async fn get_text(&self, path: &str) -> Result<String, Error> {
+        let response = check_status(self.request(Method::GET, self.url(path))).await?;
+        Ok(response.text().await?)
+    }
+
+    pub(crate) async fn post<B, T>(&self, path: &str, body: &B) -> Result<T, Error>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        send(self.request(Method::POST, self.url(path)).json(body)).await
+    }
+
+    pub(crate) async fn post_multipart(&self, path: &str, form: Form) -> Result<(), Error> {
+        send(self.request(Method::POST, self.url(path)).multipart(form)).await
+    }
+
+    pub(crate) async fn patch<B, T>(&self, path: &str, body: &B) -> Result<T, Error>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        send(self.request(Method::PATCH, self.url(path)).json(body)).await
+    }
+
+    pub(crate) async fn delete(&self, path: &str) -> Result<(), Error> {
+        send(self.request(Method::DELETE, self.url(path))).await
+    }
+}
+
+fn query_string<Q: Serialize>(query: &Q) -> Result<String, serde_qs::Error> {
+    serde_qs::to_string(query)
+}
+
+async fn check_status(request: RequestBuilder) -> Result<Response, Error> {
+    let response = request.send().await?;
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let body = response.bytes().await?;
+
+    match serde_json::from_slice::<ApiErrorResponse>(&body) {
+        Ok(err) => Err(err.into()),
+        Err(_) => Err(Error::UnexpectedResponse {
+            status: status.as_u16(),
+            body: String::from_utf8_lossy(&body).into_owned(),
+        }),
+    }
+}
+
+async fn send<T: DeserializeOwned>(request: RequestBuilder) -> Result<T, Error> {
+    let response = check_status(request).await?;
+    Ok(response.json().await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use cellnoor_models::{
+        specimen::{SpecimenFilter, SpecimenInclude, SpecimenOrderBy, SpecimenQuery},
+        stats::{SpecimenGroupBy, SpecimenStatsQuery, TimeBucket},
+    };
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use url::Url;
+    use uuid::Uuid;
+
+    use super::Client;
+    use crate::error::{ApiError, ApiErrorResponse, DatabaseError};
+
+    // How the API parses query strings
+    fn server_config() -> serde_qs::Config {
+        serde_qs::Config::new().use_form_encoding(true)
+    }
+
+    #[rstest]
+    fn url_keeps_base_path(#[values("http://localhost/api", "http://localhost/api/")] base: &str
+    ) {
+        let client = Client::builder()
+            .base_url(Url::parse(base).unwrap())
+            .api_key("key")
+            .build();
+
+        let url = client
+            .url_with_query("/specimens", &SpecimenQuery::default())
+            .unwrap();
+
+        assert_eq!(url.path(), "/api/specimens");
+    }
+
+    #[rstest]
+    fn specimen_query_round_trips() {
+        let query = SpecimenQuery::builder()
+            .filter(
+                SpecimenFilter::builder()
+                    .names(vec!["a specimen".to_owned(), "another&one".to_owned()])
+                    .labs(vec![Uuid::from_u128(1)])
+                    .build(),
+            )
+            .order_by(SpecimenOrderBy::name {
+                descending: Some(true),
+            })
+            .include(vec![SpecimenInclude::Lab])
+            .limit(10)
+            .build();
+
+        let url = Client::builder()
+            .base_url(Url::parse("http://localhost/api").unwrap())
+            .api_key("key")
+            .build()
+            .url_with_query("/specimens", &query)
+            .unwrap();
+
+        let parsed: SpecimenQuery = server_config()
+            .deserialize_str(url.query().unwrap())
+            .unwrap();
+
+        assert_eq!(parsed, query);
+    }
+
+    #[rstest]
+    fn stats_query_round_trips() {
+        let query = SpecimenStatsQuery {
+            filter: Some(
+                SpecimenFilter::builder()
+                    .tissues(vec!["blood".to_owned()])
+                    .build(),
+            ),
+            group_by: Some(SpecimenGroupBy::Lab),
+            bucket: Some(TimeBucket::Month),
+        };
+
+        let parsed: SpecimenStatsQuery = server_config()
+            .deserialize_str(&super::query_string(&query).unwrap())
+            .unwrap();
+
+        assert_eq!(parsed, query);
+    }
+
+    #[rstest]
+    fn parse_error_response() {
+        let body = r#"{"status":404,"type":"database","info":{"type":"resource_not_found","info":{"resource":"specimen","resource_id":"00000000-0000-0000-0000-000000000001"}}}"#;
+        let response: ApiErrorResponse = serde_json::from_str(body).unwrap();
+
+        assert_eq!(response.status, 404);
+        assert!(matches!(
+            response.error,
+            ApiError::Database(DatabaseError::ResourceNotFound { resource_id, .. }) if resource_id == Uuid::from_u128(1)
+        ));
+    }
+}
@@ -0,0 +1,83 @@
+use serde_json::Value;
+use uuid::Uuid;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The API responded with an `ApiErrorResponse`
+    #[error(transparent)]
+    Api(#[from] ApiErrorResponse),
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+    #[error("failed to serialize query string: {0}")]
+    Query(#[from] serde_qs::Error),
+    /// A server-sent event whose data isn't the expected JSON
+    #[error("failed to parse event: {0}")]
+    Event(#[from] serde_json::Error),
+    /// The API responded with an error that isn't an `ApiErrorResponse`, such
+    /// as one from a reverse proxy
+    #[error("unexpected response with status {status}: {body}")]
+    UnexpectedResponse { status: u16, body: String },
+}
+
+/// The body of every error response from the API
+#[derive(Debug, thiserror::Error, serde::Deserialize)]
+#[error("{status}: {error}")]
+pub struct ApiErrorResponse {
+    pub status: u16,
+    #[serde(flatten)]
+    pub error: ApiError,
+    /// The `X-Request-Id` of the failed request, to quote when asking for help
+    #[serde(default)]
+    pub request_id: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error, serde::Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type", content = "info")]
+pub enum ApiError {
+    #[error(transparent)]
+    Auth(AuthError),
+    /// A validation error, whose shape depends on the resource being created
+    #[error("invalid data: {0}")]
+    Data(Value),
+    #[error(transparent)]
+    Database(DatabaseError),
+    #[error("{message}")]
+    MalformedRequest { message: String },
+    /// The caller has made too many requests and should wait before retrying
+    #[error("too many requests, retry after {retry_after_secs} seconds")]
+    RateLimited { retry_after_secs: u32 },
+    #[error("something went wrong")]
+    Other,
+}
+
+#[derive(Debug, thiserror::Error, serde::Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type", content = "info")]
+pub enum AuthError {
+    #[error("{message}")]
+    Unauthorized { message: String },
+    #[error(transparent)]
+    Database(DatabaseError),
+}
+
+#[derive(Debug, thiserror::Error, serde::Deserialize)]
+#[serde(rename_all = "snake_case", tag = "type", content = "info")]
+pub enum DatabaseError {
+    #[error("failed to find {resource} with ID {resource_id}")]
+    ResourceNotFound { resource: String, resource_id: Uuid },
+    #[error("{message}")]
+    Data { message: String },
+    #[error("duplicate {resource} with fields {fields:?} and values {values:?}")]
+    DuplicateResource {
+        resource: String,
+        fields: Vec<String>,
+        values: Vec<String>,
+    },
+    #[error("invalid reference from {resource} to {referenced_resource} with value: {}", value.clone().unwrap_or_default())]
+    InvalidReference {
+        resource: String,
+        referenced_resource: String,
+        value: Option<String>,
+    },
+    #[error("{message}")]
+    Other { message: String },
+}
@@ -0,0 +1,11 @@
+//! A typed client for the cellnoor API, built on the request and response
+//! types of [`cellnoor_models`]
+
+mod client;
+mod error;
+mod routes;
+
+pub use cellnoor_models as models;
+pub use client::{API_KEY_HEADER, Client, ExportFormat};
+pub use error::{ApiError, ApiErrorResponse, AuthError, DatabaseError, Error};
+pub use routes::{chromium_datasets::DatasetFile, events::EventStream};
@@ -0,0 +1,23 @@
+mod api_keys;
+mod cdna;
+pub(crate) mod chromium_datasets;
+mod chromium_runs;
+mod committee_approvals;
+mod donors;
+pub(crate) mod events;
+mod gem_pools;
+mod institutions;
+mod labs;
+mod libraries;
+mod multiplexing_tags;
+mod people;
+mod projects;
+mod search;
+mod sequencing_runs;
+mod specimens;
+mod stats;
+mod storage;
+mod suspension_pools;
+mod suspensions;
+mod tenx_assays;
+mod webhooks;
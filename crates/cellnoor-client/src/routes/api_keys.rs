@@ -0,0 +1,19 @@
+use cellnoor_models::api_key::{ApiKey, CreatedApiKey};
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_api_keys(&self) -> Result<Vec<ApiKey>, Error> {
+        self.get_without_query("/api-keys").await
+    }
+
+    /// Create an API key for the requesting user. The key is only returned
+    /// once.
+    pub async fn create_api_key(&self) -> Result<CreatedApiKey, Error> {
+        self.post("/api-keys", &()).await
+    }
+
+    pub async fn delete_api_key(&self, prefix: &str) -> Result<(), Error> {
+        self.delete(&format!("/api-keys/{prefix}")).await
+    }
+}
@@ -0,0 +1,43 @@
+use cellnoor_models::{
+    cdna::{
+        Cdna, CdnaCreation, CdnaQuery, CdnaSummary,
+        measurement::{CdnaMeasurement, CdnaMeasurementCreation},
+    },
+    generic_query::Page,
+    read_options::{NoInclude, ReadOptions},
+};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_cdna(&self, query: &CdnaQuery) -> Result<Page<CdnaSummary>, Error> {
+        self.get("/cdna", query).await
+    }
+
+    pub async fn create_cdna(&self, cdna: &CdnaCreation) -> Result<Cdna, Error> {
+        self.post("/cdna", cdna).await
+    }
+
+    pub async fn fetch_cdna(
+        &self,
+        id: Uuid,
+        options: &ReadOptions<NoInclude>,
+    ) -> Result<Cdna, Error> {
+        self.get(&format!("/cdna/{id}"), options).await
+    }
+
+    pub async fn list_cdna_measurements(&self, id: Uuid) -> Result<Vec<CdnaMeasurement>, Error> {
+        self.get_without_query(&format!("/cdna/{id}/measurements"))
+            .await
+    }
+
+    pub async fn create_cdna_measurement(
+        &self,
+        id: Uuid,
+        measurement: &CdnaMeasurementCreation,
+    ) -> Result<CdnaMeasurement, Error> {
+        self.post(&format!("/cdna/{id}/measurements"), measurement)
+            .await
+    }
+}
@@ -0,0 +1,156 @@
+use std::path::Path;
+
+use cellnoor_models::{
+    chromium_dataset::{
+        ChromiumDataset, ChromiumDatasetCreation, ChromiumDatasetInclude, ChromiumDatasetQuery,
+        ChromiumDatasetSummary, metrics::ParsedMetricsData,
+    },
+    generic_query::Page,
+    library::{LibraryQuery, LibrarySummary},
+    lineage::Lineage,
+    read_options::ReadOptions,
+    specimen::{SpecimenQuery, SpecimenSummary},
+};
+use reqwest::multipart::{Form, Part};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+/// A file produced by a pipeline run, such as `web_summary.html` in the
+/// `sample1` output directory
+#[derive(Clone, Debug)]
+pub struct DatasetFile {
+    pub directory: String,
+    pub filename: String,
+    pub content: Vec<u8>,
+}
+
+impl DatasetFile {
+    fn into_part(self, content_type: &str) -> Result<Part, Error> {
+        let Self {
+            directory,
+            filename,
+            content,
+        } = self;
+
+        Ok(Part::bytes(content)
+            .file_name(format!("{directory}/{filename}"))
+            .mime_str(content_type)?)
+    }
+
+    /// Metrics files are either CSV or JSON, and the API distinguishes them
+    /// by content type
+    fn metrics_content_type(&self) -> &'static str {
+        let is_csv = Path::new(&self.filename)
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("csv"));
+
+        if is_csv {
+            "text/csv"
+        } else {
+            "application/json"
+        }
+    }
+}
+
+impl Client {
+    pub async fn list_chromium_datasets(
+        &self,
+        query: &ChromiumDatasetQuery,
+    ) -> Result<Page<ChromiumDatasetSummary>, Error> {
+        self.get("/chromium-datasets", query).await
+    }
+
+    pub async fn create_chromium_dataset(
+        &self,
+        dataset: &ChromiumDatasetCreation,
+    ) -> Result<ChromiumDataset, Error> {
+        self.post("/chromium-datasets", dataset).await
+    }
+
+    pub async fn fetch_chromium_dataset(
+        &self,
+        id: Uuid,
+        options: &ReadOptions<ChromiumDatasetInclude>,
+    ) -> Result<ChromiumDataset, Error> {
+        self.get(&format!("/chromium-datasets/{id}"), options).await
+    }
+
+    pub async fn list_chromium_dataset_libraries(
+        &self,
+        id: Uuid,
+        query: &LibraryQuery,
+    ) -> Result<Page<LibrarySummary>, Error> {
+        self.get(&format!("/chromium-datasets/{id}/libraries"), query)
+            .await
+    }
+
+    pub async fn list_chromium_dataset_specimens(
+        &self,
+        id: Uuid,
+        query: &SpecimenQuery,
+    ) -> Result<Page<SpecimenSummary>, Error> {
+        self.get(&format!("/chromium-datasets/{id}/specimens"), query)
+            .await
+    }
+
+    /// Use [`Lineage::to_dot`] for a Graphviz rendering
+    pub async fn fetch_chromium_dataset_lineage(&self, id: Uuid) -> Result<Lineage, Error> {
+        self.get_without_query(&format!("/chromium-datasets/{id}/lineage"))
+            .await
+    }
+
+    pub async fn upload_web_summaries(
+        &self,
+        id: Uuid,
+        files: impl IntoIterator<Item = DatasetFile>,
+    ) -> Result<(), Error> {
+        let mut form = Form::new();
+        for file in files {
+            form = form.part("file", file.into_part("text/html")?);
+        }
+
+        self.post_multipart(&format!("/chromium-datasets/{id}/web-summaries"), form)
+            .await
+    }
+
+    pub async fn fetch_web_summary(
+        &self,
+        id: Uuid,
+        directory: &str,
+        filename: &str,
+    ) -> Result<String, Error> {
+        self.get_text(&format!(
+            "/chromium-datasets/{id}/web-summaries/{directory}/{filename}"
+        ))
+        .await
+    }
+
+    /// Files ending in `.csv` are uploaded as CSV, and everything else as JSON
+    pub async fn upload_metrics_files(
+        &self,
+        id: Uuid,
+        files: impl IntoIterator<Item = DatasetFile>,
+    ) -> Result<(), Error> {
+        let mut form = Form::new();
+        for file in files {
+            let content_type = file.metrics_content_type();
+            form = form.part("file", file.into_part(content_type)?);
+        }
+
+        self.post_multipart(&format!("/chromium-datasets/{id}/metrics-files"), form)
+            .await
+    }
+
+    pub async fn fetch_metrics_file(
+        &self,
+        id: Uuid,
+        directory: &str,
+        filename: &str,
+    ) -> Result<ParsedMetricsData, Error> {
+        self.get_without_query(&format!(
+            "/chromium-datasets/{id}/metrics-files/{directory}/{filename}"
+        ))
+        .await
+    }
+}
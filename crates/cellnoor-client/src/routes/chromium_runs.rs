@@ -0,0 +1,32 @@
+use cellnoor_models::{
+    chromium_run::{ChromiumRun, ChromiumRunCreation, ChromiumRunQuery, ChromiumRunSummary},
+    generic_query::Page,
+    read_options::{NoInclude, ReadOptions},
+};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_chromium_runs(
+        &self,
+        query: &ChromiumRunQuery,
+    ) -> Result<Page<ChromiumRunSummary>, Error> {
+        self.get("/chromium-runs", query).await
+    }
+
+    pub async fn create_chromium_run(
+        &self,
+        run: &ChromiumRunCreation,
+    ) -> Result<ChromiumRun, Error> {
+        self.post("/chromium-runs", run).await
+    }
+
+    pub async fn fetch_chromium_run(
+        &self,
+        id: Uuid,
+        options: &ReadOptions<NoInclude>,
+    ) -> Result<ChromiumRun, Error> {
+        self.get(&format!("/chromium-runs/{id}"), options).await
+    }
+}
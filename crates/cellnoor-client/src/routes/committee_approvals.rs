@@ -0,0 +1,49 @@
+use cellnoor_models::{
+    committee_approval::{
+        CommitteeApproval, CommitteeApprovalCreation, CommitteeApprovalQuery,
+        CommitteeApprovalSummary, CommitteeApprovalUpdate,
+    },
+    generic_query::Page,
+    read_options::{NoInclude, ReadOptions},
+};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_committee_approvals(
+        &self,
+        query: &CommitteeApprovalQuery,
+    ) -> Result<Page<CommitteeApprovalSummary>, Error> {
+        self.get("/committee-approvals", query).await
+    }
+
+    pub async fn create_committee_approval(
+        &self,
+        approval: &CommitteeApprovalCreation,
+    ) -> Result<CommitteeApproval, Error> {
+        self.post("/committee-approvals", approval).await
+    }
+
+    pub async fn fetch_committee_approval(
+        &self,
+        id: Uuid,
+        options: &ReadOptions<NoInclude>,
+    ) -> Result<CommitteeApproval, Error> {
+        self.get(&format!("/committee-approvals/{id}"), options)
+            .await
+    }
+
+    pub async fn update_committee_approval(
+        &self,
+        id: Uuid,
+        update: &CommitteeApprovalUpdate,
+    ) -> Result<CommitteeApproval, Error> {
+        self.patch(&format!("/committee-approvals/{id}"), update)
+            .await
+    }
+
+    pub async fn delete_committee_approval(&self, id: Uuid) -> Result<(), Error> {
+        self.delete(&format!("/committee-approvals/{id}")).await
+    }
+}
@@ -0,0 +1,30 @@
+use cellnoor_models::{
+    donor::{Donor, DonorCreation, DonorQuery, DonorSummary, DonorUpdate},
+    generic_query::Page,
+    read_options::{NoInclude, ReadOptions},
+};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_donors(&self, query: &DonorQuery) -> Result<Page<DonorSummary>, Error> {
+        self.get("/donors", query).await
+    }
+
+    pub async fn create_donor(&self, donor: &DonorCreation) -> Result<Donor, Error> {
+        self.post("/donors", donor).await
+    }
+
+    pub async fn fetch_donor(
+        &self,
+        id: Uuid,
+        options: &ReadOptions<NoInclude>,
+    ) -> Result<Donor, Error> {
+        self.get(&format!("/donors/{id}"), options).await
+    }
+
+    pub async fn update_donor(&self, id: Uuid, update: &DonorUpdate) -> Result<Donor, Error> {
+        self.patch(&format!("/donors/{id}"), update).await
+    }
+}
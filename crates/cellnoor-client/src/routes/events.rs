@@ -0,0 +1,108 @@
+use cellnoor_models::change_event::{ChangeEvent, ChangeEventFilter};
+use reqwest::Response;
+
+use crate::{Client, Error};
+
+impl Client {
+    /// Stream inserts and updates as they're committed. Pass the
+    /// [`EventStream::last_event_id`] of an earlier stream to first receive the
+    /// events it missed.
+    pub async fn stream_events(
+        &self,
+        filter: &ChangeEventFilter,
+        last_event_id: Option<&str>,
+    ) -> Result<EventStream, Error> {
+        let response = self
+            .get_event_stream("/events", filter, last_event_id)
+            .await?;
+
+        Ok(EventStream {
+            response,
+            buffer: String::new(),
+            last_event_id: last_event_id.map(str::to_owned),
+        })
+    }
+}
+
+/// Server-sent change events, read one at a time with [`EventStream::next`]
+#[derive(Debug)]
+pub struct EventStream {
+    response: Response,
+    buffer: String,
+    last_event_id: Option<String>,
+}
+
+impl EventStream {
+    /// The next event, or `None` once the server ends the stream, which it does
+    /// when shutting down
+    pub async fn next(&mut self) -> Result<Option<ChangeEvent>, Error> {
+        loop {
+            while let Some(end) = self.buffer.find("\n\n") {
+                let block: String = self.buffer.drain(..end + 2).collect();
+                let (id, data) = parse_block(&block);
+
+                // Keep-alives are comments, so they have no data
+                let Some(data) = data else {
+                    continue;
+                };
+
+                if let Some(id) = id {
+                    self.last_event_id = Some(id.to_owned());
+                }
+
+                return Ok(Some(serde_json::from_str(&data)?));
+            }
+
+            match self.response.chunk().await? {
+                Some(chunk) => self.buffer.push_str(&String::from_utf8_lossy(&chunk)),
+                None => return Ok(None),
+            }
+        }
+    }
+
+    /// The ID of the most recent event, to resume from after reconnecting
+    #[must_use]
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+}
+
+/// The `id` and the joined `data` lines of one event
+fn parse_block(block: &str) -> (Option<&str>, Option<String>) {
+    let mut id = None;
+    let mut data: Option<String> = None;
+
+    for line in block.lines() {
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+
+        match field {
+            "id" => id = Some(value),
+            "data" => match &mut data {
+                Some(data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => data = Some(value.to_owned()),
+            },
+            _ => {}
+        }
+    }
+
+    (id, data)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::parse_block;
+
+    #[rstest]
+    #[case::event("id: 3\ndata: {\"a\":\ndata: 1}\n\n", (Some("3"), Some("{\"a\":\n1}".to_owned())))]
+    #[case::keep_alive(":\n\n", (None, None))]
+    fn parse_event_block(#[case] block: &str, #[case] expected: (Option<&str>, Option<String>)) {
+        assert_eq!(parse_block(block), expected);
+    }
+}
@@ -0,0 +1,25 @@
+use cellnoor_models::{
+    chromium_run::{GemPool, GemPoolQuery, GemPoolSummary},
+    generic_query::Page,
+    read_options::{NoInclude, ReadOptions},
+};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_gem_pools(
+        &self,
+        query: &GemPoolQuery,
+    ) -> Result<Page<GemPoolSummary>, Error> {
+        self.get("/gem-pools", query).await
+    }
+
+    pub async fn fetch_gem_pool(
+        &self,
+        id: Uuid,
+        options: &ReadOptions<NoInclude>,
+    ) -> Result<GemPool, Error> {
+        self.get(&format!("/gem-pools/{id}"), options).await
+    }
+}
@@ -0,0 +1,50 @@
+use cellnoor_models::{
+    generic_query::Page,
+    institution::{Institution, InstitutionCreation, InstitutionQuery, InstitutionUpdate},
+    person::{PersonQuery, PersonSummary},
+    read_options::{NoInclude, ReadOptions},
+};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_institutions(
+        &self,
+        query: &InstitutionQuery,
+    ) -> Result<Page<Institution>, Error> {
+        self.get("/institutions", query).await
+    }
+
+    pub async fn create_institution(
+        &self,
+        institution: &InstitutionCreation,
+    ) -> Result<Institution, Error> {
+        self.post("/institutions", institution).await
+    }
+
+    pub async fn fetch_institution(
+        &self,
+        id: Uuid,
+        options: &ReadOptions<NoInclude>,
+    ) -> Result<Institution, Error> {
+        self.get(&format!("/institutions/{id}"), options).await
+    }
+
+    pub async fn update_institution(
+        &self,
+        id: Uuid,
+        update: &InstitutionUpdate,
+    ) -> Result<Institution, Error> {
+        self.patch(&format!("/institutions/{id}"), update).await
+    }
+
+    pub async fn list_institution_members(
+        &self,
+        id: Uuid,
+        query: &PersonQuery,
+    ) -> Result<Page<PersonSummary>, Error> {
+        self.get(&format!("/institutions/{id}/members"), query)
+            .await
+    }
+}
@@ -0,0 +1,16 @@
+use cellnoor_models::{
+    generic_query::Page,
+    lab::{Lab, LabCreation, LabQuery, LabSummary},
+};
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_labs(&self, query: &LabQuery) -> Result<Page<LabSummary>, Error> {
+        self.get("/labs", query).await
+    }
+
+    pub async fn create_lab(&self, lab: &LabCreation) -> Result<Lab, Error> {
+        self.post("/labs", lab).await
+    }
+}
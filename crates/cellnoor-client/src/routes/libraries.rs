@@ -0,0 +1,49 @@
+use cellnoor_models::{
+    generic_query::Page,
+    library::{
+        Library, LibraryCreation, LibraryQuery, LibrarySummary,
+        measurement::{LibraryMeasurement, LibraryMeasurementCreation},
+    },
+    read_options::{NoInclude, ReadOptions},
+};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_libraries(
+        &self,
+        query: &LibraryQuery,
+    ) -> Result<Page<LibrarySummary>, Error> {
+        self.get("/libraries", query).await
+    }
+
+    pub async fn create_library(&self, library: &LibraryCreation) -> Result<Library, Error> {
+        self.post("/libraries", library).await
+    }
+
+    pub async fn fetch_library(
+        &self,
+        id: Uuid,
+        options: &ReadOptions<NoInclude>,
+    ) -> Result<Library, Error> {
+        self.get(&format!("/libraries/{id}"), options).await
+    }
+
+    pub async fn list_library_measurements(
+        &self,
+        id: Uuid,
+    ) -> Result<Vec<LibraryMeasurement>, Error> {
+        self.get_without_query(&format!("/libraries/{id}/measurements"))
+            .await
+    }
+
+    pub async fn create_library_measurement(
+        &self,
+        id: Uuid,
+        measurement: &LibraryMeasurementCreation,
+    ) -> Result<LibraryMeasurement, Error> {
+        self.post(&format!("/libraries/{id}/measurements"), measurement)
+            .await
+    }
+}
@@ -0,0 +1,9 @@
+use cellnoor_models::multiplexing_tag::MultiplexingTag;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_multiplexing_tags(&self) -> Result<Vec<MultiplexingTag>, Error> {
+        self.get_without_query("/multiplexing-tags").await
+    }
+}
@@ -0,0 +1,30 @@
+use cellnoor_models::{
+    generic_query::Page,
+    person::{Person, PersonCreation, PersonQuery, PersonSummary, PersonUpdate},
+    read_options::{NoInclude, ReadOptions},
+};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_people(&self, query: &PersonQuery) -> Result<Page<PersonSummary>, Error> {
+        self.get("/people", query).await
+    }
+
+    pub async fn create_person(&self, person: &PersonCreation) -> Result<Person, Error> {
+        self.post("/people", person).await
+    }
+
+    pub async fn fetch_person(
+        &self,
+        id: Uuid,
+        options: &ReadOptions<NoInclude>,
+    ) -> Result<Person, Error> {
+        self.get(&format!("/people/{id}"), options).await
+    }
+
+    pub async fn update_person(&self, id: Uuid, update: &PersonUpdate) -> Result<Person, Error> {
+        self.patch(&format!("/people/{id}"), update).await
+    }
+}
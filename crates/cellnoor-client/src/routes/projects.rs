@@ -0,0 +1,67 @@
+use cellnoor_models::{
+    chromium_dataset::{ChromiumDatasetQuery, ChromiumDatasetSummary},
+    generic_query::Page,
+    project::{Project, ProjectCreation, ProjectQuery, ProjectSummary, ProjectUpdate},
+    read_options::{NoInclude, ReadOptions},
+    specimen::{SpecimenQuery, SpecimenSummary},
+};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_projects(&self, query: &ProjectQuery) -> Result<Page<ProjectSummary>, Error> {
+        self.get("/projects", query).await
+    }
+
+    pub async fn create_project(&self, project: &ProjectCreation) -> Result<Project, Error> {
+        self.post("/projects", project).await
+    }
+
+    pub async fn fetch_project(
+        &self,
+        id: Uuid,
+        options: &ReadOptions<NoInclude>,
+    ) -> Result<Project, Error> {
+        self.get(&format!("/projects/{id}"), options).await
+    }
+
+    pub async fn update_project(&self, id: Uuid, update: &ProjectUpdate) -> Result<Project, Error> {
+        self.patch(&format!("/projects/{id}"), update).await
+    }
+
+    pub async fn list_project_chromium_datasets(
+        &self,
+        id: Uuid,
+        query: &ChromiumDatasetQuery,
+    ) -> Result<Page<ChromiumDatasetSummary>, Error> {
+        self.get(&format!("/projects/{id}/chromium-datasets"), query)
+            .await
+    }
+
+    pub async fn add_chromium_datasets_to_project(
+        &self,
+        id: Uuid,
+        dataset_ids: &[Uuid],
+    ) -> Result<(), Error> {
+        self.post(&format!("/projects/{id}/chromium-datasets"), dataset_ids)
+            .await
+    }
+
+    pub async fn list_project_specimens(
+        &self,
+        id: Uuid,
+        query: &SpecimenQuery,
+    ) -> Result<Page<SpecimenSummary>, Error> {
+        self.get(&format!("/projects/{id}/specimens"), query).await
+    }
+
+    pub async fn add_specimens_to_project(
+        &self,
+        id: Uuid,
+        specimen_ids: &[Uuid],
+    ) -> Result<(), Error> {
+        self.post(&format!("/projects/{id}/specimens"), specimen_ids)
+            .await
+    }
+}
@@ -0,0 +1,9 @@
+use cellnoor_models::search::{SearchQuery, SearchResults};
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn search(&self, query: &SearchQuery) -> Result<SearchResults, Error> {
+        self.get("/search", query).await
+    }
+}
@@ -0,0 +1,22 @@
+use cellnoor_models::sequencing_run::{SequencingRun, SequencingRunCreation};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn create_sequencing_run(
+        &self,
+        run: &SequencingRunCreation,
+    ) -> Result<SequencingRun, Error> {
+        self.post("/sequencing-runs", run).await
+    }
+
+    pub async fn add_libraries_to_sequencing_run(
+        &self,
+        id: Uuid,
+        library_ids: &[Uuid],
+    ) -> Result<(), Error> {
+        self.post(&format!("/sequencing-runs/{id}/libraries"), library_ids)
+            .await
+    }
+}
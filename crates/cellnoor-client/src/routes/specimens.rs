@@ -0,0 +1,79 @@
+use cellnoor_models::{
+    chromium_dataset::{ChromiumDatasetQuery, ChromiumDatasetSummary},
+    generic_query::Page,
+    lineage::Lineage,
+    read_options::ReadOptions,
+    specimen::{
+        Specimen, SpecimenCreation, SpecimenInclude, SpecimenProgress, SpecimenQuery,
+        SpecimenSummary, StalledSpecimenQuery,
+        measurement::{SpecimenMeasurement, SpecimenMeasurementCreation},
+    },
+};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_specimens(
+        &self,
+        query: &SpecimenQuery,
+    ) -> Result<Page<SpecimenSummary>, Error> {
+        self.get("/specimens", query).await
+    }
+
+    pub async fn create_specimen(&self, specimen: &SpecimenCreation) -> Result<Specimen, Error> {
+        self.post("/specimens", specimen).await
+    }
+
+    pub async fn fetch_specimen(
+        &self,
+        id: Uuid,
+        options: &ReadOptions<SpecimenInclude>,
+    ) -> Result<Specimen, Error> {
+        self.get(&format!("/specimens/{id}"), options).await
+    }
+
+    pub async fn list_specimen_chromium_datasets(
+        &self,
+        id: Uuid,
+        query: &ChromiumDatasetQuery,
+    ) -> Result<Page<ChromiumDatasetSummary>, Error> {
+        self.get(&format!("/specimens/{id}/chromium-datasets"), query)
+            .await
+    }
+
+    /// Use [`Lineage::to_dot`] for a Graphviz rendering
+    pub async fn fetch_specimen_lineage(&self, id: Uuid) -> Result<Lineage, Error> {
+        self.get_without_query(&format!("/specimens/{id}/lineage"))
+            .await
+    }
+
+    pub async fn list_specimen_measurements(
+        &self,
+        id: Uuid,
+    ) -> Result<Vec<SpecimenMeasurement>, Error> {
+        self.get_without_query(&format!("/specimens/{id}/measurements"))
+            .await
+    }
+
+    pub async fn create_specimen_measurement(
+        &self,
+        id: Uuid,
+        measurement: &SpecimenMeasurementCreation,
+    ) -> Result<SpecimenMeasurement, Error> {
+        self.post(&format!("/specimens/{id}/measurements"), measurement)
+            .await
+    }
+
+    pub async fn fetch_specimen_progress(&self, id: Uuid) -> Result<SpecimenProgress, Error> {
+        self.get_without_query(&format!("/specimens/{id}/progress"))
+            .await
+    }
+
+    pub async fn list_stalled_specimens(
+        &self,
+        query: &StalledSpecimenQuery,
+    ) -> Result<Vec<SpecimenProgress>, Error> {
+        self.get("/specimens/stalled", query).await
+    }
+}
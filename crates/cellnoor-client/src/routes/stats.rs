@@ -0,0 +1,33 @@
+use cellnoor_models::stats::{ChromiumDatasetStatsQuery, SpecimenStatsQuery, StatsSeries};
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn specimen_stats(
+        &self,
+        query: &SpecimenStatsQuery,
+    ) -> Result<Vec<StatsSeries>, Error> {
+        self.get("/stats/specimens", query).await
+    }
+
+    pub async fn chromium_dataset_stats(
+        &self,
+        query: &ChromiumDatasetStatsQuery,
+    ) -> Result<Vec<StatsSeries>, Error> {
+        self.get("/stats/chromium-datasets", query).await
+    }
+
+    pub async fn viability_stats(
+        &self,
+        query: &SpecimenStatsQuery,
+    ) -> Result<Vec<StatsSeries>, Error> {
+        self.get("/stats/viability", query).await
+    }
+
+    pub async fn turnaround_stats(
+        &self,
+        query: &ChromiumDatasetStatsQuery,
+    ) -> Result<Vec<StatsSeries>, Error> {
+        self.get("/stats/turnaround", query).await
+    }
+}
@@ -0,0 +1,63 @@
+use cellnoor_models::{
+    generic_query::Page,
+    read_options::{NoInclude, ReadOptions},
+    storage::{
+        OccupiedPosition, StorageCheckIn, StorageCheckOut, StorageUnit, StorageUnitCreation,
+        StorageUnitQuery,
+    },
+};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_storage_units(
+        &self,
+        query: &StorageUnitQuery,
+    ) -> Result<Page<StorageUnit>, Error> {
+        self.get("/storage", query).await
+    }
+
+    pub async fn create_storage_unit(
+        &self,
+        unit: &StorageUnitCreation,
+    ) -> Result<StorageUnit, Error> {
+        self.post("/storage", unit).await
+    }
+
+    pub async fn fetch_storage_unit(
+        &self,
+        id: Uuid,
+        options: &ReadOptions<NoInclude>,
+    ) -> Result<StorageUnit, Error> {
+        self.get(&format!("/storage/{id}"), options).await
+    }
+
+    /// Returns the positions of the box that are occupied afterwards
+    pub async fn check_in(
+        &self,
+        box_id: Uuid,
+        check_in: &StorageCheckIn,
+    ) -> Result<Vec<OccupiedPosition>, Error> {
+        self.post(&format!("/storage/{box_id}/check-in"), check_in)
+            .await
+    }
+
+    /// Returns the positions of the box that are occupied afterwards
+    pub async fn check_out(
+        &self,
+        box_id: Uuid,
+        check_out: &StorageCheckOut,
+    ) -> Result<Vec<OccupiedPosition>, Error> {
+        self.post(&format!("/storage/{box_id}/check-out"), check_out)
+            .await
+    }
+
+    pub async fn list_occupied_positions(
+        &self,
+        box_id: Uuid,
+    ) -> Result<Vec<OccupiedPosition>, Error> {
+        self.get_without_query(&format!("/storage/{box_id}/occupied-positions"))
+            .await
+    }
+}
@@ -0,0 +1,80 @@
+use cellnoor_models::{
+    generic_query::Page,
+    read_options::{NoInclude, ReadOptions},
+    suspension::{SuspensionQuery, SuspensionSummary},
+    suspension_pool::{
+        SuspensionPool, SuspensionPoolCreation, SuspensionPoolQuery,
+        measurement::{
+            CellSuspensionPoolMeasurementCreation, NucleusSuspensionPoolMeasurementCreation,
+            SuspensionPoolMeasurement,
+        },
+    },
+};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_suspension_pools(
+        &self,
+        query: &SuspensionPoolQuery,
+    ) -> Result<Page<SuspensionPool>, Error> {
+        self.get("/suspension-pools", query).await
+    }
+
+    pub async fn create_suspension_pool(
+        &self,
+        pool: &SuspensionPoolCreation,
+    ) -> Result<SuspensionPool, Error> {
+        self.post("/suspension-pools", pool).await
+    }
+
+    pub async fn fetch_suspension_pool(
+        &self,
+        id: Uuid,
+        options: &ReadOptions<NoInclude>,
+    ) -> Result<SuspensionPool, Error> {
+        self.get(&format!("/suspension-pools/{id}"), options).await
+    }
+
+    pub async fn list_suspension_pool_suspensions(
+        &self,
+        id: Uuid,
+        query: &SuspensionQuery,
+    ) -> Result<Page<SuspensionSummary>, Error> {
+        self.get(&format!("/suspension-pools/{id}/suspensions"), query)
+            .await
+    }
+
+    pub async fn list_suspension_pool_measurements(
+        &self,
+        id: Uuid,
+    ) -> Result<Vec<SuspensionPoolMeasurement>, Error> {
+        self.get_without_query(&format!("/suspension-pools/{id}/measurements"))
+            .await
+    }
+
+    pub async fn create_cell_suspension_pool_measurement(
+        &self,
+        id: Uuid,
+        measurement: &CellSuspensionPoolMeasurementCreation,
+    ) -> Result<SuspensionPoolMeasurement, Error> {
+        self.post(
+            &format!("/suspension-pools/cells/{id}/measurements"),
+            measurement,
+        )
+        .await
+    }
+
+    pub async fn create_nucleus_suspension_pool_measurement(
+        &self,
+        id: Uuid,
+        measurement: &NucleusSuspensionPoolMeasurementCreation,
+    ) -> Result<SuspensionPoolMeasurement, Error> {
+        self.post(
+            &format!("/suspension-pools/nuclei/{id}/measurements"),
+            measurement,
+        )
+        .await
+    }
+}
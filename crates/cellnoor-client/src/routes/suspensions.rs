@@ -0,0 +1,77 @@
+use cellnoor_models::{
+    generic_query::Page,
+    read_options::{NoInclude, ReadOptions},
+    suspension::{
+        Suspension, SuspensionCreation, SuspensionQuery, SuspensionSummary,
+        measurement::{
+            CellSuspensionMeasurementCreation, NucleusSuspensionMeasurementCreation,
+            SuspensionMeasurement,
+        },
+    },
+};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_suspensions(
+        &self,
+        query: &SuspensionQuery,
+    ) -> Result<Page<SuspensionSummary>, Error> {
+        self.get("/suspensions", query).await
+    }
+
+    pub async fn create_cell_suspension(
+        &self,
+        suspension: &SuspensionCreation,
+    ) -> Result<Suspension, Error> {
+        self.post("/suspensions/cells", suspension).await
+    }
+
+    pub async fn create_nucleus_suspension(
+        &self,
+        suspension: &SuspensionCreation,
+    ) -> Result<Suspension, Error> {
+        self.post("/suspensions/nuclei", suspension).await
+    }
+
+    pub async fn fetch_suspension(
+        &self,
+        id: Uuid,
+        options: &ReadOptions<NoInclude>,
+    ) -> Result<Suspension, Error> {
+        self.get(&format!("/suspensions/{id}"), options).await
+    }
+
+    pub async fn list_suspension_measurements(
+        &self,
+        id: Uuid,
+    ) -> Result<Vec<SuspensionMeasurement>, Error> {
+        self.get_without_query(&format!("/suspensions/{id}/measurements"))
+            .await
+    }
+
+    pub async fn create_cell_suspension_measurement(
+        &self,
+        id: Uuid,
+        measurement: &CellSuspensionMeasurementCreation,
+    ) -> Result<SuspensionMeasurement, Error> {
+        self.post(
+            &format!("/suspensions/cells/{id}/measurements"),
+            measurement,
+        )
+        .await
+    }
+
+    pub async fn create_nucleus_suspension_measurement(
+        &self,
+        id: Uuid,
+        measurement: &NucleusSuspensionMeasurementCreation,
+    ) -> Result<SuspensionMeasurement, Error> {
+        self.post(
+            &format!("/suspensions/nuclei/{id}/measurements"),
+            measurement,
+        )
+        .await
+    }
+}
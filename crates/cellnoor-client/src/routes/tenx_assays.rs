@@ -0,0 +1,12 @@
+use cellnoor_models::{
+    generic_query::Page,
+    tenx_assay::{TenxAssay, TenxAssayQuery},
+};
+
+use crate::{Client, Error};
+
+impl Client {
+    pub async fn list_tenx_assays(&self, query: &TenxAssayQuery) -> Result<Page<TenxAssay>, Error> {
+        self.get("/10x-assays", query).await
+    }
+}
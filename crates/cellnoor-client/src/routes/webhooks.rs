@@ -0,0 +1,35 @@
+use cellnoor_models::{
+    generic_query::Page,
+    webhook::{
+        WebhookDelivery, WebhookDeliveryQuery, WebhookSubscription, WebhookSubscriptionCreation,
+    },
+};
+use uuid::Uuid;
+
+use crate::{Client, Error};
+
+impl Client {
+    /// The secret is used to sign deliveries, and can't be read back
+    pub async fn create_webhook_subscription(
+        &self,
+        subscription: &WebhookSubscriptionCreation,
+    ) -> Result<WebhookSubscription, Error> {
+        self.post("/webhooks", subscription).await
+    }
+
+    pub async fn list_webhook_subscriptions(&self) -> Result<Vec<WebhookSubscription>, Error> {
+        self.get_without_query("/webhooks").await
+    }
+
+    pub async fn delete_webhook_subscription(&self, id: Uuid) -> Result<(), Error> {
+        self.delete(&format!("/webhooks/{id}")).await
+    }
+
+    pub async fn list_webhook_deliveries(
+        &self,
+        id: Uuid,
+        query: &WebhookDeliveryQuery,
+    ) -> Result<Page<WebhookDelivery>, Error> {
+        self.get(&format!("/webhooks/{id}/deliveries"), query).await
+    }
+}
@@ -15,6 +15,7 @@ fn base_derives(input: TokenStream, with_default: bool) -> proc_macro2::TokenStr
             #[derive(Clone, Debug, Default, PartialEq)]
             #[cfg_attr(feature = "typescript", derive(::ts_rs::TS))]
             #[cfg_attr(feature = "typescript", ts(optional_fields))]
+            #[cfg_attr(feature = "schema", derive(::schemars::JsonSchema))]
             #serde_default
         }
     } else {
@@ -22,6 +23,7 @@ fn base_derives(input: TokenStream, with_default: bool) -> proc_macro2::TokenStr
             #[derive(Clone, Debug, PartialEq)]
             #[cfg_attr(feature = "typescript", derive(::ts_rs::TS))]
             #[cfg_attr(feature = "typescript", ts(optional_fields))]
+            #[cfg_attr(feature = "schema", derive(::schemars::JsonSchema))]
         }
     }
 }
@@ -108,6 +110,7 @@ pub fn insert(_attr: TokenStream, input: TokenStream) -> TokenStream {
 
     quote! {
         #[derive(::serde::Deserialize)]
+        #[cfg_attr(feature = "client", derive(::serde::Serialize))]
         #base_derives
         #insertable
         #check_for_backend
@@ -144,6 +147,7 @@ pub fn select(_attr: TokenStream, input: TokenStream) -> TokenStream {
 
     quote! {
         #[derive(::serde::Serialize)]
+        #[cfg_attr(feature = "client", derive(::serde::Deserialize))]
         #base_derives
         #has_query
         #check_for_backend
@@ -161,6 +165,7 @@ pub fn update(_attr: TokenStream, input: TokenStream) -> TokenStream {
 
     quote! {
         #[derive(::serde::Deserialize)]
+        #[cfg_attr(feature = "client", derive(::serde::Serialize))]
         #base_derives
         #[serde(deny_unknown_fields)]
         #[cfg_attr(feature = "app", derive(::diesel::AsChangeset, ::diesel::Identifiable))]
@@ -180,36 +185,88 @@ pub fn order_by(attr: TokenStream, input: TokenStream) -> TokenStream {
 
     let enum_name = &enum_def.ident;
 
+    // A variant with a `path` field orders by the first item found at that
+    // JSON path within the column of the same name
     let items = enum_def.variants.iter().map(|v| {
+        let is_json_path = v
+            .fields
+            .iter()
+            .any(|f| f.ident.as_ref().is_some_and(|i| i == "path"));
         let v = &v.ident;
 
         let asc_static = format_ident!("asc_{v}");
         let desc_static = format_ident!("desc_{v}");
 
-        (v, asc_static, desc_static)
+        (v, asc_static, desc_static, is_json_path)
     });
 
+    let copy = if items.clone().any(|(.., is_json_path)| is_json_path) {
+        quote! {}
+    } else {
+        quote! { #[derive(Copy)] }
+    };
+
     let first_field = items.clone().map(|(v, ..)| v).next().unwrap();
 
-    let static_defs = items.clone().map(|(v, asc_static, desc_static)| {
+    let static_defs = items
+        .clone()
+        .filter(|(.., is_json_path)| !is_json_path)
+        .map(|(v, asc_static, desc_static, _)| {
+            quote! {
+                #[allow(non_upper_case_globals)]
+                static #asc_static: LazyLock<Asc<#cellnoor_schema_mod::#v>> = LazyLock::new(|| #cellnoor_schema_mod::#v.asc());
+                static #desc_static: LazyLock<Desc<#cellnoor_schema_mod::#v>> = LazyLock::new(|| #cellnoor_schema_mod::#v.desc());
+            }
+        });
+
+    let match_bodies = items
+        .clone()
+        .map(|(v, asc_static, desc_static, is_json_path)| {
+            if is_json_path {
+                quote! {
+                    Self::#v { path, descending } => {
+                        let column: &'static #cellnoor_schema_mod::#v = &#cellnoor_schema_mod::#v;
+                        crate::json_path::walk_first_match(column, path, pass.reborrow())?;
+                        pass.push_sql(if descending.unwrap_or(false) { " DESC" } else { " ASC" });
+                        Ok(())
+                    }
+                }
+            } else {
+                quote! {
+                    Self::#v { descending: None | Some(false) } => #asc_static.walk_ast(pass),
+                    Self::#v { descending: Some(true) } => #desc_static.walk_ast(pass),
+                }
+            }
+        });
+
+    let descending_bodies = items.clone().map(|(v, ..)| {
         quote! {
-            #[allow(non_upper_case_globals)]
-            static #asc_static: LazyLock<Asc<#cellnoor_schema_mod::#v>> = LazyLock::new(|| #cellnoor_schema_mod::#v.asc());
-            static #desc_static: LazyLock<Desc<#cellnoor_schema_mod::#v>> = LazyLock::new(|| #cellnoor_schema_mod::#v.desc());
+            Self::#v { descending, .. } => descending.unwrap_or(false),
         }
     });
 
-    let match_bodies = items.map(|(v, asc_static, desc_static)| {
-        quote! {
-            Self::#v { descending: None | Some(false) } => #asc_static.walk_ast(pass),
-            Self::#v { descending: Some(true) } => #desc_static.walk_ast(pass),
+    let column_bodies = items.map(|(v, _, _, is_json_path)| {
+        if is_json_path {
+            quote! {
+                Self::#v { path, .. } => {
+                    let column: &'static #cellnoor_schema_mod::#v = &#cellnoor_schema_mod::#v;
+                    crate::json_path::walk_first_match(column, path, pass)
+                }
+            }
+        } else {
+            quote! {
+                Self::#v { .. } => {
+                    let column: &'static #cellnoor_schema_mod::#v = &#cellnoor_schema_mod::#v;
+                    column.walk_ast(pass)
+                }
+            }
         }
     });
 
     quote! {
         #base_derives
         #[derive(::serde::Deserialize, ::serde::Serialize)]
-        #[derive(Copy)]
+        #copy
         #[serde(rename_all = "snake_case")]
         #enum_def
 
@@ -235,15 +292,47 @@ pub fn order_by(attr: TokenStream, input: TokenStream) -> TokenStream {
             impl<T> AppearsOnTable<T> for #enum_name where #cellnoor_schema_mod::#first_field: AppearsOnTable<T> {}
 
             impl QueryFragment<Pg> for #enum_name {
+                #[allow(unused_mut)]
                 fn walk_ast<'b>(
                     &'b self,
-                    pass: diesel::query_builder::AstPass<'_, 'b, Pg>,
+                    mut pass: diesel::query_builder::AstPass<'_, 'b, Pg>,
                 ) -> diesel::QueryResult<()> {
                     match self {
                         #(#match_bodies)*
                     }
                 }
             }
+
+            impl crate::generic_query::KeysetColumn for #enum_name {
+                fn descending(&self) -> bool {
+                    match self {
+                        #(#descending_bodies)*
+                    }
+                }
+
+                fn walk_column<'b>(
+                    &'b self,
+                    pass: diesel::query_builder::AstPass<'_, 'b, Pg>,
+                ) -> diesel::QueryResult<()> {
+                    match self {
+                        #(#column_bodies)*
+                    }
+                }
+
+                fn walk_table(
+                    pass: diesel::query_builder::AstPass<'_, '_, Pg>,
+                ) -> diesel::QueryResult<()> {
+                    let table: &'static #cellnoor_schema_mod::table = &#cellnoor_schema_mod::table;
+                    table.walk_ast(pass)
+                }
+
+                fn walk_id(
+                    pass: diesel::query_builder::AstPass<'_, '_, Pg>,
+                ) -> diesel::QueryResult<()> {
+                    let id: &'static #cellnoor_schema_mod::id = &#cellnoor_schema_mod::id;
+                    id.walk_ast(pass)
+                }
+            }
         }
     }
     .into()
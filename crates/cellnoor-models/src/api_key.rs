@@ -0,0 +1,73 @@
+use jiff::Timestamp;
+use macro_attributes::base_model;
+use non_empty::NonEmptyString;
+
+/// An API key belonging to the requesting user. Only the hex-encoded prefix
+/// is known after creation, since the key itself is stored as a hash.
+#[base_model]
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "client", derive(serde::Deserialize))]
+pub struct ApiKey {
+    prefix: String,
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    created_at: Timestamp,
+}
+
+impl ApiKey {
+    #[must_use]
+    pub fn new(prefix: String, created_at: Timestamp) -> Self {
+        Self { prefix, created_at }
+    }
+
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    #[must_use]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+}
+
+/// A newly created API key. This is the only time `api_key` is returned.
+#[base_model]
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "client", derive(serde::Deserialize))]
+pub struct CreatedApiKey {
+    api_key: String,
+    prefix: String,
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    created_at: Timestamp,
+}
+
+impl CreatedApiKey {
+    #[must_use]
+    pub fn new(api_key: String, prefix: String, created_at: Timestamp) -> Self {
+        Self {
+            api_key,
+            prefix,
+            created_at,
+        }
+    }
+
+    #[must_use]
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    #[must_use]
+    pub fn created_at(&self) -> Timestamp {
+        self.created_at
+    }
+}
+
+#[derive(Debug, Clone, ::serde::Deserialize, ::serde::Serialize)]
+#[cfg_attr(feature = "app", derive(axum_extra::routing::TypedPath))]
+#[cfg_attr(feature = "app", typed_path("/{prefix}"))]
+pub struct ApiKeyPrefix(pub NonEmptyString);
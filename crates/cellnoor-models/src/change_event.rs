@@ -0,0 +1,153 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::change_events;
+use jiff::Timestamp;
+use macro_attributes::{base_model, filter, simple_enum};
+use macros::{impl_enum_from_sql, impl_enum_to_sql};
+use uuid::Uuid;
+
+#[cfg(feature = "app")]
+use crate::utils::{EnumFromSql, EnumToSql};
+
+#[simple_enum]
+pub enum ChangeEntityType {
+    Donor,
+    Project,
+    Specimen,
+    Suspension,
+    SuspensionPool,
+    ChromiumRun,
+    Cdna,
+    Library,
+    SequencingRun,
+    ChromiumDataset,
+    ChromiumDatasetMetricsFile,
+    ChromiumDatasetWebSummary,
+}
+
+#[cfg(feature = "app")]
+impl EnumFromSql for ChangeEntityType {}
+impl_enum_from_sql!(ChangeEntityType);
+
+#[cfg(feature = "app")]
+impl EnumToSql for ChangeEntityType {}
+impl_enum_to_sql!(ChangeEntityType);
+
+#[simple_enum]
+pub enum ChangeOperation {
+    Insert,
+    Update,
+}
+
+#[cfg(feature = "app")]
+impl EnumFromSql for ChangeOperation {}
+impl_enum_from_sql!(ChangeOperation);
+
+/// An insert or update of an entity. For metrics files and web summaries,
+/// `entity_id` is the ID of the Chromium dataset they belong to.
+#[base_model]
+#[derive(serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "app", derive(diesel::HasQuery))]
+#[cfg_attr(feature = "app", diesel(table_name = change_events, check_for_backend(diesel::pg::Pg)))]
+pub struct ChangeEvent {
+    #[cfg_attr(feature = "typescript", ts(type = "number"))]
+    id: i64,
+    entity_type: ChangeEntityType,
+    operation: ChangeOperation,
+    entity_id: Uuid,
+    lab_id: Option<Uuid>,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    occurred_at: Timestamp,
+}
+
+impl ChangeEvent {
+    #[must_use]
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+
+    #[must_use]
+    pub fn entity_type(&self) -> ChangeEntityType {
+        self.entity_type
+    }
+
+    #[must_use]
+    pub fn operation(&self) -> ChangeOperation {
+        self.operation
+    }
+
+    #[must_use]
+    pub fn entity_id(&self) -> Uuid {
+        self.entity_id
+    }
+}
+
+/// Which events to stream. Events for entities that don't belong to a lab
+/// never match `labs`.
+#[filter]
+pub struct ChangeEventFilter {
+    pub entity_types: Option<Vec<ChangeEntityType>>,
+    pub labs: Option<Vec<Uuid>>,
+}
+
+impl ChangeEventFilter {
+    #[must_use]
+    pub fn matches(&self, event: &ChangeEvent) -> bool {
+        let entity_type_matches = self
+            .entity_types
+            .as_ref()
+            .is_none_or(|types| types.contains(&event.entity_type));
+        let lab_matches = self
+            .labs
+            .as_ref()
+            .is_none_or(|labs| event.lab_id.is_some_and(|lab_id| labs.contains(&lab_id)));
+
+        entity_type_matches && lab_matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    use super::{ChangeEntityType, ChangeEvent, ChangeEventFilter};
+
+    #[rstest]
+    fn filter_by_entity_type_and_lab() {
+        let lab_id = Uuid::now_v7();
+        let event = |entity_type, lab_id| -> ChangeEvent {
+            serde_json::from_value(serde_json::json!({
+                "id": 1,
+                "entity_type": entity_type,
+                "operation": "insert",
+                "entity_id": Uuid::now_v7(),
+                "lab_id": lab_id,
+                "occurred_at": "2026-10-19T13:04:46.660444+00:00",
+            }))
+            .unwrap()
+        };
+        let dataset = event("chromium_dataset", Some(lab_id));
+        let run = event("chromium_run", None);
+
+        let filter: ChangeEventFilter = serde_qs::from_str(&format!(
+            "entity_types[0]=chromium_dataset&labs[0]={lab_id}"
+        ))
+        .unwrap();
+        assert_eq!(
+            filter.entity_types,
+            Some(vec![ChangeEntityType::ChromiumDataset])
+        );
+        assert!(filter.matches(&dataset));
+        assert!(!filter.matches(&run));
+
+        // Entities without a lab never match a lab filter
+        let by_lab = ChangeEventFilter {
+            labs: Some(vec![lab_id]),
+            ..Default::default()
+        };
+        assert!(!by_lab.matches(&run));
+        assert!(ChangeEventFilter::default().matches(&run));
+    }
+}
@@ -6,11 +6,10 @@ mod update;
 
 pub use common::ChromiumDatasetFields;
 pub use creation::{ChromiumDatasetCmdline, ChromiumDatasetCreation, metrics};
-#[cfg(feature = "app")]
-pub use query::ChromiumDatasetQuery;
 pub use query::{
-    ChromiumDatasetFilter, ChromiumDatasetId, ChromiumDatasetIdLibraries, ChromiumDatasetIdMetrics,
-    ChromiumDatasetIdSpecimens, ChromiumDatasetIdWebSummaries, ChromiumDatasetMetricsFilename,
-    ChromiumDatasetOrderBy, ChromiumDatasetWebSummaryFilename,
+    ChromiumDatasetEmbeds, ChromiumDatasetFilter, ChromiumDatasetId, ChromiumDatasetIdLibraries,
+    ChromiumDatasetIdLineage, ChromiumDatasetIdMetrics, ChromiumDatasetIdSpecimens,
+    ChromiumDatasetIdWebSummaries, ChromiumDatasetInclude, ChromiumDatasetMetricsFilename,
+    ChromiumDatasetOrderBy, ChromiumDatasetQuery, ChromiumDatasetWebSummaryFilename,
 };
 pub use read::{ChromiumDataset, ChromiumDatasetSummary};
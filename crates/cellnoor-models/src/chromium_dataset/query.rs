@@ -3,13 +3,14 @@
 #[cfg(feature = "app")]
 use cellnoor_schema::chromium_datasets;
 use jiff::Timestamp;
-use macro_attributes::{filter, order_by};
+use macro_attributes::{filter, order_by, simple_enum};
 use macros::uuid_newtype;
 use uuid::Uuid;
 
-#[cfg(feature = "app")]
-use crate::generic_query;
-use crate::{specimen::SpecimenFilter, tenx_assay::TenxAssayFilter};
+use crate::{
+    generic_query, lab::LabSummary, library::LibrarySummary, read_options::Include,
+    specimen::SpecimenFilter, tenx_assay::TenxAssayFilter,
+};
 
 #[filter]
 pub struct ChromiumDatasetFilter {
@@ -18,6 +19,7 @@ pub struct ChromiumDatasetFilter {
     pub specimen: Option<SpecimenFilter>,
     pub assay: Option<TenxAssayFilter>,
     pub lab_ids: Option<Vec<Uuid>>,
+    pub project_ids: Option<Vec<Uuid>>,
     #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
     pub delivered_before: Option<Timestamp>,
     #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
@@ -41,8 +43,33 @@ impl Default for ChromiumDatasetOrderBy {
     }
 }
 
-#[cfg(feature = "app")]
-pub type ChromiumDatasetQuery = generic_query::Query<ChromiumDatasetFilter, ChromiumDatasetOrderBy>;
+/// Related objects that can be embedded in a Chromium dataset with `include`
+#[simple_enum]
+pub enum ChromiumDatasetInclude {
+    Lab,
+    Libraries,
+}
+
+impl Include for ChromiumDatasetInclude {
+    type Embeds = ChromiumDatasetEmbeds;
+}
+
+/// The related objects embedded in a Chromium dataset by
+/// [`ChromiumDatasetInclude`]
+#[derive(Clone, Debug, PartialEq, ::serde::Serialize)]
+#[cfg_attr(feature = "typescript", derive(::ts_rs::TS), ts(optional_fields))]
+#[cfg_attr(feature = "schema", derive(::schemars::JsonSchema))]
+pub struct ChromiumDatasetEmbeds {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(with = "LabSummary"))]
+    pub lab: Option<LabSummary>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(with = "Vec<LibrarySummary>"))]
+    pub libraries: Option<Vec<LibrarySummary>>,
+}
+
+pub type ChromiumDatasetQuery =
+    generic_query::Query<ChromiumDatasetFilter, ChromiumDatasetOrderBy, ChromiumDatasetInclude>;
 
 uuid_newtype!(ChromiumDatasetId, "/{id}");
 
@@ -50,6 +77,8 @@ uuid_newtype!(ChromiumDatasetIdSpecimens, "/{id}/specimens");
 
 uuid_newtype!(ChromiumDatasetIdLibraries, "/{id}/libraries");
 
+uuid_newtype!(ChromiumDatasetIdLineage, "/{id}/lineage");
+
 uuid_newtype!(ChromiumDatasetIdWebSummaries, "/{id}/web-summaries");
 
 uuid_newtype!(ChromiumDatasetIdMetrics, "/{id}/metrics-files");
@@ -10,8 +10,7 @@ pub use creation::{
     PoolMultiplexChipLoading, PoolMultiplexGemPool, SingleplexChipLoading, SingleplexGemPool,
 };
 pub use query::{
-    ChromiumRunFilter, ChromiumRunId, ChromiumRunOrderBy, GemPoolFilter, GemPoolId, GemPoolOrderBy,
+    ChromiumRunFilter, ChromiumRunId, ChromiumRunOrderBy, ChromiumRunQuery, GemPoolFilter,
+    GemPoolId, GemPoolOrderBy, GemPoolQuery,
 };
-#[cfg(feature = "app")]
-pub use query::{ChromiumRunQuery, GemPoolQuery};
 pub use read::{ChromiumRun, ChromiumRunSummary, GemPool, GemPoolSummary};
@@ -17,6 +17,7 @@ pub const MAX_GEM_POOLS_PER_NON_OCM_RUN: usize = 8;
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 #[serde(tag = "plexy", rename_all = "snake_case")]
 pub enum ChromiumRunCreation {
     OnChipMultiplexing {
@@ -43,6 +43,7 @@ impl OcmChipLoading {
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 pub struct OcmGemPool {
     #[serde(flatten)]
     pub inner: GemPoolFields,
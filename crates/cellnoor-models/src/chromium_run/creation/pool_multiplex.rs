@@ -23,6 +23,7 @@ impl PoolMultiplexChipLoading {
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 pub struct PoolMultiplexGemPool {
     #[serde(flatten)]
     pub inner: GemPoolFields,
@@ -23,6 +23,7 @@ impl SingleplexChipLoading {
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 pub struct SingleplexGemPool {
     #[serde(flatten)]
     pub inner: GemPoolFields,
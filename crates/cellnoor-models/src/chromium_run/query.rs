@@ -4,23 +4,42 @@ use macro_attributes::{filter, order_by};
 use macros::uuid_newtype;
 use uuid::Uuid;
 
-#[cfg(feature = "app")]
-use crate::generic_query;
+use crate::{
+    generic_query,
+    json_path::{JsonPath, JsonPathPredicate},
+};
 
 #[filter]
 pub struct ChromiumRunFilter {
     pub ids: Option<Vec<Uuid>>,
+    pub additional_data_predicates: Option<Vec<JsonPathPredicate>>,
 }
 
 #[order_by(chromium_runs)]
 #[allow(non_camel_case_types)]
 pub enum ChromiumRunOrderBy {
-    id { descending: Option<bool> },
-    readable_id { descending: Option<bool> },
-    assay_id { descending: Option<bool> },
-    run_at { descending: Option<bool> },
-    run_by { descending: Option<bool> },
-    succeeded { descending: Option<bool> },
+    id {
+        descending: Option<bool>,
+    },
+    readable_id {
+        descending: Option<bool>,
+    },
+    assay_id {
+        descending: Option<bool>,
+    },
+    run_at {
+        descending: Option<bool>,
+    },
+    run_by {
+        descending: Option<bool>,
+    },
+    succeeded {
+        descending: Option<bool>,
+    },
+    additional_data {
+        path: JsonPath,
+        descending: Option<bool>,
+    },
 }
 
 impl Default for ChromiumRunOrderBy {
@@ -31,7 +50,6 @@ impl Default for ChromiumRunOrderBy {
     }
 }
 
-#[cfg(feature = "app")]
 pub type ChromiumRunQuery = generic_query::Query<ChromiumRunFilter, ChromiumRunOrderBy>;
 
 uuid_newtype!(ChromiumRunId, "/{id}");
@@ -54,7 +72,6 @@ impl Default for GemPoolOrderBy {
     }
 }
 
-#[cfg(feature = "app")]
 pub type GemPoolQuery = generic_query::Query<GemPoolFilter, GemPoolOrderBy>;
 
 uuid_newtype!(GemPoolId, "/{id}");
@@ -38,6 +38,13 @@ pub struct ChromiumRunSummary {
     inner: ChromiumRunFields,
 }
 
+impl ChromiumRunSummary {
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
 #[select]
 #[cfg_attr(feature = "app", diesel(base_query = gem_pools::table.inner_join(chromium_runs::table)))]
 pub struct GemPool {
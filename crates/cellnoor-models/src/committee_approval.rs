@@ -0,0 +1,13 @@
+mod common;
+mod creation;
+mod query;
+mod read;
+mod update;
+
+pub use common::{CommitteeApprovalFields, CommitteeType};
+pub use creation::CommitteeApprovalCreation;
+pub use query::{
+    CommitteeApprovalFilter, CommitteeApprovalId, CommitteeApprovalOrderBy, CommitteeApprovalQuery,
+};
+pub use read::{CommitteeApproval, CommitteeApprovalSummary};
+pub use update::CommitteeApprovalUpdate;
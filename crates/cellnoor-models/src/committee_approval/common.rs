@@ -0,0 +1,44 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::committee_approvals;
+use macro_attributes::{insert_select, simple_enum};
+use macros::{impl_enum_from_sql, impl_enum_to_sql};
+use non_empty::NonEmptyString;
+use uuid::Uuid;
+
+use crate::specimen::Species;
+#[cfg(feature = "app")]
+use crate::utils::{EnumFromSql, EnumToSql};
+
+#[simple_enum]
+pub enum CommitteeType {
+    Iacuc,
+    Irb,
+}
+
+impl CommitteeType {
+    /// The committee that must approve work on specimens of `species`, if any
+    #[must_use]
+    pub fn required_for(species: Species) -> Option<Self> {
+        match species {
+            Species::HomoSapiens => Some(Self::Irb),
+            Species::MusMusculus => Some(Self::Iacuc),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "app")]
+impl EnumFromSql for CommitteeType {}
+impl_enum_from_sql!(CommitteeType);
+
+#[cfg(feature = "app")]
+impl EnumToSql for CommitteeType {}
+impl_enum_to_sql!(CommitteeType);
+
+#[insert_select]
+#[cfg_attr(feature = "app", diesel(table_name = committee_approvals))]
+pub struct CommitteeApprovalFields {
+    pub(super) institution_id: Uuid,
+    pub(super) committee_type: CommitteeType,
+    pub(super) compliance_identifier: NonEmptyString,
+}
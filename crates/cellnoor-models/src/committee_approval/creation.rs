@@ -0,0 +1,32 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::committee_approvals;
+use jiff::Timestamp;
+use macro_attributes::insert;
+
+use crate::committee_approval::common::CommitteeApprovalFields;
+
+#[insert]
+#[cfg_attr(feature = "app", diesel(table_name = committee_approvals))]
+pub struct CommitteeApprovalCreation {
+    #[serde(flatten)]
+    #[cfg_attr(feature = "app", diesel(embed))]
+    inner: CommitteeApprovalFields,
+    #[cfg_attr(feature = "app", diesel(serialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    valid_from: Timestamp,
+    #[cfg_attr(feature = "app", diesel(serialize_as = jiff_diesel::NullableTimestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    valid_until: Option<Timestamp>,
+}
+
+impl CommitteeApprovalCreation {
+    #[must_use]
+    pub fn valid_from(&self) -> Timestamp {
+        self.valid_from
+    }
+
+    #[must_use]
+    pub fn valid_until(&self) -> Option<Timestamp> {
+        self.valid_until
+    }
+}
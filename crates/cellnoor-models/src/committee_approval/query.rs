@@ -0,0 +1,42 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::committee_approvals;
+use jiff::Timestamp;
+use macro_attributes::{filter, order_by};
+use macros::uuid_newtype;
+use uuid::Uuid;
+
+use crate::committee_approval::common::CommitteeType;
+
+#[order_by(committee_approvals)]
+#[allow(non_camel_case_types)]
+pub enum CommitteeApprovalOrderBy {
+    id { descending: Option<bool> },
+    institution_id { descending: Option<bool> },
+    committee_type { descending: Option<bool> },
+    compliance_identifier { descending: Option<bool> },
+    valid_from { descending: Option<bool> },
+    valid_until { descending: Option<bool> },
+}
+
+impl Default for CommitteeApprovalOrderBy {
+    fn default() -> Self {
+        Self::valid_from {
+            descending: Some(true),
+        }
+    }
+}
+
+#[filter]
+pub struct CommitteeApprovalFilter {
+    pub ids: Option<Vec<Uuid>>,
+    pub institution_ids: Option<Vec<Uuid>>,
+    pub committee_types: Option<Vec<CommitteeType>>,
+    pub compliance_identifiers: Option<Vec<String>>,
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    pub valid_at: Option<Timestamp>,
+}
+
+pub type CommitteeApprovalQuery =
+    crate::generic_query::Query<CommitteeApprovalFilter, CommitteeApprovalOrderBy>;
+
+uuid_newtype!(CommitteeApprovalId, "/{id}");
@@ -0,0 +1,88 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::{committee_approvals, institutions};
+#[cfg(feature = "app")]
+use diesel::prelude::*;
+use jiff::Timestamp;
+use macro_attributes::select;
+use uuid::Uuid;
+
+use crate::{
+    committee_approval::common::{CommitteeApprovalFields, CommitteeType},
+    institution::Institution,
+    links::Links,
+};
+
+#[select]
+#[cfg_attr(feature = "app", diesel(table_name = committee_approvals))]
+pub struct CommitteeApprovalSummary {
+    id: Uuid,
+    links: Links,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "app", diesel(embed))]
+    inner: CommitteeApprovalFields,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    valid_from: Timestamp,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::NullableTimestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    valid_until: Option<Timestamp>,
+}
+
+impl CommitteeApprovalSummary {
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    #[must_use]
+    pub fn institution_id(&self) -> Uuid {
+        self.inner.institution_id
+    }
+
+    #[must_use]
+    pub fn committee_type(&self) -> CommitteeType {
+        self.inner.committee_type
+    }
+
+    #[must_use]
+    pub fn valid_from(&self) -> Timestamp {
+        self.valid_from
+    }
+
+    #[must_use]
+    pub fn valid_until(&self) -> Option<Timestamp> {
+        self.valid_until
+    }
+
+    #[must_use]
+    pub fn is_valid_at(&self, timestamp: Timestamp) -> bool {
+        self.valid_from <= timestamp && self.valid_until.is_none_or(|until| timestamp < until)
+    }
+}
+
+#[select]
+#[cfg_attr(feature = "app", diesel(base_query = committee_approvals::table.inner_join(institutions::table)))]
+pub struct CommitteeApproval {
+    #[serde(flatten)]
+    #[cfg_attr(feature = "app", diesel(embed))]
+    summary: CommitteeApprovalSummary,
+    #[cfg_attr(feature = "app", diesel(embed))]
+    institution: Institution,
+}
+
+impl CommitteeApproval {
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.summary.id()
+    }
+
+    #[must_use]
+    pub fn valid_from(&self) -> Timestamp {
+        self.summary.valid_from()
+    }
+
+    #[must_use]
+    pub fn valid_until(&self) -> Option<Timestamp> {
+        self.summary.valid_until()
+    }
+}
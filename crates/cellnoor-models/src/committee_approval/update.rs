@@ -0,0 +1,36 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::committee_approvals;
+use jiff::Timestamp;
+use macro_attributes::update;
+use non_empty::NonEmptyString;
+use uuid::Uuid;
+
+#[update]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "builder", builder(on(_, into)))]
+#[cfg_attr(feature = "app", diesel(table_name = committee_approvals))]
+#[cfg_attr(feature = "typescript", ts(rename = "CommitteeApprovalUpdate"))]
+pub struct CommitteeApprovalUpdate {
+    #[serde(skip)]
+    #[cfg_attr(feature = "builder", builder(skip))]
+    id: Uuid,
+    compliance_identifier: Option<NonEmptyString>,
+    #[cfg_attr(feature = "app", diesel(serialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    valid_from: Option<Timestamp>,
+    #[cfg_attr(feature = "app", diesel(serialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    valid_until: Option<Timestamp>,
+}
+
+impl CommitteeApprovalUpdate {
+    #[must_use]
+    pub fn valid_from(&self) -> Option<Timestamp> {
+        self.valid_from
+    }
+
+    #[must_use]
+    pub fn valid_until(&self) -> Option<Timestamp> {
+        self.valid_until
+    }
+}
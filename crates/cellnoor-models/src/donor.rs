@@ -0,0 +1,11 @@
+mod common;
+mod creation;
+mod query;
+mod read;
+mod update;
+
+pub use common::{DonorFields, Sex};
+pub use creation::DonorCreation;
+pub use query::{DonorFilter, DonorId, DonorOrderBy, DonorQuery};
+pub use read::{Donor, DonorSummary};
+pub use update::DonorUpdate;
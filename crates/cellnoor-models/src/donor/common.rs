@@ -0,0 +1,37 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::donors;
+use macro_attributes::{insert_select, simple_enum};
+use macros::{impl_enum_from_sql, impl_enum_to_sql};
+use non_empty::NonEmptyString;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::specimen::Species;
+#[cfg(feature = "app")]
+use crate::utils::{EnumFromSql, EnumToSql};
+
+#[simple_enum]
+pub enum Sex {
+    Female,
+    Male,
+}
+
+#[cfg(feature = "app")]
+impl EnumFromSql for Sex {}
+impl_enum_from_sql!(Sex);
+
+#[cfg(feature = "app")]
+impl EnumToSql for Sex {}
+impl_enum_to_sql!(Sex);
+
+#[insert_select]
+#[cfg_attr(feature = "app", diesel(table_name = donors))]
+pub struct DonorFields {
+    pub(super) external_id: NonEmptyString,
+    pub(super) lab_id: Uuid,
+    pub(super) species: Species,
+    pub(super) sex: Option<Sex>,
+    pub(super) strain: Option<NonEmptyString>,
+    pub(super) genotype: Option<NonEmptyString>,
+    pub(super) additional_data: Option<Value>,
+}
@@ -0,0 +1,31 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::donors;
+use jiff::Timestamp;
+use macro_attributes::insert;
+use ranged::RangedU16;
+
+use crate::{donor::common::DonorFields, specimen::Species};
+
+#[insert]
+#[cfg_attr(feature = "app", diesel(table_name = donors))]
+pub struct DonorCreation {
+    #[serde(flatten)]
+    #[cfg_attr(feature = "app", diesel(embed))]
+    inner: DonorFields,
+    #[cfg_attr(feature = "app", diesel(serialize_as = jiff_diesel::NullableTimestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    born_at: Option<Timestamp>,
+    age_in_days: Option<RangedU16<0, { u16::MAX }>>,
+}
+
+impl DonorCreation {
+    #[must_use]
+    pub fn species(&self) -> Species {
+        self.inner.species
+    }
+
+    #[must_use]
+    pub fn born_at(&self) -> Option<Timestamp> {
+        self.born_at
+    }
+}
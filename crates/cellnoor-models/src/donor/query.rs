@@ -0,0 +1,42 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::donors;
+use macro_attributes::{filter, order_by};
+use macros::uuid_newtype;
+use uuid::Uuid;
+
+use crate::{donor::common::Sex, specimen::Species};
+
+#[order_by(donors)]
+#[allow(non_camel_case_types)]
+pub enum DonorOrderBy {
+    id { descending: Option<bool> },
+    external_id { descending: Option<bool> },
+    lab_id { descending: Option<bool> },
+    species { descending: Option<bool> },
+    sex { descending: Option<bool> },
+    born_at { descending: Option<bool> },
+    age_in_days { descending: Option<bool> },
+    strain { descending: Option<bool> },
+    genotype { descending: Option<bool> },
+}
+
+impl Default for DonorOrderBy {
+    fn default() -> Self {
+        Self::external_id { descending: None }
+    }
+}
+
+#[filter]
+pub struct DonorFilter {
+    pub ids: Option<Vec<Uuid>>,
+    pub external_ids: Option<Vec<String>>,
+    pub labs: Option<Vec<Uuid>>,
+    pub species: Option<Vec<Species>>,
+    pub sexes: Option<Vec<Sex>>,
+    pub strains: Option<Vec<String>>,
+    pub genotypes: Option<Vec<String>>,
+}
+
+pub type DonorQuery = crate::generic_query::Query<DonorFilter, DonorOrderBy>;
+
+uuid_newtype!(DonorId, "/{id}");
@@ -0,0 +1,72 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::{donors, labs};
+#[cfg(feature = "app")]
+use diesel::prelude::*;
+use jiff::Timestamp;
+use macro_attributes::select;
+use uuid::Uuid;
+
+use crate::{donor::common::DonorFields, lab::LabSummary, links::Links, specimen::Species};
+
+#[select]
+#[cfg_attr(feature = "app", diesel(table_name = donors))]
+pub struct DonorSummary {
+    id: Uuid,
+    links: Links,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "app", diesel(embed))]
+    inner: DonorFields,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::NullableTimestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    born_at: Option<Timestamp>,
+    age_in_days: Option<i32>,
+}
+
+impl DonorSummary {
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    #[must_use]
+    pub fn external_id(&self) -> &str {
+        self.inner.external_id.as_ref()
+    }
+
+    #[must_use]
+    pub fn lab_id(&self) -> Uuid {
+        self.inner.lab_id
+    }
+
+    #[must_use]
+    pub fn species(&self) -> Species {
+        self.inner.species
+    }
+}
+
+#[select]
+#[cfg_attr(feature = "app", diesel(base_query = donors::table.inner_join(labs::table)))]
+pub struct Donor {
+    #[serde(flatten)]
+    #[cfg_attr(feature = "app", diesel(embed))]
+    summary: DonorSummary,
+    #[cfg_attr(feature = "app", diesel(embed))]
+    lab: LabSummary,
+}
+
+impl Donor {
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.summary.id()
+    }
+
+    #[must_use]
+    pub fn lab_id(&self) -> Uuid {
+        self.summary.lab_id()
+    }
+
+    #[must_use]
+    pub fn species(&self) -> Species {
+        self.summary.species()
+    }
+}
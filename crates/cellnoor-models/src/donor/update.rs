@@ -0,0 +1,38 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::donors;
+use jiff::Timestamp;
+use macro_attributes::update;
+use non_empty::NonEmptyString;
+use ranged::RangedU16;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{donor::common::Sex, specimen::Species};
+
+#[update]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "builder", builder(on(_, into)))]
+#[cfg_attr(feature = "app", diesel(table_name = donors))]
+#[cfg_attr(feature = "typescript", ts(rename = "DonorUpdate"))]
+pub struct DonorUpdate {
+    #[serde(skip)]
+    #[cfg_attr(feature = "builder", builder(skip))]
+    id: Uuid,
+    external_id: Option<NonEmptyString>,
+    species: Option<Species>,
+    sex: Option<Sex>,
+    #[cfg_attr(feature = "app", diesel(serialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    born_at: Option<Timestamp>,
+    age_in_days: Option<RangedU16<0, { u16::MAX }>>,
+    strain: Option<NonEmptyString>,
+    genotype: Option<NonEmptyString>,
+    additional_data: Option<Value>,
+}
+
+impl DonorUpdate {
+    #[must_use]
+    pub fn species(&self) -> Option<Species> {
+        self.species
+    }
+}
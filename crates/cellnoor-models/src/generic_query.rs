@@ -1,9 +1,19 @@
 use default_vec::DefaultVec;
+#[cfg(feature = "app")]
+use diesel::{
+    expression::ValidGrouping,
+    pg::Pg,
+    prelude::*,
+    query_builder::{AstPass, QueryFragment, QueryId},
+};
 use uuid::Uuid;
 
+use crate::read_options::{NoInclude, ReadOptions, comma_separated};
+
 #[derive(Clone, Debug, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+#[cfg_attr(feature = "schema", derive(::schemars::JsonSchema))]
 #[serde(default, deny_unknown_fields)]
-pub struct Query<F, O>
+pub struct Query<F, O, I = NoInclude>
 where
     O: Default,
 {
@@ -11,9 +21,61 @@ where
     pub limit: i64,
     pub offset: i64,
     pub order_by: DefaultVec<O>,
+    /// The `next_cursor` of the previous page. Only items that come after the
+    /// cursor according to `order_by` are returned
+    pub cursor: Option<Uuid>,
+    pub total: TotalMode,
+    /// See [`ReadOptions`]
+    #[serde(deserialize_with = "comma_separated")]
+    pub fields: Vec<String>,
+    #[serde(
+        deserialize_with = "comma_separated",
+        bound(deserialize = "I: ::serde::Deserialize<'de>")
+    )]
+    pub include: Vec<I>,
+}
+
+/// How the `total` of a [`Page`] is computed
+#[derive(Clone, Copy, Debug, Default, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+#[cfg_attr(feature = "typescript", derive(::ts_rs::TS))]
+#[cfg_attr(feature = "schema", derive(::schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TotalMode {
+    Exact,
+    /// Use the query planner's row estimate, which is much cheaper for large
+    /// tables
+    Estimated,
+    /// Counting means running the query twice, so clients have to ask for it
+    #[default]
+    Skip,
 }
 
-impl<F, O> Default for Query<F, O>
+#[derive(Clone, Debug, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+#[cfg_attr(feature = "typescript", derive(::ts_rs::TS))]
+#[cfg_attr(feature = "schema", derive(::schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "Page_for_{T}"))]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: Option<i64>,
+    pub next_cursor: Option<Uuid>,
+}
+
+impl<T> Page<T> {
+    /// `next_cursor` is only set if the page is full, since otherwise there are
+    /// no more items
+    pub fn new(items: Vec<T>, total: Option<i64>, limit: i64, id: impl Fn(&T) -> Uuid) -> Self {
+        let is_full = i64::try_from(items.len()).is_ok_and(|len| len == limit);
+        let next_cursor = is_full.then(|| items.last().map(id)).flatten();
+
+        Self {
+            items,
+            total,
+            next_cursor,
+        }
+    }
+}
+
+impl<F, O, I> Default for Query<F, O, I>
 where
     O: Default,
 {
@@ -23,12 +85,16 @@ where
             limit: 500,
             offset: 0,
             order_by: DefaultVec::default(),
+            cursor: None,
+            total: TotalMode::default(),
+            fields: Vec::new(),
+            include: Vec::new(),
         }
     }
 }
 
 #[cfg_attr(feature = "builder", bon::bon)]
-impl<F, O> Query<F, O>
+impl<F, O, I> Query<F, O, I>
 where
     O: Default,
 {
@@ -39,6 +105,10 @@ where
         filter: Option<F>,
         limit: Option<i64>,
         offset: Option<i64>,
+        cursor: Option<Uuid>,
+        total: Option<TotalMode>,
+        #[builder(default)] fields: Vec<String>,
+        #[builder(default)] include: Vec<I>,
     ) -> Self {
         let default = Self::default();
 
@@ -47,6 +117,18 @@ where
             order_by,
             limit: limit.unwrap_or(default.limit),
             offset: offset.unwrap_or(default.offset),
+            cursor,
+            total: total.unwrap_or(default.total),
+            fields,
+            include,
+        }
+    }
+
+    /// Take `fields` and `include` out of the query, leaving them empty
+    pub fn take_read_options(&mut self) -> ReadOptions<I> {
+        ReadOptions {
+            fields: std::mem::take(&mut self.fields),
+            include: std::mem::take(&mut self.include),
         }
     }
 
@@ -66,6 +148,145 @@ where
     }
 }
 
+/// Implemented by the `order_by` macro so that keyset pagination can be derived
+/// from a list of orderings
+#[cfg(feature = "app")]
+pub trait KeysetColumn {
+    fn descending(&self) -> bool;
+    fn walk_column<'b>(&'b self, pass: AstPass<'_, 'b, Pg>) -> QueryResult<()>;
+    fn walk_table(pass: AstPass<'_, '_, Pg>) -> QueryResult<()>;
+    fn walk_id(pass: AstPass<'_, '_, Pg>) -> QueryResult<()>;
+}
+
+/// A filter that matches rows coming after the row identified by `cursor` when
+/// ordered by `order_by` and then by `id`. Postgres sorts `NULL`s last when
+/// ascending and first when descending, so that's handled explicitly.
+#[cfg(feature = "app")]
+#[derive(Debug, Clone, Copy)]
+pub struct Keyset<'a, O> {
+    order_by: &'a [O],
+    cursor: Uuid,
+}
+
+#[cfg(feature = "app")]
+impl<'a, O> Keyset<'a, O> {
+    pub fn new(order_by: &'a [O], cursor: Uuid) -> Self {
+        Self { order_by, cursor }
+    }
+}
+
+#[cfg(feature = "app")]
+impl<O: KeysetColumn> Keyset<'_, O> {
+    // (select <column> from <table> where id = <cursor>)
+    fn walk_cursor_value<'b>(
+        &'b self,
+        ordering: &'b O,
+        mut pass: AstPass<'_, 'b, Pg>,
+    ) -> QueryResult<()> {
+        pass.push_sql("(select ");
+        ordering.walk_column(pass.reborrow())?;
+        pass.push_sql(" from ");
+        O::walk_table(pass.reborrow())?;
+        pass.push_sql(" where ");
+        O::walk_id(pass.reborrow())?;
+        pass.push_sql(" = ");
+        pass.push_bind_param::<diesel::sql_types::Uuid, _>(&self.cursor)?;
+        pass.push_sql(")");
+
+        Ok(())
+    }
+
+    fn walk_equal<'b>(&'b self, ordering: &'b O, mut pass: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        pass.push_sql("(");
+        ordering.walk_column(pass.reborrow())?;
+        pass.push_sql(" is not distinct from ");
+        self.walk_cursor_value(ordering, pass.reborrow())?;
+        pass.push_sql(")");
+
+        Ok(())
+    }
+
+    fn walk_after<'b>(&'b self, ordering: &'b O, mut pass: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        let descending = ordering.descending();
+        let (operator, column_null_check, cursor_null_check) = if descending {
+            (" < ", " is not null", " is null")
+        } else {
+            (" > ", " is null", " is not null")
+        };
+
+        pass.push_sql("((");
+        ordering.walk_column(pass.reborrow())?;
+        pass.push_sql(operator);
+        self.walk_cursor_value(ordering, pass.reborrow())?;
+        pass.push_sql(") is true or (");
+        ordering.walk_column(pass.reborrow())?;
+        pass.push_sql(column_null_check);
+        pass.push_sql(" and ");
+        self.walk_cursor_value(ordering, pass.reborrow())?;
+        pass.push_sql(cursor_null_check);
+        pass.push_sql("))");
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "app")]
+impl<O> Expression for Keyset<'_, O> {
+    type SqlType = diesel::sql_types::Bool;
+}
+
+#[cfg(feature = "app")]
+impl<O, QS> AppearsOnTable<QS> for Keyset<'_, O> where O: AppearsOnTable<QS> {}
+
+#[cfg(feature = "app")]
+impl<O, QS> SelectableExpression<QS> for Keyset<'_, O> where O: AppearsOnTable<QS> {}
+
+#[cfg(feature = "app")]
+impl<O, GB> ValidGrouping<GB> for Keyset<'_, O> {
+    type IsAggregate = diesel::expression::is_aggregate::Never;
+}
+
+#[cfg(feature = "app")]
+impl<O> QueryId for Keyset<'_, O> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+#[cfg(feature = "app")]
+impl<O: KeysetColumn> QueryFragment<Pg> for Keyset<'_, O> {
+    // For orderings (a, b), this is
+    // (a after cursor.a) or (a = cursor.a and b after cursor.b) or (a = cursor.a
+    // and b = cursor.b and id > cursor)
+    fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        pass.push_sql("(false");
+
+        for (i, ordering) in self.order_by.iter().enumerate() {
+            pass.push_sql(" or (true");
+            for previous in &self.order_by[..i] {
+                pass.push_sql(" and ");
+                self.walk_equal(previous, pass.reborrow())?;
+            }
+            pass.push_sql(" and ");
+            self.walk_after(ordering, pass.reborrow())?;
+            pass.push_sql(")");
+        }
+
+        pass.push_sql(" or (true");
+        for ordering in self.order_by {
+            pass.push_sql(" and ");
+            self.walk_equal(ordering, pass.reborrow())?;
+        }
+        pass.push_sql(" and ");
+        O::walk_id(pass.reborrow())?;
+        pass.push_sql(" > ");
+        pass.push_bind_param::<diesel::sql_types::Uuid, _>(&self.cursor)?;
+        pass.push_sql("))");
+
+        Ok(())
+    }
+}
+
 pub trait SetParentId<T>
 where
     Uuid: From<T>,
@@ -95,7 +316,7 @@ where
     }
 }
 
-impl<F, O> Query<F, O>
+impl<F, O, I> Query<F, O, I>
 where
     O: Default,
 {
@@ -113,7 +334,7 @@ where
 }
 
 #[cfg(feature = "builder")]
-impl<F, O, S> QueryBuilder<F, O, S>
+impl<F, O, I, S> QueryBuilder<F, O, I, S>
 where
     F: Default,
     O: Default,
@@ -130,7 +351,7 @@ where
 mod tests {
     use pretty_assertions::assert_eq;
 
-    use super::Query;
+    use super::{Query, TotalMode};
 
     #[rstest::rstest]
     fn query_builder() {
@@ -164,8 +385,12 @@ mod tests {
                     OrderBy::Field1 { descending: false },
                     OrderBy::Field2 { descending: true },
                 ]
-                .into()
+                .into(),
+                cursor: None,
+                total: TotalMode::Skip,
+                fields: Vec::new(),
+                include: Vec::new(),
             }
-        )
+        );
     }
 }
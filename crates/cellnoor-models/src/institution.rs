@@ -5,7 +5,8 @@ mod read;
 mod update;
 
 pub use creation::InstitutionCreation;
-#[cfg(feature = "app")]
-pub use query::InstitutionQuery;
-pub use query::{InstitutionFilter, InstitutionId, InstitutionIdMembers, InstitutionOrderBy};
+pub use query::{
+    InstitutionFilter, InstitutionId, InstitutionIdMembers, InstitutionOrderBy, InstitutionQuery,
+};
 pub use read::Institution;
+pub use update::InstitutionUpdate;
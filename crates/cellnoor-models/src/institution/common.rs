@@ -10,4 +10,7 @@ use uuid::Uuid;
 pub struct InstitutionFields {
     pub(super) id: Uuid,
     pub(super) name: NonEmptyString,
+    #[serde(default)]
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub(super) requires_committee_approval: bool,
 }
@@ -19,7 +19,11 @@ impl InstitutionCreation {
     #[must_use]
     pub fn new(id: Uuid, name: NonEmptyString) -> Self {
         Self {
-            inner: InstitutionFields { id, name },
+            inner: InstitutionFields {
+                id,
+                name,
+                requires_committee_approval: false,
+            },
         }
     }
 }
@@ -35,7 +35,6 @@ pub struct InstitutionFilter {
     pub names: Option<Vec<String>>,
 }
 
-#[cfg(feature = "app")]
 pub type InstitutionQuery = crate::generic_query::Query<InstitutionFilter, InstitutionOrderBy>;
 
 uuid_newtype!(InstitutionId, "/{id}");
@@ -24,4 +24,9 @@ impl Institution {
     pub fn name(&self) -> &str {
         self.inner.name.as_ref()
     }
+
+    #[must_use]
+    pub fn requires_committee_approval(&self) -> bool {
+        self.inner.requires_committee_approval
+    }
 }
@@ -1 +1,24 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::institutions;
+use macro_attributes::update;
+use non_empty::NonEmptyString;
+use uuid::Uuid;
 
+#[update]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "builder", builder(on(_, into)))]
+#[cfg_attr(feature = "app", diesel(table_name = institutions))]
+#[cfg_attr(feature = "typescript", ts(rename = "InstitutionUpdate"))]
+pub struct InstitutionUpdate {
+    #[serde(skip)]
+    #[cfg_attr(feature = "builder", builder(skip))]
+    id: Uuid,
+    name: Option<NonEmptyString>,
+    requires_committee_approval: Option<bool>,
+}
+
+impl InstitutionUpdate {
+    pub fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+}
@@ -0,0 +1,395 @@
+use std::fmt::Display;
+
+#[cfg(feature = "app")]
+use diesel::{
+    expression::ValidGrouping,
+    pg::Pg,
+    prelude::*,
+    query_builder::{AstPass, QueryFragment, QueryId},
+    sql_types::{Bool, Jsonb, Text},
+};
+use macro_attributes::base_model;
+use serde_json::Value;
+
+/// A JSON path made up only of member accessors and array subscripts, such as
+/// `$.donor.age` or `$.measurements[*].value`. Keys are stored quoted so that
+/// the path can be safely embedded in a larger JSON path expression.
+#[derive(Clone, Debug, PartialEq, Eq, ::serde::Deserialize, ::serde::Serialize)]
+#[cfg_attr(feature = "typescript", derive(::ts_rs::TS))]
+#[cfg_attr(feature = "schema", derive(::schemars::JsonSchema))]
+#[serde(try_from = "String", into = "String")]
+pub struct JsonPath(String);
+
+impl JsonPath {
+    pub fn new(path: &str) -> Result<Self, JsonPathError> {
+        let invalid = || JsonPathError::InvalidPath(path.to_owned());
+
+        let mut rest = path.strip_prefix('$').ok_or_else(invalid)?;
+        let mut canonical = String::from("$");
+
+        while let Some(c) = rest.chars().next() {
+            rest = &rest[c.len_utf8()..];
+
+            match c {
+                '.' if rest.starts_with('*') => {
+                    canonical.push_str(".*");
+                    rest = &rest[1..];
+                }
+                '.' if rest.starts_with('"') => {
+                    let mut stream = serde_json::Deserializer::from_str(rest).into_iter::<String>();
+                    let Some(Ok(key)) = stream.next() else {
+                        return Err(invalid());
+                    };
+                    rest = &rest[stream.byte_offset()..];
+
+                    canonical.push('.');
+                    canonical.push_str(&serde_json::to_string(&key).map_err(|_| invalid())?);
+                }
+                '.' => {
+                    let end = rest
+                        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                        .unwrap_or(rest.len());
+                    if end == 0 {
+                        return Err(invalid());
+                    }
+
+                    canonical.push('.');
+                    canonical
+                        .push_str(&serde_json::to_string(&rest[..end]).map_err(|_| invalid())?);
+                    rest = &rest[end..];
+                }
+                '[' => {
+                    let (subscript, remainder) = rest.split_once(']').ok_or_else(invalid)?;
+                    if subscript != "*" && subscript.parse::<u32>().is_err() {
+                        return Err(invalid());
+                    }
+
+                    canonical.push('[');
+                    canonical.push_str(subscript);
+                    canonical.push(']');
+                    rest = remainder;
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(Self(canonical))
+    }
+}
+
+impl AsRef<str> for JsonPath {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for JsonPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<JsonPath> for String {
+    fn from(value: JsonPath) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<String> for JsonPath {
+    type Error = JsonPathError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(&value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonPathError {
+    InvalidPath(String),
+    MissingValue(JsonPathOp),
+    UnexpectedValue(JsonPathOp),
+    NonStringValue(JsonPathOp),
+}
+
+impl Display for JsonPathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidPath(path) => write!(
+                f,
+                "invalid JSON path {path:?}: only member accessors and array subscripts are \
+                 supported"
+            ),
+            Self::MissingValue(op) => write!(f, "operator {} requires a value", op.as_str()),
+            Self::UnexpectedValue(op) => {
+                write!(f, "operator {} does not take a value", op.as_str())
+            }
+            Self::NonStringValue(op) => {
+                write!(f, "operator {} requires a string value", op.as_str())
+            }
+        }
+    }
+}
+
+impl std::error::Error for JsonPathError {}
+
+#[base_model]
+#[derive(Copy, Eq, ::serde::Deserialize, ::serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JsonPathOp {
+    Exists,
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    StartsWith,
+}
+
+impl JsonPathOp {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Exists => "exists",
+            Self::Eq => "eq",
+            Self::Ne => "ne",
+            Self::Gt => "gt",
+            Self::Gte => "gte",
+            Self::Lt => "lt",
+            Self::Lte => "lte",
+            Self::StartsWith => "starts_with",
+        }
+    }
+
+    #[cfg(feature = "app")]
+    fn operator(self) -> Option<&'static str> {
+        let operator = match self {
+            Self::Exists => return None,
+            Self::Eq => "==",
+            Self::Ne => "!=",
+            Self::Gt => ">",
+            Self::Gte => ">=",
+            Self::Lt => "<",
+            Self::Lte => "<=",
+            Self::StartsWith => "starts with",
+        };
+
+        Some(operator)
+    }
+}
+
+/// A condition on the JSON found at `path`, such as
+/// `{"path": "$.donor.age", "op": "gt", "value": 10}`. The condition holds if
+/// any item found at `path` satisfies it.
+///
+/// Because filters are usually sent in a query string, a string `value` that
+/// is itself valid JSON (such as `"10"` or `"true"`) is interpreted as that
+/// JSON. To compare against the string `"10"`, send `"\"10\""`.
+#[base_model]
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[serde(try_from = "UncheckedJsonPathPredicate")]
+pub struct JsonPathPredicate {
+    path: JsonPath,
+    op: JsonPathOp,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<Value>,
+}
+
+#[derive(::serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(::schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+struct UncheckedJsonPathPredicate {
+    path: JsonPath,
+    op: JsonPathOp,
+    #[serde(default)]
+    value: Option<Value>,
+}
+
+impl TryFrom<UncheckedJsonPathPredicate> for JsonPathPredicate {
+    type Error = JsonPathError;
+
+    fn try_from(
+        UncheckedJsonPathPredicate { path, op, value }: UncheckedJsonPathPredicate,
+    ) -> Result<Self, Self::Error> {
+        let value = value.map(|v| match v {
+            Value::String(s) if op != JsonPathOp::StartsWith => {
+                serde_json::from_str(&s).unwrap_or(Value::String(s))
+            }
+            v => v,
+        });
+
+        Self::new(path, op, value)
+    }
+}
+
+impl JsonPathPredicate {
+    pub fn new(
+        path: JsonPath,
+        op: JsonPathOp,
+        value: Option<Value>,
+    ) -> Result<Self, JsonPathError> {
+        match (op, &value) {
+            (JsonPathOp::Exists, Some(_)) => return Err(JsonPathError::UnexpectedValue(op)),
+            (JsonPathOp::Exists, None) => {}
+            (_, None) => return Err(JsonPathError::MissingValue(op)),
+            (JsonPathOp::StartsWith, Some(v)) if !v.is_string() => {
+                return Err(JsonPathError::NonStringValue(op));
+            }
+            _ => {}
+        }
+
+        Ok(Self { path, op, value })
+    }
+
+    /// The JSON path passed to `jsonb_path_exists`, in which the value is
+    /// referred to by the variable `$value`
+    #[cfg(feature = "app")]
+    fn expression(&self) -> String {
+        let Self { path, op, .. } = self;
+
+        match op.operator() {
+            Some(operator) => format!("{path} ? (@ {operator} $value)"),
+            None => path.to_string(),
+        }
+    }
+
+    #[cfg(feature = "app")]
+    fn variables(&self) -> Value {
+        let mut variables = serde_json::Map::new();
+        if let Some(value) = &self.value {
+            variables.insert("value".to_owned(), value.clone());
+        }
+
+        Value::Object(variables)
+    }
+
+    /// `jsonb_path_exists(column, <path>, <variables>)`
+    #[cfg(feature = "app")]
+    pub fn matches<C>(&self, column: C) -> JsonPathExists<C> {
+        JsonPathExists {
+            column,
+            expression: self.expression(),
+            variables: self.variables(),
+        }
+    }
+}
+
+#[cfg(feature = "app")]
+#[derive(Debug, Clone)]
+pub struct JsonPathExists<C> {
+    column: C,
+    expression: String,
+    variables: Value,
+}
+
+#[cfg(feature = "app")]
+impl<C> Expression for JsonPathExists<C> {
+    type SqlType = Bool;
+}
+
+#[cfg(feature = "app")]
+impl<C, QS> AppearsOnTable<QS> for JsonPathExists<C> where C: AppearsOnTable<QS> {}
+
+#[cfg(feature = "app")]
+impl<C, QS> SelectableExpression<QS> for JsonPathExists<C> where C: SelectableExpression<QS> {}
+
+#[cfg(feature = "app")]
+impl<C, GB> ValidGrouping<GB> for JsonPathExists<C>
+where
+    C: ValidGrouping<GB>,
+{
+    type IsAggregate = C::IsAggregate;
+}
+
+#[cfg(feature = "app")]
+impl<C> QueryId for JsonPathExists<C> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+#[cfg(feature = "app")]
+impl<C: QueryFragment<Pg>> QueryFragment<Pg> for JsonPathExists<C> {
+    fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        pass.push_sql("jsonb_path_exists(");
+        self.column.walk_ast(pass.reborrow())?;
+        pass.push_sql(", ");
+        pass.push_bind_param::<Text, _>(&self.expression)?;
+        pass.push_sql("::jsonpath, ");
+        pass.push_bind_param::<Jsonb, _>(&self.variables)?;
+        pass.push_sql(")");
+
+        Ok(())
+    }
+}
+
+/// `jsonb_path_query_first(column, <path>)`, used by the `order_by` macro for
+/// variants that order by a path within a JSON column
+#[cfg(feature = "app")]
+pub fn walk_first_match<'b, C: QueryFragment<Pg>>(
+    column: &'b C,
+    path: &'b JsonPath,
+    mut pass: AstPass<'_, 'b, Pg>,
+) -> QueryResult<()> {
+    pass.push_sql("jsonb_path_query_first(");
+    column.walk_ast(pass.reborrow())?;
+    pass.push_sql(", ");
+    pass.push_bind_param::<Text, _>(&path.0)?;
+    pass.push_sql("::jsonpath)");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::{JsonPath, JsonPathPredicate};
+
+    #[rstest]
+    #[case("$", "$")]
+    #[case("$.donor.age", r#"$."donor"."age""#)]
+    #[case(r#"$."weird key".x"#, r#"$."weird key"."x""#)]
+    #[case("$.measurements[*].value", r#"$."measurements"[*]."value""#)]
+    #[case("$.a[0].*", r#"$."a"[0].*"#)]
+    fn valid_path(#[case] path: &str, #[case] canonical: &str) {
+        assert_eq!(JsonPath::new(path).unwrap().as_ref(), canonical);
+    }
+
+    #[rstest]
+    #[case("donor.age")]
+    #[case("$.")]
+    #[case("$.a ? (@ > 1)")]
+    #[case("$.a[last]")]
+    #[case(r#"$."unterminated"#)]
+    fn invalid_path(#[case] path: &str) {
+        assert!(JsonPath::new(path).is_err());
+    }
+
+    #[rstest]
+    #[case(json!({"path": "$.age", "op": "gt", "value": 10}), r#"$."age" ? (@ > $value)"#, json!({"value": 10}))]
+    #[case(json!({"path": "$.age", "op": "gt", "value": "10"}), r#"$."age" ? (@ > $value)"#, json!({"value": 10}))]
+    #[case(json!({"path": "$.id", "op": "eq", "value": "\"10\""}), r#"$."id" ? (@ == $value)"#, json!({"value": "10"}))]
+    #[case(json!({"path": "$.tags[*]", "op": "starts_with", "value": "abc"}), r#"$."tags"[*] ? (@ starts with $value)"#, json!({"value": "abc"}))]
+    #[case(json!({"path": "$.age", "op": "exists"}), r#"$."age""#, json!({}))]
+    #[cfg(feature = "app")]
+    fn predicate_expression(
+        #[case] predicate: serde_json::Value,
+        #[case] expression: &str,
+        #[case] variables: serde_json::Value,
+    ) {
+        let predicate: JsonPathPredicate = serde_json::from_value(predicate).unwrap();
+
+        assert_eq!(predicate.expression(), expression);
+        assert_eq!(predicate.variables(), variables);
+    }
+
+    #[rstest]
+    #[case(json!({"path": "$.age", "op": "gt"}))]
+    #[case(json!({"path": "$.age", "op": "exists", "value": 1}))]
+    #[case(json!({"path": "$.age", "op": "starts_with", "value": 1}))]
+    fn invalid_predicate(#[case] predicate: serde_json::Value) {
+        assert!(serde_json::from_value::<JsonPathPredicate>(predicate).is_err());
+    }
+}
@@ -6,7 +6,5 @@ mod update;
 
 pub use common::LabFields;
 pub use creation::LabCreation;
-#[cfg(feature = "app")]
-pub use query::LabQuery;
-pub use query::{LabFilter, LabOrderBy};
+pub use query::{LabFilter, LabOrderBy, LabQuery};
 pub use read::{Lab, LabSummary};
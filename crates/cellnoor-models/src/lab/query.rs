@@ -25,7 +25,6 @@ pub struct LabFilter {
     pub names: Option<Vec<String>>,
 }
 
-#[cfg(feature = "app")]
 pub type LabQuery = crate::generic_query::Query<LabFilter, LabOrderBy>;
 
 uuid_newtype!(LabId, "/{id}");
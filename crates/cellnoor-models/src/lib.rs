@@ -1,22 +1,33 @@
 #![allow(uncommon_codepoints)]
 
+pub mod api_key;
+pub mod change_event;
 pub mod chromium_dataset;
 pub mod chromium_run;
-#[cfg(feature = "app")]
+pub mod committee_approval;
+pub mod donor;
 pub mod generic_query;
 pub mod institution;
+pub mod json_path;
 pub mod lab;
+pub mod lineage;
 mod links;
 pub mod multiplexing_tag;
 mod nucleic_acid;
 pub mod person;
+pub mod project;
+pub mod read_options;
+pub mod search;
 pub mod sequencing_run;
 pub mod specimen;
+pub mod stats;
+pub mod storage;
 pub mod suspension;
 pub mod suspension_pool;
 pub mod tenx_assay;
 mod units;
 #[cfg(feature = "app")]
 mod utils;
+pub mod webhook;
 
 pub use nucleic_acid::{cdna, library, measurement as nucleic_acid_measurement};
@@ -0,0 +1,157 @@
+use std::{collections::BTreeSet, fmt::Write};
+
+use macro_attributes::base_model;
+use uuid::Uuid;
+
+#[base_model]
+#[derive(Copy, Eq, Hash, PartialOrd, Ord, serde::Serialize, strum::IntoStaticStr)]
+#[cfg_attr(feature = "client", derive(serde::Deserialize))]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum LineageNodeType {
+    Specimen,
+    Suspension,
+    SuspensionPool,
+    ChipLoading,
+    GemPool,
+    Cdna,
+    Library,
+    SequencingRun,
+    ChromiumDataset,
+}
+
+#[base_model]
+#[derive(Eq, Hash, PartialOrd, Ord, serde::Serialize)]
+#[cfg_attr(feature = "client", derive(serde::Deserialize))]
+pub struct LineageNode {
+    type_: LineageNodeType,
+    id: Uuid,
+    label: String,
+}
+
+impl LineageNode {
+    #[must_use]
+    pub fn type_(&self) -> LineageNodeType {
+        self.type_
+    }
+
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+#[base_model]
+#[derive(Copy, Eq, Hash, PartialOrd, Ord, serde::Serialize)]
+#[cfg_attr(feature = "client", derive(serde::Deserialize))]
+pub struct LineageEdge {
+    from: Uuid,
+    to: Uuid,
+}
+
+/// The provenance graph of a specimen or Chromium dataset. Nodes and edges are
+/// kept sorted so that repeated requests produce identical output.
+#[base_model]
+#[derive(Default, serde::Serialize)]
+#[cfg_attr(feature = "client", derive(serde::Deserialize))]
+pub struct Lineage {
+    nodes: BTreeSet<LineageNode>,
+    edges: BTreeSet<LineageEdge>,
+}
+
+impl Lineage {
+    pub fn add_node(&mut self, type_: LineageNodeType, id: Uuid, label: impl Into<String>) {
+        self.nodes.insert(LineageNode {
+            type_,
+            id,
+            label: label.into(),
+        });
+    }
+
+    pub fn add_edge(&mut self, from: Uuid, to: Uuid) {
+        self.edges.insert(LineageEdge { from, to });
+    }
+
+    #[must_use]
+    pub fn nodes(&self) -> &BTreeSet<LineageNode> {
+        &self.nodes
+    }
+
+    #[must_use]
+    pub fn edges(&self) -> &BTreeSet<LineageEdge> {
+        &self.edges
+    }
+
+    /// Render the graph in the Graphviz DOT language
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph lineage {\n    rankdir=LR;\n");
+
+        for LineageNode { type_, id, label } in &self.nodes {
+            let type_: &str = type_.into();
+            // Backslashes have to be escaped first so that the escaped quotes
+            // keep theirs
+            let label = label.replace('\\', "\\\\").replace('"', "\\\"");
+            writeln!(dot, r#"    "{id}" [label="{type_}\n{label}"];"#).unwrap();
+        }
+
+        for LineageEdge { from, to } in &self.edges {
+            writeln!(dot, r#"    "{from}" -> "{to}";"#).unwrap();
+        }
+
+        dot.push_str("}\n");
+
+        dot
+    }
+}
+
+#[base_model]
+#[derive(Copy, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineageFormat {
+    #[default]
+    Json,
+    Dot,
+}
+
+#[base_model]
+#[derive(Default, serde::Deserialize, serde::Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LineageQuery {
+    pub format: LineageFormat,
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use uuid::Uuid;
+
+    use super::{Lineage, LineageNodeType};
+
+    #[rstest::rstest]
+    fn lineage_to_dot() {
+        let specimen_id = Uuid::from_u128(1);
+        let suspension_id = Uuid::from_u128(2);
+        let dataset_id = Uuid::from_u128(3);
+
+        let mut lineage = Lineage::default();
+        lineage.add_node(LineageNodeType::Suspension, suspension_id, "SU1");
+        lineage.add_node(LineageNodeType::Specimen, specimen_id, r#"S"1"#);
+        lineage.add_node(LineageNodeType::ChromiumDataset, dataset_id, r"D\");
+        lineage.add_edge(specimen_id, suspension_id);
+
+        assert_eq!(
+            lineage.to_dot(),
+            [
+                "digraph lineage {",
+                "    rankdir=LR;",
+                r#"    "00000000-0000-0000-0000-000000000001" [label="specimen\nS\"1"];"#,
+                r#"    "00000000-0000-0000-0000-000000000002" [label="suspension\nSU1"];"#,
+                r#"    "00000000-0000-0000-0000-000000000003" [label="chromium_dataset\nD\\"];"#,
+                r#"    "00000000-0000-0000-0000-000000000001" -> "00000000-0000-0000-0000-000000000002";"#,
+                "}\n",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -6,7 +6,5 @@ mod read;
 
 pub use common::CdnaFields;
 pub use creation::CdnaCreation;
-#[cfg(feature = "app")]
-pub use query::CdnaQuery;
-pub use query::{CdnaFilter, CdnaId, CdnaIdMeasurements, CdnaOrderBy};
+pub use query::{CdnaFilter, CdnaId, CdnaIdMeasurements, CdnaOrderBy, CdnaQuery};
 pub use read::{Cdna, CdnaSummary};
@@ -4,23 +4,42 @@ use macro_attributes::{filter, order_by};
 use macros::uuid_newtype;
 use uuid::Uuid;
 
-#[cfg(feature = "app")]
-use crate::generic_query;
+use crate::{
+    generic_query,
+    json_path::{JsonPath, JsonPathPredicate},
+};
 
 #[filter]
 pub struct CdnaFilter {
     pub ids: Option<Vec<Uuid>>,
+    pub additional_data_predicates: Option<Vec<JsonPathPredicate>>,
 }
 
 #[order_by(cdna)]
 #[allow(non_camel_case_types)]
 pub enum CdnaOrderBy {
-    id { descending: Option<bool> },
-    readable_id { descending: Option<bool> },
-    library_type { descending: Option<bool> },
-    prepared_at { descending: Option<bool> },
-    gem_pool_id { descending: Option<bool> },
-    n_amplification_cycles { descending: Option<bool> },
+    id {
+        descending: Option<bool>,
+    },
+    readable_id {
+        descending: Option<bool>,
+    },
+    library_type {
+        descending: Option<bool>,
+    },
+    prepared_at {
+        descending: Option<bool>,
+    },
+    gem_pool_id {
+        descending: Option<bool>,
+    },
+    n_amplification_cycles {
+        descending: Option<bool>,
+    },
+    additional_data {
+        path: JsonPath,
+        descending: Option<bool>,
+    },
 }
 
 impl Default for CdnaOrderBy {
@@ -31,7 +50,6 @@ impl Default for CdnaOrderBy {
     }
 }
 
-#[cfg(feature = "app")]
 pub type CdnaQuery = generic_query::Query<CdnaFilter, CdnaOrderBy>;
 
 uuid_newtype!(CdnaId, "/{id}");
@@ -6,7 +6,5 @@ mod read;
 
 pub use common::LibraryFields;
 pub use creation::LibraryCreation;
-#[cfg(feature = "app")]
-pub use query::LibraryQuery;
-pub use query::{LibraryFilter, LibraryId, LibraryIdMeasurements, LibraryOrderBy};
+pub use query::{LibraryFilter, LibraryId, LibraryIdMeasurements, LibraryOrderBy, LibraryQuery};
 pub use read::{Library, LibrarySummary};
@@ -4,25 +4,49 @@ use macro_attributes::{filter, order_by};
 use macros::uuid_newtype;
 use uuid::Uuid;
 
-#[cfg(feature = "app")]
-use crate::generic_query;
+use crate::{
+    generic_query,
+    json_path::{JsonPath, JsonPathPredicate},
+};
 
 #[filter]
 pub struct LibraryFilter {
     pub ids: Option<Vec<Uuid>>,
+    pub readable_ids: Option<Vec<String>>,
+    pub additional_data_predicates: Option<Vec<JsonPathPredicate>>,
 }
 
 #[order_by(libraries)]
 #[allow(non_camel_case_types)]
 pub enum LibraryOrderBy {
-    id { descending: Option<bool> },
-    readable_id { descending: Option<bool> },
-    cdna_id { descending: Option<bool> },
-    single_index_set_name { descending: Option<bool> },
-    dual_index_set_name { descending: Option<bool> },
-    number_of_sample_index_pcr_cycles { descending: Option<bool> },
-    target_reads_per_cell { descending: Option<bool> },
-    prepared_at { descending: Option<bool> },
+    id {
+        descending: Option<bool>,
+    },
+    readable_id {
+        descending: Option<bool>,
+    },
+    cdna_id {
+        descending: Option<bool>,
+    },
+    single_index_set_name {
+        descending: Option<bool>,
+    },
+    dual_index_set_name {
+        descending: Option<bool>,
+    },
+    number_of_sample_index_pcr_cycles {
+        descending: Option<bool>,
+    },
+    target_reads_per_cell {
+        descending: Option<bool>,
+    },
+    prepared_at {
+        descending: Option<bool>,
+    },
+    additional_data {
+        path: JsonPath,
+        descending: Option<bool>,
+    },
 }
 
 impl Default for LibraryOrderBy {
@@ -33,7 +57,6 @@ impl Default for LibraryOrderBy {
     }
 }
 
-#[cfg(feature = "app")]
 pub type LibraryQuery = generic_query::Query<LibraryFilter, LibraryOrderBy>;
 
 uuid_newtype!(LibraryId, "/{id}");
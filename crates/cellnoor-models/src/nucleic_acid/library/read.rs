@@ -29,6 +29,11 @@ impl LibrarySummary {
         self.id
     }
 
+    #[must_use]
+    pub fn readable_id(&self) -> &str {
+        self.inner.readable_id.as_ref()
+    }
+
     #[must_use]
     pub fn prepared_at(&self) -> Timestamp {
         self.prepared_at
@@ -8,6 +8,7 @@ use crate::utils::{JsonFromSql, JsonToSql};
 
 #[json]
 #[cfg_attr(feature = "typescript", ts(concrete(N = String)))]
+#[cfg_attr(feature = "schema", schemars(rename = "Concentration_for_{N}"))]
 pub struct Concentration<N> {
     value: u32,
     #[cfg_attr(feature = "typescript", ts(as = "String"))]
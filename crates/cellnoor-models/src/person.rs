@@ -6,8 +6,6 @@ mod update;
 
 pub use common::{PersonFields, UserRole};
 pub use creation::PersonCreation;
-#[cfg(feature = "app")]
-pub use query::PersonQuery;
-pub use query::{PersonFilter, PersonId, PersonOrderBy};
+pub use query::{PersonFilter, PersonId, PersonOrderBy, PersonQuery};
 pub use read::{Person, PersonSummary, PersonSummaryWithParents};
 pub use update::PersonUpdate;
@@ -32,7 +32,6 @@ pub struct PersonFilter {
     pub microsoft_entra_oids: Option<Vec<Uuid>>,
 }
 
-#[cfg(feature = "app")]
 pub type PersonQuery = crate::generic_query::Query<PersonFilter, PersonOrderBy>;
 
 uuid_newtype!(PersonId, "/{id}");
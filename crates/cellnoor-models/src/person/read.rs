@@ -52,6 +52,7 @@ pub struct PersonSummaryWithParents {
 
 #[base_model]
 #[derive(serde::Serialize)]
+#[cfg_attr(feature = "client", derive(serde::Deserialize))]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct Person {
     #[serde(flatten)]
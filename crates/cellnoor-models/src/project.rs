@@ -0,0 +1,14 @@
+mod common;
+mod creation;
+mod query;
+mod read;
+mod update;
+
+pub use common::ProjectFields;
+pub use creation::ProjectCreation;
+pub use query::{
+    ProjectFilter, ProjectId, ProjectIdChromiumDatasets, ProjectIdSpecimens, ProjectOrderBy,
+    ProjectQuery,
+};
+pub use read::{Project, ProjectSummary};
+pub use update::ProjectUpdate;
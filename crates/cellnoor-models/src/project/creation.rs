@@ -0,0 +1,36 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::projects;
+use jiff::Timestamp;
+use macro_attributes::insert;
+use non_empty::NonEmptyString;
+
+use crate::project::common::ProjectFields;
+
+#[insert]
+#[cfg_attr(feature = "app", diesel(table_name = projects))]
+pub struct ProjectCreation {
+    #[serde(flatten)]
+    #[cfg_attr(feature = "app", diesel(embed))]
+    inner: ProjectFields,
+    #[cfg_attr(feature = "app", diesel(serialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    started_at: Timestamp,
+    #[cfg_attr(feature = "app", diesel(serialize_as = jiff_diesel::NullableTimestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    ended_at: Option<Timestamp>,
+    #[serde(default)]
+    #[cfg_attr(feature = "builder", builder(default))]
+    grant_ids: Vec<NonEmptyString>,
+}
+
+impl ProjectCreation {
+    #[must_use]
+    pub fn started_at(&self) -> Timestamp {
+        self.started_at
+    }
+
+    #[must_use]
+    pub fn ended_at(&self) -> Option<Timestamp> {
+        self.ended_at
+    }
+}
@@ -0,0 +1,38 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::projects;
+use macro_attributes::{filter, order_by};
+use macros::uuid_newtype;
+use uuid::Uuid;
+
+#[order_by(projects)]
+#[allow(non_camel_case_types)]
+pub enum ProjectOrderBy {
+    id { descending: Option<bool> },
+    name { descending: Option<bool> },
+    lab_id { descending: Option<bool> },
+    started_at { descending: Option<bool> },
+    ended_at { descending: Option<bool> },
+}
+
+impl Default for ProjectOrderBy {
+    fn default() -> Self {
+        Self::started_at {
+            descending: Some(true),
+        }
+    }
+}
+
+#[filter]
+pub struct ProjectFilter {
+    pub ids: Option<Vec<Uuid>>,
+    pub names: Option<Vec<String>>,
+    pub labs: Option<Vec<Uuid>>,
+}
+
+pub type ProjectQuery = crate::generic_query::Query<ProjectFilter, ProjectOrderBy>;
+
+uuid_newtype!(ProjectId, "/{id}");
+
+uuid_newtype!(ProjectIdSpecimens, "/{id}/specimens");
+
+uuid_newtype!(ProjectIdChromiumDatasets, "/{id}/chromium-datasets");
@@ -0,0 +1,75 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::{labs, projects};
+#[cfg(feature = "app")]
+use diesel::prelude::*;
+use jiff::Timestamp;
+use macro_attributes::select;
+use uuid::Uuid;
+
+use crate::{lab::LabSummary, links::Links, project::common::ProjectFields};
+
+#[select]
+#[cfg_attr(feature = "app", diesel(table_name = projects))]
+pub struct ProjectSummary {
+    id: Uuid,
+    links: Links,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "app", diesel(embed))]
+    inner: ProjectFields,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    started_at: Timestamp,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::NullableTimestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    ended_at: Option<Timestamp>,
+    grant_ids: Vec<Option<String>>,
+}
+
+impl ProjectSummary {
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.inner.name.as_ref()
+    }
+
+    #[must_use]
+    pub fn started_at(&self) -> Timestamp {
+        self.started_at
+    }
+
+    #[must_use]
+    pub fn ended_at(&self) -> Option<Timestamp> {
+        self.ended_at
+    }
+}
+
+#[select]
+#[cfg_attr(feature = "app", diesel(base_query = projects::table.inner_join(labs::table)))]
+pub struct Project {
+    #[serde(flatten)]
+    #[cfg_attr(feature = "app", diesel(embed))]
+    summary: ProjectSummary,
+    #[cfg_attr(feature = "app", diesel(embed))]
+    lab: LabSummary,
+}
+
+impl Project {
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.summary.id()
+    }
+
+    #[must_use]
+    pub fn started_at(&self) -> Timestamp {
+        self.summary.started_at()
+    }
+
+    #[must_use]
+    pub fn ended_at(&self) -> Option<Timestamp> {
+        self.summary.ended_at()
+    }
+}
@@ -0,0 +1,38 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::projects;
+use jiff::Timestamp;
+use macro_attributes::update;
+use non_empty::NonEmptyString;
+use uuid::Uuid;
+
+#[update]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "builder", builder(on(_, into)))]
+#[cfg_attr(feature = "app", diesel(table_name = projects))]
+#[cfg_attr(feature = "typescript", ts(rename = "ProjectUpdate"))]
+pub struct ProjectUpdate {
+    #[serde(skip)]
+    #[cfg_attr(feature = "builder", builder(skip))]
+    id: Uuid,
+    name: Option<NonEmptyString>,
+    description: Option<NonEmptyString>,
+    #[cfg_attr(feature = "app", diesel(serialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    started_at: Option<Timestamp>,
+    #[cfg_attr(feature = "app", diesel(serialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    ended_at: Option<Timestamp>,
+    grant_ids: Option<Vec<NonEmptyString>>,
+}
+
+impl ProjectUpdate {
+    #[must_use]
+    pub fn started_at(&self) -> Option<Timestamp> {
+        self.started_at
+    }
+
+    #[must_use]
+    pub fn ended_at(&self) -> Option<Timestamp> {
+        self.ended_at
+    }
+}
@@ -0,0 +1,292 @@
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    Deserialize, Deserializer,
+    de::{IntoDeserializer, SeqAccess, Visitor},
+};
+
+/// Controls the shape of a read response. `fields` trims each item to the
+/// listed top-level fields (`id` is always kept), and `include` embeds related
+/// objects in place of their ids.
+///
+/// Both are comma-separated in a query string, such as
+/// `?fields=name,lab&include=lab`.
+#[derive(Clone, Debug, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+#[cfg_attr(feature = "schema", derive(::schemars::JsonSchema))]
+#[serde(default, deny_unknown_fields)]
+pub struct ReadOptions<I> {
+    #[serde(deserialize_with = "comma_separated")]
+    pub fields: Vec<String>,
+    #[serde(
+        deserialize_with = "comma_separated",
+        bound(deserialize = "I: Deserialize<'de>")
+    )]
+    pub include: Vec<I>,
+}
+
+impl<I> Default for ReadOptions<I> {
+    fn default() -> Self {
+        Self {
+            fields: Vec::new(),
+            include: Vec::new(),
+        }
+    }
+}
+
+impl<I> ReadOptions<I> {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.include.is_empty()
+    }
+}
+
+/// The `include` of a resource that has no related objects to embed, so that
+/// any value is rejected
+#[derive(Clone, Copy, Debug, PartialEq, ::serde::Serialize)]
+#[cfg_attr(feature = "typescript", derive(::ts_rs::TS))]
+pub enum NoInclude {}
+
+impl<'de> Deserialize<'de> for NoInclude {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let relation = String::deserialize(deserializer)?;
+
+        Err(serde::de::Error::custom(format!(
+            "cannot include `{relation}`: this resource has no related objects to include"
+        )))
+    }
+}
+
+/// An `include`, along with the related objects it embeds
+pub trait Include {
+    /// An object with an optional field for each related object, named as it's
+    /// embedded
+    type Embeds;
+}
+
+impl Include for NoInclude {
+    type Embeds = NoEmbeds;
+}
+
+/// The related objects embedded by [`NoInclude`], of which there are none
+#[derive(Clone, Copy, Debug, PartialEq, ::serde::Serialize)]
+#[cfg_attr(feature = "typescript", derive(::ts_rs::TS))]
+#[cfg_attr(feature = "schema", derive(::schemars::JsonSchema))]
+pub struct NoEmbeds {}
+
+/// Documents a read response shaped by [`ReadOptions`]. Without `fields` or
+/// `include` it's just `T`. Otherwise only `id` is guaranteed to be present,
+/// and each of the related objects named by `I` replaces the field of the same
+/// name, if there is one.
+///
+/// The API builds these responses as JSON objects, so this type only exists to
+/// describe them.
+pub struct Shaped<T, I>(PhantomData<fn() -> (T, I)>);
+
+/// Deserialize either a comma-separated string or a sequence
+pub(crate) fn comma_separated<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    struct CommaSeparated<T>(PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for CommaSeparated<T> {
+        type Value = Vec<T>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a comma-separated string or a sequence")
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(|s| T::deserialize(s.to_owned().into_deserializer()))
+                .collect()
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut items = Vec::new();
+            while let Some(item) = seq.next_element()? {
+                items.push(item);
+            }
+
+            Ok(items)
+        }
+    }
+
+    deserializer.deserialize_any(CommaSeparated(PhantomData))
+}
+
+#[cfg(feature = "schema")]
+mod schema_impls {
+    use std::borrow::Cow;
+
+    use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+    use serde_json::{Map, Value};
+
+    use super::{Include, NoInclude, Shaped};
+
+    impl JsonSchema for NoInclude {
+        fn schema_name() -> Cow<'static, str> {
+            "NoInclude".into()
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "description": "This resource has no related objects to include",
+                "not": {}
+            })
+        }
+    }
+
+    impl<T, I> JsonSchema for Shaped<T, I>
+    where
+        T: JsonSchema,
+        I: Include,
+        I::Embeds: JsonSchema,
+    {
+        fn schema_name() -> Cow<'static, str> {
+            format!("Shaped_{}", T::schema_name()).into()
+        }
+
+        fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+            let mut shaped = T::json_schema(generator);
+            keep_only_id_required(&mut shaped);
+
+            let embeds = I::Embeds::json_schema(generator);
+            if let Some(Value::Object(embeds)) = embeds.get("properties").cloned() {
+                let properties = shaped
+                    .ensure_object()
+                    .entry("properties")
+                    .or_insert_with(|| Value::Object(Map::new()));
+                if let Value::Object(properties) = properties {
+                    properties.extend(embeds);
+                }
+            }
+
+            json_schema!({
+                "description": "The full object if neither `fields` nor `include` was given, and otherwise the object trimmed to `fields` with the related objects named by `include` embedded",
+                "anyOf": [generator.subschema_for::<T>(), shaped]
+            })
+        }
+    }
+
+    fn keep_only_id_required(schema: &mut Schema) {
+        let Some(schema) = schema.as_object_mut() else {
+            return;
+        };
+
+        if let Some(Value::Array(required)) = schema.get_mut("required") {
+            required.retain(|field| field == "id");
+        }
+
+        for key in ["allOf", "anyOf", "oneOf"] {
+            if let Some(Value::Array(subschemas)) = schema.get_mut(key) {
+                for subschema in subschemas {
+                    if let Ok(subschema) = <&mut Schema>::try_from(subschema) {
+                        keep_only_id_required(subschema);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "typescript")]
+mod ts_impls {
+    use ts_rs::{Dummy, TS, TypeVisitor};
+
+    use super::{Include, NoInclude, Shaped};
+
+    // `Shaped` is always inlined, so it only needs a name and the types it refers
+    // to
+    impl<T, I> TS for Shaped<T, I>
+    where
+        T: TS,
+        I: Include,
+        I::Embeds: TS,
+    {
+        type OptionInnerType = Self;
+        type WithoutGenerics = Shaped<Dummy, NoInclude>;
+
+        fn name() -> String {
+            shaped(&T::name(), &I::Embeds::name())
+        }
+
+        fn inline() -> String {
+            shaped(&T::inline(), &I::Embeds::inline())
+        }
+
+        fn visit_dependencies(v: &mut impl TypeVisitor)
+        where
+            Self: 'static,
+        {
+            T::visit_dependencies(v);
+            I::Embeds::visit_dependencies(v);
+        }
+
+        fn visit_generics(v: &mut impl TypeVisitor)
+        where
+            Self: 'static,
+        {
+            T::visit_generics(v);
+            v.visit::<T>();
+            I::Embeds::visit_generics(v);
+            v.visit::<I::Embeds>();
+        }
+
+        fn decl() -> String {
+            panic!("{} cannot be declared", Self::name())
+        }
+
+        fn decl_concrete() -> String {
+            panic!("{} cannot be declared", Self::name())
+        }
+
+        fn inline_flattened() -> String {
+            panic!("{} cannot be flattened", Self::name())
+        }
+    }
+
+    fn shaped(item: &str, embeds: &str) -> String {
+        format!("{item} | (Omit<Partial<{item}>, keyof {embeds}> & {{ id: string }} & {embeds})")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::ReadOptions;
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Include {
+        Lab,
+        SubmittedBy,
+    }
+
+    #[rstest]
+    #[case("fields=id,name&include=lab,submitted_by")]
+    #[case("fields[0]=id&fields[1]=name&include[0]=lab&include[1]=submitted_by")]
+    fn comma_separated_or_sequence(#[case] query: &str) {
+        let options: ReadOptions<Include> = serde_qs::from_str(query).unwrap();
+
+        assert_eq!(
+            options,
+            ReadOptions {
+                fields: vec!["id".to_owned(), "name".to_owned()],
+                include: vec![Include::Lab, Include::SubmittedBy],
+            }
+        );
+    }
+
+    #[rstest]
+    fn unknown_include() {
+        let result: Result<ReadOptions<Include>, _> = serde_qs::from_str("include=donor");
+
+        assert!(result.is_err());
+    }
+}
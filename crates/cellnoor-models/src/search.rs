@@ -0,0 +1,86 @@
+use std::collections::BTreeMap;
+
+use macro_attributes::{base_model, simple_enum};
+use macros::impl_enum_from_sql;
+use non_empty::NonEmptyString;
+use uuid::Uuid;
+
+use crate::links::Links;
+#[cfg(feature = "app")]
+use crate::utils::EnumFromSql;
+
+#[simple_enum]
+pub enum SearchEntityType {
+    Institution,
+    Person,
+    Lab,
+    Project,
+    Donor,
+    Specimen,
+    Suspension,
+    SuspensionPool,
+    ChromiumRun,
+    Cdna,
+    Library,
+    SequencingRun,
+    ChromiumDataset,
+}
+
+#[cfg(feature = "app")]
+impl EnumFromSql for SearchEntityType {}
+impl_enum_from_sql!(SearchEntityType);
+
+#[base_model]
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "client", derive(serde::Deserialize))]
+#[cfg_attr(feature = "app", derive(diesel::Queryable))]
+pub struct SearchHit {
+    id: Uuid,
+    label: String,
+    links: Links,
+    rank: f32,
+}
+
+impl SearchHit {
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    #[must_use]
+    pub fn rank(&self) -> f32 {
+        self.rank
+    }
+}
+
+/// Hits grouped by the type of the entity they refer to, each group ordered
+/// from best to worst match
+#[base_model]
+#[derive(Default, serde::Serialize)]
+#[cfg_attr(feature = "client", derive(serde::Deserialize))]
+pub struct SearchResults(BTreeMap<SearchEntityType, Vec<SearchHit>>);
+
+impl SearchResults {
+    pub fn push(&mut self, entity_type: SearchEntityType, hit: SearchHit) {
+        self.0.entry(entity_type).or_default().push(hit);
+    }
+
+    #[must_use]
+    pub fn get(&self, entity_type: SearchEntityType) -> &[SearchHit] {
+        self.0.get(&entity_type).map_or(&[], Vec::as_slice)
+    }
+}
+
+const fn default_limit() -> i64 {
+    100
+}
+
+#[base_model]
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SearchQuery {
+    pub q: NonEmptyString,
+    /// The maximum number of hits across all entity types
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+}
@@ -6,9 +6,8 @@ mod read;
 
 pub use common::SequencingRunFields;
 pub use creation::SequencingRunCreation;
-#[cfg(feature = "app")]
-pub use query::SequencingRunQuery;
 pub use query::{
     SequencingRunFilter, SequencingRunId, SequencingRunIdLibraries, SequencingRunOrderBy,
+    SequencingRunQuery,
 };
 pub use read::SequencingRun;
@@ -23,7 +23,6 @@ impl Default for SequencingRunOrderBy {
 #[filter]
 pub struct SequencingRunFilter {}
 
-#[cfg(feature = "app")]
 pub type SequencingRunQuery =
     crate::generic_query::Query<SequencingRunFilter, SequencingRunOrderBy>;
 
@@ -1,6 +1,7 @@
 mod common;
 mod creation;
 pub mod measurement;
+mod progress;
 mod query;
 mod read;
 
@@ -22,10 +23,12 @@ pub use creation::{
         CryopreservedTissueCreation, FixedTissueCreation, FrozenTissueCreation, TissueFixative,
     },
 };
-#[cfg(feature = "app")]
-pub use query::SpecimenQuery;
+pub use progress::{
+    SpecimenProgress, SpecimenStage, SpecimenStageTimes, StageDuration, StalledSpecimenQuery,
+};
 pub use query::{
-    SpecimenFilter, SpecimenId, SpecimenIdChromiumDatasets, SpecimenIdMeasurements,
-    SpecimenIdSuspensions, SpecimenOrderBy,
+    SpecimenEmbeds, SpecimenFilter, SpecimenId, SpecimenIdChromiumDatasets, SpecimenIdLineage,
+    SpecimenIdMeasurements, SpecimenIdProgress, SpecimenIdSuspensions, SpecimenInclude,
+    SpecimenOrderBy, SpecimenQuery,
 };
 pub use read::{Specimen, SpecimenSummary};
@@ -1,5 +1,6 @@
 use jiff::Timestamp;
 use macro_attributes::base_model;
+use uuid::Uuid;
 
 use crate::specimen::{
     common::{Species, SpecimenCommonFields},
@@ -19,6 +20,7 @@ pub mod tissue;
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SpecimenCreation {
     FixedBlock(FixedBlockCreation),
@@ -52,6 +54,31 @@ impl SpecimenCreation {
         }
     }
 
+    #[must_use]
+    pub fn committee_approval_ids(&self) -> &[Uuid] {
+        use SpecimenCreation::{
+            CryopreservedSuspension, CryopreservedTissue, FixedBlock, FixedSuspension, FixedTissue,
+            FreshSuspension, FrozenBlock, FrozenSuspension, FrozenTissue,
+        };
+
+        match self {
+            FixedBlock(s) => &s.committee_approval_ids,
+            FrozenBlock(s) => &s.committee_approval_ids,
+            CryopreservedSuspension(s) => &s.committee_approval_ids,
+            FixedSuspension(s) => &s.committee_approval_ids,
+            FreshSuspension(s) => &s.committee_approval_ids,
+            FrozenSuspension(s) => &s.committee_approval_ids,
+            CryopreservedTissue(s) => &s.committee_approval_ids,
+            FixedTissue(s) => &s.committee_approval_ids,
+            FrozenTissue(s) => &s.committee_approval_ids,
+        }
+    }
+
+    #[must_use]
+    pub fn submitted_by(&self) -> Uuid {
+        self.inner().submitted_by
+    }
+
     #[must_use]
     pub fn received_at(&self) -> Timestamp {
         self.inner().received_at
@@ -62,6 +89,11 @@ impl SpecimenCreation {
         self.inner().returned_at
     }
 
+    #[must_use]
+    pub fn lab_id(&self) -> Uuid {
+        self.inner().lab_id
+    }
+
     #[must_use]
     pub fn species(&self) -> Species {
         self.inner().species
@@ -71,4 +103,9 @@ impl SpecimenCreation {
     pub fn host_species(&self) -> Option<Species> {
         self.inner().host_species
     }
+
+    #[must_use]
+    pub fn donor_id(&self) -> Option<Uuid> {
+        self.inner().donor_id
+    }
 }
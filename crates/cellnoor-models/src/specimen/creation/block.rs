@@ -1,4 +1,5 @@
 use macro_attributes::{base_model, simple_enum};
+use uuid::Uuid;
 
 use crate::specimen::common::{
     EmbeddingMatrix, Fixative, SpecimenCommonFields, SpecimenType, SpecimenVariableFields,
@@ -8,10 +9,14 @@ const TYPE: SpecimenType = SpecimenType::Block;
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct FixedBlockCreation {
     #[serde(flatten)]
     pub(super) inner: SpecimenCommonFields,
+    #[serde(default)]
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub(super) committee_approval_ids: Vec<Uuid>,
     embedded_in: FixedBlockEmbeddingMatrix,
     fixative: BlockFixative,
 }
@@ -21,6 +26,7 @@ impl FixedBlockCreation {
     pub fn split_for_insertion(self) -> (SpecimenCommonFields, SpecimenVariableFields) {
         let Self {
             inner,
+            committee_approval_ids: _,
             embedded_in,
             fixative,
         } = self;
@@ -52,10 +58,14 @@ pub enum BlockFixative {
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct FrozenBlockCreation {
     #[serde(flatten)]
     pub(super) inner: SpecimenCommonFields,
+    #[serde(default)]
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub(super) committee_approval_ids: Vec<Uuid>,
     embedded_in: FrozenBlockEmbeddingMatrix,
     fixative: Option<BlockFixative>,
 }
@@ -65,6 +75,7 @@ impl FrozenBlockCreation {
     pub fn split_for_insertion(self) -> (SpecimenCommonFields, SpecimenVariableFields) {
         let Self {
             inner,
+            committee_approval_ids: _,
             embedded_in,
             fixative,
         } = self;
@@ -1,4 +1,5 @@
 use macro_attributes::{base_model, simple_enum};
+use uuid::Uuid;
 
 use crate::specimen::common::{
     Fixative, SpecimenCommonFields, SpecimenType, SpecimenVariableFields,
@@ -8,16 +9,23 @@ const TYPE: SpecimenType = SpecimenType::Suspension;
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct CryopreservedSuspensionCreation {
     #[serde(flatten)]
     pub(super) inner: SpecimenCommonFields,
+    #[serde(default)]
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub(super) committee_approval_ids: Vec<Uuid>,
 }
 
 impl CryopreservedSuspensionCreation {
     #[must_use]
     pub fn split_for_insertion(self) -> (SpecimenCommonFields, SpecimenVariableFields) {
-        let Self { inner } = self;
+        let Self {
+            inner,
+            committee_approval_ids: _,
+        } = self;
 
         (
             inner,
@@ -34,17 +42,25 @@ impl CryopreservedSuspensionCreation {
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct FixedSuspensionCreation {
     #[serde(flatten)]
     pub(super) inner: SpecimenCommonFields,
+    #[serde(default)]
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub(super) committee_approval_ids: Vec<Uuid>,
     fixative: SuspensionFixative,
 }
 
 impl FixedSuspensionCreation {
     #[must_use]
     pub fn split_for_insertion(self) -> (SpecimenCommonFields, SpecimenVariableFields) {
-        let Self { inner, fixative } = self;
+        let Self {
+            inner,
+            committee_approval_ids: _,
+            fixative,
+        } = self;
 
         (
             inner,
@@ -61,16 +77,23 @@ impl FixedSuspensionCreation {
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct FreshSuspensionCreation {
     #[serde(flatten)]
     pub(super) inner: SpecimenCommonFields,
+    #[serde(default)]
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub(super) committee_approval_ids: Vec<Uuid>,
 }
 
 impl FreshSuspensionCreation {
     #[must_use]
     pub fn split_for_insertion(self) -> (SpecimenCommonFields, SpecimenVariableFields) {
-        let Self { inner } = self;
+        let Self {
+            inner,
+            committee_approval_ids: _,
+        } = self;
 
         (
             inner,
@@ -94,16 +117,23 @@ pub enum SuspensionFixative {
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct FrozenSuspensionCreation {
     #[serde(flatten)]
     pub(super) inner: SpecimenCommonFields,
+    #[serde(default)]
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub(super) committee_approval_ids: Vec<Uuid>,
 }
 
 impl FrozenSuspensionCreation {
     #[must_use]
     pub fn split_for_insertion(self) -> (SpecimenCommonFields, SpecimenVariableFields) {
-        let Self { inner } = self;
+        let Self {
+            inner,
+            committee_approval_ids: _,
+        } = self;
 
         (
             inner,
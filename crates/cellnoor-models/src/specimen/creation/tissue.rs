@@ -1,4 +1,5 @@
 use macro_attributes::{base_model, simple_enum};
+use uuid::Uuid;
 
 use crate::specimen::common::{
     Fixative, SpecimenCommonFields, SpecimenType, SpecimenVariableFields,
@@ -8,16 +9,23 @@ const TYPE: SpecimenType = SpecimenType::Tissue;
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct CryopreservedTissueCreation {
     #[serde(flatten)]
     pub(super) inner: SpecimenCommonFields,
+    #[serde(default)]
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub(super) committee_approval_ids: Vec<Uuid>,
 }
 
 impl CryopreservedTissueCreation {
     #[must_use]
     pub fn split_for_insertion(self) -> (SpecimenCommonFields, SpecimenVariableFields) {
-        let Self { inner } = self;
+        let Self {
+            inner,
+            committee_approval_ids: _,
+        } = self;
 
         (
             inner,
@@ -34,17 +42,25 @@ impl CryopreservedTissueCreation {
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct FixedTissueCreation {
     #[serde(flatten)]
     pub(super) inner: SpecimenCommonFields,
+    #[serde(default)]
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub(super) committee_approval_ids: Vec<Uuid>,
     fixative: TissueFixative,
 }
 
 impl FixedTissueCreation {
     #[must_use]
     pub fn split_for_insertion(self) -> (SpecimenCommonFields, SpecimenVariableFields) {
-        let Self { inner, fixative } = self;
+        let Self {
+            inner,
+            committee_approval_ids: _,
+            fixative,
+        } = self;
 
         (
             inner,
@@ -67,16 +83,23 @@ pub enum TissueFixative {
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct FrozenTissueCreation {
     #[serde(flatten)]
     pub(super) inner: SpecimenCommonFields,
+    #[serde(default)]
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub(super) committee_approval_ids: Vec<Uuid>,
 }
 
 impl FrozenTissueCreation {
     #[must_use]
     pub fn split_for_insertion(self) -> (SpecimenCommonFields, SpecimenVariableFields) {
-        let Self { inner } = self;
+        let Self {
+            inner,
+            committee_approval_ids: _,
+        } = self;
 
         (
             inner,
@@ -0,0 +1,240 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::specimen_progress;
+use jiff::Timestamp;
+use macro_attributes::{base_model, select, simple_enum};
+use macros::{impl_enum_from_sql, impl_enum_to_sql};
+use uuid::Uuid;
+
+use crate::specimen::SpecimenFilter;
+#[cfg(feature = "app")]
+use crate::utils::{EnumFromSql, EnumToSql};
+
+/// The stages of the pipeline, in order
+#[simple_enum]
+pub enum SpecimenStage {
+    Received,
+    SuspensionPrepared,
+    ChipLoaded,
+    CdnaPrepared,
+    LibraryPrepared,
+    Sequenced,
+    Delivered,
+}
+
+#[cfg(feature = "app")]
+impl EnumFromSql for SpecimenStage {}
+impl_enum_from_sql!(SpecimenStage);
+
+#[cfg(feature = "app")]
+impl EnumToSql for SpecimenStage {}
+impl_enum_to_sql!(SpecimenStage);
+
+/// When a specimen first reached each stage. The time a suspension was prepared
+/// is optional, so it may be missing even though a later stage was reached.
+#[select]
+#[cfg_attr(feature = "app", diesel(table_name = specimen_progress))]
+pub struct SpecimenStageTimes {
+    specimen_id: Uuid,
+    stage: SpecimenStage,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    received_at: Timestamp,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::NullableTimestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    suspension_prepared_at: Option<Timestamp>,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::NullableTimestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    chip_loaded_at: Option<Timestamp>,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::NullableTimestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    cdna_prepared_at: Option<Timestamp>,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::NullableTimestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    library_prepared_at: Option<Timestamp>,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::NullableTimestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    sequenced_at: Option<Timestamp>,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::NullableTimestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    delivered_at: Option<Timestamp>,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    stage_entered_at: Timestamp,
+}
+
+impl SpecimenStageTimes {
+    #[must_use]
+    pub fn specimen_id(&self) -> Uuid {
+        self.specimen_id
+    }
+
+    #[must_use]
+    pub fn stage(&self) -> SpecimenStage {
+        self.stage
+    }
+
+    #[must_use]
+    pub fn stage_entered_at(&self) -> Timestamp {
+        self.stage_entered_at
+    }
+
+    fn entered_at(&self) -> [(SpecimenStage, Option<Timestamp>); 7] {
+        use SpecimenStage::{
+            CdnaPrepared, ChipLoaded, Delivered, LibraryPrepared, Received, Sequenced,
+            SuspensionPrepared,
+        };
+
+        [
+            (Received, Some(self.received_at)),
+            (SuspensionPrepared, self.suspension_prepared_at),
+            (ChipLoaded, self.chip_loaded_at),
+            (CdnaPrepared, self.cdna_prepared_at),
+            (LibraryPrepared, self.library_prepared_at),
+            (Sequenced, self.sequenced_at),
+            (Delivered, self.delivered_at),
+        ]
+    }
+}
+
+#[base_model]
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "client", derive(serde::Deserialize))]
+pub struct StageDuration {
+    stage: SpecimenStage,
+    days: f64,
+}
+
+impl StageDuration {
+    #[must_use]
+    pub fn stage(&self) -> SpecimenStage {
+        self.stage
+    }
+
+    #[must_use]
+    pub fn days(&self) -> f64 {
+        self.days
+    }
+}
+
+#[base_model]
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "client", derive(serde::Deserialize))]
+pub struct SpecimenProgress {
+    #[serde(flatten)]
+    times: SpecimenStageTimes,
+    /// The time spent in each stage that was reached, up to `now` for the
+    /// current stage. Delivery is the last stage, so it has no duration.
+    durations: Vec<StageDuration>,
+}
+
+impl SpecimenProgress {
+    #[must_use]
+    pub fn new(times: SpecimenStageTimes, now: Timestamp) -> Self {
+        let reached: Vec<_> = times
+            .entered_at()
+            .into_iter()
+            .filter(|(stage, _)| *stage <= times.stage)
+            .collect();
+
+        let mut durations = Vec::new();
+        for (i, (stage, start)) in reached.iter().enumerate() {
+            let Some(start) = start else {
+                continue;
+            };
+            if *stage == SpecimenStage::Delivered {
+                break;
+            }
+
+            let end = reached[i + 1..].iter().find_map(|(_, t)| *t).unwrap_or(now);
+            let days = end.duration_since(*start).as_secs_f64() / 86_400.0;
+
+            durations.push(StageDuration {
+                stage: *stage,
+                days,
+            });
+        }
+
+        Self { times, durations }
+    }
+
+    #[must_use]
+    pub fn times(&self) -> &SpecimenStageTimes {
+        &self.times
+    }
+
+    #[must_use]
+    pub fn durations(&self) -> &[StageDuration] {
+        &self.durations
+    }
+}
+
+const fn default_sla_days() -> f64 {
+    14.0
+}
+
+/// Specimens that have spent longer than `sla_days` in their current stage,
+/// not counting delivered specimens. Use `filter.stage` to apply a different
+/// SLA to each stage.
+#[base_model]
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct StalledSpecimenQuery {
+    #[serde(default = "default_sla_days")]
+    pub sla_days: f64,
+    #[serde(default)]
+    pub filter: Option<SpecimenFilter>,
+}
+
+impl Default for StalledSpecimenQuery {
+    fn default() -> Self {
+        Self {
+            sla_days: default_sla_days(),
+            filter: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::{Timestamp, ToSpan};
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use uuid::Uuid;
+
+    use super::{SpecimenProgress, SpecimenStage, SpecimenStageTimes};
+
+    #[rstest]
+    fn missing_suspension_time_counts_toward_received() {
+        let received_at: Timestamp = "2026-01-01T00:00:00Z".parse().unwrap();
+        let chip_loaded_at = received_at + 72.hours();
+        let now = chip_loaded_at + 24.hours();
+
+        let times = SpecimenStageTimes {
+            specimen_id: Uuid::nil(),
+            stage: SpecimenStage::ChipLoaded,
+            received_at,
+            suspension_prepared_at: None,
+            chip_loaded_at: Some(chip_loaded_at),
+            cdna_prepared_at: None,
+            library_prepared_at: None,
+            sequenced_at: None,
+            delivered_at: None,
+            stage_entered_at: chip_loaded_at,
+        };
+
+        let progress = SpecimenProgress::new(times, now);
+        let durations: Vec<_> = progress
+            .durations()
+            .iter()
+            .map(|d| (d.stage(), d.days()))
+            .collect();
+
+        assert_eq!(
+            durations,
+            [
+                (SpecimenStage::Received, 3.0),
+                (SpecimenStage::ChipLoaded, 1.0)
+            ]
+        );
+    }
+}
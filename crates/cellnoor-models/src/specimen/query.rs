@@ -1,14 +1,23 @@
 #[cfg(feature = "app")]
 use cellnoor_schema::specimens;
 use jiff::Timestamp;
-use macro_attributes::{filter, order_by};
+use macro_attributes::{filter, order_by, simple_enum};
 use macros::uuid_newtype;
 use serde_json::Value;
 use uuid::Uuid;
 
-#[cfg(feature = "app")]
-use crate::generic_query;
-use crate::specimen::common::{EmbeddingMatrix, Fixative, Species, SpecimenType};
+use crate::{
+    donor::DonorSummary,
+    generic_query,
+    json_path::{JsonPath, JsonPathPredicate},
+    lab::LabSummary,
+    person::PersonSummary,
+    read_options::Include,
+    specimen::{
+        common::{EmbeddingMatrix, Fixative, Species, SpecimenType},
+        progress::SpecimenStage,
+    },
+};
 
 #[filter]
 pub struct SpecimenFilter {
@@ -16,6 +25,8 @@ pub struct SpecimenFilter {
     pub names: Option<Vec<String>>,
     pub submitted_by: Option<Vec<Uuid>>,
     pub labs: Option<Vec<Uuid>>,
+    pub donor_ids: Option<Vec<Uuid>>,
+    pub project_ids: Option<Vec<Uuid>>,
     #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
     pub received_before: Option<Timestamp>,
     #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
@@ -34,27 +45,68 @@ pub struct SpecimenFilter {
     pub returned_after: Option<Timestamp>,
     pub returned_by: Option<Vec<Uuid>>,
     pub additional_data: Option<Value>,
+    pub additional_data_predicates: Option<Vec<JsonPathPredicate>>,
+    pub stage: Option<Vec<SpecimenStage>>,
 }
 
 #[order_by(specimens)]
 #[allow(non_camel_case_types)]
 pub enum SpecimenOrderBy {
-    id { descending: Option<bool> },
-    readable_id { descending: Option<bool> },
-    name { descending: Option<bool> },
-    submitted_by { descending: Option<bool> },
-    lab_id { descending: Option<bool> },
-    received_at { descending: Option<bool> },
-    species { descending: Option<bool> },
-    host_species { descending: Option<bool> },
-    returned_at { descending: Option<bool> },
-    returned_by { descending: Option<bool> },
-    type_ { descending: Option<bool> },
-    embedded_in { descending: Option<bool> },
-    fixative { descending: Option<bool> },
-    frozen { descending: Option<bool> },
-    cryopreserved { descending: Option<bool> },
-    tissue { descending: Option<bool> },
+    id {
+        descending: Option<bool>,
+    },
+    readable_id {
+        descending: Option<bool>,
+    },
+    name {
+        descending: Option<bool>,
+    },
+    submitted_by {
+        descending: Option<bool>,
+    },
+    lab_id {
+        descending: Option<bool>,
+    },
+    donor_id {
+        descending: Option<bool>,
+    },
+    received_at {
+        descending: Option<bool>,
+    },
+    species {
+        descending: Option<bool>,
+    },
+    host_species {
+        descending: Option<bool>,
+    },
+    returned_at {
+        descending: Option<bool>,
+    },
+    returned_by {
+        descending: Option<bool>,
+    },
+    type_ {
+        descending: Option<bool>,
+    },
+    embedded_in {
+        descending: Option<bool>,
+    },
+    fixative {
+        descending: Option<bool>,
+    },
+    frozen {
+        descending: Option<bool>,
+    },
+    cryopreserved {
+        descending: Option<bool>,
+    },
+    tissue {
+        descending: Option<bool>,
+    },
+    additional_data {
+        path: JsonPath,
+        descending: Option<bool>,
+    },
 }
 
 impl Default for SpecimenOrderBy {
@@ -65,8 +117,34 @@ impl Default for SpecimenOrderBy {
     }
 }
 
-#[cfg(feature = "app")]
-pub type SpecimenQuery = generic_query::Query<SpecimenFilter, SpecimenOrderBy>;
+/// Related objects that can be embedded in a specimen with `include`
+#[simple_enum]
+pub enum SpecimenInclude {
+    Lab,
+    SubmittedBy,
+    Donor,
+}
+
+impl Include for SpecimenInclude {
+    type Embeds = SpecimenEmbeds;
+}
+
+/// The related objects embedded in a specimen by [`SpecimenInclude`]
+#[derive(Clone, Debug, PartialEq, ::serde::Serialize)]
+#[cfg_attr(feature = "typescript", derive(::ts_rs::TS), ts(optional_fields))]
+#[cfg_attr(feature = "schema", derive(::schemars::JsonSchema))]
+pub struct SpecimenEmbeds {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(with = "LabSummary"))]
+    pub lab: Option<LabSummary>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "schema", schemars(with = "PersonSummary"))]
+    pub submitted_by: Option<PersonSummary>,
+    /// `null` if the specimen has no donor
+    pub donor: Option<Option<DonorSummary>>,
+}
+
+pub type SpecimenQuery = generic_query::Query<SpecimenFilter, SpecimenOrderBy, SpecimenInclude>;
 
 uuid_newtype!(SpecimenId, "/{id}");
 
@@ -75,3 +153,7 @@ uuid_newtype!(SpecimenIdMeasurements, "/{id}/measurements");
 uuid_newtype!(SpecimenIdSuspensions, "/{id}/suspensions");
 
 uuid_newtype!(SpecimenIdChromiumDatasets, "/{id}/chromium-datasets");
+
+uuid_newtype!(SpecimenIdLineage, "/{id}/lineage");
+
+uuid_newtype!(SpecimenIdProgress, "/{id}/progress");
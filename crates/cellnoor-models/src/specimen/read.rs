@@ -71,6 +71,11 @@ pub struct Specimen {
 }
 
 impl Specimen {
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.summary.id()
+    }
+
     #[must_use]
     pub fn received_at(&self) -> Timestamp {
         self.summary.received_at()
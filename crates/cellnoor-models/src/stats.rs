@@ -0,0 +1,168 @@
+use jiff::Timestamp;
+use macro_attributes::{base_model, simple_enum};
+
+use crate::{chromium_dataset::ChromiumDatasetFilter, specimen::SpecimenFilter};
+
+/// The unit that timestamps are truncated to, in UTC
+#[simple_enum]
+pub enum TimeBucket {
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+#[simple_enum]
+pub enum SpecimenGroupBy {
+    Lab,
+    Species,
+    Type,
+    Tissue,
+}
+
+#[simple_enum]
+pub enum ChromiumDatasetGroupBy {
+    Lab,
+    Assay,
+}
+
+/// The items matching `filter` are split into one series per `group_by` value,
+/// and each series into one point per `bucket`. Omitting either yields a single
+/// series or a single point respectively.
+#[derive(Clone, Debug, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+#[cfg_attr(feature = "schema", derive(::schemars::JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct StatsQuery<F, G> {
+    pub filter: Option<F>,
+    pub group_by: Option<G>,
+    pub bucket: Option<TimeBucket>,
+}
+
+impl<F, G> Default for StatsQuery<F, G> {
+    fn default() -> Self {
+        Self {
+            filter: None,
+            group_by: None,
+            bucket: None,
+        }
+    }
+}
+
+pub type SpecimenStatsQuery = StatsQuery<SpecimenFilter, SpecimenGroupBy>;
+
+pub type ChromiumDatasetStatsQuery = StatsQuery<ChromiumDatasetFilter, ChromiumDatasetGroupBy>;
+
+#[base_model]
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "client", derive(serde::Deserialize))]
+pub struct StatsPoint {
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    bucket: Option<Timestamp>,
+    count: i64,
+    /// Only set for statistics that are averages, in which case `count` is the
+    /// number of values averaged
+    mean: Option<f64>,
+}
+
+impl StatsPoint {
+    #[must_use]
+    pub fn new(bucket: Option<Timestamp>, count: i64, mean: Option<f64>) -> Self {
+        Self {
+            bucket,
+            count,
+            mean,
+        }
+    }
+
+    #[must_use]
+    pub fn bucket(&self) -> Option<Timestamp> {
+        self.bucket
+    }
+
+    #[must_use]
+    pub fn count(&self) -> i64 {
+        self.count
+    }
+
+    #[must_use]
+    pub fn mean(&self) -> Option<f64> {
+        self.mean
+    }
+}
+
+/// The points of one group, ordered by bucket
+#[base_model]
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "client", derive(serde::Deserialize))]
+pub struct StatsSeries {
+    group: Option<String>,
+    points: Vec<StatsPoint>,
+}
+
+impl StatsSeries {
+    #[must_use]
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    #[must_use]
+    pub fn points(&self) -> &[StatsPoint] {
+        &self.points
+    }
+
+    /// Collect points that are ordered by group into one series per group
+    pub fn collect(rows: impl IntoIterator<Item = (Option<String>, StatsPoint)>) -> Vec<Self> {
+        let mut series: Vec<Self> = Vec::new();
+
+        for (group, point) in rows {
+            match series.last_mut() {
+                Some(last) if last.group == group => last.points.push(point),
+                _ => series.push(Self {
+                    group,
+                    points: vec![point],
+                }),
+            }
+        }
+
+        series
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::{SpecimenGroupBy, SpecimenStatsQuery, StatsPoint, StatsSeries, TimeBucket};
+
+    #[rstest]
+    fn group_by_and_bucket() {
+        let query: SpecimenStatsQuery =
+            serde_qs::from_str("group_by=lab&bucket=month&filter[tissues][0]=blood").unwrap();
+
+        assert_eq!(query.group_by, Some(SpecimenGroupBy::Lab));
+        assert_eq!(query.bucket, Some(TimeBucket::Month));
+        assert_eq!(
+            query.filter.unwrap().tissues,
+            Some(vec!["blood".to_owned()])
+        );
+    }
+
+    #[rstest]
+    fn rows_are_collected_by_group() {
+        let point = |count| StatsPoint::new(None, count, None);
+        let rows = [
+            (Some("a".to_owned()), point(1)),
+            (Some("a".to_owned()), point(2)),
+            (Some("b".to_owned()), point(3)),
+            (None, point(4)),
+        ];
+
+        let series = StatsSeries::collect(rows);
+
+        let groups: Vec<_> = series.iter().map(StatsSeries::group).collect();
+        assert_eq!(groups, [Some("a"), Some("b"), None]);
+        assert_eq!(series[0].points().len(), 2);
+    }
+}
@@ -0,0 +1,12 @@
+mod common;
+mod creation;
+mod query;
+mod read;
+
+pub use common::{StorageEventKind, StorageUnitFields, StorageUnitKind, StoredItem};
+pub use creation::{StorageCheckIn, StorageCheckOut, StorageEventCreation, StorageUnitCreation};
+pub use query::{
+    StorageUnitFilter, StorageUnitId, StorageUnitIdCheckIn, StorageUnitIdCheckOut,
+    StorageUnitIdOccupiedPositions, StorageUnitOrderBy, StorageUnitQuery,
+};
+pub use read::{OccupiedPosition, StorageUnit};
@@ -0,0 +1,97 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::storage_units;
+use macro_attributes::{base_model, insert_select, simple_enum};
+use macros::{impl_enum_from_sql, impl_enum_to_sql};
+use non_empty::NonEmptyString;
+use uuid::Uuid;
+
+#[cfg(feature = "app")]
+use crate::utils::{EnumFromSql, EnumToSql};
+
+#[simple_enum]
+pub enum StorageUnitKind {
+    Freezer,
+    Rack,
+    Box,
+}
+
+impl StorageUnitKind {
+    /// Whether a unit of this kind may be placed inside a unit of kind `parent`
+    #[must_use]
+    pub fn can_be_placed_in(self, parent: Option<Self>) -> bool {
+        matches!(
+            (self, parent),
+            (Self::Freezer, None)
+                | (Self::Rack, Some(Self::Freezer))
+                | (Self::Box, Some(Self::Freezer | Self::Rack))
+        )
+    }
+}
+
+#[cfg(feature = "app")]
+impl EnumFromSql for StorageUnitKind {}
+impl_enum_from_sql!(StorageUnitKind);
+
+#[cfg(feature = "app")]
+impl EnumToSql for StorageUnitKind {}
+impl_enum_to_sql!(StorageUnitKind);
+
+#[simple_enum]
+pub enum StorageEventKind {
+    CheckIn,
+    CheckOut,
+}
+
+#[cfg(feature = "app")]
+impl EnumFromSql for StorageEventKind {}
+impl_enum_from_sql!(StorageEventKind);
+
+#[cfg(feature = "app")]
+impl EnumToSql for StorageEventKind {}
+impl_enum_to_sql!(StorageEventKind);
+
+#[base_model]
+#[derive(Copy, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", content = "id", rename_all = "snake_case")]
+pub enum StoredItem {
+    Specimen(Uuid),
+    Suspension(Uuid),
+    Cdna(Uuid),
+    Library(Uuid),
+}
+
+impl StoredItem {
+    /// The item's ID in the position of the matching column, in the order
+    /// `(specimen_id, suspension_id, cdna_id, library_id)`
+    #[must_use]
+    pub fn as_columns(self) -> (Option<Uuid>, Option<Uuid>, Option<Uuid>, Option<Uuid>) {
+        match self {
+            Self::Specimen(id) => (Some(id), None, None, None),
+            Self::Suspension(id) => (None, Some(id), None, None),
+            Self::Cdna(id) => (None, None, Some(id), None),
+            Self::Library(id) => (None, None, None, Some(id)),
+        }
+    }
+
+    #[must_use]
+    pub fn from_columns(
+        specimen_id: Option<Uuid>,
+        suspension_id: Option<Uuid>,
+        cdna_id: Option<Uuid>,
+        library_id: Option<Uuid>,
+    ) -> Option<Self> {
+        specimen_id
+            .map(Self::Specimen)
+            .or(suspension_id.map(Self::Suspension))
+            .or(cdna_id.map(Self::Cdna))
+            .or(library_id.map(Self::Library))
+    }
+}
+
+#[insert_select]
+#[cfg_attr(feature = "app", diesel(table_name = storage_units))]
+pub struct StorageUnitFields {
+    pub(super) name: NonEmptyString,
+    pub(super) kind: StorageUnitKind,
+    pub(super) parent_id: Option<Uuid>,
+}
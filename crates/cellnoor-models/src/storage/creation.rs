@@ -0,0 +1,159 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::{storage_events, storage_units};
+use jiff::Timestamp;
+use macro_attributes::{base_model, insert};
+use ranged::RangedU16;
+use uuid::Uuid;
+
+use crate::storage::common::{StorageEventKind, StorageUnitFields, StorageUnitKind, StoredItem};
+
+#[insert]
+#[cfg_attr(feature = "app", diesel(table_name = storage_units))]
+pub struct StorageUnitCreation {
+    #[serde(flatten)]
+    #[cfg_attr(feature = "app", diesel(embed))]
+    inner: StorageUnitFields,
+    capacity: Option<RangedU16<1, { u16::MAX }>>,
+}
+
+impl StorageUnitCreation {
+    #[must_use]
+    pub fn kind(&self) -> StorageUnitKind {
+        self.inner.kind
+    }
+
+    #[must_use]
+    pub fn parent_id(&self) -> Option<Uuid> {
+        self.inner.parent_id
+    }
+}
+
+#[base_model]
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct StorageCheckIn {
+    item: StoredItem,
+    position: i32,
+    performed_by: Uuid,
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    performed_at: Timestamp,
+}
+
+impl StorageCheckIn {
+    #[must_use]
+    pub fn item(&self) -> StoredItem {
+        self.item
+    }
+
+    #[must_use]
+    pub fn position(&self) -> i32 {
+        self.position
+    }
+
+    #[must_use]
+    pub fn performed_at(&self) -> Timestamp {
+        self.performed_at
+    }
+
+    #[must_use]
+    pub fn into_event(self, box_id: Uuid) -> StorageEventCreation {
+        let Self {
+            item,
+            position,
+            performed_by,
+            performed_at,
+        } = self;
+
+        StorageEventCreation::new(
+            box_id,
+            position,
+            item,
+            StorageEventKind::CheckIn,
+            performed_by,
+            performed_at,
+        )
+    }
+}
+
+#[base_model]
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct StorageCheckOut {
+    item: StoredItem,
+    performed_by: Uuid,
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    performed_at: Timestamp,
+}
+
+impl StorageCheckOut {
+    #[must_use]
+    pub fn item(&self) -> StoredItem {
+        self.item
+    }
+
+    #[must_use]
+    pub fn performed_at(&self) -> Timestamp {
+        self.performed_at
+    }
+
+    #[must_use]
+    pub fn into_event(self, box_id: Uuid, position: i32) -> StorageEventCreation {
+        let Self {
+            item,
+            performed_by,
+            performed_at,
+        } = self;
+
+        StorageEventCreation::new(
+            box_id,
+            position,
+            item,
+            StorageEventKind::CheckOut,
+            performed_by,
+            performed_at,
+        )
+    }
+}
+
+#[insert]
+#[cfg_attr(feature = "app", diesel(table_name = storage_events))]
+pub struct StorageEventCreation {
+    box_id: Uuid,
+    position: i32,
+    specimen_id: Option<Uuid>,
+    suspension_id: Option<Uuid>,
+    cdna_id: Option<Uuid>,
+    library_id: Option<Uuid>,
+    kind: StorageEventKind,
+    performed_by: Uuid,
+    #[cfg_attr(feature = "app", diesel(serialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    performed_at: Timestamp,
+}
+
+impl StorageEventCreation {
+    fn new(
+        box_id: Uuid,
+        position: i32,
+        item: StoredItem,
+        kind: StorageEventKind,
+        performed_by: Uuid,
+        performed_at: Timestamp,
+    ) -> Self {
+        let (specimen_id, suspension_id, cdna_id, library_id) = item.as_columns();
+
+        Self {
+            box_id,
+            position,
+            specimen_id,
+            suspension_id,
+            cdna_id,
+            library_id,
+            kind,
+            performed_by,
+            performed_at,
+        }
+    }
+}
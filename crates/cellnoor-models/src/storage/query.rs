@@ -0,0 +1,40 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::storage_units;
+use macro_attributes::{filter, order_by};
+use macros::uuid_newtype;
+use uuid::Uuid;
+
+use crate::storage::common::StorageUnitKind;
+
+#[order_by(storage_units)]
+#[allow(non_camel_case_types)]
+pub enum StorageUnitOrderBy {
+    id { descending: Option<bool> },
+    name { descending: Option<bool> },
+    kind { descending: Option<bool> },
+    parent_id { descending: Option<bool> },
+}
+
+impl Default for StorageUnitOrderBy {
+    fn default() -> Self {
+        Self::name { descending: None }
+    }
+}
+
+#[filter]
+pub struct StorageUnitFilter {
+    pub ids: Option<Vec<Uuid>>,
+    pub names: Option<Vec<String>>,
+    pub kinds: Option<Vec<StorageUnitKind>>,
+    pub parent_ids: Option<Vec<Uuid>>,
+}
+
+pub type StorageUnitQuery = crate::generic_query::Query<StorageUnitFilter, StorageUnitOrderBy>;
+
+uuid_newtype!(StorageUnitId, "/{id}");
+
+uuid_newtype!(StorageUnitIdCheckIn, "/{id}/check-in");
+
+uuid_newtype!(StorageUnitIdCheckOut, "/{id}/check-out");
+
+uuid_newtype!(StorageUnitIdOccupiedPositions, "/{id}/occupied-positions");
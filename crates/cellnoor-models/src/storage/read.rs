@@ -0,0 +1,75 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::{storage_occupancy, storage_units};
+use jiff::Timestamp;
+use macro_attributes::select;
+use uuid::Uuid;
+
+use crate::{
+    links::Links,
+    storage::common::{StorageUnitFields, StorageUnitKind, StoredItem},
+};
+
+#[select]
+#[cfg_attr(feature = "app", diesel(table_name = storage_units))]
+pub struct StorageUnit {
+    id: Uuid,
+    links: Links,
+    #[serde(flatten)]
+    #[cfg_attr(feature = "app", diesel(embed))]
+    inner: StorageUnitFields,
+    capacity: Option<i32>,
+}
+
+impl StorageUnit {
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    #[must_use]
+    pub fn kind(&self) -> StorageUnitKind {
+        self.inner.kind
+    }
+
+    #[must_use]
+    pub fn capacity(&self) -> Option<i32> {
+        self.capacity
+    }
+}
+
+#[select]
+#[cfg_attr(feature = "app", diesel(table_name = storage_occupancy))]
+pub struct OccupiedPosition {
+    box_id: Uuid,
+    position: i32,
+    specimen_id: Option<Uuid>,
+    suspension_id: Option<Uuid>,
+    cdna_id: Option<Uuid>,
+    library_id: Option<Uuid>,
+    checked_in_by: Uuid,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    checked_in_at: Timestamp,
+}
+
+impl OccupiedPosition {
+    #[must_use]
+    pub fn box_id(&self) -> Uuid {
+        self.box_id
+    }
+
+    #[must_use]
+    pub fn position(&self) -> i32 {
+        self.position
+    }
+
+    #[must_use]
+    pub fn item(&self) -> Option<StoredItem> {
+        StoredItem::from_columns(
+            self.specimen_id,
+            self.suspension_id,
+            self.cdna_id,
+            self.library_id,
+        )
+    }
+}
@@ -17,6 +17,10 @@ use crate::utils::{EnumFromSql, EnumToSql, JsonFromSql, JsonToSql};
 #[insert_select]
 #[cfg_attr(feature = "app", diesel(table_name = suspension_measurements))]
 #[cfg_attr(feature = "typescript", ts(concrete(C = SuspensionContent)))]
+#[cfg_attr(
+    feature = "schema",
+    schemars(rename = "SuspensionMeasurementFields_for_{C}")
+)]
 pub struct SuspensionMeasurementFields<C> {
     measured_by: Uuid,
     #[cfg_attr(feature = "app", diesel(
@@ -41,6 +45,10 @@ impl<C> SuspensionMeasurementFields<C> {
 #[json]
 #[serde(tag = "quantity")]
 #[cfg_attr(feature = "typescript", ts(concrete(C = SuspensionContent)))]
+#[cfg_attr(
+    feature = "schema",
+    schemars(rename = "SuspensionMeasurementData_for_{C}")
+)]
 pub enum SuspensionMeasurementData<C> {
     Concentration {
         #[serde(flatten)]
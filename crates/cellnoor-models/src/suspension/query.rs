@@ -4,23 +4,42 @@ use macro_attributes::{filter, order_by};
 use macros::uuid_newtype;
 use uuid::Uuid;
 
-#[cfg(feature = "app")]
-use crate::generic_query;
+use crate::{
+    generic_query,
+    json_path::{JsonPath, JsonPathPredicate},
+};
 
 #[filter]
 pub struct SuspensionFilter {
     pub ids: Option<Vec<Uuid>>,
+    pub additional_data_predicates: Option<Vec<JsonPathPredicate>>,
 }
 
 #[order_by(suspensions)]
 #[allow(non_camel_case_types)]
 pub enum SuspensionOrderBy {
-    id { descending: Option<bool> },
-    readable_id { descending: Option<bool> },
-    parent_specimen_id { descending: Option<bool> },
-    created_at { descending: Option<bool> },
-    lysis_duration_minutes { descending: Option<bool> },
-    target_cell_recovery { descending: Option<bool> },
+    id {
+        descending: Option<bool>,
+    },
+    readable_id {
+        descending: Option<bool>,
+    },
+    parent_specimen_id {
+        descending: Option<bool>,
+    },
+    created_at {
+        descending: Option<bool>,
+    },
+    lysis_duration_minutes {
+        descending: Option<bool>,
+    },
+    target_cell_recovery {
+        descending: Option<bool>,
+    },
+    additional_data {
+        path: JsonPath,
+        descending: Option<bool>,
+    },
 }
 
 impl Default for SuspensionOrderBy {
@@ -31,7 +50,6 @@ impl Default for SuspensionOrderBy {
     }
 }
 
-#[cfg(feature = "app")]
 pub type SuspensionQuery = generic_query::Query<SuspensionFilter, SuspensionOrderBy>;
 
 uuid_newtype!(SuspensionId, "/{id}");
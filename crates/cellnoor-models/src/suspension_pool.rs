@@ -6,10 +6,8 @@ mod read;
 
 pub use common::SuspensionPoolFields;
 pub use creation::{SuspensionPoolCreation, SuspensionTagging};
-#[cfg(feature = "app")]
-pub use query::SuspensionPoolQuery;
 pub use query::{
     SuspensionPoolFilter, SuspensionPoolId, SuspensionPoolIdMeasurements,
-    SuspensionPoolIdSuspensions, SuspensionPoolOrderBy,
+    SuspensionPoolIdSuspensions, SuspensionPoolOrderBy, SuspensionPoolQuery,
 };
 pub use read::SuspensionPool;
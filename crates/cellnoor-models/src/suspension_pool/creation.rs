@@ -22,6 +22,7 @@ impl SuspensionTagging {
 
 #[base_model]
 #[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
 pub struct SuspensionPoolCreation {
     #[serde(flatten)]
     pub inner: SuspensionPoolFields,
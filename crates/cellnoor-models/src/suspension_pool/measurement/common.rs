@@ -18,6 +18,10 @@ use crate::utils::{JsonFromSql, JsonToSql};
 #[insert_select]
 #[cfg_attr(feature = "app", diesel(table_name = suspension_pool_measurements))]
 #[cfg_attr(feature = "typescript", ts(concrete(C = SuspensionContent)))]
+#[cfg_attr(
+    feature = "schema",
+    schemars(rename = "SuspensionPoolMeasurementFields_for_{C}")
+)]
 pub struct SuspensionPoolMeasurementFields<C> {
     measured_by: Uuid,
     #[cfg_attr(feature = "app", diesel(
@@ -42,6 +46,10 @@ impl<C> SuspensionPoolMeasurementFields<C> {
 #[json]
 #[serde(tag = "quantity")]
 #[cfg_attr(feature = "typescript", ts(concrete(C = SuspensionContent)))]
+#[cfg_attr(
+    feature = "schema",
+    schemars(rename = "SuspensionPoolMeasurementData_for_{C}")
+)]
 pub enum SuspensionPoolMeasurementData<C> {
     Concentration {
         #[serde(flatten)]
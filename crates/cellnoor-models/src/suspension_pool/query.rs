@@ -4,21 +4,36 @@ use macro_attributes::{filter, order_by};
 use macros::uuid_newtype;
 use uuid::Uuid;
 
-#[cfg(feature = "app")]
-use crate::generic_query;
+use crate::{
+    generic_query,
+    json_path::{JsonPath, JsonPathPredicate},
+};
 
 #[filter]
 pub struct SuspensionPoolFilter {
     pub ids: Option<Vec<Uuid>>,
+    pub additional_data_predicates: Option<Vec<JsonPathPredicate>>,
 }
 
 #[order_by(suspension_pools)]
 #[allow(non_camel_case_types)]
 pub enum SuspensionPoolOrderBy {
-    id { descending: Option<bool> },
-    readable_id { descending: Option<bool> },
-    name { descending: Option<bool> },
-    pooled_at { descending: Option<bool> },
+    id {
+        descending: Option<bool>,
+    },
+    readable_id {
+        descending: Option<bool>,
+    },
+    name {
+        descending: Option<bool>,
+    },
+    pooled_at {
+        descending: Option<bool>,
+    },
+    additional_data {
+        path: JsonPath,
+        descending: Option<bool>,
+    },
 }
 
 impl Default for SuspensionPoolOrderBy {
@@ -29,7 +44,6 @@ impl Default for SuspensionPoolOrderBy {
     }
 }
 
-#[cfg(feature = "app")]
 pub type SuspensionPoolQuery = generic_query::Query<SuspensionPoolFilter, SuspensionPoolOrderBy>;
 
 uuid_newtype!(SuspensionPoolId, "/{id}");
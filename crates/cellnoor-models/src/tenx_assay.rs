@@ -5,7 +5,5 @@ mod read;
 
 pub use common::{LibraryType, SampleMultiplexing};
 pub use creation::TenxAssayCreation;
-#[cfg(feature = "app")]
-pub use query::TenxAssayQuery;
-pub use query::{TenxAssayFilter, TenxAssayOrderBy};
+pub use query::{TenxAssayFilter, TenxAssayOrderBy, TenxAssayQuery};
 pub use read::TenxAssay;
@@ -3,9 +3,10 @@ use cellnoor_schema::tenx_assays;
 use macro_attributes::{filter, order_by};
 use uuid::Uuid;
 
-#[cfg(feature = "app")]
-use crate::generic_query;
-use crate::tenx_assay::common::{LibraryType, SampleMultiplexing};
+use crate::{
+    generic_query,
+    tenx_assay::common::{LibraryType, SampleMultiplexing},
+};
 
 #[filter]
 pub struct TenxAssayFilter {
@@ -36,5 +37,4 @@ impl Default for TenxAssayOrderBy {
     }
 }
 
-#[cfg(feature = "app")]
 pub type TenxAssayQuery = generic_query::Query<TenxAssayFilter, TenxAssayOrderBy>;
@@ -0,0 +1,183 @@
+#[cfg(feature = "app")]
+use cellnoor_schema::{webhook_deliveries, webhook_subscriptions};
+use jiff::Timestamp;
+use macro_attributes::{base_model, filter, order_by, select, simple_enum};
+use macros::{impl_enum_from_sql, impl_enum_to_sql, uuid_newtype};
+use non_empty::{NonEmptyString, NonEmptyVec};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::generic_query;
+#[cfg(feature = "app")]
+use crate::utils::{EnumFromSql, EnumToSql};
+
+#[simple_enum]
+pub enum WebhookEventType {
+    ChromiumDatasetDelivered,
+    /// A Chromium run that a lab's specimens were loaded onto failed QC, which
+    /// is recorded as the run not having succeeded
+    ChromiumRunFailed,
+    SpecimenReturned,
+}
+
+#[cfg(feature = "app")]
+impl EnumFromSql for WebhookEventType {}
+impl_enum_from_sql!(WebhookEventType);
+
+#[cfg(feature = "app")]
+impl EnumToSql for WebhookEventType {}
+impl_enum_to_sql!(WebhookEventType);
+
+// Postgres arrays can always hold nulls, so the elements of
+// `webhook_subscriptions.event_types` are nullable even though they're never
+// null
+#[cfg(feature = "app")]
+impl
+    diesel::deserialize::FromSql<
+        diesel::sql_types::Nullable<diesel::sql_types::Text>,
+        diesel::pg::Pg,
+    > for WebhookEventType
+{
+    fn from_sql(bytes: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {
+        Self::from_sql_inner(bytes)
+    }
+}
+
+#[simple_enum]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+#[cfg(feature = "app")]
+impl EnumFromSql for WebhookDeliveryStatus {}
+impl_enum_from_sql!(WebhookDeliveryStatus);
+
+#[cfg(feature = "app")]
+impl EnumToSql for WebhookDeliveryStatus {}
+impl_enum_to_sql!(WebhookDeliveryStatus);
+
+/// Where to send a lab's lifecycle events. `url` must be an HTTPS URL on a
+/// public host. Each delivery is signed with `secret`, which can't be read back
+/// once the subscription is created.
+#[base_model]
+#[derive(serde::Deserialize)]
+#[cfg_attr(feature = "client", derive(serde::Serialize))]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+#[cfg_attr(feature = "builder", builder(on(_, into)))]
+pub struct WebhookSubscriptionCreation {
+    pub lab_id: Uuid,
+    pub url: NonEmptyString,
+    pub secret: NonEmptyString,
+    pub event_types: NonEmptyVec<WebhookEventType, { usize::MAX }>,
+}
+
+#[select]
+#[cfg_attr(feature = "app", diesel(table_name = webhook_subscriptions))]
+pub struct WebhookSubscription {
+    id: Uuid,
+    lab_id: Uuid,
+    url: String,
+    event_types: Vec<WebhookEventType>,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    created_at: Timestamp,
+}
+
+impl WebhookSubscription {
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    #[must_use]
+    pub fn lab_id(&self) -> Uuid {
+        self.lab_id
+    }
+
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    #[must_use]
+    pub fn event_types(&self) -> &[WebhookEventType] {
+        &self.event_types
+    }
+}
+
+/// An attempt, or series of attempts, to send an event to a subscription.
+/// `response_status` and `error` describe the most recent attempt.
+#[select]
+#[cfg_attr(feature = "app", diesel(table_name = webhook_deliveries))]
+pub struct WebhookDelivery {
+    id: Uuid,
+    subscription_id: Uuid,
+    event_type: WebhookEventType,
+    payload: Value,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    created_at: Timestamp,
+    status: WebhookDeliveryStatus,
+    n_attempts: i32,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::Timestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "String"))]
+    next_attempt_at: Timestamp,
+    #[cfg_attr(feature = "app", diesel(deserialize_as = jiff_diesel::NullableTimestamp))]
+    #[cfg_attr(feature = "typescript", ts(as = "Option<String>"))]
+    last_attempted_at: Option<Timestamp>,
+    response_status: Option<i32>,
+    error: Option<String>,
+}
+
+impl WebhookDelivery {
+    #[must_use]
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    #[must_use]
+    pub fn event_type(&self) -> WebhookEventType {
+        self.event_type
+    }
+
+    #[must_use]
+    pub fn status(&self) -> WebhookDeliveryStatus {
+        self.status
+    }
+
+    #[must_use]
+    pub fn n_attempts(&self) -> i32 {
+        self.n_attempts
+    }
+}
+
+#[filter]
+pub struct WebhookDeliveryFilter {
+    pub statuses: Option<Vec<WebhookDeliveryStatus>>,
+}
+
+#[order_by(webhook_deliveries)]
+#[allow(non_camel_case_types)]
+pub enum WebhookDeliveryOrderBy {
+    id { descending: Option<bool> },
+    created_at { descending: Option<bool> },
+    n_attempts { descending: Option<bool> },
+    next_attempt_at { descending: Option<bool> },
+}
+
+// The most recent deliveries come first
+impl Default for WebhookDeliveryOrderBy {
+    fn default() -> Self {
+        Self::created_at {
+            descending: Some(true),
+        }
+    }
+}
+
+pub type WebhookDeliveryQuery = generic_query::Query<WebhookDeliveryFilter, WebhookDeliveryOrderBy>;
+
+uuid_newtype!(WebhookSubscriptionId, "/{id}");
+
+uuid_newtype!(WebhookSubscriptionIdDeliveries, "/{id}/deliveries");
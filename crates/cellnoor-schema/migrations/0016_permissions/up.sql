@@ -1,5 +1,6 @@
 -- Anyone can read any table (API keys are okay because only their hashes are stored, and they are protected by
--- row-level security anyways)
+-- row-level security anyways). These grants only cover the tables that exist now, so later migrations grant access to
+-- the tables they create
 grant select on all tables in schema public to public;
 
 -- Users with app_admin can do anything (TODO: app_admin should be more restricted)
@@ -0,0 +1,2 @@
+alter table specimens drop column donor_id;
+drop table donors;
@@ -0,0 +1,23 @@
+create table donors (
+    id uuid primary key default uuidv7(),
+    links jsonb generated always as (construct_links('donors', id)) stored not null,
+    external_id case_insensitive_text not null,
+    lab_id uuid references labs on delete restrict on update restrict not null,
+    species case_insensitive_text not null,
+    sex case_insensitive_text,
+    born_at timestamptz,
+    age_in_days integer,
+    strain case_insensitive_text,
+    genotype case_insensitive_text,
+    additional_data jsonb,
+
+    unique (lab_id, external_id),
+    constraint not_both_born_at_and_age check (born_at is null or age_in_days is null),
+    constraint age_is_nonnegative check (age_in_days >= 0)
+);
+
+alter table specimens add column donor_id uuid references donors on delete restrict on update restrict;
+
+-- Like the specimens they're linked to, donors can be read by anyone and changed by admins
+grant select on donors to public;
+grant all on donors to app_admin;
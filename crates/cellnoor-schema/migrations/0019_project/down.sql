@@ -0,0 +1 @@
+drop table project_chromium_datasets, project_specimens, projects;
@@ -0,0 +1,29 @@
+create table projects (
+    id uuid primary key default uuidv7(),
+    links jsonb generated always as (
+        construct_links('projects', id, '{"specimens", "chromium-datasets"}')
+    ) stored not null,
+    name case_insensitive_text unique not null,
+    lab_id uuid references labs on delete restrict on update restrict not null,
+    description text,
+    started_at timestamptz not null,
+    ended_at timestamptz,
+    grant_ids case_insensitive_text [] not null default '{}',
+
+    constraint started_before_ended check (started_at <= ended_at)
+);
+
+create table project_specimens (
+    project_id uuid references projects on delete restrict on update restrict not null,
+    specimen_id uuid references specimens on delete restrict on update restrict not null,
+    primary key (project_id, specimen_id)
+);
+
+create table project_chromium_datasets (
+    project_id uuid references projects on delete restrict on update restrict not null,
+    dataset_id uuid references chromium_datasets on delete restrict on update restrict not null,
+    primary key (project_id, dataset_id)
+);
+
+grant select on projects, project_specimens, project_chromium_datasets to public;
+grant all on projects, project_specimens, project_chromium_datasets to app_admin;
@@ -0,0 +1,20 @@
+alter table institutions drop column requires_committee_approval;
+
+create table committee_approval (
+    institution_id uuid references institutions on delete restrict on update restrict not null,
+    specimen_id uuid references specimens on delete restrict on update restrict not null,
+    committee_type case_insensitive_text not null,
+    compliance_identifier case_insensitive_text not null,
+    primary key (institution_id, committee_type, specimen_id)
+);
+
+insert into committee_approval (institution_id, specimen_id, committee_type, compliance_identifier)
+select distinct on (a.institution_id, a.committee_type, sca.specimen_id)
+    a.institution_id,
+    sca.specimen_id,
+    a.committee_type,
+    a.compliance_identifier
+from specimen_committee_approvals as sca
+inner join committee_approvals as a on sca.approval_id = a.id;
+
+drop table specimen_committee_approvals, committee_approvals;
@@ -0,0 +1,51 @@
+create table committee_approvals (
+    id uuid primary key default uuidv7(),
+    links jsonb generated always as (construct_links('committee-approvals', id)) stored not null,
+    institution_id uuid references institutions on delete restrict on update restrict not null,
+    committee_type case_insensitive_text not null,
+    compliance_identifier case_insensitive_text not null,
+    valid_from timestamptz not null,
+    valid_until timestamptz,
+
+    unique (institution_id, committee_type, compliance_identifier),
+    constraint valid_from_before_valid_until check (valid_from < valid_until)
+);
+
+create table specimen_committee_approvals (
+    specimen_id uuid references specimens on delete restrict on update restrict not null,
+    approval_id uuid references committee_approvals on delete restrict on update restrict not null,
+    primary key (specimen_id, approval_id)
+);
+
+-- Existing approvals have no validity window, so we assume they were valid for
+-- the earliest specimen they were attached to
+insert into committee_approvals (institution_id, committee_type, compliance_identifier, valid_from)
+select
+    ca.institution_id,
+    lower(ca.committee_type),
+    ca.compliance_identifier,
+    min(s.received_at)
+from committee_approval as ca
+inner join specimens as s on ca.specimen_id = s.id
+group by ca.institution_id, lower(ca.committee_type), ca.compliance_identifier;
+
+insert into specimen_committee_approvals (specimen_id, approval_id)
+select
+    ca.specimen_id,
+    a.id
+from committee_approval as ca
+inner join committee_approvals as a
+    on
+        ca.institution_id = a.institution_id
+        and ca.committee_type = a.committee_type
+        and ca.compliance_identifier = a.compliance_identifier;
+
+drop table committee_approval;
+
+alter table institutions add column requires_committee_approval boolean not null default false;
+
+-- Approvals, and the specimens they cover, can be read by anyone and recorded by admins
+grant select on committee_approvals to public;
+grant all on committee_approvals to app_admin;
+grant select on specimen_committee_approvals to public;
+grant all on specimen_committee_approvals to app_admin;
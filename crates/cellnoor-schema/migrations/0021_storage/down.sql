@@ -0,0 +1,3 @@
+drop view storage_occupancy;
+drop table storage_events, storage_units;
+drop function check_storage_event;
@@ -0,0 +1,118 @@
+create table storage_units (
+    id uuid primary key default uuidv7(),
+    links jsonb generated always as (
+        construct_links('storage', id, '{"occupied-positions"}')
+    ) stored not null,
+    name case_insensitive_text not null,
+    kind case_insensitive_text not null,
+    parent_id uuid references storage_units on delete restrict on update restrict,
+    capacity integer,
+
+    unique nulls not distinct (parent_id, name),
+    constraint only_boxes_have_capacity check ((kind = 'box') = (capacity is not null)),
+    constraint capacity_is_positive check (capacity > 0)
+);
+
+create table storage_events (
+    id uuid primary key default uuidv7(),
+    box_id uuid references storage_units on delete restrict on update restrict not null,
+    position integer not null,
+    specimen_id uuid references specimens on delete restrict on update restrict,
+    suspension_id uuid references suspensions on delete restrict on update restrict,
+    cdna_id uuid references cdna on delete restrict on update restrict,
+    library_id uuid references libraries on delete restrict on update restrict,
+    kind case_insensitive_text not null,
+    performed_by uuid references people on delete restrict on update restrict not null,
+    performed_at timestamptz not null,
+
+    constraint exactly_one_item check (
+        num_nonnulls(specimen_id, suspension_id, cdna_id, library_id) = 1
+    ),
+    constraint position_is_positive check (position > 0)
+);
+
+create index storage_events_box_idx on storage_events (box_id);
+create index storage_events_item_idx on storage_events (
+    (coalesce(specimen_id, suspension_id, cdna_id, library_id)), performed_at desc
+);
+
+-- An item occupies a position if the most recent event for that item is a
+-- check-in
+create view storage_occupancy as
+select
+    event_id,
+    box_id,
+    position,
+    specimen_id,
+    suspension_id,
+    cdna_id,
+    library_id,
+    checked_in_by,
+    checked_in_at
+from (
+    select distinct on (coalesce(specimen_id, suspension_id, cdna_id, library_id))
+        id as event_id,
+        box_id,
+        position,
+        specimen_id,
+        suspension_id,
+        cdna_id,
+        library_id,
+        kind,
+        performed_by as checked_in_by,
+        performed_at as checked_in_at
+    from storage_events
+    order by coalesce(specimen_id, suspension_id, cdna_id, library_id), performed_at desc, id desc
+) as latest
+where kind = 'check_in';
+
+-- Events are validated before the transaction that inserts them, so two
+-- concurrent check-ins could both find a position empty, or both find that they
+-- come after an item's latest event. Locking the box serializes its events and
+-- locking the item serializes the item's events, and the checks are repeated
+-- under those locks.
+create function check_storage_event() returns trigger language plpgsql volatile strict as $$
+    declare
+        item_id uuid := coalesce(new.specimen_id, new.suspension_id, new.cdna_id, new.library_id);
+        latest_at timestamptz;
+    begin
+        perform from storage_units where id = new.box_id for update;
+        perform pg_advisory_xact_lock(hashtext(item_id::text));
+
+        select max(performed_at) into latest_at
+        from storage_events
+        where coalesce(specimen_id, suspension_id, cdna_id, library_id) = item_id;
+
+        if new.performed_at < latest_at then
+            raise exception 'storage event predates the latest event for its item' using
+                errcode = 'check_violation',
+                detail = format(
+                    'item %s was last moved at %s, which is after %s',
+                    item_id,
+                    latest_at,
+                    new.performed_at
+                );
+        end if;
+
+        if new.kind = 'check_in' and exists (
+            select from storage_occupancy as o
+            where
+                o.box_id = new.box_id
+                and o.position = new.position
+                and coalesce(o.specimen_id, o.suspension_id, o.cdna_id, o.library_id) != item_id
+        ) then
+            raise exception 'storage position is already occupied' using
+                errcode = 'check_violation',
+                detail = format('position %s of box %s is already occupied', new.position, new.box_id);
+        end if;
+
+        return new;
+    end;
+$$;
+
+create trigger check_storage_event before insert on storage_events for each row execute function
+check_storage_event();
+
+-- Anyone can see what is stored where, but only admins can change it directly
+grant select on storage_units, storage_events, storage_occupancy to public;
+grant all on storage_units, storage_events, storage_occupancy to app_admin;
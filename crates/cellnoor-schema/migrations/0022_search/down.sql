@@ -0,0 +1,30 @@
+drop view search_documents;
+
+drop index institutions_search_trgm_idx;
+drop index institutions_search_fts_idx;
+drop index people_search_trgm_idx;
+drop index people_search_fts_idx;
+drop index labs_search_trgm_idx;
+drop index labs_search_fts_idx;
+drop index projects_search_trgm_idx;
+drop index projects_search_fts_idx;
+drop index donors_search_trgm_idx;
+drop index donors_search_fts_idx;
+drop index specimens_search_trgm_idx;
+drop index specimens_search_fts_idx;
+drop index suspensions_search_trgm_idx;
+drop index suspensions_search_fts_idx;
+drop index suspension_pools_search_trgm_idx;
+drop index suspension_pools_search_fts_idx;
+drop index chromium_runs_search_trgm_idx;
+drop index chromium_runs_search_fts_idx;
+drop index cdna_search_trgm_idx;
+drop index cdna_search_fts_idx;
+drop index libraries_search_trgm_idx;
+drop index libraries_search_fts_idx;
+drop index sequencing_runs_search_trgm_idx;
+drop index sequencing_runs_search_fts_idx;
+drop index chromium_datasets_search_trgm_idx;
+drop index chromium_datasets_search_fts_idx;
+
+drop extension pg_trgm;
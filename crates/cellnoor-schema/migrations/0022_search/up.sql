@@ -0,0 +1,83 @@
+create extension if not exists pg_trgm;
+
+-- Everything that can be found with `/search`. `content` is matched with trigrams so that partial names work, and
+-- `document` with full-text search. Each branch has matching indexes below, so the expressions must be kept in sync
+create view search_documents as
+select
+    entity_type,
+    id,
+    links,
+    label,
+    content,
+    to_tsvector('simple', content) as document
+from (
+    select 'institution' as entity_type, id, links, name::text as label, name::text as content
+    from institutions
+    union all
+    select 'person' as entity_type, id, links, name::text as label, name::text as content
+    from people
+    union all
+    select 'lab' as entity_type, id, links, name::text as label, name::text as content
+    from labs
+    union all
+    select 'project' as entity_type, id, links, name::text as label, name::text as content
+    from projects
+    union all
+    select 'donor' as entity_type, id, links, external_id::text as label, external_id::text as content
+    from donors
+    union all
+    select 'specimen' as entity_type, id, links, name::text as label, readable_id::text || ' ' || name::text || ' ' || tissue::text as content
+    from specimens
+    union all
+    select 'suspension' as entity_type, id, links, readable_id::text as label, readable_id::text as content
+    from suspensions
+    union all
+    select 'suspension_pool' as entity_type, id, links, name::text as label, readable_id::text || ' ' || name::text as content
+    from suspension_pools
+    union all
+    select 'chromium_run' as entity_type, id, links, readable_id::text as label, readable_id::text as content
+    from chromium_runs
+    union all
+    select 'cdna' as entity_type, id, links, readable_id::text as label, readable_id::text as content
+    from cdna
+    union all
+    select 'library' as entity_type, id, links, readable_id::text as label, readable_id::text as content
+    from libraries
+    union all
+    select 'sequencing_run' as entity_type, id, links, readable_id::text as label, readable_id::text as content
+    from sequencing_runs
+    union all
+    select 'chromium_dataset' as entity_type, id, links, name::text as label, name::text as content
+    from chromium_datasets
+) as documents;
+
+create index institutions_search_trgm_idx on institutions using gin ((name::text) gin_trgm_ops);
+create index institutions_search_fts_idx on institutions using gin (to_tsvector('simple', name::text));
+create index people_search_trgm_idx on people using gin ((name::text) gin_trgm_ops);
+create index people_search_fts_idx on people using gin (to_tsvector('simple', name::text));
+create index labs_search_trgm_idx on labs using gin ((name::text) gin_trgm_ops);
+create index labs_search_fts_idx on labs using gin (to_tsvector('simple', name::text));
+create index projects_search_trgm_idx on projects using gin ((name::text) gin_trgm_ops);
+create index projects_search_fts_idx on projects using gin (to_tsvector('simple', name::text));
+create index donors_search_trgm_idx on donors using gin ((external_id::text) gin_trgm_ops);
+create index donors_search_fts_idx on donors using gin (to_tsvector('simple', external_id::text));
+create index specimens_search_trgm_idx on specimens using gin ((readable_id::text || ' ' || name::text || ' ' || tissue::text) gin_trgm_ops);
+create index specimens_search_fts_idx on specimens using gin (to_tsvector('simple', readable_id::text || ' ' || name::text || ' ' || tissue::text));
+create index suspensions_search_trgm_idx on suspensions using gin ((readable_id::text) gin_trgm_ops);
+create index suspensions_search_fts_idx on suspensions using gin (to_tsvector('simple', readable_id::text));
+create index suspension_pools_search_trgm_idx on suspension_pools using gin ((readable_id::text || ' ' || name::text) gin_trgm_ops);
+create index suspension_pools_search_fts_idx on suspension_pools using gin (to_tsvector('simple', readable_id::text || ' ' || name::text));
+create index chromium_runs_search_trgm_idx on chromium_runs using gin ((readable_id::text) gin_trgm_ops);
+create index chromium_runs_search_fts_idx on chromium_runs using gin (to_tsvector('simple', readable_id::text));
+create index cdna_search_trgm_idx on cdna using gin ((readable_id::text) gin_trgm_ops);
+create index cdna_search_fts_idx on cdna using gin (to_tsvector('simple', readable_id::text));
+create index libraries_search_trgm_idx on libraries using gin ((readable_id::text) gin_trgm_ops);
+create index libraries_search_fts_idx on libraries using gin (to_tsvector('simple', readable_id::text));
+create index sequencing_runs_search_trgm_idx on sequencing_runs using gin ((readable_id::text) gin_trgm_ops);
+create index sequencing_runs_search_fts_idx on sequencing_runs using gin (to_tsvector('simple', readable_id::text));
+create index chromium_datasets_search_trgm_idx on chromium_datasets using gin ((name::text) gin_trgm_ops);
+create index chromium_datasets_search_fts_idx on chromium_datasets using gin (to_tsvector('simple', name::text));
+
+-- Like the tables it's built from, the view can be read by anyone
+grant select on search_documents to public;
+grant all on search_documents to app_admin;
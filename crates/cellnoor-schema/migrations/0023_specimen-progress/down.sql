@@ -0,0 +1 @@
+drop view specimen_progress;
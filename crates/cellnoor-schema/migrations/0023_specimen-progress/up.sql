@@ -0,0 +1,83 @@
+-- The earliest time each specimen reached each stage of the pipeline. A specimen reaches a stage as soon as anything
+-- derived from it does, whether directly or through a suspension pool
+create view specimen_progress as
+with specimen_gem_pools as (
+    select suspensions.parent_specimen_id as specimen_id, chip_loadings.gem_pool_id
+    from suspensions
+    join chip_loadings on chip_loadings.suspension_id = suspensions.id
+    union
+    select suspensions.parent_specimen_id as specimen_id, chip_loadings.gem_pool_id
+    from suspensions
+    join suspension_tagging on suspension_tagging.suspension_id = suspensions.id
+    join chip_loadings on chip_loadings.suspension_pool_id = suspension_tagging.pool_id
+),
+prepared_suspensions as (
+    select parent_specimen_id as specimen_id, min(created_at) as prepared_at
+    from suspensions
+    group by parent_specimen_id
+),
+downstream as (
+    select
+        specimen_gem_pools.specimen_id,
+        min(chromium_runs.run_at) as chip_loaded_at,
+        min(cdna.prepared_at) as cdna_prepared_at,
+        min(libraries.prepared_at) as library_prepared_at,
+        min(sequencing_runs.finished_at) as sequenced_at,
+        min(chromium_datasets.delivered_at) as delivered_at
+    from specimen_gem_pools
+    join gem_pools on gem_pools.id = specimen_gem_pools.gem_pool_id
+    join chromium_runs on chromium_runs.id = gem_pools.chromium_run_id
+    left join cdna on cdna.gem_pool_id = gem_pools.id
+    left join libraries on libraries.cdna_id = cdna.id
+    left join sequencing_submissions on sequencing_submissions.library_id = libraries.id
+    left join sequencing_runs on sequencing_runs.id = sequencing_submissions.sequencing_run_id
+    left join chromium_dataset_libraries on chromium_dataset_libraries.library_id = libraries.id
+    left join chromium_datasets on chromium_datasets.id = chromium_dataset_libraries.dataset_id
+    group by specimen_gem_pools.specimen_id
+)
+select
+    specimen_id,
+    case
+        when delivered_at is not null then 'delivered'
+        when sequenced_at is not null then 'sequenced'
+        when library_prepared_at is not null then 'library_prepared'
+        when cdna_prepared_at is not null then 'cdna_prepared'
+        when chip_loaded_at is not null then 'chip_loaded'
+        when has_suspension then 'suspension_prepared'
+        else 'received'
+    end as stage,
+    received_at,
+    suspension_prepared_at,
+    chip_loaded_at,
+    cdna_prepared_at,
+    library_prepared_at,
+    sequenced_at,
+    delivered_at,
+    -- `suspensions.created_at` is optional, so fall back to when the specimen was received
+    coalesce(
+        delivered_at,
+        sequenced_at,
+        library_prepared_at,
+        cdna_prepared_at,
+        chip_loaded_at,
+        suspension_prepared_at,
+        received_at
+    ) as stage_entered_at
+from (
+    select
+        specimens.id as specimen_id,
+        specimens.received_at,
+        prepared_suspensions.specimen_id is not null as has_suspension,
+        prepared_suspensions.prepared_at as suspension_prepared_at,
+        downstream.chip_loaded_at,
+        downstream.cdna_prepared_at,
+        downstream.library_prepared_at,
+        downstream.sequenced_at,
+        downstream.delivered_at
+    from specimens
+    left join prepared_suspensions on prepared_suspensions.specimen_id = specimens.id
+    left join downstream on downstream.specimen_id = specimens.id
+) as progress;
+
+grant select on specimen_progress to public;
+grant all on specimen_progress to app_admin;
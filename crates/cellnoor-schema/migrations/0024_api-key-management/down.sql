@@ -0,0 +1 @@
+revoke insert on api_keys from public;
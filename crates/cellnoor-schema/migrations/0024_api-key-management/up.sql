@@ -0,0 +1,2 @@
+-- People manage their own API keys through the API, and row-level security already restricts them to their own
+grant insert on api_keys to public;
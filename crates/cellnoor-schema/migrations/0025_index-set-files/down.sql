@@ -0,0 +1 @@
+drop table index_set_files;
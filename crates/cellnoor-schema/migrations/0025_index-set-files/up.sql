@@ -0,0 +1,7 @@
+-- The checksum of each index set file that was last loaded successfully, so that unchanged files can be skipped and a
+-- failed download can fall back to what's already in the database
+create table index_set_files (
+    url text primary key,
+    sha256 text not null,
+    loaded_at timestamptz not null default now()
+);
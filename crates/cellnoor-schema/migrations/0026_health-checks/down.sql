@@ -0,0 +1 @@
+revoke select on index_set_files from public;
@@ -0,0 +1,3 @@
+-- The readiness check runs as cellnoor_api and needs to see whether index sets were loaded. Note that
+-- __diesel_schema_migrations is already readable by everyone because of 0016_permissions
+grant select on index_set_files to public;
@@ -0,0 +1,16 @@
+drop trigger record_change_event on chromium_dataset_web_summaries;
+drop trigger record_change_event on chromium_dataset_metrics_files;
+drop trigger record_change_event on chromium_datasets;
+drop trigger record_change_event on sequencing_runs;
+drop trigger record_change_event on libraries;
+drop trigger record_change_event on cdna;
+drop trigger record_change_event on chromium_runs;
+drop trigger record_change_event on suspension_pools;
+drop trigger record_change_event on suspensions;
+drop trigger record_change_event on specimens;
+drop trigger record_change_event on projects;
+drop trigger record_change_event on donors;
+
+drop function record_change_event;
+
+drop table change_events;
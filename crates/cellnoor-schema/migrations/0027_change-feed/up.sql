@@ -0,0 +1,78 @@
+-- Inserts and updates of the main tables, so that clients of `/events` can catch up on what they missed. Old events are
+-- deleted by cellnoor-api.
+--
+-- Identity values are handed out when rows are inserted rather than when they're committed, so a transaction can commit
+-- an event with a lower ID than one that's already been streamed. Events are therefore ordered by the ID of the
+-- transaction that recorded them, and only streamed once every transaction with a lower ID has finished (that is, once
+-- they're below `pg_snapshot_xmin(pg_current_snapshot())`). Transaction IDs are `xid8`, which diesel has no type for,
+-- but which fit in a `bigint` in practice
+create table change_events (
+    id bigint primary key generated always as identity,
+    xact_id bigint not null default pg_current_xact_id()::text::bigint,
+    entity_type text not null,
+    operation text not null,
+    entity_id uuid not null,
+    lab_id uuid,
+    occurred_at timestamptz not null default now()
+);
+
+create index change_events_occurred_at_idx on change_events (occurred_at);
+create index change_events_xact_id_id_idx on change_events (xact_id, id);
+
+-- Records an event for the row and sends it on the `change_events` channel. The arguments are the entity type, the
+-- column holding the entity's ID, and either the column holding its lab's ID, 'chromium_datasets' to look up the lab of
+-- the dataset the row belongs to, or '' if the entity has no lab. This is `security definer` because the inserting user
+-- can't write to `change_events`
+create function record_change_event() returns trigger language plpgsql volatile security definer set search_path = public as $$
+    declare row_entity_id uuid;
+    declare row_lab_id uuid;
+    declare event change_events;
+    begin
+        -- Only pull out the columns we need, since some rows hold entire files
+        execute format('select ($1).%I', tg_argv[1]) using new into row_entity_id;
+
+        if tg_argv[2] = 'chromium_datasets' then
+            select lab_id from chromium_datasets where id = row_entity_id into row_lab_id;
+        elsif tg_argv[2] <> '' then
+            execute format('select ($1).%I', tg_argv[2]) using new into row_lab_id;
+        end if;
+
+        insert into change_events (entity_type, operation, entity_id, lab_id)
+        values (tg_argv[0], lower(tg_op), row_entity_id, row_lab_id)
+        returning * into event;
+
+        perform pg_notify('change_events', row_to_json(event)::text);
+
+        return null;
+    end;
+$$;
+
+create trigger record_change_event after insert or update on donors
+for each row execute function record_change_event('donor', 'id', 'lab_id');
+create trigger record_change_event after insert or update on projects
+for each row execute function record_change_event('project', 'id', 'lab_id');
+create trigger record_change_event after insert or update on specimens
+for each row execute function record_change_event('specimen', 'id', 'lab_id');
+create trigger record_change_event after insert or update on suspensions
+for each row execute function record_change_event('suspension', 'id', '');
+create trigger record_change_event after insert or update on suspension_pools
+for each row execute function record_change_event('suspension_pool', 'id', '');
+create trigger record_change_event after insert or update on chromium_runs
+for each row execute function record_change_event('chromium_run', 'id', '');
+create trigger record_change_event after insert or update on cdna
+for each row execute function record_change_event('cdna', 'id', '');
+create trigger record_change_event after insert or update on libraries
+for each row execute function record_change_event('library', 'id', '');
+create trigger record_change_event after insert or update on sequencing_runs
+for each row execute function record_change_event('sequencing_run', 'id', '');
+create trigger record_change_event after insert or update on chromium_datasets
+for each row execute function record_change_event('chromium_dataset', 'id', 'lab_id');
+create trigger record_change_event after insert or update on chromium_dataset_metrics_files
+for each row execute function record_change_event('chromium_dataset_metrics_file', 'dataset_id', 'chromium_datasets');
+create trigger record_change_event after insert or update on chromium_dataset_web_summaries
+for each row execute function record_change_event('chromium_dataset_web_summary', 'dataset_id', 'chromium_datasets');
+
+-- Anyone can read events, and cellnoor-api deletes old ones
+grant select on change_events to public;
+grant all on change_events to app_admin;
+grant delete on change_events to cellnoor_api;
@@ -0,0 +1,12 @@
+drop trigger webhook_chromium_run_marked_failed on chromium_runs;
+drop trigger webhook_chromium_run_failed on chromium_runs;
+drop trigger webhook_specimen_returned on specimens;
+drop trigger webhook_chromium_dataset_delivered on chromium_datasets;
+
+drop function webhook_chromium_run_failed;
+drop function webhook_specimen_returned;
+drop function webhook_chromium_dataset_delivered;
+drop function enqueue_webhook_deliveries;
+
+drop table webhook_deliveries;
+drop table webhook_subscriptions;
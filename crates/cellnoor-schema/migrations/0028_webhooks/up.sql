@@ -0,0 +1,152 @@
+-- Where to send a lab's lifecycle events. The secret signs each delivery, so only cellnoor-api can read it back
+create table webhook_subscriptions (
+    id uuid primary key default uuidv7(),
+    lab_id uuid references labs on delete restrict on update restrict not null,
+    url text not null,
+    secret text not null,
+    event_types text [] not null,
+    created_at timestamptz not null default now()
+);
+
+create index webhook_subscriptions_lab_id_idx on webhook_subscriptions (lab_id);
+
+-- Both the outbox that cellnoor-api sends from and the log of what it sent. A delivery stays pending, with its next
+-- attempt pushed further back each time it fails, until it succeeds or runs out of attempts
+create table webhook_deliveries (
+    id uuid primary key default uuidv7(),
+    subscription_id uuid references webhook_subscriptions on delete cascade on update restrict not null,
+    event_type text not null,
+    payload jsonb not null,
+    created_at timestamptz not null default now(),
+    status text not null default 'pending',
+    n_attempts integer not null default 0,
+    next_attempt_at timestamptz not null default now(),
+    last_attempted_at timestamptz,
+    response_status integer,
+    error text
+);
+
+create index webhook_deliveries_subscription_id_idx on webhook_deliveries (subscription_id, created_at);
+create index webhook_deliveries_pending_idx on webhook_deliveries (next_attempt_at) where status = 'pending';
+
+-- Queues a delivery of the event to every subscription of the lab that wants it. The arguments are the event type, the
+-- lab's ID, and the payload. This is `security definer` because the user whose change caused the event can't write to
+-- `webhook_deliveries`
+create function enqueue_webhook_deliveries(
+    event_type text,
+    lab_id uuid,
+    payload jsonb
+) returns void language sql volatile security definer set search_path = public as $$
+    insert into webhook_deliveries (subscription_id, event_type, payload)
+    select id, $1, $3 from webhook_subscriptions where webhook_subscriptions.lab_id = $2 and $1 = any(event_types);
+$$;
+
+-- A dataset is delivered when it's created
+create function webhook_chromium_dataset_delivered() returns trigger language plpgsql volatile as $$
+    begin
+        perform enqueue_webhook_deliveries(
+            'chromium_dataset_delivered',
+            new.lab_id,
+            jsonb_build_object('id', new.id, 'name', new.name, 'lab_id', new.lab_id, 'delivered_at', new.delivered_at)
+        );
+
+        return null;
+    end;
+$$;
+
+create trigger webhook_chromium_dataset_delivered after insert on chromium_datasets
+for each row execute function webhook_chromium_dataset_delivered();
+
+create function webhook_specimen_returned() returns trigger language plpgsql volatile as $$
+    begin
+        perform enqueue_webhook_deliveries(
+            'specimen_returned',
+            new.lab_id,
+            jsonb_build_object(
+                'id', new.id,
+                'readable_id', new.readable_id,
+                'name', new.name,
+                'lab_id', new.lab_id,
+                'returned_at', new.returned_at,
+                'returned_by', new.returned_by
+            )
+        );
+
+        return null;
+    end;
+$$;
+
+create trigger webhook_specimen_returned after update of returned_at on specimens
+for each row when (old.returned_at is null and new.returned_at is not null)
+execute function webhook_specimen_returned();
+
+-- A Chromium run fails QC when it's recorded as not having succeeded, whether when it's created or later. Every lab whose
+-- specimens were loaded onto the run, directly or in a pool, is told. The triggers are deferred to the end of the
+-- transaction so that the run's chip loadings, which are inserted after it, can be found. This is `security definer`
+-- because the user who recorded the run might not be able to see every lab's specimens
+create function webhook_chromium_run_failed() returns trigger language plpgsql volatile security definer set search_path = public as $$
+    begin
+        perform enqueue_webhook_deliveries(
+            'chromium_run_failed',
+            run_labs.lab_id,
+            jsonb_build_object(
+                'id', new.id,
+                'readable_id', new.readable_id,
+                'lab_id', run_labs.lab_id,
+                'run_at', new.run_at,
+                'run_by', new.run_by
+            )
+        )
+        from (
+            select distinct specimens.lab_id
+            from gem_pools
+            inner join chip_loadings on gem_pools.id = chip_loadings.gem_pool_id
+            left join suspension_tagging on chip_loadings.suspension_pool_id = suspension_tagging.pool_id
+            inner join suspensions
+                on coalesce(chip_loadings.suspension_id, suspension_tagging.suspension_id) = suspensions.id
+            inner join specimens on suspensions.parent_specimen_id = specimens.id
+            where gem_pools.chromium_run_id = new.id
+        ) as run_labs;
+
+        return null;
+    end;
+$$;
+
+create constraint trigger webhook_chromium_run_failed after insert on chromium_runs
+deferrable initially deferred
+for each row when (not new.succeeded)
+execute function webhook_chromium_run_failed();
+
+create constraint trigger webhook_chromium_run_marked_failed after update of succeeded on chromium_runs
+deferrable initially deferred
+for each row when (old.succeeded and not new.succeeded)
+execute function webhook_chromium_run_failed();
+
+-- A lab's subscriptions, and their deliveries, are visible to its members and its PI. cellnoor-api needs to see all of
+-- them to send deliveries
+alter table webhook_subscriptions enable row level security;
+create policy lab_webhook_subscription on webhook_subscriptions using (
+    current_user = 'cellnoor_api'
+    or pg_has_role(current_user, 'app_admin', 'member')
+    or exists (
+        select 1 from labs where labs.id = webhook_subscriptions.lab_id and labs.pi_id::text = current_user
+    )
+    or exists (
+        select 1 from lab_membership
+        where lab_membership.lab_id = webhook_subscriptions.lab_id and lab_membership.member_id::text = current_user
+    )
+);
+
+alter table webhook_deliveries enable row level security;
+create policy lab_webhook_delivery on webhook_deliveries using (
+    current_user = 'cellnoor_api'
+    or exists (select 1 from webhook_subscriptions where webhook_subscriptions.id = subscription_id)
+);
+
+-- People can manage their labs' subscriptions but can't read a secret back. cellnoor-api reads secrets to sign
+-- deliveries and records each attempt
+grant select (id, lab_id, url, event_types, created_at), insert, delete on webhook_subscriptions to public;
+grant select on webhook_deliveries to public;
+grant all on webhook_subscriptions, webhook_deliveries to app_admin;
+grant select on webhook_subscriptions to cellnoor_api;
+grant update on webhook_deliveries to cellnoor_api;
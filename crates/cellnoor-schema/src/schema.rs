@@ -45,6 +45,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    change_events (id) {
+        id -> Int8,
+        xact_id -> Int8,
+        entity_type -> Text,
+        operation -> Text,
+        entity_id -> Uuid,
+        lab_id -> Nullable<Uuid>,
+        occurred_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     chip_loadings (id) {
         id -> Uuid,
@@ -109,11 +121,30 @@ diesel::table! {
 }
 
 diesel::table! {
-    committee_approval (institution_id, committee_type, specimen_id) {
+    committee_approvals (id) {
+        id -> Uuid,
+        links -> Jsonb,
         institution_id -> Uuid,
-        specimen_id -> Uuid,
         committee_type -> Text,
         compliance_identifier -> Text,
+        valid_from -> Timestamptz,
+        valid_until -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    donors (id) {
+        id -> Uuid,
+        links -> Jsonb,
+        external_id -> Text,
+        lab_id -> Uuid,
+        species -> Text,
+        sex -> Nullable<Text>,
+        born_at -> Nullable<Timestamptz>,
+        age_in_days -> Nullable<Int4>,
+        strain -> Nullable<Text>,
+        genotype -> Nullable<Text>,
+        additional_data -> Nullable<Jsonb>,
     }
 }
 
@@ -143,11 +174,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    index_set_files (url) {
+        url -> Text,
+        sha256 -> Text,
+        loaded_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     institutions (id) {
         id -> Uuid,
         links -> Jsonb,
         name -> Text,
+        requires_committee_approval -> Bool,
     }
 }
 
@@ -234,6 +274,46 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    project_chromium_datasets (project_id, dataset_id) {
+        project_id -> Uuid,
+        dataset_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    project_specimens (project_id, specimen_id) {
+        project_id -> Uuid,
+        specimen_id -> Uuid,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::CaseInsensitiveText;
+
+    projects (id) {
+        id -> Uuid,
+        links -> Jsonb,
+        name -> Text,
+        lab_id -> Uuid,
+        description -> Nullable<Text>,
+        started_at -> Timestamptz,
+        ended_at -> Nullable<Timestamptz>,
+        grant_ids -> Array<Nullable<CaseInsensitiveText>>,
+    }
+}
+
+diesel::table! {
+    search_documents (id) {
+        entity_type -> Text,
+        id -> Uuid,
+        links -> Jsonb,
+        label -> Text,
+        content -> Text,
+    }
+}
+
 diesel::table! {
     sequencing_runs (id) {
         id -> Uuid,
@@ -265,6 +345,13 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    specimen_committee_approvals (specimen_id, approval_id) {
+        specimen_id -> Uuid,
+        approval_id -> Uuid,
+    }
+}
+
 diesel::table! {
     specimen_measurements (id) {
         id -> Uuid,
@@ -275,6 +362,21 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    specimen_progress (specimen_id) {
+        specimen_id -> Uuid,
+        stage -> Text,
+        received_at -> Timestamptz,
+        suspension_prepared_at -> Nullable<Timestamptz>,
+        chip_loaded_at -> Nullable<Timestamptz>,
+        cdna_prepared_at -> Nullable<Timestamptz>,
+        library_prepared_at -> Nullable<Timestamptz>,
+        sequenced_at -> Nullable<Timestamptz>,
+        delivered_at -> Nullable<Timestamptz>,
+        stage_entered_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     specimens (id) {
         id -> Uuid,
@@ -296,6 +398,47 @@ diesel::table! {
         cryopreserved -> Bool,
         tissue -> Text,
         additional_data -> Nullable<Jsonb>,
+        donor_id -> Nullable<Uuid>,
+    }
+}
+
+diesel::table! {
+    storage_events (id) {
+        id -> Uuid,
+        box_id -> Uuid,
+        position -> Int4,
+        specimen_id -> Nullable<Uuid>,
+        suspension_id -> Nullable<Uuid>,
+        cdna_id -> Nullable<Uuid>,
+        library_id -> Nullable<Uuid>,
+        kind -> Text,
+        performed_by -> Uuid,
+        performed_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    storage_occupancy (event_id) {
+        event_id -> Uuid,
+        box_id -> Uuid,
+        position -> Int4,
+        specimen_id -> Nullable<Uuid>,
+        suspension_id -> Nullable<Uuid>,
+        cdna_id -> Nullable<Uuid>,
+        library_id -> Nullable<Uuid>,
+        checked_in_by -> Uuid,
+        checked_in_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    storage_units (id) {
+        id -> Uuid,
+        links -> Jsonb,
+        name -> Text,
+        kind -> Text,
+        parent_id -> Nullable<Uuid>,
+        capacity -> Nullable<Int4>,
     }
 }
 
@@ -383,6 +526,33 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    webhook_deliveries (id) {
+        id -> Uuid,
+        subscription_id -> Uuid,
+        event_type -> Text,
+        payload -> Jsonb,
+        created_at -> Timestamptz,
+        status -> Text,
+        n_attempts -> Int4,
+        next_attempt_at -> Timestamptz,
+        last_attempted_at -> Nullable<Timestamptz>,
+        response_status -> Nullable<Int4>,
+        error -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    webhook_subscriptions (id) {
+        id -> Uuid,
+        lab_id -> Uuid,
+        url -> Text,
+        secret -> Text,
+        event_types -> Array<Nullable<Text>>,
+        created_at -> Timestamptz,
+    }
+}
+
 diesel::joinable!(api_keys -> people (user_id));
 diesel::joinable!(cdna -> gem_pools (gem_pool_id));
 diesel::joinable!(cdna_measurements -> cdna (cdna_id));
@@ -399,8 +569,8 @@ diesel::joinable!(chromium_dataset_web_summaries -> chromium_datasets (dataset_i
 diesel::joinable!(chromium_datasets -> labs (lab_id));
 diesel::joinable!(chromium_runs -> people (run_by));
 diesel::joinable!(chromium_runs -> tenx_assays (assay_id));
-diesel::joinable!(committee_approval -> institutions (institution_id));
-diesel::joinable!(committee_approval -> specimens (specimen_id));
+diesel::joinable!(committee_approvals -> institutions (institution_id));
+diesel::joinable!(donors -> labs (lab_id));
 diesel::joinable!(dual_index_sets -> index_kits (kit));
 diesel::joinable!(gem_pools -> chromium_runs (chromium_run_id));
 diesel::joinable!(lab_membership -> labs (lab_id));
@@ -416,12 +586,27 @@ diesel::joinable!(library_preparers -> people (prepared_by));
 diesel::joinable!(library_type_specifications -> index_kits (index_kit));
 diesel::joinable!(library_type_specifications -> tenx_assays (assay_id));
 diesel::joinable!(people -> institutions (institution_id));
+diesel::joinable!(project_chromium_datasets -> chromium_datasets (dataset_id));
+diesel::joinable!(project_chromium_datasets -> projects (project_id));
+diesel::joinable!(project_specimens -> projects (project_id));
+diesel::joinable!(project_specimens -> specimens (specimen_id));
+diesel::joinable!(projects -> labs (lab_id));
 diesel::joinable!(sequencing_submissions -> libraries (library_id));
 diesel::joinable!(sequencing_submissions -> sequencing_runs (sequencing_run_id));
 diesel::joinable!(single_index_sets -> index_kits (kit));
+diesel::joinable!(specimen_committee_approvals -> committee_approvals (approval_id));
+diesel::joinable!(specimen_committee_approvals -> specimens (specimen_id));
 diesel::joinable!(specimen_measurements -> people (measured_by));
 diesel::joinable!(specimen_measurements -> specimens (specimen_id));
+diesel::joinable!(specimen_progress -> specimens (specimen_id));
+diesel::joinable!(specimens -> donors (donor_id));
 diesel::joinable!(specimens -> labs (lab_id));
+diesel::joinable!(storage_events -> cdna (cdna_id));
+diesel::joinable!(storage_events -> libraries (library_id));
+diesel::joinable!(storage_events -> people (performed_by));
+diesel::joinable!(storage_events -> specimens (specimen_id));
+diesel::joinable!(storage_events -> storage_units (box_id));
+diesel::joinable!(storage_events -> suspensions (suspension_id));
 diesel::joinable!(suspension_measurements -> people (measured_by));
 diesel::joinable!(suspension_measurements -> suspensions (suspension_id));
 diesel::joinable!(suspension_pool_measurements -> people (measured_by));
@@ -434,22 +619,27 @@ diesel::joinable!(suspension_tagging -> multiplexing_tags (tag_id));
 diesel::joinable!(suspension_tagging -> suspension_pools (pool_id));
 diesel::joinable!(suspension_tagging -> suspensions (suspension_id));
 diesel::joinable!(suspensions -> specimens (parent_specimen_id));
+diesel::joinable!(webhook_deliveries -> webhook_subscriptions (subscription_id));
+diesel::joinable!(webhook_subscriptions -> labs (lab_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     api_keys,
     cdna,
     cdna_measurements,
     cdna_preparers,
+    change_events,
     chip_loadings,
     chromium_dataset_libraries,
     chromium_dataset_metrics_files,
     chromium_dataset_web_summaries,
     chromium_datasets,
     chromium_runs,
-    committee_approval,
+    committee_approvals,
+    donors,
     dual_index_sets,
     gem_pools,
     index_kits,
+    index_set_files,
     institutions,
     lab_membership,
     labs,
@@ -459,11 +649,20 @@ diesel::allow_tables_to_appear_in_same_query!(
     library_type_specifications,
     multiplexing_tags,
     people,
+    project_chromium_datasets,
+    project_specimens,
+    projects,
+    search_documents,
     sequencing_runs,
     sequencing_submissions,
     single_index_sets,
+    specimen_committee_approvals,
     specimen_measurements,
+    specimen_progress,
     specimens,
+    storage_events,
+    storage_occupancy,
+    storage_units,
     suspension_measurements,
     suspension_pool_measurements,
     suspension_pool_preparers,
@@ -472,4 +671,6 @@ diesel::allow_tables_to_appear_in_same_query!(
     suspension_tagging,
     suspensions,
     tenx_assays,
+    webhook_deliveries,
+    webhook_subscriptions,
 );
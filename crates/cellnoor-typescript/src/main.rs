@@ -2,21 +2,41 @@
 
 use cellnoor_api::api::ErrorResponse;
 use cellnoor_models::{
+    api_key::{ApiKey, CreatedApiKey},
     cdna::{Cdna, CdnaCreation, CdnaFilter, CdnaOrderBy, CdnaSummary},
+    change_event::{ChangeEvent, ChangeEventFilter},
     chromium_dataset::{
-        ChromiumDataset, ChromiumDatasetCreation, ChromiumDatasetFilter, ChromiumDatasetOrderBy,
-        ChromiumDatasetSummary,
+        ChromiumDataset, ChromiumDatasetCreation, ChromiumDatasetFilter, ChromiumDatasetInclude,
+        ChromiumDatasetOrderBy, ChromiumDatasetSummary,
     },
     chromium_run::{ChromiumRunFilter, ChromiumRunOrderBy, GemPoolFilter, GemPoolOrderBy},
-    institution::{Institution, InstitutionCreation, InstitutionFilter, InstitutionOrderBy},
+    committee_approval::{
+        CommitteeApproval, CommitteeApprovalCreation, CommitteeApprovalFilter,
+        CommitteeApprovalOrderBy, CommitteeApprovalUpdate,
+    },
+    donor::{Donor, DonorCreation, DonorFilter, DonorOrderBy, DonorUpdate},
+    generic_query::{Page, TotalMode},
+    institution::{
+        Institution, InstitutionCreation, InstitutionFilter, InstitutionOrderBy, InstitutionUpdate,
+    },
     lab::{Lab, LabCreation, LabFilter, LabOrderBy},
     library::{Library, LibraryCreation, LibraryFilter, LibraryOrderBy, LibrarySummary},
+    lineage::{Lineage, LineageQuery},
     person::{Person, PersonCreation, PersonFilter, PersonOrderBy},
+    project::{Project, ProjectCreation, ProjectFilter, ProjectOrderBy, ProjectUpdate},
+    read_options::{NoInclude, Shaped},
+    search::{SearchQuery, SearchResults},
     sequencing_run::{SequencingRun, SequencingRunCreation},
     specimen::{
-        Specimen, SpecimenCreation, SpecimenFilter, SpecimenOrderBy,
+        Specimen, SpecimenCreation, SpecimenFilter, SpecimenInclude, SpecimenOrderBy,
+        SpecimenProgress, SpecimenSummary, StalledSpecimenQuery,
         measurement::{SpecimenMeasurement, SpecimenMeasurementCreation},
     },
+    stats::{ChromiumDatasetGroupBy, SpecimenGroupBy, StatsSeries, TimeBucket},
+    storage::{
+        OccupiedPosition, StorageCheckIn, StorageCheckOut, StorageUnit, StorageUnitCreation,
+        StorageUnitFilter, StorageUnitOrderBy,
+    },
     suspension::{
         Suspension, SuspensionCreation, SuspensionFilter, SuspensionOrderBy, SuspensionSummary,
         measurement::{
@@ -31,9 +51,14 @@ use cellnoor_models::{
             SuspensionPoolMeasurement,
         },
     },
+    webhook::{
+        WebhookDelivery, WebhookDeliveryFilter, WebhookDeliveryOrderBy, WebhookSubscription,
+        WebhookSubscriptionCreation,
+    },
 };
 use clap::Parser;
 use ts_rs::TS;
+use uuid::Uuid;
 
 #[derive(clap::Parser)]
 #[command(version, about)]
@@ -44,11 +69,13 @@ struct Cli {
 
 #[derive(TS)]
 #[ts(optional_fields)]
-struct Query<F, O>
+struct Query<F, O, I>
 where
     F: TS,
     O: TS,
     <O as TS>::OptionInnerType: TS,
+    I: TS,
+    <I as TS>::OptionInnerType: TS,
 {
     #[ts(inline)]
     filter: Option<F>,
@@ -56,46 +83,119 @@ where
     offset: Option<i64>,
     #[ts(inline)]
     order_by: Option<Vec<O>>,
+    cursor: Option<Uuid>,
+    total: Option<TotalMode>,
+    fields: Option<Vec<String>>,
+    include: Option<Vec<I>>,
+}
+
+#[derive(TS)]
+#[ts(optional_fields)]
+struct ReadOptions<I>
+where
+    I: TS,
+    <I as TS>::OptionInnerType: TS,
+{
+    fields: Option<Vec<String>>,
+    include: Option<Vec<I>>,
+}
+
+#[derive(TS)]
+#[ts(optional_fields)]
+struct StatsQuery<F, G>
+where
+    F: TS,
+    G: TS,
+    <G as TS>::OptionInnerType: TS,
+{
+    #[ts(inline)]
+    filter: Option<F>,
+    group_by: Option<G>,
+    bucket: Option<TimeBucket>,
 }
 
 #[derive(TS)]
-struct InstitutionQuery(#[ts(inline)] Query<InstitutionFilter, InstitutionOrderBy>);
+struct InstitutionQuery(#[ts(inline)] Query<InstitutionFilter, InstitutionOrderBy, NoInclude>);
+
+#[derive(TS)]
+struct PersonQuery(#[ts(inline)] Query<PersonFilter, PersonOrderBy, NoInclude>);
+
+#[derive(TS)]
+struct LabQuery(#[ts(inline)] Query<LabFilter, LabOrderBy, NoInclude>);
+
+#[derive(TS)]
+struct ProjectQuery(#[ts(inline)] Query<ProjectFilter, ProjectOrderBy, NoInclude>);
+
+#[derive(TS)]
+struct CommitteeApprovalQuery(
+    #[ts(inline)] Query<CommitteeApprovalFilter, CommitteeApprovalOrderBy, NoInclude>,
+);
+
+#[derive(TS)]
+struct DonorQuery(#[ts(inline)] Query<DonorFilter, DonorOrderBy, NoInclude>);
+
+#[derive(TS)]
+struct SpecimenQuery(#[ts(inline)] Query<SpecimenFilter, SpecimenOrderBy, SpecimenInclude>);
+
+#[derive(TS)]
+struct StorageUnitQuery(#[ts(inline)] Query<StorageUnitFilter, StorageUnitOrderBy, NoInclude>);
+
+#[derive(TS)]
+struct SuspensionQuery(#[ts(inline)] Query<SuspensionFilter, SuspensionOrderBy, NoInclude>);
+
+#[derive(TS)]
+struct SuspensionPoolQuery(
+    #[ts(inline)] Query<SuspensionPoolFilter, SuspensionPoolOrderBy, NoInclude>,
+);
+
+#[derive(TS)]
+struct GemPoolQuery(#[ts(inline)] Query<GemPoolFilter, GemPoolOrderBy, NoInclude>);
 
 #[derive(TS)]
-struct PersonQuery(#[ts(inline)] Query<PersonFilter, PersonOrderBy>);
+struct ChromiumRunQuery(#[ts(inline)] Query<ChromiumRunFilter, ChromiumRunOrderBy, NoInclude>);
 
 #[derive(TS)]
-struct LabQuery(#[ts(inline)] Query<LabFilter, LabOrderBy>);
+struct CdnaQuery(#[ts(inline)] Query<CdnaFilter, CdnaOrderBy, NoInclude>);
 
 #[derive(TS)]
-struct SpecimenQuery(#[ts(inline)] Query<SpecimenFilter, SpecimenOrderBy>);
+struct LibraryQuery(#[ts(inline)] Query<LibraryFilter, LibraryOrderBy, NoInclude>);
 
 #[derive(TS)]
-struct SuspensionQuery(#[ts(inline)] Query<SuspensionFilter, SuspensionOrderBy>);
+struct ChromiumDatasetQuery(
+    #[ts(inline)] Query<ChromiumDatasetFilter, ChromiumDatasetOrderBy, ChromiumDatasetInclude>,
+);
 
 #[derive(TS)]
-struct SuspensionPoolQuery(#[ts(inline)] Query<SuspensionPoolFilter, SuspensionPoolOrderBy>);
+struct WebhookDeliveryQuery(
+    #[ts(inline)] Query<WebhookDeliveryFilter, WebhookDeliveryOrderBy, NoInclude>,
+);
 
 #[derive(TS)]
-struct GemPoolQuery(#[ts(inline)] Query<GemPoolFilter, GemPoolOrderBy>);
+struct ShapedSpecimen(Shaped<Specimen, SpecimenInclude>);
 
 #[derive(TS)]
-struct ChromiumRunQuery(#[ts(inline)] Query<ChromiumRunFilter, ChromiumRunOrderBy>);
+struct ShapedSpecimenSummary(Shaped<SpecimenSummary, SpecimenInclude>);
 
 #[derive(TS)]
-struct CdnaQuery(#[ts(inline)] Query<CdnaFilter, CdnaOrderBy>);
+struct ShapedChromiumDataset(Shaped<ChromiumDataset, ChromiumDatasetInclude>);
 
 #[derive(TS)]
-struct LibraryQuery(#[ts(inline)] Query<LibraryFilter, LibraryOrderBy>);
+struct ShapedChromiumDatasetSummary(Shaped<ChromiumDatasetSummary, ChromiumDatasetInclude>);
 
 #[derive(TS)]
-struct ChromiumDatasetQuery(#[ts(inline)] Query<ChromiumDatasetFilter, ChromiumDatasetOrderBy>);
+struct SpecimenStatsQuery(#[ts(inline)] StatsQuery<SpecimenFilter, SpecimenGroupBy>);
+
+#[derive(TS)]
+struct ChromiumDatasetStatsQuery(
+    #[ts(inline)] StatsQuery<ChromiumDatasetFilter, ChromiumDatasetGroupBy>,
+);
 
 fn main() {
     let Cli { output_dir } = Cli::parse();
 
     InstitutionCreation::export_all_to(&output_dir).unwrap();
     InstitutionQuery::export_all_to(&output_dir).unwrap();
+    InstitutionUpdate::export_all_to(&output_dir).unwrap();
     Institution::export_all_to(&output_dir).unwrap();
 
     PersonCreation::export_all_to(&output_dir).unwrap();
@@ -106,11 +206,37 @@ fn main() {
     LabQuery::export_all_to(&output_dir).unwrap();
     Lab::export_all_to(&output_dir).unwrap();
 
+    CommitteeApprovalCreation::export_all_to(&output_dir).unwrap();
+    CommitteeApprovalQuery::export_all_to(&output_dir).unwrap();
+    CommitteeApprovalUpdate::export_all_to(&output_dir).unwrap();
+    CommitteeApproval::export_all_to(&output_dir).unwrap();
+
+    ProjectCreation::export_all_to(&output_dir).unwrap();
+    ProjectQuery::export_all_to(&output_dir).unwrap();
+    ProjectUpdate::export_all_to(&output_dir).unwrap();
+    Project::export_all_to(&output_dir).unwrap();
+
+    DonorCreation::export_all_to(&output_dir).unwrap();
+    DonorQuery::export_all_to(&output_dir).unwrap();
+    DonorUpdate::export_all_to(&output_dir).unwrap();
+    Donor::export_all_to(&output_dir).unwrap();
+
     SpecimenCreation::export_all_to(&output_dir).unwrap();
     SpecimenQuery::export_all_to(&output_dir).unwrap();
     Specimen::export_all_to(&output_dir).unwrap();
+    ShapedSpecimen::export_all_to(&output_dir).unwrap();
+    ShapedSpecimenSummary::export_all_to(&output_dir).unwrap();
     SpecimenMeasurementCreation::export_all_to(&output_dir).unwrap();
     SpecimenMeasurement::export_all_to(&output_dir).unwrap();
+    SpecimenProgress::export_all_to(&output_dir).unwrap();
+    StalledSpecimenQuery::export_all_to(&output_dir).unwrap();
+
+    StorageUnitCreation::export_all_to(&output_dir).unwrap();
+    StorageUnitQuery::export_all_to(&output_dir).unwrap();
+    StorageUnit::export_all_to(&output_dir).unwrap();
+    StorageCheckIn::export_all_to(&output_dir).unwrap();
+    StorageCheckOut::export_all_to(&output_dir).unwrap();
+    OccupiedPosition::export_all_to(&output_dir).unwrap();
 
     SequencingRunCreation::export_all_to(&output_dir).unwrap();
     SequencingRun::export_all_to(&output_dir).unwrap();
@@ -144,6 +270,32 @@ fn main() {
     ChromiumDatasetQuery::export_all_to(&output_dir).unwrap();
     ChromiumDatasetSummary::export_all_to(&output_dir).unwrap();
     ChromiumDataset::export_all_to(&output_dir).unwrap();
+    ShapedChromiumDataset::export_all_to(&output_dir).unwrap();
+    ShapedChromiumDatasetSummary::export_all_to(&output_dir).unwrap();
+
+    LineageQuery::export_all_to(&output_dir).unwrap();
+    Lineage::export_all_to(&output_dir).unwrap();
+
+    Page::<()>::export_all_to(&output_dir).unwrap();
+    ReadOptions::<NoInclude>::export_all_to(&output_dir).unwrap();
+
+    SearchQuery::export_all_to(&output_dir).unwrap();
+    SearchResults::export_all_to(&output_dir).unwrap();
+
+    ChangeEventFilter::export_all_to(&output_dir).unwrap();
+    ChangeEvent::export_all_to(&output_dir).unwrap();
+
+    SpecimenStatsQuery::export_all_to(&output_dir).unwrap();
+    ChromiumDatasetStatsQuery::export_all_to(&output_dir).unwrap();
+    StatsSeries::export_all_to(&output_dir).unwrap();
+
+    ApiKey::export_all_to(&output_dir).unwrap();
+    CreatedApiKey::export_all_to(&output_dir).unwrap();
+
+    WebhookSubscriptionCreation::export_all_to(&output_dir).unwrap();
+    WebhookSubscription::export_all_to(&output_dir).unwrap();
+    WebhookDeliveryQuery::export_all_to(&output_dir).unwrap();
+    WebhookDelivery::export_all_to(&output_dir).unwrap();
 
     ErrorResponse::export_all_to(&output_dir).unwrap();
 }
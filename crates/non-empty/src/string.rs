@@ -104,6 +104,32 @@ mod diesel_impls {
     }
 }
 
+#[cfg(feature = "schema")]
+mod schema_impls {
+    use std::borrow::Cow;
+
+    use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+    use super::NonEmptyString;
+
+    impl JsonSchema for NonEmptyString {
+        fn schema_name() -> Cow<'static, str> {
+            "NonEmptyString".into()
+        }
+
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "string",
+                "minLength": 1
+            })
+        }
+    }
+}
+
 #[cfg(feature = "serde")]
 #[cfg(test)]
 mod serde_tests {
@@ -66,6 +66,34 @@ impl<T, const N: usize> TryFrom<Vec<T>> for NonEmptyVec<T, N> {
     }
 }
 
+#[cfg(feature = "schema")]
+mod schema_impls {
+    use std::borrow::Cow;
+
+    use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+    use super::NonEmptyVec;
+
+    impl<T: JsonSchema, const N: usize> JsonSchema for NonEmptyVec<T, N> {
+        fn schema_name() -> Cow<'static, str> {
+            format!("NonEmptyArray_of_{}_{N}", T::schema_name()).into()
+        }
+
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "array",
+                "items": generator.subschema_for::<T>(),
+                "minItems": 1,
+                "maxItems": N
+            })
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
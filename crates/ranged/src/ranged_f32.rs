@@ -43,6 +43,34 @@ mod serde_impls {
     }
 }
 
+#[cfg(feature = "schema")]
+mod schema_impls {
+    use std::borrow::Cow;
+
+    use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+    use super::RangedF32;
+
+    impl<const MIN: u32, const MAX: u32> JsonSchema for RangedF32<MIN, MAX> {
+        fn schema_name() -> Cow<'static, str> {
+            format!("RangedF32_{MIN}_{MAX}").into()
+        }
+
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "number",
+                "format": "float",
+                "minimum": MIN,
+                "maximum": MAX
+            })
+        }
+    }
+}
+
 #[cfg(feature = "diesel")]
 mod diesel_impls {
     use diesel::{
@@ -16,6 +16,33 @@ impl<const MIN: u16, const MAX: u16> RangedU16<MIN, MAX> {
     }
 }
 
+#[cfg(feature = "schema")]
+mod schema_impls {
+    use std::borrow::Cow;
+
+    use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+    use super::RangedU16;
+
+    impl<const MIN: u16, const MAX: u16> JsonSchema for RangedU16<MIN, MAX> {
+        fn schema_name() -> Cow<'static, str> {
+            format!("RangedU16_{MIN}_{MAX}").into()
+        }
+
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "integer",
+                "minimum": MIN,
+                "maximum": MAX
+            })
+        }
+    }
+}
+
 #[cfg(feature = "diesel")]
 mod diesel_impls {
     use diesel::{
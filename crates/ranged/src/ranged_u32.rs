@@ -16,6 +16,33 @@ impl<const MIN: u32, const MAX: u32> RangedU32<MIN, MAX> {
     }
 }
 
+#[cfg(feature = "schema")]
+mod schema_impls {
+    use std::borrow::Cow;
+
+    use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+    use super::RangedU32;
+
+    impl<const MIN: u32, const MAX: u32> JsonSchema for RangedU32<MIN, MAX> {
+        fn schema_name() -> Cow<'static, str> {
+            format!("RangedU32_{MIN}_{MAX}").into()
+        }
+
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn json_schema(_: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "integer",
+                "minimum": MIN,
+                "maximum": MAX
+            })
+        }
+    }
+}
+
 #[cfg(feature = "diesel")]
 mod diesel_impls {
     use diesel::{
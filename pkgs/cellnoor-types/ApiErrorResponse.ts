@@ -3,4 +3,9 @@ import type { AuthError } from "./AuthError";
 import type { DataValidationError } from "./DataValidationError";
 import type { DatabaseError } from "./DatabaseError";
 
-export type ApiErrorResponse = { status: number, } & ({ "type": "auth", "info": AuthError } | { "type": "data", "info": DataValidationError } | { "type": "database", "info": DatabaseError } | { "type": "malformed_request", "info": { message: string, } } | { "type": "other" });
+export type ApiErrorResponse = { status: number, 
+/**
+ * Filled in from the request's `X-Request-Id` when the response is sent,
+ * so that users can quote it to support
+ */
+request_id?: string, } & ({ "type": "auth", "info": AuthError } | { "type": "data", "info": DataValidationError } | { "type": "database", "info": DatabaseError } | { "type": "malformed_request", "info": { message: string, } } | { "type": "rate_limited", "info": { retry_after_secs: number, } } | { "type": "other" });
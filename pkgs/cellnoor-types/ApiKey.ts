@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * An API key belonging to the requesting user. Only the hex-encoded prefix
+ * is known after creation, since the key itself is stored as a hash.
+ */
+export type ApiKey = { prefix: string, created_at: string, };
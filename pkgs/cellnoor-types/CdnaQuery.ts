@@ -1,3 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonPath } from "./JsonPath";
+import type { JsonPathPredicate } from "./JsonPathPredicate";
+import type { NoInclude } from "./NoInclude";
+import type { TotalMode } from "./TotalMode";
 
-export type CdnaQuery = { filter?: { ids?: Array<string>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "readable_id": { descending?: boolean, } } | { "library_type": { descending?: boolean, } } | { "prepared_at": { descending?: boolean, } } | { "gem_pool_id": { descending?: boolean, } } | { "n_amplification_cycles": { descending?: boolean, } }>, };
+export type CdnaQuery = { filter?: { ids?: Array<string>, additional_data_predicates?: Array<JsonPathPredicate>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "readable_id": { descending?: boolean, } } | { "library_type": { descending?: boolean, } } | { "prepared_at": { descending?: boolean, } } | { "gem_pool_id": { descending?: boolean, } } | { "n_amplification_cycles": { descending?: boolean, } } | { "additional_data": { path: JsonPath, descending?: boolean, } }>, cursor?: string, total?: TotalMode, fields?: Array<string>, include?: Array<NoInclude>, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChangeEntityType } from "./ChangeEntityType";
+import type { ChangeOperation } from "./ChangeOperation";
+
+/**
+ * An insert or update of an entity. For metrics files and web summaries,
+ * `entity_id` is the ID of the Chromium dataset they belong to.
+ */
+export type ChangeEvent = { id: number, entity_type: ChangeEntityType, operation: ChangeOperation, entity_id: string, lab_id?: string, occurred_at: string, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChangeEntityType } from "./ChangeEntityType";
+
+/**
+ * Which events to stream. Events for entities that don't belong to a lab
+ * never match `labs`.
+ */
+export type ChangeEventFilter = { entity_types?: Array<ChangeEntityType>, labs?: Array<string>, };
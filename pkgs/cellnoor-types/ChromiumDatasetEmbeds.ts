@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LabSummary } from "./LabSummary";
+import type { LibrarySummary } from "./LibrarySummary";
+
+/**
+ * The related objects embedded in a Chromium dataset by
+ * [`ChromiumDatasetInclude`]
+ */
+export type ChromiumDatasetEmbeds = { lab?: LabSummary, libraries?: Array<LibrarySummary>, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Related objects that can be embedded in a Chromium dataset with `include`
+ */
+export type ChromiumDatasetInclude = "lab" | "libraries";
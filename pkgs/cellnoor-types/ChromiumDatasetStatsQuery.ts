@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChromiumDatasetGroupBy } from "./ChromiumDatasetGroupBy";
+import type { SpecimenFilter } from "./SpecimenFilter";
+import type { TenxAssayFilter } from "./TenxAssayFilter";
+import type { TimeBucket } from "./TimeBucket";
+
+export type ChromiumDatasetStatsQuery = { filter?: { ids?: Array<string>, names?: Array<string>, specimen?: SpecimenFilter, assay?: TenxAssayFilter, lab_ids?: Array<string>, project_ids?: Array<string>, delivered_before?: string, delivered_after?: string, }, group_by?: ChromiumDatasetGroupBy, bucket?: TimeBucket, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CommitteeType } from "./CommitteeType";
+import type { Institution } from "./Institution";
+import type { Links } from "./Links";
+import type { NonEmptyString } from "./NonEmptyString";
+
+export type CommitteeApproval = { institution: Institution, id: string, links: Links, valid_from: string, valid_until?: string, institution_id: string, committee_type: CommitteeType, compliance_identifier: NonEmptyString, };
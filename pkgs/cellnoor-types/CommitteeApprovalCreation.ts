@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CommitteeType } from "./CommitteeType";
+import type { NonEmptyString } from "./NonEmptyString";
+
+export type CommitteeApprovalCreation = { valid_from: string, valid_until?: string, institution_id: string, committee_type: CommitteeType, compliance_identifier: NonEmptyString, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CommitteeType } from "./CommitteeType";
+import type { NoInclude } from "./NoInclude";
+import type { TotalMode } from "./TotalMode";
+
+export type CommitteeApprovalQuery = { filter?: { ids?: Array<string>, institution_ids?: Array<string>, committee_types?: Array<CommitteeType>, compliance_identifiers?: Array<string>, valid_at?: string, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "institution_id": { descending?: boolean, } } | { "committee_type": { descending?: boolean, } } | { "compliance_identifier": { descending?: boolean, } } | { "valid_from": { descending?: boolean, } } | { "valid_until": { descending?: boolean, } }>, cursor?: string, total?: TotalMode, fields?: Array<string>, include?: Array<NoInclude>, };
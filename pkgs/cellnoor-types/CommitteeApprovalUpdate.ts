@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NonEmptyString } from "./NonEmptyString";
+
+export type CommitteeApprovalUpdate = { compliance_identifier?: NonEmptyString, valid_from?: string, valid_until?: string, };
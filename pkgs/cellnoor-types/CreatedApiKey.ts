@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A newly created API key. This is the only time `api_key` is returned.
+ */
+export type CreatedApiKey = { api_key: string, prefix: string, created_at: string, };
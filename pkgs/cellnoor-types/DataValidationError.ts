@@ -2,14 +2,17 @@
 import type { CdnaValidationError } from "./CdnaValidationError";
 import type { ChromiumDatasetValidationError } from "./ChromiumDatasetValidationError";
 import type { DatabaseError } from "./DatabaseError";
+import type { DonorValidationError } from "./DonorValidationError";
 import type { InitialDataValidationError } from "./InitialDataValidationError";
 import type { LabValidationError } from "./LabValidationError";
 import type { LibraryValidationError } from "./LibraryValidationError";
 import type { NucleicAcidMeasurementError } from "./NucleicAcidMeasurementError";
 import type { PersonValidationError } from "./PersonValidationError";
 import type { SpecimenValidationError } from "./SpecimenValidationError";
+import type { StorageValidationError } from "./StorageValidationError";
 import type { SuspensionPoolValidationError } from "./SuspensionPoolValidationError";
 import type { SuspensionValidationError } from "./SuspensionValidationError";
 import type { TimestampError } from "./TimestampError";
+import type { WebhookValidationError } from "./WebhookValidationError";
 
-export type DataValidationError = { "type": "insert_initial_data", "info": InitialDataValidationError } | { "type": "create_person", "info": PersonValidationError } | { "type": "create_lab", "info": LabValidationError } | { "type": "create_specimen", "info": SpecimenValidationError } | { "type": "create_suspension", "info": SuspensionValidationError } | { "type": "create_suspension_pool", "info": SuspensionPoolValidationError } | { "type": "create_cdna", "info": CdnaValidationError } | { "type": "create_library", "info": LibraryValidationError } | { "type": "create_nucleic_acid_measurement", "info": NucleicAcidMeasurementError } | { "type": "create_chromium_dataset", "info": ChromiumDatasetValidationError } | { "type": "timestamp", "info": TimestampError } | { "type": "database", "info": DatabaseError };
+export type DataValidationError = { "type": "insert_initial_data", "info": InitialDataValidationError } | { "type": "create_person", "info": PersonValidationError } | { "type": "create_lab", "info": LabValidationError } | { "type": "create_donor", "info": DonorValidationError } | { "type": "create_specimen", "info": SpecimenValidationError } | { "type": "storage", "info": StorageValidationError } | { "type": "create_suspension", "info": SuspensionValidationError } | { "type": "create_suspension_pool", "info": SuspensionPoolValidationError } | { "type": "create_cdna", "info": CdnaValidationError } | { "type": "create_library", "info": LibraryValidationError } | { "type": "create_nucleic_acid_measurement", "info": NucleicAcidMeasurementError } | { "type": "create_chromium_dataset", "info": ChromiumDatasetValidationError } | { "type": "create_webhook_subscription", "info": WebhookValidationError } | { "type": "timestamp", "info": TimestampError } | { "type": "database", "info": DatabaseError };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NonEmptyString } from "./NonEmptyString";
+import type { RangedU16 } from "./RangedU16";
+import type { Sex } from "./Sex";
+import type { Species } from "./Species";
+import type { JsonValue } from "./serde_json/JsonValue";
+
+export type DonorCreation = { born_at?: string, age_in_days?: RangedU16, external_id: NonEmptyString, lab_id: string, species: Species, sex?: Sex, strain?: NonEmptyString, genotype?: NonEmptyString, additional_data?: JsonValue, };
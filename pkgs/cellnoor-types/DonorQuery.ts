@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NoInclude } from "./NoInclude";
+import type { Sex } from "./Sex";
+import type { Species } from "./Species";
+import type { TotalMode } from "./TotalMode";
+
+export type DonorQuery = { filter?: { ids?: Array<string>, external_ids?: Array<string>, labs?: Array<string>, species?: Array<Species>, sexes?: Array<Sex>, strains?: Array<string>, genotypes?: Array<string>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "external_id": { descending?: boolean, } } | { "lab_id": { descending?: boolean, } } | { "species": { descending?: boolean, } } | { "sex": { descending?: boolean, } } | { "born_at": { descending?: boolean, } } | { "age_in_days": { descending?: boolean, } } | { "strain": { descending?: boolean, } } | { "genotype": { descending?: boolean, } }>, cursor?: string, total?: TotalMode, fields?: Array<string>, include?: Array<NoInclude>, };
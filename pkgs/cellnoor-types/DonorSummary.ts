@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Links } from "./Links";
+import type { NonEmptyString } from "./NonEmptyString";
+import type { Sex } from "./Sex";
+import type { Species } from "./Species";
+import type { JsonValue } from "./serde_json/JsonValue";
+
+export type DonorSummary = { id: string, links: Links, born_at?: string, age_in_days?: number, external_id: NonEmptyString, lab_id: string, species: Species, sex?: Sex, strain?: NonEmptyString, genotype?: NonEmptyString, additional_data?: JsonValue, };
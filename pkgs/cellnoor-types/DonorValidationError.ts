@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Species } from "./Species";
+
+export type DonorValidationError = { "type": "species_conflicts_with_specimens", "info": { species: Species, specimen_ids: Array<string>, } };
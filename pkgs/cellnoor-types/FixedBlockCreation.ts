@@ -5,4 +5,4 @@ import type { NonEmptyString } from "./NonEmptyString";
 import type { Species } from "./Species";
 import type { JsonValue } from "./serde_json/JsonValue";
 
-export type FixedBlockCreation = { embedded_in: FixedBlockEmbeddingMatrix, fixative: BlockFixative, readable_id: NonEmptyString, name: NonEmptyString, submitted_by: string, received_at: string, lab_id: string, species: Species, host_species?: Species, returned_by?: string, returned_at?: string, tissue: NonEmptyString, additional_data?: JsonValue, };
+export type FixedBlockCreation = { committee_approval_ids: Array<string>, embedded_in: FixedBlockEmbeddingMatrix, fixative: BlockFixative, readable_id: NonEmptyString, name: NonEmptyString, submitted_by: string, received_at: string, lab_id: string, donor_id?: string, species: Species, host_species?: Species, returned_by?: string, returned_at?: string, tissue: NonEmptyString, additional_data?: JsonValue, };
@@ -4,4 +4,4 @@ import type { Species } from "./Species";
 import type { TissueFixative } from "./TissueFixative";
 import type { JsonValue } from "./serde_json/JsonValue";
 
-export type FixedTissueCreation = { fixative: TissueFixative, readable_id: NonEmptyString, name: NonEmptyString, submitted_by: string, received_at: string, lab_id: string, species: Species, host_species?: Species, returned_by?: string, returned_at?: string, tissue: NonEmptyString, additional_data?: JsonValue, };
+export type FixedTissueCreation = { committee_approval_ids: Array<string>, fixative: TissueFixative, readable_id: NonEmptyString, name: NonEmptyString, submitted_by: string, received_at: string, lab_id: string, donor_id?: string, species: Species, host_species?: Species, returned_by?: string, returned_at?: string, tissue: NonEmptyString, additional_data?: JsonValue, };
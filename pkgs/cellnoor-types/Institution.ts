@@ -2,4 +2,4 @@
 import type { Links } from "./Links";
 import type { NonEmptyString } from "./NonEmptyString";
 
-export type Institution = { links: Links, id: string, name: NonEmptyString, };
+export type Institution = { links: Links, id: string, name: NonEmptyString, requires_committee_approval: boolean, };
@@ -1,3 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NoInclude } from "./NoInclude";
+import type { TotalMode } from "./TotalMode";
 
-export type InstitutionQuery = { filter?: { ids?: Array<string>, names?: Array<string>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "name": { descending?: boolean, } }>, };
+export type InstitutionQuery = { filter?: { ids?: Array<string>, names?: Array<string>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "name": { descending?: boolean, } }>, cursor?: string, total?: TotalMode, fields?: Array<string>, include?: Array<NoInclude>, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A JSON path made up only of member accessors and array subscripts, such as
+ * `$.donor.age` or `$.measurements[*].value`. Keys are stored quoted so that
+ * the path can be safely embedded in a larger JSON path expression.
+ */
+export type JsonPath = string;
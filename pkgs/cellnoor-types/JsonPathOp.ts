@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type JsonPathOp = "exists" | "eq" | "ne" | "gt" | "gte" | "lt" | "lte" | "starts_with";
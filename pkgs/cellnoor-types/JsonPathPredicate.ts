@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonPath } from "./JsonPath";
+import type { JsonPathOp } from "./JsonPathOp";
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * A condition on the JSON found at `path`, such as
+ * `{"path": "$.donor.age", "op": "gt", "value": 10}`. The condition holds if
+ * any item found at `path` satisfies it.
+ *
+ * Because filters are usually sent in a query string, a string `value` that
+ * is itself valid JSON (such as `"10"` or `"true"`) is interpreted as that
+ * JSON. To compare against the string `"10"`, send `"\"10\""`.
+ */
+export type JsonPathPredicate = { path: JsonPath, op: JsonPathOp, value?: JsonValue, };
@@ -1,3 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NoInclude } from "./NoInclude";
+import type { TotalMode } from "./TotalMode";
 
-export type LabQuery = { filter?: { ids?: Array<string>, names?: Array<string>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "name": { descending?: boolean, } } | { "delivery_dir": { descending?: boolean, } } | { "pi_id": { descending?: boolean, } }>, };
+export type LabQuery = { filter?: { ids?: Array<string>, names?: Array<string>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "name": { descending?: boolean, } } | { "delivery_dir": { descending?: boolean, } } | { "pi_id": { descending?: boolean, } }>, cursor?: string, total?: TotalMode, fields?: Array<string>, include?: Array<NoInclude>, };
@@ -1,3 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonPath } from "./JsonPath";
+import type { JsonPathPredicate } from "./JsonPathPredicate";
+import type { NoInclude } from "./NoInclude";
+import type { TotalMode } from "./TotalMode";
 
-export type LibraryQuery = { filter?: { ids?: Array<string>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "readable_id": { descending?: boolean, } } | { "cdna_id": { descending?: boolean, } } | { "single_index_set_name": { descending?: boolean, } } | { "dual_index_set_name": { descending?: boolean, } } | { "number_of_sample_index_pcr_cycles": { descending?: boolean, } } | { "target_reads_per_cell": { descending?: boolean, } } | { "prepared_at": { descending?: boolean, } }>, };
+export type LibraryQuery = { filter?: { ids?: Array<string>, readable_ids?: Array<string>, additional_data_predicates?: Array<JsonPathPredicate>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "readable_id": { descending?: boolean, } } | { "cdna_id": { descending?: boolean, } } | { "single_index_set_name": { descending?: boolean, } } | { "dual_index_set_name": { descending?: boolean, } } | { "number_of_sample_index_pcr_cycles": { descending?: boolean, } } | { "target_reads_per_cell": { descending?: boolean, } } | { "prepared_at": { descending?: boolean, } } | { "additional_data": { path: JsonPath, descending?: boolean, } }>, cursor?: string, total?: TotalMode, fields?: Array<string>, include?: Array<NoInclude>, };
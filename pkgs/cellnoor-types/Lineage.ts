@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LineageEdge } from "./LineageEdge";
+import type { LineageNode } from "./LineageNode";
+
+/**
+ * The provenance graph of a specimen or Chromium dataset. Nodes and edges are
+ * kept sorted so that repeated requests produce identical output.
+ */
+export type Lineage = { nodes: Array<LineageNode>, edges: Array<LineageEdge>, };
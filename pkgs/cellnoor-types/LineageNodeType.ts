@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type LineageNodeType = "specimen" | "suspension" | "suspension_pool" | "chip_loading" | "gem_pool" | "cdna" | "library" | "sequencing_run" | "chromium_dataset";
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The `include` of a resource that has no related objects to embed, so that
+ * any value is rejected
+ */
+export type NoInclude = never;
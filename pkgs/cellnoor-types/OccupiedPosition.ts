@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type OccupiedPosition = { box_id: string, position: number, specimen_id?: string, suspension_id?: string, cdna_id?: string, library_id?: string, checked_in_by: string, checked_in_at: string, };
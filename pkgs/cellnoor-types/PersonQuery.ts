@@ -1,3 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NoInclude } from "./NoInclude";
+import type { TotalMode } from "./TotalMode";
 
-export type PersonQuery = { filter?: { ids?: Array<string>, names?: Array<string>, emails?: Array<string>, institution_ids?: Array<string>, orcids?: Array<string>, microsoft_entra_oids?: Array<string>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "name": { descending?: boolean, } } | { "email": { descending?: boolean, } } | { "email_verified": { descending?: boolean, } } | { "institution_id": { descending?: boolean, } } | { "orcid": { descending?: boolean, } } | { "microsoft_entra_oid": { descending?: boolean, } }>, };
+export type PersonQuery = { filter?: { ids?: Array<string>, names?: Array<string>, emails?: Array<string>, institution_ids?: Array<string>, orcids?: Array<string>, microsoft_entra_oids?: Array<string>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "name": { descending?: boolean, } } | { "email": { descending?: boolean, } } | { "email_verified": { descending?: boolean, } } | { "institution_id": { descending?: boolean, } } | { "orcid": { descending?: boolean, } } | { "microsoft_entra_oid": { descending?: boolean, } }>, cursor?: string, total?: TotalMode, fields?: Array<string>, include?: Array<NoInclude>, };
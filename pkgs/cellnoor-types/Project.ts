@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LabSummary } from "./LabSummary";
+import type { Links } from "./Links";
+import type { NonEmptyString } from "./NonEmptyString";
+
+export type Project = { lab: LabSummary, id: string, links: Links, started_at: string, ended_at?: string, grant_ids: Array<string | null>, name: NonEmptyString, lab_id: string, description?: NonEmptyString, };
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NoInclude } from "./NoInclude";
+import type { TotalMode } from "./TotalMode";
+
+export type ProjectQuery = { filter?: { ids?: Array<string>, names?: Array<string>, labs?: Array<string>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "name": { descending?: boolean, } } | { "lab_id": { descending?: boolean, } } | { "started_at": { descending?: boolean, } } | { "ended_at": { descending?: boolean, } }>, cursor?: string, total?: TotalMode, fields?: Array<string>, include?: Array<NoInclude>, };
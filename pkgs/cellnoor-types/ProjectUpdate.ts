@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NonEmptyString } from "./NonEmptyString";
+
+export type ProjectUpdate = { name?: NonEmptyString, description?: NonEmptyString, started_at?: string, ended_at?: string, grant_ids?: Array<NonEmptyString>, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SearchEntityType = "institution" | "person" | "lab" | "project" | "donor" | "specimen" | "suspension" | "suspension_pool" | "chromium_run" | "cdna" | "library" | "sequencing_run" | "chromium_dataset";
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Links } from "./Links";
+
+export type SearchHit = { id: string, label: string, links: Links, rank: number, };
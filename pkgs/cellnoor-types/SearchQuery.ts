@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NonEmptyString } from "./NonEmptyString";
+
+export type SearchQuery = { q: NonEmptyString, 
+/**
+ * The maximum number of hits across all entity types
+ */
+limit: bigint, };
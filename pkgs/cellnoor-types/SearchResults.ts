@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SearchEntityType } from "./SearchEntityType";
+import type { SearchHit } from "./SearchHit";
+
+/**
+ * Hits grouped by the type of the entity they refer to, each group ordered
+ * from best to worst match
+ */
+export type SearchResults = { [key in SearchEntityType]?: Array<SearchHit> };
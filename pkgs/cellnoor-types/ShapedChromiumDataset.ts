@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChromiumDataset } from "./ChromiumDataset";
+import type { ChromiumDatasetEmbeds } from "./ChromiumDatasetEmbeds";
+
+export type ShapedChromiumDataset = ChromiumDataset | (Omit<Partial<ChromiumDataset>, keyof ChromiumDatasetEmbeds> & { id: string } & ChromiumDatasetEmbeds);
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChromiumDatasetEmbeds } from "./ChromiumDatasetEmbeds";
+import type { ChromiumDatasetSummary } from "./ChromiumDatasetSummary";
+
+export type ShapedChromiumDatasetSummary = ChromiumDatasetSummary | (Omit<Partial<ChromiumDatasetSummary>, keyof ChromiumDatasetEmbeds> & { id: string } & ChromiumDatasetEmbeds);
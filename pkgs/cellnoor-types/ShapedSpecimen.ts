@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Specimen } from "./Specimen";
+import type { SpecimenEmbeds } from "./SpecimenEmbeds";
+
+export type ShapedSpecimen = Specimen | (Omit<Partial<Specimen>, keyof SpecimenEmbeds> & { id: string } & SpecimenEmbeds);
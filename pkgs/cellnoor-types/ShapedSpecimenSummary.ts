@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SpecimenEmbeds } from "./SpecimenEmbeds";
+import type { SpecimenSummary } from "./SpecimenSummary";
+
+export type ShapedSpecimenSummary = SpecimenSummary | (Omit<Partial<SpecimenSummary>, keyof SpecimenEmbeds> & { id: string } & SpecimenEmbeds);
@@ -0,0 +1,13 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DonorSummary } from "./DonorSummary";
+import type { LabSummary } from "./LabSummary";
+import type { PersonSummary } from "./PersonSummary";
+
+/**
+ * The related objects embedded in a specimen by [`SpecimenInclude`]
+ */
+export type SpecimenEmbeds = { lab?: LabSummary, submitted_by?: PersonSummary, 
+/**
+ * `null` if the specimen has no donor
+ */
+donor?: DonorSummary | null, };
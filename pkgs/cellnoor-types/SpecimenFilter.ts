@@ -1,8 +1,10 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { EmbeddingMatrix } from "./EmbeddingMatrix";
 import type { Fixative } from "./Fixative";
+import type { JsonPathPredicate } from "./JsonPathPredicate";
 import type { Species } from "./Species";
+import type { SpecimenStage } from "./SpecimenStage";
 import type { SpecimenType } from "./SpecimenType";
 import type { JsonValue } from "./serde_json/JsonValue";
 
-export type SpecimenFilter = { ids?: Array<string>, names?: Array<string>, submitted_by?: Array<string>, labs?: Array<string>, received_before?: string, received_after?: string, species?: Array<Species>, host_species?: Array<Species>, types?: Array<SpecimenType>, embedded_in?: Array<EmbeddingMatrix>, fixatives?: Array<Fixative>, frozen?: boolean, cryopreserved?: boolean, tissues?: Array<string>, returned_before?: string, returned_after?: string, returned_by?: Array<string>, additional_data?: JsonValue, };
+export type SpecimenFilter = { ids?: Array<string>, names?: Array<string>, submitted_by?: Array<string>, labs?: Array<string>, donor_ids?: Array<string>, project_ids?: Array<string>, received_before?: string, received_after?: string, species?: Array<Species>, host_species?: Array<Species>, types?: Array<SpecimenType>, embedded_in?: Array<EmbeddingMatrix>, fixatives?: Array<Fixative>, frozen?: boolean, cryopreserved?: boolean, tissues?: Array<string>, returned_before?: string, returned_after?: string, returned_by?: Array<string>, additional_data?: JsonValue, additional_data_predicates?: Array<JsonPathPredicate>, stage?: Array<SpecimenStage>, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SpecimenStage } from "./SpecimenStage";
+import type { StageDuration } from "./StageDuration";
+
+export type SpecimenProgress = { 
+/**
+ * The time spent in each stage that was reached, up to `now` for the
+ * current stage. Delivery is the last stage, so it has no duration.
+ */
+durations: Array<StageDuration>, specimen_id: string, stage: SpecimenStage, received_at: string, suspension_prepared_at?: string, chip_loaded_at?: string, cdna_prepared_at?: string, library_prepared_at?: string, sequenced_at?: string, delivered_at?: string, stage_entered_at: string, };
@@ -1,8 +1,13 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { EmbeddingMatrix } from "./EmbeddingMatrix";
 import type { Fixative } from "./Fixative";
+import type { JsonPath } from "./JsonPath";
+import type { JsonPathPredicate } from "./JsonPathPredicate";
 import type { Species } from "./Species";
+import type { SpecimenInclude } from "./SpecimenInclude";
+import type { SpecimenStage } from "./SpecimenStage";
 import type { SpecimenType } from "./SpecimenType";
+import type { TotalMode } from "./TotalMode";
 import type { JsonValue } from "./serde_json/JsonValue";
 
-export type SpecimenQuery = { filter?: { ids?: Array<string>, names?: Array<string>, submitted_by?: Array<string>, labs?: Array<string>, received_before?: string, received_after?: string, species?: Array<Species>, host_species?: Array<Species>, types?: Array<SpecimenType>, embedded_in?: Array<EmbeddingMatrix>, fixatives?: Array<Fixative>, frozen?: boolean, cryopreserved?: boolean, tissues?: Array<string>, returned_before?: string, returned_after?: string, returned_by?: Array<string>, additional_data?: JsonValue, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "readable_id": { descending?: boolean, } } | { "name": { descending?: boolean, } } | { "submitted_by": { descending?: boolean, } } | { "lab_id": { descending?: boolean, } } | { "received_at": { descending?: boolean, } } | { "species": { descending?: boolean, } } | { "host_species": { descending?: boolean, } } | { "returned_at": { descending?: boolean, } } | { "returned_by": { descending?: boolean, } } | { "type_": { descending?: boolean, } } | { "embedded_in": { descending?: boolean, } } | { "fixative": { descending?: boolean, } } | { "frozen": { descending?: boolean, } } | { "cryopreserved": { descending?: boolean, } } | { "tissue": { descending?: boolean, } }>, };
+export type SpecimenQuery = { filter?: { ids?: Array<string>, names?: Array<string>, submitted_by?: Array<string>, labs?: Array<string>, donor_ids?: Array<string>, project_ids?: Array<string>, received_before?: string, received_after?: string, species?: Array<Species>, host_species?: Array<Species>, types?: Array<SpecimenType>, embedded_in?: Array<EmbeddingMatrix>, fixatives?: Array<Fixative>, frozen?: boolean, cryopreserved?: boolean, tissues?: Array<string>, returned_before?: string, returned_after?: string, returned_by?: Array<string>, additional_data?: JsonValue, additional_data_predicates?: Array<JsonPathPredicate>, stage?: Array<SpecimenStage>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "readable_id": { descending?: boolean, } } | { "name": { descending?: boolean, } } | { "submitted_by": { descending?: boolean, } } | { "lab_id": { descending?: boolean, } } | { "donor_id": { descending?: boolean, } } | { "received_at": { descending?: boolean, } } | { "species": { descending?: boolean, } } | { "host_species": { descending?: boolean, } } | { "returned_at": { descending?: boolean, } } | { "returned_by": { descending?: boolean, } } | { "type_": { descending?: boolean, } } | { "embedded_in": { descending?: boolean, } } | { "fixative": { descending?: boolean, } } | { "frozen": { descending?: boolean, } } | { "cryopreserved": { descending?: boolean, } } | { "tissue": { descending?: boolean, } } | { "additional_data": { path: JsonPath, descending?: boolean, } }>, cursor?: string, total?: TotalMode, fields?: Array<string>, include?: Array<SpecimenInclude>, };
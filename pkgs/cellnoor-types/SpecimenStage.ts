@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The stages of the pipeline, in order
+ */
+export type SpecimenStage = "received" | "suspension_prepared" | "chip_loaded" | "cdna_prepared" | "library_prepared" | "sequenced" | "delivered";
@@ -7,4 +7,4 @@ import type { Species } from "./Species";
 import type { SpecimenType } from "./SpecimenType";
 import type { JsonValue } from "./serde_json/JsonValue";
 
-export type SpecimenSummary = { id: string, links: Links, readable_id: NonEmptyString, name: NonEmptyString, submitted_by: string, received_at: string, lab_id: string, species: Species, host_species?: Species, returned_by?: string, returned_at?: string, tissue: NonEmptyString, additional_data?: JsonValue, type_: SpecimenType, embedded_in?: EmbeddingMatrix, fixative?: Fixative, frozen: boolean, cryopreserved: boolean, };
+export type SpecimenSummary = { id: string, links: Links, readable_id: NonEmptyString, name: NonEmptyString, submitted_by: string, received_at: string, lab_id: string, donor_id?: string, species: Species, host_species?: Species, returned_by?: string, returned_at?: string, tissue: NonEmptyString, additional_data?: JsonValue, type_: SpecimenType, embedded_in?: EmbeddingMatrix, fixative?: Fixative, frozen: boolean, cryopreserved: boolean, };
@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CommitteeType } from "./CommitteeType";
 import type { Species } from "./Species";
 
-export type SpecimenValidationError = { "type": "same_donor_and_host_species", "info": { species: Species, } } | { "type": "returned_before_received", "info": { received_at: string, returned_at: string, } };
+export type SpecimenValidationError = { "type": "same_donor_and_host_species", "info": { species: Species, } } | { "type": "donor_species_mismatch", "info": { donor_id: string, donor_species: Species, specimen_species: Species, } } | { "type": "donor_lab_mismatch", "info": { donor_id: string, donor_lab_id: string, specimen_lab_id: string, } } | { "type": "missing_committee_approval", "info": { species: Species, committee_type: CommitteeType, institution_id: string, received_at: string, } } | { "type": "returned_before_received", "info": { received_at: string, returned_at: string, } };
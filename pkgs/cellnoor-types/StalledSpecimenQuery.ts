@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SpecimenFilter } from "./SpecimenFilter";
+
+/**
+ * Specimens that have spent longer than `sla_days` in their current stage,
+ * not counting delivered specimens. Use `filter.stage` to apply a different
+ * SLA to each stage.
+ */
+export type StalledSpecimenQuery = { sla_days: number, filter?: SpecimenFilter, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type StatsPoint = { bucket?: string, count: bigint, 
+/**
+ * Only set for statistics that are averages, in which case `count` is the
+ * number of values averaged
+ */
+mean?: number, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StatsPoint } from "./StatsPoint";
+
+/**
+ * The points of one group, ordered by bucket
+ */
+export type StatsSeries = { group?: string, points: Array<StatsPoint>, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StoredItem } from "./StoredItem";
+
+export type StorageCheckOut = { item: StoredItem, performed_by: string, performed_at: string, };
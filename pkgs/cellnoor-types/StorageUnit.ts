@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Links } from "./Links";
+import type { NonEmptyString } from "./NonEmptyString";
+import type { StorageUnitKind } from "./StorageUnitKind";
+
+export type StorageUnit = { id: string, links: Links, capacity?: number, name: NonEmptyString, kind: StorageUnitKind, parent_id?: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NonEmptyString } from "./NonEmptyString";
+import type { RangedU16 } from "./RangedU16";
+import type { StorageUnitKind } from "./StorageUnitKind";
+
+export type StorageUnitCreation = { capacity?: RangedU16, name: NonEmptyString, kind: StorageUnitKind, parent_id?: string, };
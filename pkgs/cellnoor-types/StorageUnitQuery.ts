@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NoInclude } from "./NoInclude";
+import type { StorageUnitKind } from "./StorageUnitKind";
+import type { TotalMode } from "./TotalMode";
+
+export type StorageUnitQuery = { filter?: { ids?: Array<string>, names?: Array<string>, kinds?: Array<StorageUnitKind>, parent_ids?: Array<string>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "name": { descending?: boolean, } } | { "kind": { descending?: boolean, } } | { "parent_id": { descending?: boolean, } }>, cursor?: string, total?: TotalMode, fields?: Array<string>, include?: Array<NoInclude>, };
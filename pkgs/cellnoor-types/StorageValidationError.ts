@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { StorageUnitKind } from "./StorageUnitKind";
+import type { StoredItem } from "./StoredItem";
+
+export type StorageValidationError = { "type": "invalid_parent", "info": { kind: StorageUnitKind, parent_kind: StorageUnitKind | null, } } | { "type": "not_a_box", "info": { unit_id: string, } } | { "type": "position_out_of_range", "info": { box_id: string, position: number, capacity: number, } } | { "type": "position_occupied", "info": { box_id: string, position: number, occupied_by: StoredItem, } } | { "type": "not_in_box", "info": { box_id: string, item: StoredItem, } } | { "type": "earlier_than_latest_event", "info": { item: StoredItem, latest_at: string, performed_at: string, } };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type StoredItem = { "type": "specimen", "id": string } | { "type": "suspension", "id": string } | { "type": "cdna", "id": string } | { "type": "library", "id": string };
@@ -1,3 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonPath } from "./JsonPath";
+import type { JsonPathPredicate } from "./JsonPathPredicate";
+import type { NoInclude } from "./NoInclude";
+import type { TotalMode } from "./TotalMode";
 
-export type SuspensionPoolQuery = { filter?: { ids?: Array<string>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "readable_id": { descending?: boolean, } } | { "name": { descending?: boolean, } } | { "pooled_at": { descending?: boolean, } }>, };
+export type SuspensionPoolQuery = { filter?: { ids?: Array<string>, additional_data_predicates?: Array<JsonPathPredicate>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "readable_id": { descending?: boolean, } } | { "name": { descending?: boolean, } } | { "pooled_at": { descending?: boolean, } } | { "additional_data": { path: JsonPath, descending?: boolean, } }>, cursor?: string, total?: TotalMode, fields?: Array<string>, include?: Array<NoInclude>, };
@@ -1,3 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonPath } from "./JsonPath";
+import type { JsonPathPredicate } from "./JsonPathPredicate";
+import type { NoInclude } from "./NoInclude";
+import type { TotalMode } from "./TotalMode";
 
-export type SuspensionQuery = { filter?: { ids?: Array<string>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "readable_id": { descending?: boolean, } } | { "parent_specimen_id": { descending?: boolean, } } | { "created_at": { descending?: boolean, } } | { "lysis_duration_minutes": { descending?: boolean, } } | { "target_cell_recovery": { descending?: boolean, } }>, };
+export type SuspensionQuery = { filter?: { ids?: Array<string>, additional_data_predicates?: Array<JsonPathPredicate>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "readable_id": { descending?: boolean, } } | { "parent_specimen_id": { descending?: boolean, } } | { "created_at": { descending?: boolean, } } | { "lysis_duration_minutes": { descending?: boolean, } } | { "target_cell_recovery": { descending?: boolean, } } | { "additional_data": { path: JsonPath, descending?: boolean, } }>, cursor?: string, total?: TotalMode, fields?: Array<string>, include?: Array<NoInclude>, };
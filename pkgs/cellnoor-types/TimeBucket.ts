@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The unit that timestamps are truncated to, in UTC
+ */
+export type TimeBucket = "day" | "week" | "month" | "quarter" | "year";
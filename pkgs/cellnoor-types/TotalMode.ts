@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How the `total` of a [`Page`] is computed
+ */
+export type TotalMode = "exact" | "estimated" | "skip";
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebhookDeliveryStatus } from "./WebhookDeliveryStatus";
+import type { WebhookEventType } from "./WebhookEventType";
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * An attempt, or series of attempts, to send an event to a subscription.
+ * `response_status` and `error` describe the most recent attempt.
+ */
+export type WebhookDelivery = { id: string, subscription_id: string, event_type: WebhookEventType, payload: JsonValue, created_at: string, status: WebhookDeliveryStatus, n_attempts: number, next_attempt_at: string, last_attempted_at?: string, response_status?: number, error?: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NoInclude } from "./NoInclude";
+import type { TotalMode } from "./TotalMode";
+import type { WebhookDeliveryStatus } from "./WebhookDeliveryStatus";
+
+export type WebhookDeliveryQuery = { filter?: { statuses?: Array<WebhookDeliveryStatus>, }, limit?: bigint, offset?: bigint, order_by?: Array<{ "id": { descending?: boolean, } } | { "created_at": { descending?: boolean, } } | { "n_attempts": { descending?: boolean, } } | { "next_attempt_at": { descending?: boolean, } }>, cursor?: string, total?: TotalMode, fields?: Array<string>, include?: Array<NoInclude>, };
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type WebhookEventType = "chromium_dataset_delivered" | "chromium_run_failed" | "specimen_returned";
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebhookEventType } from "./WebhookEventType";
+
+export type WebhookSubscription = { id: string, lab_id: string, url: string, event_types: Array<WebhookEventType>, created_at: string, };
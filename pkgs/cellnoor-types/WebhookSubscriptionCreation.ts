@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NonEmptyString } from "./NonEmptyString";
+import type { NonEmptyVec } from "./NonEmptyVec";
+import type { WebhookEventType } from "./WebhookEventType";
+
+/**
+ * Where to send a lab's lifecycle events. `url` must be an HTTPS URL on a
+ * public host. Each delivery is signed with `secret`, which can't be read back
+ * once the subscription is created.
+ */
+export type WebhookSubscriptionCreation = { lab_id: string, url: NonEmptyString, secret: NonEmptyString, event_types: NonEmptyVec<WebhookEventType>, };
@@ -0,0 +1,16 @@
+#!/usr/bin/env bash
+
+set -euo pipefail
+
+# Download the index sets listed in initial_data.sample.json into cellnoor-api/index-sets/<version>, where they're
+# compiled into the cellnoor-api binary. Initial data can then refer to them as bundle:<version>/<filename> in
+# air-gapped deployments
+version="$1"
+bundle_dir="cellnoor-api/index-sets/$version"
+
+mkdir --parents "$bundle_dir"
+
+jq --raw-output '.single_index_set_urls[], .dual_index_set_urls[]' initial_data.sample.json | while read -r url; do
+    filename=$(basename "$url")
+    curl --fail --silent --show-error --location "$url" --output "$bundle_dir/$filename"
+done